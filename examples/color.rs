@@ -10,8 +10,9 @@ fn main() -> Result<()> {
         title: String::from("Color - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -65,6 +66,7 @@ impl Game for Colors {
                 },
                 position: Point::new(0.0, 0.0),
                 size: (500.0, 500.0),
+                ..Quad::default()
             },
             target,
         );
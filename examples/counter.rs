@@ -1,5 +1,5 @@
 use coffee::graphics::{
-    Color, Frame, HorizontalAlignment, VerticalAlignment, Window,
+    Color, Frame, HorizontalAlignment, UpdateMode, VerticalAlignment, Window,
     WindowSettings,
 };
 use coffee::load::Task;
@@ -14,8 +14,10 @@ pub fn main() -> Result<()> {
         title: String::from("Counter - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        updates: UpdateMode::OnEvent,
+        ..WindowSettings::default()
     })
 }
 
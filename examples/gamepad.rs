@@ -12,8 +12,9 @@ fn main() -> Result<()> {
         title: String::from("Gamepad - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -17,8 +17,9 @@ fn main() -> Result<()> {
         title: String::from("Input - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -64,7 +65,9 @@ impl Input for CustomInput {
                 keyboard::Event::TextEntered { character } => {
                     self.text_buffer.push(character);
                 }
-                keyboard::Event::Input { key_code, state } => match state {
+                keyboard::Event::Input {
+                    key_code, state, ..
+                } => match state {
                     input::ButtonState::Pressed => {
                         self.keys_pressed.insert(key_code);
                     }
@@ -155,6 +158,7 @@ impl Game for InputExample {
                 },
                 position: self.cursor_position - Vector::new(3.0, 3.0),
                 scale: (6.0, 6.0),
+                ..Sprite::default()
             },
             &mut frame.as_target(),
         );
@@ -17,8 +17,9 @@ fn main() -> Result<()> {
         title: String::from("Mesh - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
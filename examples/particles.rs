@@ -19,8 +19,9 @@ fn main() -> Result<()> {
         title: String::from("Particles - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
@@ -150,6 +151,7 @@ impl Game for Particles {
                 },
                 position: particle.position + velocity * delta_factor,
                 scale: (1.0, 1.0),
+                ..Sprite::default()
             }
         });
 
@@ -13,8 +13,9 @@ pub fn main() -> Result<()> {
         title: String::from("Progress - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
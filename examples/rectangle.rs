@@ -9,8 +9,9 @@ fn main() -> coffee::Result<()> {
         title: String::from("Rectangle - Coffee"),
         size: (1280, 1024),
         resizable: true,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 
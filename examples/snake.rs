@@ -17,7 +17,8 @@ fn main() {
         size: (900, 600),
         resizable: false,
         maximized: false,
-        fullscreen: false,
+        fullscreen: None,
+        ..WindowSettings::default()
     })
     .expect("An error occured while starting the game");
 }
@@ -306,7 +307,9 @@ impl Input for CustomInput {
     fn update(&mut self, event: input::Event) {
         match event {
             input::Event::Keyboard(keyboard_event) => match keyboard_event {
-                keyboard::Event::Input { key_code, state } => match state {
+                keyboard::Event::Input {
+                    key_code, state, ..
+                } => match state {
                     input::ButtonState::Pressed => {
                         self.keys_pressed.push(key_code);
                     }
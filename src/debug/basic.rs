@@ -34,6 +34,7 @@ pub struct Debug {
     debug_start: time::Instant,
     debug_durations: TimeBuffer,
     text: Vec<(String, String)>,
+    shader_error: Option<String>,
     draw_rate: u16,
     frames_until_refresh: u16,
 }
@@ -61,6 +62,7 @@ impl Debug {
             debug_start: now,
             debug_durations: TimeBuffer::new(200),
             text: Vec::new(),
+            shader_error: None,
             draw_rate: 10,
             frames_until_refresh: 0,
         }
@@ -183,6 +185,46 @@ impl Debug {
         self.debug_durations.average()
     }
 
+    pub(crate) fn shaders_reloaded(&mut self, result: Result<(), String>) {
+        self.shader_error = result.err();
+    }
+
+    // Shader errors are drawn even when the debug view is disabled, so they
+    // are not missed while editing shaders
+    pub(crate) fn draw_errors(&mut self, frame: &mut graphics::Frame<'_>) {
+        let error = match &self.shader_error {
+            Some(error) => error,
+            None => return,
+        };
+
+        let bounds = (
+            frame.width() - Self::MARGIN * 2.0,
+            frame.height() - Self::MARGIN * 2.0,
+        );
+
+        self.font.add(graphics::Text {
+            content: "Shader error:",
+            position: graphics::Point::new(Self::MARGIN, Self::MARGIN),
+            size: 20.0,
+            color: graphics::Color::from_rgb(230, 80, 80),
+            ..graphics::Text::default()
+        });
+
+        self.font.add(graphics::Text {
+            content: error,
+            position: graphics::Point::new(
+                Self::MARGIN,
+                Self::MARGIN + Self::ROW_HEIGHT,
+            ),
+            bounds,
+            size: 16.0,
+            color: graphics::Color::WHITE,
+            ..graphics::Text::default()
+        });
+
+        self.font.draw(&mut frame.as_target());
+    }
+
     pub(crate) fn is_enabled(&self) -> bool {
         self.enabled
     }
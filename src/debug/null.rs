@@ -28,6 +28,10 @@ impl Debug {
     #[allow(dead_code)]
     pub(crate) fn toggle(&mut self) {}
 
+    pub(crate) fn shaders_reloaded(&mut self, _result: Result<(), String>) {}
+
+    pub(crate) fn draw_errors(&mut self, _frame: &mut graphics::Frame<'_>) {}
+
     pub(crate) fn is_enabled(&self) -> bool {
         false
     }
@@ -1,6 +1,14 @@
+mod control_flow;
+mod frame_timing;
 mod r#loop;
+mod loop_settings;
+mod systems;
 
+pub use control_flow::ControlFlow;
+pub use frame_timing::FrameTiming;
+pub use loop_settings::LoopSettings;
 pub(crate) use r#loop::Loop;
+pub use systems::Systems;
 
 use crate::graphics::{CursorIcon, Frame, Window, WindowSettings};
 use crate::input::{keyboard, Input};
@@ -59,6 +67,15 @@ pub trait Game {
     /// [`debug`]: #method.debug
     const DEBUG_KEY: Option<keyboard::KeyCode> = Some(keyboard::KeyCode::F12);
 
+    /// Defines the [`LoopSettings`] of the game, which limit the amount of
+    /// updates per frame when the game cannot keep up.
+    ///
+    /// By default, at most `5` updates run per frame and the remaining ticks
+    /// are skipped.
+    ///
+    /// [`LoopSettings`]: struct.LoopSettings.html
+    const LOOP_SETTINGS: LoopSettings = LoopSettings::DEFAULT;
+
     /// Loads the [`Game`].
     ///
     /// Use the [`load`] module to load your assets here.
@@ -93,8 +110,22 @@ pub trait Game {
     /// experience independently of the [`TICKS_PER_SECOND`] setting.
     ///
     /// You can access the [`Window`]. For instance, you may want to toggle
-    /// fullscreen mode based on some input, or maybe access the [`Gpu`]
-    /// to prepare some assets before rendering.
+    /// fullscreen mode based on some input, warp or grab the cursor, use the
+    /// clipboard, or maybe access the [`Gpu`] to prepare some assets before
+    /// rendering.
+    ///
+    /// Every [`Window`] operation is allowed here, as nothing is being drawn
+    /// yet. Keep in mind that:
+    ///
+    ///   * Changes that resize the [`Window`], like toggling fullscreen mode,
+    ///     are applied by the platform asynchronously. [`Window::width`] and
+    ///     [`Window::height`] keep their old values until the resize event
+    ///     arrives, usually in the next frame.
+    ///   * The cursor icon is always the one returned by [`cursor_icon`],
+    ///     which is queried after [`draw`]. Change your state here and return
+    ///     the new icon there.
+    ///   * Warping the cursor produces a new [`mouse::Event::CursorMoved`],
+    ///     which is processed in the next frame.
     ///
     /// By default, it does nothing.
     ///
@@ -104,6 +135,11 @@ pub trait Game {
     /// [`TICKS_PER_SECOND`]: #associatedconstant.TICKS_PER_SECOND
     /// [`Window`]: graphics/struct.Window.html
     /// [`Gpu`]: graphics/struct.Gpu.html
+    /// [`Window::width`]: graphics/struct.Window.html#method.width
+    /// [`Window::height`]: graphics/struct.Window.html#method.height
+    /// [`cursor_icon`]: #method.cursor_icon
+    /// [`draw`]: #tymethod.draw
+    /// [`mouse::Event::CursorMoved`]: input/mouse/enum.Event.html#variant.CursorMoved
     fn interact(&mut self, _input: &mut Self::Input, _window: &mut Window) {}
 
     /// Updates the [`Game`].
@@ -124,6 +160,32 @@ pub trait Game {
     /// [`Window`]: graphics/struct.Window.html
     fn update(&mut self, _window: &Window) {}
 
+    /// Updates the [`Game`] with access to the current [`Input`] and the
+    /// [`Timer`] of the game loop.
+    ///
+    /// It is called instead of [`update`], with the same frequency. Implement
+    /// it when your update logic needs the state of the input devices or the
+    /// timing of the loop, like when running [`Systems`].
+    ///
+    /// The [`Input`] is cleared after the updates of a frame run, so every
+    /// update of the frame sees the events of the last interaction.
+    ///
+    /// By default, it calls [`update`].
+    ///
+    /// [`Game`]: trait.Game.html
+    /// [`Input`]: #associatedtype.Input
+    /// [`Timer`]: struct.Timer.html
+    /// [`update`]: #method.update
+    /// [`Systems`]: struct.Systems.html
+    fn update_with(
+        &mut self,
+        _input: &Self::Input,
+        _timer: &Timer,
+        window: &Window,
+    ) {
+        self.update(window);
+    }
+
     /// Defines the cursor icon of the window.
     ///
     /// By default, it returns platform-dependent default cursor.
@@ -154,6 +216,27 @@ pub trait Game {
         debug.draw(frame);
     }
 
+    /// Runs at the beginning of every frame, before any input is processed.
+    ///
+    /// Together with [`on_frame_end`], it allows you to measure frames and
+    /// build your own profiling tools.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`on_frame_end`]: #method.on_frame_end
+    fn on_frame_start(&mut self) {}
+
+    /// Runs at the end of every frame, once it has been submitted for
+    /// presentation.
+    ///
+    /// It receives the [`FrameTiming`] of the frame, which you can use to
+    /// implement a profiler or to adapt the quality of your graphics.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`FrameTiming`]: struct.FrameTiming.html
+    fn on_frame_end(&mut self, _timing: &FrameTiming) {}
+
     /// Handles a close request from the operating system to the game window.
     ///
     /// This function should return true to allow the game loop to end,
@@ -164,6 +247,42 @@ pub trait Game {
         true
     }
 
+    /// Returns whether the game is paused or not.
+    ///
+    /// While the game is paused, [`update`] is not called, but the game keeps
+    /// handling input and drawing frames. This is useful to show a pause menu.
+    ///
+    /// The time spent paused is discarded, so the game does not fast-forward
+    /// when it is resumed.
+    ///
+    /// By default, it always returns false.
+    ///
+    /// [`update`]: #method.update
+    fn is_paused(&self) -> bool {
+        false
+    }
+
+    /// Returns the [`ControlFlow`] of the game loop, which defines how it
+    /// waits for events once the current frame is drawn.
+    ///
+    /// It is called once per frame, after [`update`]. Return
+    /// [`ControlFlow::Wait`] or [`ControlFlow::WaitUntil`] to stop drawing
+    /// frames while nothing happens. Keep in mind that [`update`] only runs
+    /// when a frame is drawn, and that ticks that do not fit in a frame are
+    /// skipped after waiting.
+    ///
+    /// By default, it returns [`ControlFlow::Poll`], which draws frames
+    /// continuously.
+    ///
+    /// [`ControlFlow`]: enum.ControlFlow.html
+    /// [`update`]: #method.update
+    /// [`ControlFlow::Wait`]: enum.ControlFlow.html#variant.Wait
+    /// [`ControlFlow::WaitUntil`]: enum.ControlFlow.html#variant.WaitUntil
+    /// [`ControlFlow::Poll`]: enum.ControlFlow.html#variant.Poll
+    fn control_flow(&self) -> ControlFlow {
+        ControlFlow::Poll
+    }
+
     /// Returns whether the game is finished or not.
     ///
     /// If this function returns true, the game will be closed gracefully.
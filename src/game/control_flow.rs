@@ -0,0 +1,45 @@
+use crate::graphics::window::winit;
+
+use std::time::Instant;
+
+/// How the game loop waits for events after a frame.
+///
+/// Games usually want to draw frames as fast as possible, but tools and
+/// applications that only change on user input can save a lot of CPU and GPU
+/// time by waiting for events instead.
+///
+/// Return it in [`Game::control_flow`].
+///
+/// [`Game::control_flow`]: trait.Game.html#method.control_flow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlFlow {
+    /// Start the next frame right away, even if no events are available.
+    ///
+    /// This is the default.
+    Poll,
+
+    /// Wait until new events are available before starting the next frame.
+    Wait,
+
+    /// Wait until new events are available or the given instant is reached
+    /// before starting the next frame, whichever happens first.
+    WaitUntil(Instant),
+}
+
+impl ControlFlow {
+    pub(crate) fn into_winit(self) -> winit::event_loop::ControlFlow {
+        match self {
+            ControlFlow::Poll => winit::event_loop::ControlFlow::Poll,
+            ControlFlow::Wait => winit::event_loop::ControlFlow::Wait,
+            ControlFlow::WaitUntil(instant) => {
+                winit::event_loop::ControlFlow::WaitUntil(instant)
+            }
+        }
+    }
+}
+
+impl Default for ControlFlow {
+    fn default() -> ControlFlow {
+        ControlFlow::Poll
+    }
+}
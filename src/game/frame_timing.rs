@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+/// The timing information of a frame.
+///
+/// It is provided to [`Game::on_frame_end`] once a frame has been presented.
+/// You can use it to build your own profiler or to adapt the quality of your
+/// graphics to the performance of the machine.
+///
+/// [`Game::on_frame_end`]: trait.Game.html#method.on_frame_end
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameTiming {
+    /// The time the CPU spent on the frame, from [`Game::on_frame_start`]
+    /// until the frame was submitted for presentation.
+    ///
+    /// [`Game::on_frame_start`]: trait.Game.html#method.on_frame_start
+    pub cpu: Duration,
+
+    /// The time elapsed since the end of the previous frame.
+    pub total: Duration,
+
+    /// The time the GPU spent drawing, measured with timestamp queries.
+    ///
+    /// Results are read back without stalling the GPU, so this is the time
+    /// of the latest frame whose results are available, usually a couple of
+    /// frames behind.
+    ///
+    /// It is `None` until the first results are available, and always when
+    /// the graphics backend does not support timestamp queries. Only the
+    /// OpenGL backend supports them, with OpenGL 3.3 or
+    /// `GL_ARB_timer_query`.
+    pub gpu: Option<Duration>,
+}
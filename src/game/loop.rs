@@ -1,10 +1,11 @@
 use crate::debug::Debug;
 use crate::graphics::window::winit;
-use crate::graphics::{Window, WindowSettings};
-use crate::input::{self, gamepad, keyboard, mouse, window, Input};
+use crate::graphics::{Point, UpdateMode, Window, WindowSettings};
+use crate::input::{self, gamepad, keyboard, mouse, touch, window, Input};
 use crate::load::{Join, LoadingScreen, Task};
-use crate::{Result, Timer};
+use crate::{ControlFlow, FrameTiming, Result, Timer};
 use std::convert::TryInto;
+use std::time::Instant;
 
 pub trait Loop<Game: super::Game> {
     type Attributes;
@@ -37,24 +38,33 @@ pub trait Loop<Game: super::Game> {
         Game::Input: 'static,
     {
         // Window creation
+        let updates = window_settings.updates;
         let event_loop = winit::event_loop::EventLoop::new();
         let mut window = Window::new(window_settings, &event_loop)?;
         let mut debug = Debug::new(window.gpu());
 
         // Loading
         debug.loading_started();
-        let (mut game, configuration) = {
+        let (mut game, configuration, mut crossfade) = {
             let mut loading_screen = Game::LoadingScreen::new(window.gpu())?;
 
-            loading_screen.run(
-                (Game::load(&window), Self::load(&window)).join(),
-                &mut window,
-            )?
+            let (game, configuration) = span!("load", 0, {
+                loading_screen.run(
+                    (Game::load(&window), Self::load(&window)).join().into(),
+                    &mut window,
+                )?
+            });
+
+            (game, configuration, loading_screen.crossfade())
         };
 
         let mut game_loop = Self::new(configuration, &mut game, &mut window);
         let mut input = Game::Input::new();
-        let mut gamepads = gamepad::Tracker::new();
+        let mut gamepads = if Game::LOOP_SETTINGS.poll_gamepads_in_background {
+            gamepad::Tracker::spawn()
+        } else {
+            gamepad::Tracker::new()
+        };
         debug.loading_finished();
 
         let mut timer = Timer::new(Game::TICKS_PER_SECOND);
@@ -63,8 +73,20 @@ pub trait Loop<Game: super::Game> {
         debug.frame_started();
         timer.update();
 
+        let mut is_waiting = false;
+        let mut is_focused = true;
+        let mut frame: u64 = 0;
+        let mut frame_start = Instant::now();
+        let mut last_frame_end = frame_start;
+
+        // Window events are pumped here, on the main thread, even when
+        // gamepads are polled in the background. Both the event loop and the
+        // window it creates must stay on the main thread on some platforms.
         event_loop.run(move |event, _, control_flow| match event {
             winit::event::Event::NewEvents(_) => {
+                frame_start = Instant::now();
+                game.on_frame_start();
+
                 debug.interact_started();
             }
             winit::event::Event::MainEventsCleared => {
@@ -77,33 +99,113 @@ pub trait Loop<Game: super::Game> {
                     }
                 }
 
-                game.interact(&mut input, &mut window);
-                input.clear();
+                if let Some(new_size) = window.apply_resize() {
+                    game_loop.on_input(
+                        &mut input,
+                        input::Event::Window(window::Event::Resized(new_size)),
+                    );
+                }
+
+                if window.take_display_change() {
+                    game_loop.on_input(
+                        &mut input,
+                        input::Event::Window(window::Event::DisplayChanged {
+                            width: window.width(),
+                            height: window.height(),
+                            is_fullscreen: window.is_fullscreen(),
+                        }),
+                    );
+                }
+
+                span!("interact", frame, {
+                    game.interact(&mut input, &mut window);
+                });
+
                 debug.interact_finished();
 
-                if timer.tick() {
-                    debug.update_started();
-                    game.update(&window);
-                    debug.update_finished();
+                if game.is_paused() {
+                    timer.reset();
+                } else {
+                    let settings = Game::LOOP_SETTINGS;
+                    let mut updates = 0;
+
+                    while updates < settings.max_updates_per_frame
+                        && timer.step()
+                    {
+                        debug.update_started();
+                        span!("update", frame, {
+                            game.update_with(&input, &timer, &window)
+                        });
+                        debug.update_finished();
+
+                        updates += 1;
+                    }
+
+                    let skipped = timer.skip_ticks();
+
+                    // Waiting for events is not a performance issue
+                    if skipped > 0 && settings.panic_on_spiral && !is_waiting {
+                        panic!(
+                            "The game cannot keep up: {} ticks were skipped",
+                            skipped
+                        );
+                    }
                 }
 
+                // The updates see the same input as the interaction
+                input.clear();
+
                 window.request_redraw();
 
                 if game.is_finished() {
                     *control_flow = winit::event_loop::ControlFlow::Exit;
+                } else if *control_flow != winit::event_loop::ControlFlow::Exit
+                {
+                    let flow = match (updates, game.control_flow()) {
+                        (UpdateMode::OnEvent, ControlFlow::Poll) => {
+                            ControlFlow::Wait
+                        }
+                        (_, flow) => flow,
+                    };
+
+                    // The crossfade from the loading screen is animated
+                    let flow = if crossfade.is_some() {
+                        ControlFlow::Poll
+                    } else {
+                        flow
+                    };
+
+                    is_waiting = flow != ControlFlow::Poll;
+                    *control_flow = flow.into_winit();
                 }
             }
             winit::event::Event::RedrawRequested { .. } => {
+                if let Some(result) = window.gpu().reload_shaders() {
+                    debug.shaders_reloaded(result);
+                }
+
                 debug.draw_started();
-                game.draw(&mut window.frame(), &timer);
+                span!("draw", frame, {
+                    game.draw(&mut window.frame(), &timer);
+                });
                 debug.draw_finished();
 
-                game_loop.after_draw(
-                    &mut game,
-                    &mut input,
-                    &mut window,
-                    &mut debug,
-                );
+                span!("after_draw", frame, {
+                    game_loop.after_draw(
+                        &mut game,
+                        &mut input,
+                        &mut window,
+                        &mut debug,
+                    );
+                });
+
+                if let Some(fade) = &mut crossfade {
+                    fade.draw(&mut window.frame());
+
+                    if fade.is_finished() {
+                        crossfade = None;
+                    }
+                }
 
                 if debug.is_enabled() {
                     debug.debug_started();
@@ -111,11 +213,30 @@ pub trait Loop<Game: super::Game> {
                     debug.debug_finished();
                 }
 
-                window.swap_buffers();
+                debug.draw_errors(&mut window.frame());
+
+                let cpu = frame_start.elapsed();
+
+                span!("submit", frame, { window.swap_buffers() });
                 debug.frame_finished();
 
+                let frame_end = Instant::now();
+
+                game.on_frame_end(&FrameTiming {
+                    cpu,
+                    total: frame_end - last_frame_end,
+                    gpu: window.gpu_time(),
+                });
+
+                last_frame_end = frame_end;
+                frame += 1;
+
                 debug.frame_started();
-                window.request_redraw();
+
+                if !is_waiting {
+                    window.request_redraw();
+                }
+
                 timer.update();
             }
             winit::event::Event::WindowEvent { event, .. } => match event {
@@ -124,8 +245,8 @@ pub trait Loop<Game: super::Game> {
                         *control_flow = winit::event_loop::ControlFlow::Exit;
                     }
                 }
-                winit::event::WindowEvent::Resized(logical_size) => {
-                    window.resize(logical_size);
+                winit::event::WindowEvent::Resized(new_size) => {
+                    window.queue_resize(new_size);
                 }
                 _ => {
                     match event {
@@ -142,6 +263,15 @@ pub trait Loop<Game: super::Game> {
                                 debug.toggle();
                             }
                         }
+                        winit::event::WindowEvent::Focused(focus) => {
+                            is_focused = focus;
+                        }
+                        winit::event::WindowEvent::ScaleFactorChanged {
+                            ref new_inner_size,
+                            ..
+                        } => {
+                            window.queue_resize(**new_inner_size);
+                        }
                         _ => {}
                     }
 
@@ -150,6 +280,22 @@ pub trait Loop<Game: super::Game> {
                     }
                 }
             },
+            winit::event::Event::DeviceEvent {
+                event: winit::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                // Device events are received even if the window is not
+                // focused
+                if is_focused {
+                    game_loop.on_input(
+                        &mut input,
+                        input::Event::Mouse(mouse::Event::Motion {
+                            delta_x: delta.0 as f32,
+                            delta_y: delta.1 as f32,
+                        }),
+                    );
+                }
+            }
             _ => {}
         });
     }
@@ -164,12 +310,14 @@ fn try_into_input_event(
                 winit::event::KeyboardInput {
                     state,
                     virtual_keycode: Some(key_code),
+                    scancode,
                     ..
                 },
             ..
         } => Some(input::Event::Keyboard(keyboard::Event::Input {
             state,
             key_code,
+            scancode,
         })),
         winit::event::WindowEvent::ReceivedCharacter(codepoint) => {
             Some(input::Event::Keyboard(keyboard::Event::TextEntered {
@@ -205,6 +353,21 @@ fn try_into_input_event(
         } else {
             input::Event::Window(window::Event::Unfocused)
         }),
+        winit::event::WindowEvent::Touch(winit::event::Touch {
+            id,
+            phase,
+            location,
+            ..
+        }) => Some(input::Event::Touch {
+            id: touch::Id::new(id),
+            phase,
+            position: Point::new(location.x as f32, location.y as f32),
+        }),
+        winit::event::WindowEvent::ScaleFactorChanged {
+            scale_factor, ..
+        } => Some(input::Event::Window(window::Event::DpiChanged(
+            scale_factor,
+        ))),
         winit::event::WindowEvent::Moved(position) => {
             Some(input::Event::Window(window::Event::Moved {
                 x: position.x as f32,
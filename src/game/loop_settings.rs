@@ -0,0 +1,60 @@
+/// The settings of the main loop of a [`Game`].
+///
+/// When the simulation cannot keep up with [`Game::TICKS_PER_SECOND`], the
+/// main loop runs several updates per frame to catch up. These settings limit
+/// how far it goes, avoiding a _death spiral_ where catching up takes longer
+/// than the time being simulated and the game freezes.
+///
+/// [`Game`]: trait.Game.html
+/// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoopSettings {
+    /// The maximum amount of times [`Game::update`] is called per frame.
+    ///
+    /// When the limit is reached, the remaining ticks are skipped and
+    /// [`Timer::is_running_slow`] returns `true`.
+    ///
+    /// [`Game::update`]: trait.Game.html#method.update
+    /// [`Timer::is_running_slow`]: struct.Timer.html#method.is_running_slow
+    pub max_updates_per_frame: u16,
+
+    /// Whether to panic when ticks need to be skipped, instead of skipping
+    /// them. Useful to catch performance issues during development.
+    pub panic_on_spiral: bool,
+
+    /// Whether to poll gamepads on a dedicated thread.
+    ///
+    /// Gamepad events are queued in the background and consumed right before
+    /// [`Game::interact`]. Some platforms only sample the state of the
+    /// gamepads when polled, so enabling this avoids missing quick presses
+    /// while a long frame is being drawn.
+    ///
+    /// The thread polls every few milliseconds and stops when the game loop
+    /// ends.
+    ///
+    /// Only gamepads are polled in the background. Pumping window events, like
+    /// keyboard and mouse input, on a background thread is not supported and
+    /// is not planned: `winit` requires its event loop to be created and run
+    /// on the main thread on macOS and iOS, and the window and its graphics
+    /// context are tied to that event loop. Window events are pumped on the
+    /// main thread, once per frame. The operating system queues them
+    /// meanwhile, so they are delayed by a long frame, but never lost. Keep
+    /// your frames short if you need low-latency keyboard and mouse input.
+    ///
+    /// [`Game::interact`]: trait.Game.html#method.interact
+    pub poll_gamepads_in_background: bool,
+}
+
+impl LoopSettings {
+    pub(crate) const DEFAULT: LoopSettings = LoopSettings {
+        max_updates_per_frame: 5,
+        panic_on_spiral: false,
+        poll_gamepads_in_background: false,
+    };
+}
+
+impl Default for LoopSettings {
+    fn default() -> LoopSettings {
+        LoopSettings::DEFAULT
+    }
+}
@@ -0,0 +1,191 @@
+use crate::graphics::Frame;
+use crate::Timer;
+
+/// An ordered list of systems that run on a game state.
+///
+/// A system is a function that works on a specific part of your game state,
+/// like physics, collisions or animations. [`Systems`] runs them in the same
+/// order they were added, on each stage of the game loop:
+///
+///   * [`interact`] systems receive the current [`Input`] and run in
+///     [`Game::interact`].
+///   * [`update`] systems receive the current [`Input`] and the [`Timer`]
+///     and run in [`Game::update_with`], once per tick.
+///   * [`draw`] systems receive the [`Frame`] and the [`Timer`] and run in
+///     [`Game::draw`].
+///
+/// [`Systems`] is not tied to any particular state representation. You can
+/// use it with a plain struct or with the world of your favorite ECS library.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::{Color, Frame};
+/// use coffee::input::{keyboard::KeyCode, KeyboardAndMouse};
+/// use coffee::{Systems, Timer};
+///
+/// struct World {
+///     position: f32,
+///     speed: f32,
+/// }
+///
+/// struct MyGame {
+///     world: World,
+///     systems: Systems<World, KeyboardAndMouse>,
+/// }
+///
+/// impl MyGame {
+///     fn new() -> MyGame {
+///         let systems = Systems::new()
+///             .on_interact(|world: &mut World, input: &mut KeyboardAndMouse| {
+///                 let is_moving = input.keyboard().is_key_pressed(KeyCode::D);
+///
+///                 world.speed = if is_moving { 2.0 } else { 0.0 };
+///             })
+///             .on_update(
+///                 |world: &mut World, _: &KeyboardAndMouse, _: &Timer| {
+///                     world.position += world.speed;
+///                 },
+///             )
+///             .on_draw(|_: &World, frame: &mut Frame<'_>, _: &Timer| {
+///                 frame.clear(Color::BLACK);
+///             });
+///
+///         MyGame {
+///             world: World {
+///                 position: 0.0,
+///                 speed: 0.0,
+///             },
+///             systems,
+///         }
+///     }
+///
+///     // Call these from your `Game` implementation
+///     fn interact(&mut self, input: &mut KeyboardAndMouse) {
+///         self.systems.interact(&mut self.world, input);
+///     }
+///
+///     fn update_with(&mut self, input: &KeyboardAndMouse, timer: &Timer) {
+///         self.systems.update(&mut self.world, input, timer);
+///     }
+///
+///     fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) {
+///         self.systems.draw(&self.world, frame, timer);
+///     }
+/// }
+/// ```
+///
+/// [`Systems`]: struct.Systems.html
+/// [`interact`]: #method.interact
+/// [`update`]: #method.update
+/// [`draw`]: #method.draw
+/// [`Input`]: input/trait.Input.html
+/// [`Frame`]: graphics/struct.Frame.html
+/// [`Timer`]: struct.Timer.html
+/// [`Game::interact`]: trait.Game.html#method.interact
+/// [`Game::update_with`]: trait.Game.html#method.update_with
+/// [`Game::draw`]: trait.Game.html#tymethod.draw
+pub struct Systems<State, Input> {
+    interact: Vec<Box<dyn FnMut(&mut State, &mut Input)>>,
+    update: Vec<Box<dyn FnMut(&mut State, &Input, &Timer)>>,
+    draw: Vec<Box<dyn FnMut(&State, &mut Frame<'_>, &Timer)>>,
+}
+
+impl<State, Input> Systems<State, Input> {
+    /// Creates an empty list of [`Systems`].
+    ///
+    /// [`Systems`]: struct.Systems.html
+    pub fn new() -> Systems<State, Input> {
+        Systems {
+            interact: Vec::new(),
+            update: Vec::new(),
+            draw: Vec::new(),
+        }
+    }
+
+    /// Adds a system that runs when the [`Input`] is processed.
+    ///
+    /// [`Input`]: input/trait.Input.html
+    pub fn on_interact<F>(mut self, system: F) -> Self
+    where
+        F: 'static + FnMut(&mut State, &mut Input),
+    {
+        self.interact.push(Box::new(system));
+        self
+    }
+
+    /// Adds a system that runs when the game state is updated.
+    ///
+    /// It receives the current [`Input`] and the [`Timer`] of the game loop.
+    ///
+    /// [`Input`]: input/trait.Input.html
+    /// [`Timer`]: struct.Timer.html
+    pub fn on_update<F>(mut self, system: F) -> Self
+    where
+        F: 'static + FnMut(&mut State, &Input, &Timer),
+    {
+        self.update.push(Box::new(system));
+        self
+    }
+
+    /// Adds a system that runs when a [`Frame`] is drawn.
+    ///
+    /// [`Frame`]: graphics/struct.Frame.html
+    pub fn on_draw<F>(mut self, system: F) -> Self
+    where
+        F: 'static + FnMut(&State, &mut Frame<'_>, &Timer),
+    {
+        self.draw.push(Box::new(system));
+        self
+    }
+
+    /// Runs the interact systems, in order, with the given [`Input`].
+    ///
+    /// [`Input`]: input/trait.Input.html
+    pub fn interact(&mut self, state: &mut State, input: &mut Input) {
+        for system in self.interact.iter_mut() {
+            system(state, input);
+        }
+    }
+
+    /// Runs the update systems, in order, with the given [`Input`] and
+    /// [`Timer`].
+    ///
+    /// [`Input`]: input/trait.Input.html
+    /// [`Timer`]: struct.Timer.html
+    pub fn update(&mut self, state: &mut State, input: &Input, timer: &Timer) {
+        for system in self.update.iter_mut() {
+            system(state, input, timer);
+        }
+    }
+
+    /// Runs the draw systems, in order, on the given [`Frame`].
+    ///
+    /// [`Frame`]: graphics/struct.Frame.html
+    pub fn draw(
+        &mut self,
+        state: &State,
+        frame: &mut Frame<'_>,
+        timer: &Timer,
+    ) {
+        for system in self.draw.iter_mut() {
+            system(state, frame, timer);
+        }
+    }
+}
+
+impl<State, Input> Default for Systems<State, Input> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State, Input> std::fmt::Debug for Systems<State, Input> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Systems")
+            .field("interact", &self.interact.len())
+            .field("update", &self.update.len())
+            .field("draw", &self.draw.len())
+            .finish()
+    }
+}
@@ -87,9 +87,6 @@
 
 #[cfg(feature = "opengl")]
 mod backend_gfx;
-#[cfg(feature = "opengl")]
-use backend_gfx as gpu;
-
 #[cfg(any(
     feature = "vulkan",
     feature = "metal",
@@ -97,51 +94,88 @@ use backend_gfx as gpu;
     feature = "dx12",
 ))]
 mod backend_wgpu;
-#[cfg(any(
-    feature = "vulkan",
-    feature = "metal",
-    feature = "dx11",
-    feature = "dx12",
-))]
-use backend_wgpu as gpu;
 
+mod adapter_info;
+mod backend;
 mod batch;
+mod blend_mode;
 mod canvas;
 mod color;
+mod distance_field_font;
+mod draw_parameters;
+mod drawable;
 mod font;
+mod gpu;
 mod image;
+mod image_handle;
+mod image_settings;
+mod limits;
 mod mesh;
+mod mipmap;
+mod origin;
 mod point;
+mod power_preference;
 mod quad;
 mod rectangle;
+mod resource_id;
+mod resource_stats;
+mod sampler;
 mod shape;
 mod sprite;
+mod sub_image;
 mod target;
 mod text;
+mod tiles;
 mod transformation;
 mod vector;
 
+pub mod frame_graph;
+pub mod lighting;
+#[cfg(feature = "sprite-sheet")]
+pub mod sprite_sheet;
 pub mod texture_array;
+pub mod transition;
 pub(crate) mod window;
 
 pub use self::image::Image;
-pub use batch::Batch;
+pub use adapter_info::AdapterInfo;
+pub use backend::Backend;
+pub use batch::{Batch, FrozenBatch};
+pub use blend_mode::BlendMode;
 pub use canvas::Canvas;
 pub use color::Color;
-pub use font::Font;
+pub use distance_field_font::DistanceFieldFont;
+pub use draw_parameters::DrawParameters;
+pub use drawable::Drawable;
+pub use font::{Face as FontFace, Font, Metrics as FontMetrics};
+pub use frame_graph::FrameGraph;
 pub use gpu::Gpu;
+pub use image_handle::ImageHandle;
+pub use image_settings::ImageSettings;
+pub use limits::Limits;
 pub use mesh::Mesh;
-pub use point::Point;
+pub use origin::Origin;
+pub use point::{Point, PointExt};
+pub use power_preference::PowerPreference;
 pub use quad::{IntoQuad, Quad};
 pub use rectangle::Rectangle;
+pub use resource_id::ResourceId;
+pub use resource_stats::ResourceStats;
+pub use sampler::{FilterMode, Sampler, WrapMode};
 pub use shape::Shape;
 pub use sprite::Sprite;
+pub use sub_image::SubImage;
 pub use target::Target;
 pub use text::{HorizontalAlignment, Text, VerticalAlignment};
 pub use texture_array::TextureArray;
+pub use tiles::Tiles;
 pub use transformation::Transformation;
+pub use transition::Transition;
 pub use vector::Vector;
-pub use window::{CursorIcon, Frame, Settings as WindowSettings, Window};
+pub use window::{
+    CursorIcon, Frame, Fullscreen, Monitor, PresentationStats,
+    Settings as WindowSettings, UpdateMode, VideoMode, Window,
+};
 
 mod backend_gfx;
 #[cfg(feature = "opengl")]
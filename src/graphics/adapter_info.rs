@@ -0,0 +1,24 @@
+use crate::graphics::Backend;
+
+/// Information about the graphics adapter used by a [`Gpu`].
+///
+/// You can obtain it using [`Gpu::info`]. It is mostly useful for
+/// diagnostics, like logging the adapter when reporting bugs.
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Gpu::info`]: struct.Gpu.html#method.info
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdapterInfo {
+    /// The name of the adapter, as reported by the driver.
+    pub name: String,
+
+    /// The [`Backend`] used to talk to the adapter, or [`Backend::Auto`] if
+    /// it is unknown.
+    ///
+    /// [`Backend`]: enum.Backend.html
+    /// [`Backend::Auto`]: enum.Backend.html#variant.Auto
+    pub backend: Backend,
+
+    /// The vendor of the adapter.
+    pub vendor: String,
+}
@@ -0,0 +1,81 @@
+/// A graphics backend.
+///
+/// You can choose the backend used by your game in the [`WindowSettings`].
+///
+/// Keep in mind that the available backends are still decided at compile
+/// time by the enabled features:
+///
+///   * `opengl` enables the [`OpenGl`] backend.
+///   * `vulkan`, `metal`, `dx11`, and `dx12` enable the [`Vulkan`],
+///   [`Metal`], [`Dx11`], and [`Dx12`] backends, as long as the platform
+///   supports them.
+///
+/// Both kinds of backends can be compiled in at the same time. Choosing a
+/// backend that has not been compiled in makes the window creation fail with
+/// [`Error::BackendNotCompiled`]. If the chosen backend is compiled in but
+/// not supported by the platform, the runtime falls back to the best
+/// compiled backend it can find. [`Auto`] prefers the `wgpu` backends and
+/// falls back to [`OpenGl`] when no adapter supports them.
+///
+/// The `wgpu` backends are tried in this order: the chosen one, [`Vulkan`],
+/// [`Dx12`], [`Metal`], and [`Dx11`]. [`OpenGl`] comes last, when it has been
+/// compiled in.
+///
+/// [`WindowSettings`]: struct.WindowSettings.html
+/// [`Error::BackendNotCompiled`]: ../enum.Error.html#variant.BackendNotCompiled
+/// [`Auto`]: #variant.Auto
+/// [`OpenGl`]: #variant.OpenGl
+/// [`Vulkan`]: #variant.Vulkan
+/// [`Metal`]: #variant.Metal
+/// [`Dx11`]: #variant.Dx11
+/// [`Dx12`]: #variant.Dx12
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Backend {
+    /// The best backend available.
+    Auto,
+
+    /// OpenGL, powered by `gfx`.
+    OpenGl,
+
+    /// Vulkan, powered by `wgpu`.
+    Vulkan,
+
+    /// Metal, powered by `wgpu`.
+    Metal,
+
+    /// DirectX 11, powered by `wgpu`.
+    Dx11,
+
+    /// DirectX 12, powered by `wgpu`.
+    Dx12,
+}
+
+impl Backend {
+    /// Returns whether the [`Backend`] has been compiled in.
+    ///
+    /// A compiled [`Backend`] may still be unsupported by the platform, in
+    /// which case the runtime will fall back to another compiled one.
+    ///
+    /// [`Backend`]: enum.Backend.html
+    pub fn is_compiled(self) -> bool {
+        match self {
+            Backend::Auto => true,
+            Backend::OpenGl => cfg!(feature = "opengl"),
+            Backend::Vulkan
+            | Backend::Metal
+            | Backend::Dx11
+            | Backend::Dx12 => cfg!(any(
+                feature = "vulkan",
+                feature = "metal",
+                feature = "dx11",
+                feature = "dx12",
+            )),
+        }
+    }
+}
+
+impl Default for Backend {
+    fn default() -> Backend {
+        Backend::Auto
+    }
+}
@@ -1,8 +1,11 @@
 use gfx_device_gl as gl;
 use gfx_glyph::GlyphCruncher;
 
-use crate::graphics::gpu::{TargetView, Transformation};
-use crate::graphics::{HorizontalAlignment, Text, Vector, VerticalAlignment};
+use super::TargetView;
+use crate::graphics::{
+    FontMetrics, HorizontalAlignment, Rectangle, Text, Transformation, Vector,
+    VerticalAlignment,
+};
 
 pub struct Font {
     glyphs: gfx_glyph::GlyphBrush<'static, gl::Resources, gl::Factory>,
@@ -18,18 +21,49 @@ impl Font {
         }
     }
 
+    pub fn add_face(&mut self, bytes: &'static [u8]) -> usize {
+        self.glyphs.add_font_bytes(bytes).0
+    }
+
     pub fn add(&mut self, text: Text<'_>) {
         let section: gfx_glyph::Section<'_> = text.into();
         self.glyphs.queue(section);
     }
 
+    pub fn metrics(&self, face: usize, size: f32) -> FontMetrics {
+        let scale = gfx_glyph::Scale::uniform(size);
+        let metrics = self.glyphs.fonts()[face].v_metrics(scale);
+
+        FontMetrics {
+            ascent: metrics.ascent,
+            descent: metrics.descent,
+            line_gap: metrics.line_gap,
+        }
+    }
+
     pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
+        let bounds = self.bounds(text);
+
+        (bounds.width, bounds.height)
+    }
+
+    pub fn bounds(&mut self, text: Text<'_>) -> Rectangle<f32> {
+        let (x, y) = (text.position.x, text.position.y);
         let section: gfx_glyph::Section<'_> = text.into();
-        let bounds = self.glyphs.glyph_bounds(section);
 
-        match bounds {
-            Some(bounds) => (bounds.width(), bounds.height()),
-            None => (0.0, 0.0),
+        match self.glyphs.glyph_bounds(section) {
+            Some(bounds) => Rectangle {
+                x: bounds.min.x,
+                y: bounds.min.y,
+                width: bounds.width(),
+                height: bounds.height(),
+            },
+            None => Rectangle {
+                x,
+                y,
+                width: 0.0,
+                height: 0.0,
+            },
         }
     }
 
@@ -66,7 +100,9 @@ impl<'a> From<Text<'a>> for gfx_glyph::Section<'a> {
         };
 
         let y = match text.vertical_alignment {
-            VerticalAlignment::Top => text.position.y,
+            VerticalAlignment::Top | VerticalAlignment::Baseline => {
+                text.position.y
+            }
             VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
             VerticalAlignment::Bottom => text.position.y + text.bounds.1,
         };
@@ -80,6 +116,7 @@ impl<'a> From<Text<'a>> for gfx_glyph::Section<'a> {
             },
             color: text.color.into_linear(),
             bounds: text.bounds,
+            font_id: gfx_glyph::FontId(text.face.index()),
             layout: gfx_glyph::Layout::default()
                 .h_align(text.horizontal_alignment.into())
                 .v_align(text.vertical_alignment.into()),
@@ -104,6 +141,7 @@ impl From<VerticalAlignment> for gfx_glyph::VerticalAlign {
             VerticalAlignment::Top => gfx_glyph::VerticalAlign::Top,
             VerticalAlignment::Center => gfx_glyph::VerticalAlign::Center,
             VerticalAlignment::Bottom => gfx_glyph::VerticalAlign::Bottom,
+            VerticalAlignment::Baseline => gfx_glyph::VerticalAlign::Top,
         }
     }
 }
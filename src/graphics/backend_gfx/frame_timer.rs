@@ -0,0 +1,153 @@
+// Timestamp queries are only available through raw OpenGL calls
+#![allow(unsafe_code)]
+
+use std::time::Duration;
+
+use gfx_device_gl as gl;
+use gfx_gl::types::{GLint, GLuint, GLuint64};
+
+// Frames that can be measured before their results are read back
+const SLOTS: usize = 4;
+
+// Measures the time the GPU spends on the commands of a frame using timestamp
+// queries.
+//
+// Results are read back without stalling the pipeline, so they lag a few
+// frames behind.
+pub struct FrameTimer {
+    slots: [Slot; SLOTS],
+    current: usize,
+    latest: Option<Duration>,
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    start: GLuint,
+    end: GLuint,
+    is_pending: bool,
+}
+
+impl FrameTimer {
+    pub fn new(device: &mut gl::Device) -> Option<FrameTimer> {
+        let is_supported = device.get_info().is_version_or_extension_supported(
+            3,
+            3,
+            "GL_ARB_timer_query",
+        );
+
+        if !is_supported {
+            return None;
+        }
+
+        let mut queries = [0; 2 * SLOTS];
+
+        // The queries are freed along with the context
+        unsafe {
+            device.with_gl(|gl| {
+                gl.GenQueries(queries.len() as i32, queries.as_mut_ptr())
+            });
+        }
+
+        let mut slots = [Slot {
+            start: 0,
+            end: 0,
+            is_pending: false,
+        }; SLOTS];
+
+        for (slot, pair) in slots.iter_mut().zip(queries.chunks(2)) {
+            slot.start = pair[0];
+            slot.end = pair[1];
+        }
+
+        Some(FrameTimer {
+            slots,
+            current: 0,
+            latest: None,
+        })
+    }
+
+    // Runs the given function, which should submit the commands of a frame,
+    // between two timestamps.
+    //
+    // The frame is not measured if every slot is still waiting for results.
+    pub fn measure<F>(&mut self, device: &mut gl::Device, submit: F)
+    where
+        F: FnOnce(&mut gl::Device),
+    {
+        self.collect(device);
+
+        let slot = self.slots[self.current];
+
+        if slot.is_pending {
+            submit(device);
+            return;
+        }
+
+        unsafe {
+            device.with_gl(|gl| gl.QueryCounter(slot.start, gfx_gl::TIMESTAMP));
+        }
+
+        submit(device);
+
+        unsafe {
+            device.with_gl(|gl| gl.QueryCounter(slot.end, gfx_gl::TIMESTAMP));
+        }
+
+        self.slots[self.current].is_pending = true;
+        self.current = (self.current + 1) % SLOTS;
+    }
+
+    // Returns the duration of the latest frame whose results are available
+    pub fn latest(&self) -> Option<Duration> {
+        self.latest
+    }
+
+    fn collect(&mut self, device: &mut gl::Device) {
+        let current = self.current;
+        let slots = &mut self.slots;
+        let latest = &mut self.latest;
+
+        unsafe {
+            device.with_gl(|gl| {
+                // Queries complete in order, so we go from oldest to newest
+                for offset in 0..SLOTS {
+                    let slot = &mut slots[(current + offset) % SLOTS];
+
+                    if !slot.is_pending {
+                        continue;
+                    }
+
+                    let mut is_available: GLint = 0;
+
+                    gl.GetQueryObjectiv(
+                        slot.end,
+                        gfx_gl::QUERY_RESULT_AVAILABLE,
+                        &mut is_available,
+                    );
+
+                    if is_available == 0 {
+                        break;
+                    }
+
+                    let mut start: GLuint64 = 0;
+                    let mut end: GLuint64 = 0;
+
+                    gl.GetQueryObjectui64v(
+                        slot.start,
+                        gfx_gl::QUERY_RESULT,
+                        &mut start,
+                    );
+                    gl.GetQueryObjectui64v(
+                        slot.end,
+                        gfx_gl::QUERY_RESULT,
+                        &mut end,
+                    );
+
+                    slot.is_pending = false;
+                    *latest =
+                        Some(Duration::from_nanos(end.saturating_sub(start)));
+                }
+            });
+        }
+    }
+}
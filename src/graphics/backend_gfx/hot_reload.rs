@@ -0,0 +1,54 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+// Watches the sources of the built-in shaders for changes
+pub struct Watcher {
+    directory: PathBuf,
+    last_check: Instant,
+    last_modification: Option<SystemTime>,
+}
+
+impl Watcher {
+    pub fn new() -> Watcher {
+        let directory = PathBuf::from(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/graphics/backend_gfx/shader"
+        ));
+
+        Watcher {
+            last_check: Instant::now(),
+            last_modification: last_modification(&directory),
+            directory,
+        }
+    }
+
+    pub fn has_changed(&mut self) -> bool {
+        if self.last_check.elapsed() < CHECK_INTERVAL {
+            return false;
+        }
+
+        self.last_check = Instant::now();
+
+        let modification = last_modification(&self.directory);
+        let has_changed = modification != self.last_modification;
+
+        self.last_modification = modification;
+
+        has_changed
+    }
+
+    pub fn read(&self, file: &str) -> Result<Vec<u8>, String> {
+        fs::read(self.directory.join(file))
+            .map_err(|error| format!("{}: {}", file, error))
+    }
+}
+
+fn last_modification(directory: &Path) -> Option<SystemTime> {
+    fs::read_dir(directory)
+        .ok()?
+        .filter_map(|entry| entry.ok()?.metadata().ok()?.modified().ok())
+        .max()
+}
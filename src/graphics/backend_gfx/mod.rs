@@ -1,50 +1,71 @@
 mod font;
 mod format;
+mod frame_timer;
+#[cfg(any(debug_assertions, feature = "debug"))]
+mod hot_reload;
 mod quad;
+mod resolve;
 mod surface;
-pub mod texture;
+mod texture;
 mod triangle;
 mod types;
 
 pub use font::Font;
-pub use quad::Quad;
+pub use quad::InstanceBuffer;
 pub use surface::Surface;
-pub use texture::Texture;
-pub use triangle::Vertex;
-pub use types::TargetView;
+pub use texture::{Drawable, Texture};
+pub use types::{DepthView, TargetView};
 
+use frame_timer::FrameTimer;
 use gfx::{self, Device};
+use gfx_core::factory::Factory;
 use gfx_device_gl as gl;
 
-use crate::graphics::{Color, Transformation};
+use crate::graphics::gpu::{Quad, Renderer, Vertex};
+use crate::graphics::{
+    AdapterInfo, Backend, BlendMode, Color, ImageSettings, Limits,
+    PowerPreference, Rectangle, ResourceStats, Transformation,
+};
 use crate::Result;
 
-/// A link between your game and a graphics processor.
-///
-/// It is necessary to perform any kind of graphical operation, like loading
-/// resources and drawing.
-///
-/// A [`Gpu`] can be obtained from a [`Window`] or a [`Frame`].
-///
-/// [`Gpu`]: struct.Gpu.html
-/// [`Window`]: struct.Window.html
-/// [`Frame`]: struct.Frame.html
-#[allow(missing_debug_implementations)]
 pub struct Gpu {
     device: gl::Device,
     factory: gl::Factory,
     encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer>,
     triangle_pipeline: triangle::Pipeline,
     quad_pipeline: quad::Pipeline,
+    resolve_pipeline: resolve::Pipeline,
+    text_layer: Option<Drawable>,
+    info: AdapterInfo,
+    frame_timer: Option<FrameTimer>,
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    shader_watcher: hot_reload::Watcher,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
         events_loop: &winit::event_loop::EventLoop<()>,
+        // OpenGL contexts are always created on the adapter chosen by the
+        // system, as `glutin` offers no way to pick one
+        _power_preference: PowerPreference,
+        multisampling: u16,
+        vsync: bool,
     ) -> Result<(Gpu, Surface)> {
-        let (surface, device, mut factory) =
-            Surface::new(builder, events_loop)?;
+        let (surface, mut device, mut factory) =
+            Surface::new(builder, events_loop, multisampling, vsync)?;
+
+        let info = {
+            let platform = &device.get_info().platform_name;
+
+            AdapterInfo {
+                name: String::from(platform.renderer),
+                backend: Backend::OpenGl,
+                vendor: String::from(platform.vendor),
+            }
+        };
+
+        let frame_timer = FrameTimer::new(&mut device);
 
         let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
             factory.create_command_buffer().into();
@@ -58,6 +79,8 @@ impl Gpu {
         let quad_pipeline =
             quad::Pipeline::new(&mut factory, &mut encoder, surface.target());
 
+        let resolve_pipeline = resolve::Pipeline::new(&mut factory);
+
         Ok((
             Gpu {
                 device,
@@ -65,12 +88,159 @@ impl Gpu {
                 encoder,
                 triangle_pipeline,
                 quad_pipeline,
+                resolve_pipeline,
+                text_layer: None,
+                info,
+                frame_timer,
+                #[cfg(any(debug_assertions, feature = "debug"))]
+                shader_watcher: hot_reload::Watcher::new(),
             },
             surface,
         ))
     }
 
-    pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
+    fn flush(&mut self) {
+        self.encoder.flush(&mut self.device);
+    }
+
+    fn flush_frame(&mut self) {
+        match &mut self.frame_timer {
+            Some(frame_timer) => {
+                let encoder = &mut self.encoder;
+
+                frame_timer
+                    .measure(&mut self.device, |device| encoder.flush(device));
+            }
+            None => self.flush(),
+        }
+    }
+
+    fn cleanup(&mut self) {
+        self.device.cleanup();
+    }
+
+    // Averages the samples of a texture drawn with multisampling, so it can be
+    // read. It happens every time the texture is read, as its attachment may
+    // have been drawn on since.
+    fn resolve(&mut self, texture: &Texture) {
+        if let Some(multisample) = texture.multisample() {
+            self.resolve_pipeline
+                .resolve(&mut self.encoder, multisample);
+        }
+    }
+
+    // Returns a transparent layer with the size of the given view. Text is
+    // drawn on it when it needs to be clipped, as `gfx_glyph` cannot use a
+    // scissor rectangle.
+    fn text_layer(&mut self, view: &TargetView) -> Drawable {
+        let (width, height, _, _) = view.get_dimensions();
+
+        let is_outdated = match &self.text_layer {
+            Some(layer) => {
+                layer.texture().width() != width
+                    || layer.texture().height() != height
+            }
+            None => true,
+        };
+
+        if is_outdated {
+            self.text_layer =
+                Some(Drawable::new(&mut self.factory, width, height, false));
+        }
+
+        let layer = self.text_layer.clone().expect("Text layer");
+
+        self.clear(
+            layer.target(),
+            Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            },
+        );
+
+        layer
+    }
+
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    fn rebuild_pipelines(&mut self) -> std::result::Result<(), String> {
+        let vertex = self.shader_watcher.read("quad.vert")?;
+        let fragment = self.shader_watcher.read("quad.frag")?;
+
+        self.quad_pipeline.reload_shader(
+            &mut self.factory,
+            &vertex,
+            &fragment,
+        )?;
+
+        let fragment = self.shader_watcher.read("distance_field.frag")?;
+
+        self.quad_pipeline.reload_distance_field_shader(
+            &mut self.factory,
+            &vertex,
+            &fragment,
+        )?;
+
+        let vertex = self.shader_watcher.read("triangle.vert")?;
+        let fragment = self.shader_watcher.read("triangle.frag")?;
+
+        self.triangle_pipeline.reload_shader(
+            &mut self.factory,
+            &vertex,
+            &fragment,
+        )?;
+
+        let vertex = self.shader_watcher.read("resolve.vert")?;
+        let fragment = self.shader_watcher.read("resolve.frag")?;
+
+        self.resolve_pipeline.reload_shader(
+            &mut self.factory,
+            &vertex,
+            &fragment,
+        )
+    }
+}
+
+impl Renderer for Gpu {
+    type Texture = Texture;
+    type Drawable = Drawable;
+    type Font = Font;
+    type InstanceBuffer = InstanceBuffer;
+    type TargetView = TargetView;
+    type DepthView = DepthView;
+
+    // The pixels of a target start at its bottom-left corner, like in OpenGL
+    fn scissor(
+        &self,
+        region: &Rectangle<f32>,
+        width: f32,
+        height: f32,
+    ) -> Rectangle<u32> {
+        let left = ((region.x + 1.0) / 2.0 * width).round().max(0.0).min(width);
+        let right = ((region.x + region.width + 1.0) / 2.0 * width)
+            .round()
+            .max(left)
+            .min(width);
+
+        let bottom = ((region.y + 1.0) / 2.0 * height)
+            .round()
+            .max(0.0)
+            .min(height);
+        let top = ((region.y + region.height + 1.0) / 2.0 * height)
+            .round()
+            .max(bottom)
+            .min(height);
+
+        Rectangle {
+            x: left as u32,
+            y: bottom as u32,
+            width: (right - left) as u32,
+            height: (top - bottom) as u32,
+        }
+    }
+
+    fn clear(&mut self, view: &TargetView, color: Color) {
         let typed_render_target: gfx::handle::RenderTargetView<
             gl::Resources,
             gfx::format::Srgba8,
@@ -80,55 +250,120 @@ impl Gpu {
             .clear(&typed_render_target, color.into_linear())
     }
 
-    fn flush(&mut self) {
-        self.encoder.flush(&mut self.device);
+    fn clear_depth(&mut self, depth: &DepthView) {
+        self.encoder.clear_depth(depth, 1.0);
     }
 
-    fn cleanup(&mut self) {
-        self.device.cleanup();
+    fn frame_time(&self) -> Option<std::time::Duration> {
+        self.frame_timer.as_ref().and_then(FrameTimer::latest)
+    }
+
+    fn info(&self) -> &AdapterInfo {
+        &self.info
+    }
+
+    fn collect(&mut self) -> ResourceStats {
+        self.flush();
+        self.cleanup();
+
+        ResourceStats::take()
+    }
+
+    fn limits(&self) -> Limits {
+        let max = self.factory.get_capabilities().max_texture_size;
+
+        // OpenGL guarantees texture arrays of at least 256 layers
+        Limits {
+            max_texture_size: max.min(usize::from(std::u16::MAX)) as u16,
+            max_texture_layers: 256,
+        }
+    }
+
+    #[cfg(any(debug_assertions, feature = "debug"))]
+    fn reload_shaders(&mut self) -> Option<std::result::Result<(), String>> {
+        if !self.shader_watcher.has_changed() {
+            return None;
+        }
+
+        Some(self.rebuild_pipelines())
     }
 
-    pub(super) fn upload_texture(
+    #[cfg(not(any(debug_assertions, feature = "debug")))]
+    fn reload_shaders(&mut self) -> Option<std::result::Result<(), String>> {
+        None
+    }
+
+    fn upload_texture(
         &mut self,
         image: &image::DynamicImage,
+        settings: ImageSettings,
     ) -> Texture {
-        Texture::new(&mut self.factory, image)
+        Texture::new(&mut self.factory, image, settings)
     }
 
-    pub(super) fn upload_texture_array(
+    fn upload_texture_array(
         &mut self,
         layers: &[image::DynamicImage],
+        mipmaps: bool,
+        settings: ImageSettings,
     ) -> Texture {
-        Texture::new_array(&mut self.factory, layers)
+        Texture::new_array(&mut self.factory, layers, mipmaps, settings)
     }
 
-    pub(super) fn create_drawable_texture(
+    fn create_texture(
         &mut self,
         width: u16,
         height: u16,
-    ) -> texture::Drawable {
-        texture::Drawable::new(&mut self.factory, width, height)
+        settings: ImageSettings,
+    ) -> Texture {
+        Texture::new_empty(&mut self.factory, width, height, settings)
+    }
+
+    fn update_texture(&mut self, texture: &Texture, y: u16, rgba: &[u8]) {
+        texture.update(&mut self.encoder, y, rgba);
     }
 
-    pub(super) fn read_drawable_texture_pixels(
+    fn create_drawable_texture(
         &mut self,
-        drawable: &texture::Drawable,
+        width: u16,
+        height: u16,
+        has_depth: bool,
+        samples: u16,
+    ) -> Drawable {
+        if samples > 1 {
+            Drawable::multisampled(
+                &mut self.factory,
+                width,
+                height,
+                samples as u8,
+            )
+        } else {
+            Drawable::new(&mut self.factory, width, height, has_depth)
+        }
+    }
+
+    fn read_texture_pixels(
+        &mut self,
+        texture: &Texture,
     ) -> image::DynamicImage {
+        self.resolve(texture);
         self.flush();
 
-        drawable.read_pixels(&mut self.device, &mut self.factory)
+        texture.read_pixels(&mut self.device, &mut self.factory)
     }
 
-    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
+    fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
         Font::from_bytes(&mut self.factory, bytes)
     }
 
-    pub(super) fn draw_triangles(
+    fn draw_triangles(
         &mut self,
         vertices: &[Vertex],
         indices: &[u32],
         view: &TargetView,
         transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
         self.triangle_pipeline.draw(
             &mut self.factory,
@@ -137,32 +372,198 @@ impl Gpu {
             indices,
             transformation,
             view,
+            scissor,
+            blend_mode,
         );
     }
 
-    pub(super) fn draw_texture_quads(
+    fn draw_texture_quads(
         &mut self,
         texture: &Texture,
         instances: &[Quad],
         view: &TargetView,
+        depth: Option<&DepthView>,
         transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
-        self.quad_pipeline.bind_texture(texture);
+        self.resolve(texture);
+        self.quad_pipeline.bind_texture(&mut self.factory, texture);
 
         self.quad_pipeline.draw_textured(
             &mut self.encoder,
             instances,
             transformation,
             view,
+            depth,
+            scissor,
+            blend_mode,
+        );
+    }
+
+    fn draw_distance_field_quads(
+        &mut self,
+        texture: &Texture,
+        instances: &[Quad],
+        view: &TargetView,
+        depth: Option<&DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        self.resolve(texture);
+        self.quad_pipeline.bind_texture(&mut self.factory, texture);
+
+        self.quad_pipeline.draw_distance_field(
+            &mut self.encoder,
+            instances,
+            transformation,
+            view,
+            depth,
+            scissor,
+            blend_mode,
+        );
+    }
+
+    fn upload_instances(&mut self, instances: &[Quad]) -> InstanceBuffer {
+        InstanceBuffer::new(&mut self.factory, instances)
+    }
+
+    fn draw_instance_buffer(
+        &mut self,
+        texture: &Texture,
+        instances: &InstanceBuffer,
+        view: &TargetView,
+        depth: Option<&DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        self.resolve(texture);
+        self.quad_pipeline.bind_texture(&mut self.factory, texture);
+
+        self.quad_pipeline.draw_instance_buffer(
+            &mut self.encoder,
+            instances,
+            transformation,
+            view,
+            depth,
+            scissor,
+            blend_mode,
         );
     }
 
-    pub(super) fn draw_font(
+    fn draw_font(
         &mut self,
         font: &mut Font,
         target: &TargetView,
         transformation: Transformation,
+        scissor: Option<Rectangle<u32>>,
     ) {
-        font.draw(&mut self.encoder, target, transformation);
+        let scissor = match scissor {
+            Some(scissor) => scissor,
+            None => {
+                font.draw(&mut self.encoder, target, transformation);
+                return;
+            }
+        };
+
+        // The text is drawn on a layer laid out like the target. The layer
+        // ends up premultiplied, as it starts transparent, and only its
+        // clipped region is blended on the target.
+        let layer = self.text_layer(target);
+
+        font.draw(&mut self.encoder, layer.target(), transformation);
+
+        self.quad_pipeline
+            .bind_texture(&mut self.factory, layer.texture());
+
+        self.quad_pipeline.draw_textured(
+            &mut self.encoder,
+            &[Quad {
+                source: [0.0, 0.0, 1.0, 1.0],
+                scale: [2.0, 2.0],
+                translation: [-1.0, -1.0],
+                layer: 0,
+                colors: [[1.0; 4]; 4],
+                origin: [0.0, 0.0],
+                rotation: 0.0,
+            }],
+            &Transformation::identity(),
+            target,
+            None,
+            Some(scissor),
+            BlendMode::Premultiplied,
+        );
+    }
+}
+
+// The scissor rectangle of a pipeline, which covers the whole view when no
+// scissor is given
+fn scissor_rect(
+    view: &TargetView,
+    scissor: Option<Rectangle<u32>>,
+) -> gfx::Rect {
+    match scissor {
+        Some(scissor) => gfx::Rect {
+            x: scissor.x as u16,
+            y: scissor.y as u16,
+            w: scissor.width as u16,
+            h: scissor.height as u16,
+        },
+        None => {
+            let (width, height, _, _) = view.get_dimensions();
+
+            gfx::Rect {
+                x: 0,
+                y: 0,
+                w: width,
+                h: height,
+            }
+        }
+    }
+}
+
+// The blend state of the pipelines drawing with the given blend mode. The
+// alpha channel of the view is kept when adding or multiplying colors.
+fn blend(blend_mode: BlendMode) -> gfx::state::Blend {
+    use gfx::state::{BlendChannel, BlendValue, Equation, Factor};
+
+    let keep_alpha = BlendChannel {
+        equation: Equation::Add,
+        source: Factor::Zero,
+        destination: Factor::One,
+    };
+
+    match blend_mode {
+        BlendMode::Alpha => gfx::preset::blend::ALPHA,
+        BlendMode::Additive => gfx::state::Blend {
+            color: BlendChannel {
+                equation: Equation::Add,
+                source: Factor::ZeroPlus(BlendValue::SourceAlpha),
+                destination: Factor::One,
+            },
+            alpha: keep_alpha,
+        },
+        BlendMode::Multiply => gfx::state::Blend {
+            color: BlendChannel {
+                equation: Equation::Add,
+                source: Factor::ZeroPlus(BlendValue::DestColor),
+                destination: Factor::Zero,
+            },
+            alpha: keep_alpha,
+        },
+        BlendMode::Premultiplied => gfx::state::Blend {
+            color: BlendChannel {
+                equation: Equation::Add,
+                source: Factor::One,
+                destination: Factor::OneMinus(BlendValue::SourceAlpha),
+            },
+            alpha: BlendChannel {
+                equation: Equation::Add,
+                source: Factor::One,
+                destination: Factor::OneMinus(BlendValue::SourceAlpha),
+            },
+        },
     }
 }
@@ -1,10 +1,17 @@
+use std::collections::HashMap;
+
 use gfx::traits::FactoryExt;
 use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
 use super::texture::Texture;
-use crate::graphics::{self, Transformation};
+use super::types::DepthView;
+use crate::graphics::gpu;
+use crate::graphics::{
+    BlendMode, FilterMode, ImageSettings, Rectangle, Sampler, Transformation,
+    WrapMode,
+};
 
 const MAX_INSTANCES: u32 = 100_000;
 const QUAD_INDICES: [u16; 6] = [0, 1, 2, 0, 2, 3];
@@ -34,6 +41,12 @@ gfx_defines! {
         translation: [f32; 2] = "a_Translation",
         scale: [f32; 2] = "a_Scale",
         layer: u32 = "t_Layer",
+        color_top_left: [f32; 4] = "a_ColorTopLeft",
+        color_top_right: [f32; 4] = "a_ColorTopRight",
+        color_bottom_right: [f32; 4] = "a_ColorBottomRight",
+        color_bottom_left: [f32; 4] = "a_ColorBottomLeft",
+        origin: [f32; 2] = "a_Origin",
+        rotation: f32 = "a_Rotation",
     }
 
     constant Globals {
@@ -52,6 +65,24 @@ gfx_defines! {
                gfx::state::ColorMask::all(),
                Some(gfx::preset::blend::ALPHA)
           ),
+        scissor: gfx::Scissor = (),
+    }
+
+    pipeline depth_pipe {
+        vertices: gfx::VertexBuffer<Vertex> = (),
+        texture: gfx::TextureSampler<[f32; 4]> = "t_Texture",
+        globals: gfx::ConstantBuffer<Globals> = "Globals",
+        instances: gfx::InstanceBuffer<Quad> = (),
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               Some(gfx::preset::blend::ALPHA)
+          ),
+        scissor: gfx::Scissor = (),
+        depth: gfx::DepthTarget<gfx::format::DepthStencil> =
+            gfx::preset::depth::LESS_EQUAL_WRITE,
     }
 }
 
@@ -59,7 +90,11 @@ pub struct Pipeline {
     slice: gfx::Slice<gl::Resources>,
     data: pipe::Data<gl::Resources>,
     shader: Shader,
+    distance_field_shader: Shader,
     globals: Globals,
+    samplers: HashMap<Sampler, gfx::handle::Sampler<gl::Resources>>,
+    // The instances of the current draw, converted to the vertex format
+    staging: Vec<Quad>,
 }
 
 impl Pipeline {
@@ -81,10 +116,10 @@ impl Pipeline {
         let (quads, slice) = factory
             .create_vertex_buffer_with_slice(&QUAD_VERTS, &QUAD_INDICES[..]);
 
-        let sampler = factory.create_sampler(gfx::texture::SamplerInfo::new(
-            gfx::texture::FilterMethod::Scale,
-            gfx::texture::WrapMode::Clamp,
-        ));
+        let sampler = factory.create_sampler(sampler_info(Sampler::default()));
+
+        let mut samplers = HashMap::new();
+        let _ = samplers.insert(Sampler::default(), sampler.clone());
 
         let texture = Texture::new(
             factory,
@@ -93,6 +128,7 @@ impl Pipeline {
                 1,
                 image::Rgba([255, 255, 255, 255]),
             )),
+            ImageSettings::default(),
         );
 
         let data = pipe::Data {
@@ -101,19 +137,11 @@ impl Pipeline {
             globals: factory.create_constant_buffer(1),
             instances,
             out: target.clone(),
+            scissor: super::scissor_rect(target, None),
         };
 
-        let init = pipe::Init {
-            out: (
-                "Target0",
-                format::COLOR,
-                gfx::state::ColorMask::all(),
-                Some(gfx::preset::blend::ALPHA),
-            ),
-            ..pipe::new()
-        };
-
-        let shader = Shader::new(factory, init);
+        let shader = Shader::new(factory);
+        let distance_field_shader = Shader::distance_field(factory);
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -127,33 +155,107 @@ impl Pipeline {
             slice,
             data,
             shader,
+            distance_field_shader,
             globals,
+            samplers,
+            staging: Vec::new(),
         }
     }
 
-    pub fn bind_texture(&mut self, texture: &Texture) {
-        self.data.texture.0 = texture.view().clone();
+    pub fn reload_shader(
+        &mut self,
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<(), String> {
+        self.shader = Shader::from_source(factory, vertex, fragment)?;
+
+        Ok(())
+    }
+
+    pub fn reload_distance_field_shader(
+        &mut self,
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<(), String> {
+        self.distance_field_shader =
+            Shader::from_source(factory, vertex, fragment)?;
+
+        Ok(())
+    }
+
+    pub fn bind_texture(
+        &mut self,
+        factory: &mut gl::Factory,
+        texture: &Texture,
+    ) {
+        let sampler =
+            self.samplers.entry(texture.sampler()).or_insert_with(|| {
+                factory.create_sampler(sampler_info(texture.sampler()))
+            });
+
+        self.data.texture = (texture.view().clone(), sampler.clone());
     }
 
     pub fn draw_textured(
         &mut self,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
-        instances: &[Quad],
+        instances: &[gpu::Quad],
         transformation: &Transformation,
         view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        depth: Option<&DepthView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
-        let transformation_matrix: [[f32; 4]; 4] =
-            transformation.clone().into();
-
-        if self.globals.mvp != transformation_matrix {
-            self.globals.mvp = transformation_matrix;
+        self.draw(
+            encoder,
+            instances,
+            transformation,
+            view,
+            depth,
+            scissor,
+            blend_mode,
+            false,
+        );
+    }
 
-            encoder
-                .update_buffer(&self.data.globals, &[self.globals], 0)
-                .expect("Globals upload");
-        }
+    /// Draws the quads treating the alpha channel of the bound texture as a
+    /// distance field, like the glyph atlas of a distance-field font.
+    pub fn draw_distance_field(
+        &mut self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        instances: &[gpu::Quad],
+        transformation: &Transformation,
+        view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        depth: Option<&DepthView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        self.draw(
+            encoder,
+            instances,
+            transformation,
+            view,
+            depth,
+            scissor,
+            blend_mode,
+            true,
+        );
+    }
 
-        self.data.out = view.clone();
+    fn draw(
+        &mut self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        instances: &[gpu::Quad],
+        transformation: &Transformation,
+        view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        depth: Option<&DepthView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+        is_distance_field: bool,
+    ) {
+        self.prepare(encoder, transformation, view, scissor);
 
         let mut i = 0;
         let total = instances.len();
@@ -161,64 +263,296 @@ impl Pipeline {
         while i < total {
             let end = (i + MAX_INSTANCES as usize).min(total);
 
+            self.staging.clear();
+            self.staging
+                .extend(instances[i..end].iter().map(Quad::from));
+
             encoder
-                .update_buffer(&self.data.instances, &instances[i..end], 0)
+                .update_buffer(&self.data.instances, &self.staging, 0)
                 .expect("Instance upload");
 
             self.slice.instances = Some((end as u32 - i as u32, 0));
 
-            encoder.draw(&self.slice, &self.shader.state, &self.data);
+            let shader = if is_distance_field {
+                &self.distance_field_shader
+            } else {
+                &self.shader
+            };
+
+            self.submit(encoder, shader, depth, blend_mode);
 
             i += MAX_INSTANCES as usize;
         }
     }
+
+    pub fn draw_instance_buffer(
+        &mut self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        instances: &InstanceBuffer,
+        transformation: &Transformation,
+        view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        depth: Option<&DepthView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        if let Some(buffer) = &instances.raw {
+            self.prepare(encoder, transformation, view, scissor);
+
+            // The instances are read from the given buffer for a single draw
+            let dynamic =
+                std::mem::replace(&mut self.data.instances, buffer.clone());
+
+            self.slice.instances = Some((instances.len, 0));
+
+            self.submit(encoder, &self.shader, depth, blend_mode);
+
+            self.data.instances = dynamic;
+        }
+    }
+
+    // Draws the current instances, testing them against the given depth
+    // buffer, if any
+    fn submit(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        shader: &Shader,
+        depth: Option<&DepthView>,
+        blend_mode: BlendMode,
+    ) {
+        match depth {
+            Some(depth) => {
+                let data = depth_pipe::Data {
+                    vertices: self.data.vertices.clone(),
+                    texture: self.data.texture.clone(),
+                    globals: self.data.globals.clone(),
+                    instances: self.data.instances.clone(),
+                    out: self.data.out.clone(),
+                    scissor: self.data.scissor,
+                    depth: depth.clone(),
+                };
+
+                encoder.draw(
+                    &self.slice,
+                    &shader.depth_states[&blend_mode],
+                    &data,
+                );
+            }
+            None => {
+                encoder.draw(
+                    &self.slice,
+                    &shader.states[&blend_mode],
+                    &self.data,
+                );
+            }
+        }
+    }
+
+    fn prepare(
+        &mut self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        transformation: &Transformation,
+        view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        scissor: Option<Rectangle<u32>>,
+    ) {
+        let transformation_matrix: [[f32; 4]; 4] =
+            transformation.clone().into();
+
+        if self.globals.mvp != transformation_matrix {
+            self.globals.mvp = transformation_matrix;
+
+            encoder
+                .update_buffer(&self.data.globals, &[self.globals], 0)
+                .expect("Globals upload");
+        }
+
+        self.data.out = view.clone();
+        self.data.scissor = super::scissor_rect(view, scissor);
+    }
+}
+
+#[derive(Clone)]
+pub struct InstanceBuffer {
+    raw: Option<gfx::handle::Buffer<gl::Resources, Quad>>,
+    len: u32,
+}
+
+impl InstanceBuffer {
+    pub fn new(factory: &mut gl::Factory, instances: &[gpu::Quad]) -> Self {
+        // Empty buffers cannot be created, but there is nothing to draw
+        let raw = if instances.is_empty() {
+            None
+        } else {
+            let instances: Vec<Quad> =
+                instances.iter().map(Quad::from).collect();
+
+            Some(
+                factory
+                    .create_buffer_immutable(
+                        &instances,
+                        gfx::buffer::Role::Vertex,
+                        gfx::memory::Bind::empty(),
+                    )
+                    .expect("Instance buffer creation"),
+            )
+        };
+
+        InstanceBuffer {
+            raw,
+            len: instances.len() as u32,
+        }
+    }
 }
 
 pub struct Shader {
-    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    states:
+        HashMap<BlendMode, gfx::pso::PipelineState<gl::Resources, pipe::Meta>>,
+    depth_states: HashMap<
+        BlendMode,
+        gfx::pso::PipelineState<gl::Resources, depth_pipe::Meta>,
+    >,
 }
 
 impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
+    pub fn new(factory: &mut gl::Factory) -> Shader {
+        Shader::from_source(
+            factory,
+            include_bytes!("shader/quad.vert"),
+            include_bytes!("shader/quad.frag"),
+        )
+        .expect("Shader creation")
+    }
+
+    pub fn distance_field(factory: &mut gl::Factory) -> Shader {
+        Shader::from_source(
+            factory,
+            include_bytes!("shader/quad.vert"),
+            include_bytes!("shader/distance_field.frag"),
+        )
+        .expect("Distance field shader creation")
+    }
+
+    pub fn from_source(
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<Shader, String> {
         let set = factory
-            .create_shader_set(
-                include_bytes!("shader/quad.vert"),
-                include_bytes!("shader/quad.frag"),
-            )
-            .expect("Shader set creation");
+            .create_shader_set(vertex, fragment)
+            .map_err(|error| error.to_string())?;
 
         let rasterizer = gfx::state::Rasterizer {
             front_face: gfx::state::FrontFace::CounterClockwise,
             cull_face: gfx::state::CullFace::Nothing,
             method: gfx::state::RasterMethod::Fill,
             offset: None,
-            samples: None,
+            samples: Some(gfx::state::MultiSample),
         };
 
-        let state = factory
-            .create_pipeline_state(
-                &set,
-                Primitive::TriangleList,
-                rasterizer,
-                init,
-            )
-            .expect("Pipeline state creation");
+        // A pipeline state is created for every blend mode, as the blend
+        // state cannot be changed when drawing
+        let states: HashMap<_, _> = BlendMode::ALL
+            .iter()
+            .map(|mode| {
+                factory
+                    .create_pipeline_state(
+                        &set,
+                        Primitive::TriangleList,
+                        rasterizer,
+                        init(*mode),
+                    )
+                    .map(|state| (*mode, state))
+                    .map_err(|error| error.to_string())
+            })
+            .collect::<Result<_, String>>()?;
+
+        let depth_states: HashMap<_, _> = BlendMode::ALL
+            .iter()
+            .map(|mode| {
+                factory
+                    .create_pipeline_state(
+                        &set,
+                        Primitive::TriangleList,
+                        rasterizer,
+                        depth_init(*mode),
+                    )
+                    .map(|state| (*mode, state))
+                    .map_err(|error| error.to_string())
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Shader {
+            states,
+            depth_states,
+        })
+    }
+}
 
-        Shader { state }
+fn init(blend_mode: BlendMode) -> pipe::Init<'static> {
+    pipe::Init {
+        out: (
+            "Target0",
+            format::COLOR,
+            gfx::state::ColorMask::all(),
+            Some(super::blend(blend_mode)),
+        ),
+        ..pipe::new()
     }
 }
 
-impl From<graphics::Quad> for Quad {
-    fn from(quad: graphics::Quad) -> Quad {
-        let source = quad.source;
-        let position = quad.position;
-        let (width, height) = quad.size;
+fn depth_init(blend_mode: BlendMode) -> depth_pipe::Init<'static> {
+    depth_pipe::Init {
+        out: (
+            "Target0",
+            format::COLOR,
+            gfx::state::ColorMask::all(),
+            Some(super::blend(blend_mode)),
+        ),
+        ..depth_pipe::new()
+    }
+}
+
+fn sampler_info(sampler: Sampler) -> gfx::texture::SamplerInfo {
+    let filter = if sampler.anisotropy > 1 {
+        gfx::texture::FilterMethod::Anisotropic(sampler.anisotropy)
+    } else {
+        // OpenGL cannot mix filters within and between mipmap levels, so the
+        // filter of the levels themselves is used
+        match (sampler.filter, sampler.mipmap_filter) {
+            (FilterMode::Nearest, None) => gfx::texture::FilterMethod::Scale,
+            (FilterMode::Nearest, Some(_)) => {
+                gfx::texture::FilterMethod::Mipmap
+            }
+            (FilterMode::Linear, None) => gfx::texture::FilterMethod::Bilinear,
+            (FilterMode::Linear, Some(_)) => {
+                gfx::texture::FilterMethod::Trilinear
+            }
+        }
+    };
+
+    let wrap = match sampler.wrap {
+        WrapMode::Clamp => gfx::texture::WrapMode::Clamp,
+        WrapMode::Repeat => gfx::texture::WrapMode::Tile,
+        WrapMode::MirrorRepeat => gfx::texture::WrapMode::Mirror,
+    };
+
+    gfx::texture::SamplerInfo::new(filter, wrap)
+}
+
+impl From<&gpu::Quad> for Quad {
+    fn from(quad: &gpu::Quad) -> Quad {
+        let [top_left, top_right, bottom_right, bottom_left] = quad.colors;
 
         Quad {
-            src: [source.x, source.y, source.width, source.height],
-            translation: [position.x, position.y],
-            scale: [width, height],
-            layer: 0,
+            src: quad.source,
+            translation: quad.translation,
+            scale: quad.scale,
+            layer: quad.layer,
+            color_top_left: top_left,
+            color_top_right: top_right,
+            color_bottom_right: bottom_right,
+            color_bottom_left: bottom_left,
+            origin: quad.origin,
+            rotation: quad.rotation,
         }
     }
 }
@@ -0,0 +1,99 @@
+use gfx::traits::FactoryExt;
+use gfx::{self, *};
+use gfx_device_gl as gl;
+
+use super::format;
+use super::texture::Multisample;
+
+gfx_defines! {
+    pipeline pipe {
+        samples: gfx::RawShaderResource = "t_Samples",
+        count: gfx::Global<i32> = "u_Samples",
+        out: gfx::RawRenderTarget =
+          (
+              "Target0",
+               format::COLOR,
+               gfx::state::ColorMask::all(),
+               None
+          ),
+    }
+}
+
+// Averages the samples of multisampled attachments into their textures
+pub struct Pipeline {
+    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+}
+
+impl Pipeline {
+    pub fn new(factory: &mut gl::Factory) -> Pipeline {
+        Pipeline::from_source(
+            factory,
+            include_bytes!("shader/resolve.vert"),
+            include_bytes!("shader/resolve.frag"),
+        )
+        .expect("Resolve shader creation")
+    }
+
+    fn from_source(
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<Pipeline, String> {
+        let set = factory
+            .create_shader_set(vertex, fragment)
+            .map_err(|error| error.to_string())?;
+
+        let rasterizer = gfx::state::Rasterizer {
+            front_face: gfx::state::FrontFace::CounterClockwise,
+            cull_face: gfx::state::CullFace::Nothing,
+            method: gfx::state::RasterMethod::Fill,
+            offset: None,
+            samples: None,
+        };
+
+        let state = factory
+            .create_pipeline_state(
+                &set,
+                Primitive::TriangleList,
+                rasterizer,
+                pipe::new(),
+            )
+            .map_err(|error| error.to_string())?;
+
+        Ok(Pipeline { state })
+    }
+
+    pub fn reload_shader(
+        &mut self,
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<(), String> {
+        *self = Pipeline::from_source(factory, vertex, fragment)?;
+
+        Ok(())
+    }
+
+    pub fn resolve(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        multisample: &Multisample,
+    ) {
+        let data = pipe::Data {
+            samples: multisample.samples().clone(),
+            count: i32::from(multisample.count()),
+            out: multisample.resolve().clone(),
+        };
+
+        // The vertex shader generates a triangle covering the whole target
+        let slice = gfx::Slice {
+            start: 0,
+            end: 3,
+            base_vertex: 0,
+            instances: None,
+            buffer: gfx::IndexBuffer::Auto,
+        };
+
+        encoder.draw(&slice, &self.state, &data);
+    }
+}
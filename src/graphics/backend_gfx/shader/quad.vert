@@ -6,6 +6,12 @@ in vec4 a_Src;
 in vec2 a_Scale;
 in vec2 a_Translation;
 in uint t_Layer;
+in vec4 a_ColorTopLeft;
+in vec4 a_ColorTopRight;
+in vec4 a_ColorBottomRight;
+in vec4 a_ColorBottomLeft;
+in vec2 a_Origin;
+in float a_Rotation;
 
 layout (std140) uniform Globals {
     mat4 u_MVP;
@@ -13,19 +19,23 @@ layout (std140) uniform Globals {
 
 out vec2 v_Uv;
 flat out uint v_Layer;
+out vec4 v_Color;
 
 void main() {
     v_Uv = a_Pos * a_Src.zw + a_Src.xy;
     v_Layer = t_Layer;
-
-    mat4 instance_transform = mat4(
-        vec4(a_Scale.x, 0.0, 0.0, 0.0),
-        vec4(0.0, a_Scale.y, 0.0, 0.0),
-        vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(a_Translation, 0.0, 1.0)
+    v_Color = mix(
+        mix(a_ColorTopLeft, a_ColorTopRight, a_Pos.x),
+        mix(a_ColorBottomLeft, a_ColorBottomRight, a_Pos.x),
+        a_Pos.y
     );
 
-    vec4 position = u_MVP * instance_transform * vec4(a_Pos, 0.0, 1.0);
+    // The quad is rotated around its origin, relative to its position
+    float c = cos(a_Rotation);
+    float s = sin(a_Rotation);
+
+    vec2 local = a_Pos * a_Scale - a_Origin;
+    vec2 position = mat2(c, s, -s, c) * local + a_Origin + a_Translation;
 
-    gl_Position = position;
+    gl_Position = u_MVP * vec4(position, 0.0, 1.0);
 }
@@ -0,0 +1,17 @@
+#version 150 core
+
+uniform sampler2DMS t_Samples;
+uniform int u_Samples;
+
+out vec4 Target0;
+
+void main() {
+    ivec2 position = ivec2(gl_FragCoord.xy);
+    vec4 color = vec4(0.0);
+
+    for (int i = 0; i < u_Samples; i++) {
+        color += texelFetch(t_Samples, position, i);
+    }
+
+    Target0 = color / float(u_Samples);
+}
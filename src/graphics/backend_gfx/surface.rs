@@ -12,14 +12,16 @@ impl Surface {
     pub(super) fn new(
         builder: winit::window::WindowBuilder,
         event_loop: &winit::event_loop::EventLoop<()>,
+        multisampling: u16,
+        vsync: bool,
     ) -> Result<(Self, gl::Device, gl::Factory)> {
         let gl_builder = glutin::ContextBuilder::new()
             .with_gl(glutin::GlRequest::Latest)
             .with_gl_profile(glutin::GlProfile::Core)
-            .with_multisampling(0)
+            .with_multisampling(multisampling)
             // 24 color bits, 8 alpha bits
             .with_pixel_format(24, 8)
-            .with_vsync(true);
+            .with_vsync(vsync);
 
         let (context, device, factory, target, _depth) = init_raw(
             builder,
@@ -64,10 +66,23 @@ impl Surface {
         self.context.window().request_redraw();
     }
 
-    pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
-        gpu.flush();
-        self.context.swap_buffers().expect("Buffer swap");
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> bool {
+        gpu.flush_frame();
+
+        // A failed swap is unexpected, so it is reported besides counting the
+        // frame as dropped
+        let is_presented = match self.context.swap_buffers() {
+            Ok(()) => true,
+            Err(error) => {
+                log::error!("Frame could not be presented: {}", error);
+
+                false
+            }
+        };
+
         gpu.cleanup();
+
+        is_presented
     }
 }
 
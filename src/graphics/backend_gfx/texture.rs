@@ -1,3 +1,5 @@
+use std::rc::Rc;
+
 use image;
 
 use gfx::format::{ChannelTyped, SurfaceTyped};
@@ -7,9 +9,14 @@ use gfx_core::factory::Factory;
 use gfx_device_gl as gl;
 
 use super::format::{Channel, Surface};
-use super::types::{RawTexture, ShaderResource, TargetView};
+use super::types::{
+    DepthView, RawShaderResource, RawTexture, ShaderResource, TargetView,
+};
+use crate::graphics::image_settings::{self, ImageSettings};
+use crate::graphics::mipmap;
+use crate::graphics::resource_stats::Allocation;
 use crate::graphics::vector::Vector;
-use crate::graphics::Transformation;
+use crate::graphics::{ResourceId, Sampler, Transformation};
 
 #[derive(Clone, Debug)]
 pub struct Texture {
@@ -18,22 +25,35 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    sampler: Sampler,
+    premultiplied: bool,
+    id: ResourceId,
+    multisample: Option<Multisample>,
+    // Only kept to track the memory of the texture
+    _allocation: Rc<Allocation>,
 }
 
 impl Texture {
     pub(super) fn new(
         factory: &mut gl::Factory,
         image: &image::DynamicImage,
+        settings: ImageSettings,
     ) -> Texture {
-        let rgba = image.to_rgba();
+        let mut rgba = image.to_rgba();
         let width = rgba.width() as u16;
         let height = rgba.height() as u16;
 
+        if settings.premultiply {
+            image_settings::premultiply(&mut rgba);
+        }
+
         let (raw, view) = create_texture_array(
             factory,
             width,
             height,
+            1,
             Some(&[&rgba]),
+            settings.srgb,
             gfx::memory::Bind::SHADER_RESOURCE
                 | gfx::memory::Bind::TRANSFER_SRC,
         );
@@ -44,27 +64,55 @@ impl Texture {
             width,
             height,
             layers: 1,
+            sampler: Sampler::default(),
+            premultiplied: settings.premultiply,
+            id: ResourceId::new(),
+            multisample: None,
+            _allocation: Rc::new(Allocation::texture(width, height, 1)),
         }
     }
 
     pub(super) fn new_array(
         factory: &mut gl::Factory,
         layers: &[image::DynamicImage],
+        mipmaps: bool,
+        settings: ImageSettings,
     ) -> Texture {
         let first_layer = &layers[0].to_rgba();
         let width = first_layer.width() as u16;
         let height = first_layer.height() as u16;
 
-        let rgba: Vec<Vec<u8>> =
-            layers.iter().map(|i| i.to_rgba().into_raw()).collect();
-
+        // The levels of every layer are provided one after the other
+        let rgba: Vec<Vec<u8>> = layers
+            .iter()
+            .flat_map(|layer| {
+                let mut layer = layer.to_rgba();
+
+                // Mipmaps are only correct when built from premultiplied
+                // pixels
+                if settings.premultiply {
+                    image_settings::premultiply(&mut layer);
+                }
+
+                if mipmaps {
+                    mipmap::chain(&layer)
+                } else {
+                    vec![layer]
+                }
+            })
+            .map(|level| level.into_raw())
+            .collect();
+
+        let levels = rgba.len() / layers.len();
         let raw_layers: Vec<&[u8]> = rgba.iter().map(|i| &i[..]).collect();
 
         let (raw, view) = create_texture_array(
             factory,
             width,
             height,
+            levels as u8,
             Some(&raw_layers[..]),
+            settings.srgb,
             gfx::memory::Bind::SHADER_RESOURCE
                 | gfx::memory::Bind::TRANSFER_SRC,
         );
@@ -75,9 +123,126 @@ impl Texture {
             width,
             height,
             layers: layers.len() as u16,
+            sampler: Sampler::default(),
+            premultiplied: settings.premultiply,
+            id: ResourceId::new(),
+            multisample: None,
+            _allocation: Rc::new(Allocation::texture(
+                width,
+                height,
+                layers.len() as u16,
+            )),
         }
     }
 
+    pub(super) fn new_empty(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+        settings: ImageSettings,
+    ) -> Texture {
+        let (raw, view) = create_texture_array(
+            factory,
+            width,
+            height,
+            1,
+            None,
+            settings.srgb,
+            gfx::memory::Bind::SHADER_RESOURCE
+                | gfx::memory::Bind::TRANSFER_SRC
+                | gfx::memory::Bind::TRANSFER_DST,
+        );
+
+        Texture {
+            raw,
+            view,
+            width,
+            height,
+            layers: 1,
+            sampler: Sampler::default(),
+            premultiplied: settings.premultiply,
+            id: ResourceId::new(),
+            multisample: None,
+            _allocation: Rc::new(Allocation::texture(width, height, 1)),
+        }
+    }
+
+    pub(super) fn update(
+        &self,
+        encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
+        y: u16,
+        rgba: &[u8],
+    ) {
+        let rows = rgba.len() / (4 * usize::from(self.width.max(1)));
+        let texture: gfx::handle::Texture<gl::Resources, Surface> =
+            Typed::new(self.raw.clone());
+
+        encoder
+            .update_texture::<Surface, gfx::format::Srgba8>(
+                &texture,
+                None,
+                gfx::texture::NewImageInfo {
+                    xoffset: 0,
+                    yoffset: y,
+                    zoffset: 0,
+                    width: self.width,
+                    height: rows as u16,
+                    depth: 1,
+                    format: (),
+                    mipmap: 0,
+                },
+                gfx::memory::cast_slice(rgba),
+            )
+            .expect("Update texture rows");
+    }
+
+    pub(super) fn read_pixels(
+        &self,
+        device: &mut gl::Device,
+        factory: &mut gl::Factory,
+    ) -> image::DynamicImage {
+        let width = self.width;
+        let height = self.height;
+
+        let download = factory
+            .create_download_buffer::<u8>(width as usize * height as usize * 4)
+            .expect("Create download buffer");
+
+        let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
+            factory.create_command_buffer().into();
+
+        encoder
+            .copy_texture_to_buffer_raw(
+                &self.raw,
+                None,
+                gfx::texture::RawImageInfo {
+                    xoffset: 0,
+                    yoffset: 0,
+                    zoffset: 0,
+                    width,
+                    height,
+                    depth: 0,
+                    format: <gfx::format::Srgba8 as gfx::format::Formatted>::get_format(),
+                    mipmap: 0,
+                },
+                download.raw(),
+                0,
+            )
+            .expect("Copy texture to raw buffer");
+
+        encoder.flush(device);
+
+        let reader = factory.read_mapping(&download).expect("Read mapping");
+
+        let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
+        rgba.extend(reader.into_iter());
+
+        image::DynamicImage::ImageRgba8(
+            image::ImageBuffer::from_raw(width as u32, height as u32, rgba)
+                .expect("Create RGBA8 image"),
+        )
+    }
+
     pub(super) fn handle(&self) -> &RawTexture {
         &self.raw
     }
@@ -86,6 +251,10 @@ impl Texture {
         &self.view
     }
 
+    pub(super) fn multisample(&self) -> Option<&Multisample> {
+        self.multisample.as_ref()
+    }
+
     pub fn width(&self) -> u16 {
         self.width
     }
@@ -93,21 +262,53 @@ impl Texture {
     pub fn height(&self) -> u16 {
         self.height
     }
+
+    pub fn sampler(&self) -> Sampler {
+        self.sampler
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
+
+    pub fn is_premultiplied(&self) -> bool {
+        self.premultiplied
+    }
+
+    pub fn id(&self) -> ResourceId {
+        self.id
+    }
 }
 
 #[derive(Clone)]
 pub struct Drawable {
     texture: Texture,
     target: TargetView,
+    depth: Option<Depth>,
+}
+
+// The depth buffer of a drawable texture
+#[derive(Clone)]
+struct Depth {
+    view: DepthView,
+    // Only kept to track the memory of the buffer
+    _allocation: Rc<Allocation>,
 }
 
 impl Drawable {
-    pub fn new(factory: &mut gl::Factory, width: u16, height: u16) -> Drawable {
+    pub fn new(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+        has_depth: bool,
+    ) -> Drawable {
         let (raw, view) = create_texture_array(
             factory,
             width,
             height,
+            1,
             None,
+            true,
             gfx::memory::Bind::SHADER_RESOURCE
                 | gfx::memory::Bind::RENDER_TARGET
                 | gfx::memory::Bind::TRANSFER_SRC,
@@ -119,6 +320,11 @@ impl Drawable {
             width,
             height,
             layers: 1,
+            sampler: Sampler::default(),
+            premultiplied: false,
+            id: ResourceId::new(),
+            multisample: None,
+            _allocation: Rc::new(Allocation::texture(width, height, 1)),
         };
 
         let render_desc = gfx::texture::RenderDesc {
@@ -131,7 +337,46 @@ impl Drawable {
             .view_texture_as_render_target_raw(texture.handle(), render_desc)
             .expect("View texture as render target");
 
-        Drawable { texture, target }
+        let depth = if has_depth {
+            let view = factory
+                .create_depth_stencil_view_only(width, height)
+                .expect("Depth buffer creation");
+
+            Some(Depth {
+                view,
+                _allocation: Rc::new(Allocation::texture(width, height, 1)),
+            })
+        } else {
+            None
+        };
+
+        Drawable {
+            texture,
+            target,
+            depth,
+        }
+    }
+
+    pub fn multisampled(
+        factory: &mut gl::Factory,
+        width: u16,
+        height: u16,
+        samples: u8,
+    ) -> Drawable {
+        let mut drawable = Drawable::new(factory, width, height, false);
+
+        let multisample = Multisample::new(
+            factory,
+            width,
+            height,
+            samples,
+            drawable.target.clone(),
+        );
+
+        drawable.target = multisample.target.clone();
+        drawable.texture.multisample = Some(multisample);
+
+        drawable
     }
 
     pub fn texture(&self) -> &Texture {
@@ -142,55 +387,102 @@ impl Drawable {
         &self.target
     }
 
-    pub fn read_pixels(
-        &self,
-        device: &mut gl::Device,
+    pub fn depth(&self) -> Option<&DepthView> {
+        self.depth.as_ref().map(|depth| &depth.view)
+    }
+
+    pub fn render_transformation(&self) -> Transformation {
+        Transformation::nonuniform_scale(Vector::new(1.0, -1.0))
+    }
+}
+
+// The multisampled attachment a drawable texture is drawn on. Its samples are
+// averaged into the texture before the texture is read.
+#[derive(Clone, Debug)]
+pub(super) struct Multisample {
+    target: TargetView,
+    samples: RawShaderResource,
+    resolve: TargetView,
+    count: u8,
+    // Only kept to track the memory of the attachment
+    _allocation: Rc<Allocation>,
+}
+
+impl Multisample {
+    fn new(
         factory: &mut gl::Factory,
-    ) -> image::DynamicImage {
-        let width = self.texture.width();
-        let height = self.texture.height();
+        width: u16,
+        height: u16,
+        count: u8,
+        resolve: TargetView,
+    ) -> Multisample {
+        let info = gfx::texture::Info {
+            kind: gfx::texture::Kind::D2(
+                width,
+                height,
+                gfx::texture::AaMode::Multi(count),
+            ),
+            levels: 1,
+            format: Surface::get_surface_type(),
+            bind: gfx::memory::Bind::SHADER_RESOURCE
+                | gfx::memory::Bind::RENDER_TARGET,
+            usage: gfx::memory::Usage::Data,
+        };
 
-        let download = factory
-            .create_download_buffer::<u8>(width as usize * height as usize * 4)
-            .expect("Create download buffer");
+        let channel = Channel::get_channel_type();
 
-        let mut encoder: gfx::Encoder<gl::Resources, gl::CommandBuffer> =
-            factory.create_command_buffer().into();
+        let texture = factory
+            .create_texture_raw(info, Some(channel), None)
+            .expect("Multisampled texture creation");
 
-        encoder
-            .copy_texture_to_buffer_raw(
-                &self.texture.raw,
-                None,
-                gfx::texture::RawImageInfo {
-                    xoffset: 0,
-                    yoffset: 0,
-                    zoffset: 0,
-                    width,
-                    height,
-                    depth: 0,
-                    format: <gfx::format::Srgba8 as gfx::format::Formatted>::get_format(),
-                    mipmap: 0,
+        let target = factory
+            .view_texture_as_render_target_raw(
+                &texture,
+                gfx::texture::RenderDesc {
+                    channel,
+                    level: 0,
+                    layer: None,
                 },
-                download.raw(),
-                0,
             )
-            .expect("Copy texture to raw buffer");
-
-        encoder.flush(device);
-
-        let reader = factory.read_mapping(&download).expect("Read mapping");
+            .expect("View multisampled texture as render target");
+
+        let samples = factory
+            .view_texture_as_shader_resource_raw(
+                &texture,
+                gfx::texture::ResourceDesc {
+                    channel,
+                    layer: None,
+                    min: 0,
+                    max: 0,
+                    swizzle: gfx::format::Swizzle::new(),
+                },
+            )
+            .expect("View multisampled texture as a shader resource");
+
+        Multisample {
+            target,
+            samples,
+            resolve,
+            count,
+            // Every sample takes as much memory as a layer
+            _allocation: Rc::new(Allocation::texture(
+                width,
+                height,
+                u16::from(count),
+            )),
+        }
+    }
 
-        let mut rgba = Vec::with_capacity(width as usize * height as usize * 4);
-        rgba.extend(reader.into_iter());
+    pub fn samples(&self) -> &RawShaderResource {
+        &self.samples
+    }
 
-        image::DynamicImage::ImageRgba8(
-            image::ImageBuffer::from_raw(width as u32, height as u32, rgba)
-                .expect("Create RGBA8 image"),
-        )
+    pub fn resolve(&self) -> &TargetView {
+        &self.resolve
     }
 
-    pub fn render_transformation() -> Transformation {
-        Transformation::nonuniform_scale(Vector::new(1.0, -1.0))
+    pub fn count(&self) -> u8 {
+        self.count
     }
 }
 
@@ -199,25 +491,36 @@ fn create_texture_array(
     factory: &mut gl::Factory,
     width: u16,
     height: u16,
+    levels: u8,
     layers: Option<&[&[u8]]>,
+    srgb: bool,
     bind: gfx::memory::Bind,
 ) -> (RawTexture, ShaderResource) {
     let kind = gfx::texture::Kind::D2Array(
         width,
         height,
-        layers.map(|l| l.len()).unwrap_or(1) as u16,
+        layers.map(|l| l.len() / usize::from(levels)).unwrap_or(1) as u16,
         gfx::texture::AaMode::Single,
     );
 
     let info = gfx::texture::Info {
         kind: kind,
-        levels: 1,
+        levels,
         format: Surface::get_surface_type(),
         bind: bind,
-        usage: gfx::memory::Usage::Data,
+        // Textures written after creation need a dynamic usage
+        usage: if bind.contains(gfx::memory::Bind::TRANSFER_DST) {
+            gfx::memory::Usage::Dynamic
+        } else {
+            gfx::memory::Usage::Data
+        },
     };
 
-    let channel_type = Channel::get_channel_type();
+    let channel_type = if srgb {
+        Channel::get_channel_type()
+    } else {
+        gfx::format::ChannelType::Unorm
+    };
 
     let texture = factory
         .create_texture_raw(
@@ -1,9 +1,12 @@
+use std::collections::HashMap;
+
 use gfx::traits::FactoryExt;
 use gfx::{self, *};
 use gfx_device_gl as gl;
 
 use super::format;
-use crate::graphics::Transformation;
+use crate::graphics::gpu;
+use crate::graphics::{BlendMode, Rectangle, Transformation};
 
 gfx_defines! {
     vertex Vertex {
@@ -25,6 +28,7 @@ gfx_defines! {
                gfx::state::ColorMask::all(),
                Some(gfx::preset::blend::ALPHA)
           ),
+        scissor: gfx::Scissor = (),
     }
 }
 
@@ -33,6 +37,8 @@ pub struct Pipeline {
     indices: gfx::handle::Buffer<gl::Resources, u32>,
     shader: Shader,
     globals: Globals,
+    // The vertices of the current draw, converted to the vertex format
+    staging: Vec<Vertex>,
 }
 
 impl Pipeline {
@@ -65,19 +71,10 @@ impl Pipeline {
             vertices,
             globals: factory.create_constant_buffer(1),
             out: target.clone(),
+            scissor: super::scissor_rect(target, None),
         };
 
-        let init = pipe::Init {
-            out: (
-                "Target0",
-                format::COLOR,
-                gfx::state::ColorMask::all(),
-                Some(gfx::preset::blend::ALPHA),
-            ),
-            ..pipe::new()
-        };
-
-        let shader = Shader::new(factory, init);
+        let shader = Shader::new(factory);
 
         let globals = Globals {
             mvp: Transformation::identity().into(),
@@ -92,17 +89,31 @@ impl Pipeline {
             indices,
             shader,
             globals,
+            staging: Vec::new(),
         }
     }
 
+    pub fn reload_shader(
+        &mut self,
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<(), String> {
+        self.shader = Shader::from_source(factory, vertex, fragment)?;
+
+        Ok(())
+    }
+
     pub fn draw(
         &mut self,
         factory: &mut gl::Factory,
         encoder: &mut gfx::Encoder<gl::Resources, gl::CommandBuffer>,
-        vertices: &[Vertex],
+        vertices: &[gpu::Vertex],
         indices: &[u32],
         transformation: &Transformation,
         view: &gfx::handle::RawRenderTargetView<gl::Resources>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
         let transformation_matrix: [[f32; 4]; 4] =
             transformation.clone().into();
@@ -116,6 +127,7 @@ impl Pipeline {
         }
 
         self.data.out = view.clone();
+        self.data.scissor = super::scissor_rect(view, scissor);
 
         if self.data.vertices.len() < vertices.len()
             || self.indices.len() < indices.len()
@@ -142,8 +154,11 @@ impl Pipeline {
             self.indices = indices;
         }
 
+        self.staging.clear();
+        self.staging.extend(vertices.iter().map(Vertex::from));
+
         encoder
-            .update_buffer(&self.data.vertices, &vertices, 0)
+            .update_buffer(&self.data.vertices, &self.staging, 0)
             .expect("Vertex upload");
 
         encoder
@@ -158,46 +173,80 @@ impl Pipeline {
             buffer: gfx::IndexBuffer::Index32(self.indices.clone()),
         };
 
-        encoder.draw(&slice, &self.shader.state, &self.data);
+        encoder.draw(&slice, &self.shader.states[&blend_mode], &self.data);
     }
 }
 
 pub struct Shader {
-    state: gfx::pso::PipelineState<gl::Resources, pipe::Meta>,
+    states:
+        HashMap<BlendMode, gfx::pso::PipelineState<gl::Resources, pipe::Meta>>,
 }
 
 impl Shader {
-    pub fn new(factory: &mut gl::Factory, init: pipe::Init<'_>) -> Shader {
+    pub fn new(factory: &mut gl::Factory) -> Shader {
+        Shader::from_source(
+            factory,
+            include_bytes!("shader/triangle.vert"),
+            include_bytes!("shader/triangle.frag"),
+        )
+        .expect("Shader creation")
+    }
+
+    pub fn from_source(
+        factory: &mut gl::Factory,
+        vertex: &[u8],
+        fragment: &[u8],
+    ) -> Result<Shader, String> {
         let set = factory
-            .create_shader_set(
-                include_bytes!("shader/triangle.vert"),
-                include_bytes!("shader/triangle.frag"),
-            )
-            .expect("Shader set creation");
+            .create_shader_set(vertex, fragment)
+            .map_err(|error| error.to_string())?;
 
         let rasterizer = gfx::state::Rasterizer {
             front_face: gfx::state::FrontFace::CounterClockwise,
             cull_face: gfx::state::CullFace::Nothing,
             method: gfx::state::RasterMethod::Fill,
             offset: None,
-            samples: None,
+            samples: Some(gfx::state::MultiSample),
         };
 
-        let state = factory
-            .create_pipeline_state(
-                &set,
-                Primitive::TriangleList,
-                rasterizer,
-                init,
-            )
-            .expect("Pipeline state creation");
+        // A pipeline state is created for every blend mode, as the blend
+        // state cannot be changed when drawing
+        let states: HashMap<_, _> = BlendMode::ALL
+            .iter()
+            .map(|mode| {
+                factory
+                    .create_pipeline_state(
+                        &set,
+                        Primitive::TriangleList,
+                        rasterizer,
+                        init(*mode),
+                    )
+                    .map(|state| (*mode, state))
+                    .map_err(|error| error.to_string())
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Shader { states })
+    }
+}
 
-        Shader { state }
+fn init(blend_mode: BlendMode) -> pipe::Init<'static> {
+    pipe::Init {
+        out: (
+            "Target0",
+            format::COLOR,
+            gfx::state::ColorMask::all(),
+            Some(super::blend(blend_mode)),
+        ),
+        ..pipe::new()
     }
 }
 
-impl Vertex {
-    pub fn new(position: [f32; 2], color: [f32; 4]) -> Vertex {
-        Vertex { position, color }
+impl From<&gpu::Vertex> for Vertex {
+    fn from(vertex: &gpu::Vertex) -> Vertex {
+        Vertex {
+            position: vertex.position,
+            color: vertex.color,
+        }
     }
 }
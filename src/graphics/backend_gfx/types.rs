@@ -4,7 +4,13 @@ use super::format;
 
 pub type TargetView = gfx::handle::RawRenderTargetView<gl::Resources>;
 
+pub type DepthView =
+    gfx::handle::DepthStencilView<gl::Resources, gfx::format::DepthStencil>;
+
 pub type RawTexture = gfx::handle::RawTexture<gl::Resources>;
 
+pub type RawShaderResource =
+    gfx::handle::RawShaderResourceView<gl::Resources>;
+
 pub type ShaderResource =
     gfx::handle::ShaderResourceView<gl::Resources, format::View>;
@@ -1,6 +1,7 @@
-use crate::graphics::gpu::TargetView;
+use super::TargetView;
 use crate::graphics::{
-    HorizontalAlignment, Text, Transformation, VerticalAlignment,
+    FontMetrics, HorizontalAlignment, Rectangle, Text, Transformation,
+    VerticalAlignment,
 };
 
 use wgpu_glyph::GlyphCruncher;
@@ -19,18 +20,49 @@ impl Font {
         }
     }
 
+    pub fn add_face(&mut self, bytes: &'static [u8]) -> usize {
+        self.glyphs.add_font_bytes(bytes).0
+    }
+
     pub fn add(&mut self, text: Text<'_>) {
         let section: wgpu_glyph::Section<'_> = text.into();
         self.glyphs.queue(section);
     }
 
+    pub fn metrics(&self, face: usize, size: f32) -> FontMetrics {
+        let scale = wgpu_glyph::Scale::uniform(size);
+        let metrics = self.glyphs.fonts()[face].v_metrics(scale);
+
+        FontMetrics {
+            ascent: metrics.ascent,
+            descent: metrics.descent,
+            line_gap: metrics.line_gap,
+        }
+    }
+
     pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
+        let bounds = self.bounds(text);
+
+        (bounds.width, bounds.height)
+    }
+
+    pub fn bounds(&mut self, text: Text<'_>) -> Rectangle<f32> {
+        let (x, y) = (text.position.x, text.position.y);
         let section: wgpu_glyph::Section<'_> = text.into();
-        let bounds = self.glyphs.glyph_bounds(section);
 
-        match bounds {
-            Some(bounds) => (bounds.width(), bounds.height()),
-            None => (0.0, 0.0),
+        match self.glyphs.glyph_bounds(section) {
+            Some(bounds) => Rectangle {
+                x: bounds.min.x,
+                y: bounds.min.y,
+                width: bounds.width(),
+                height: bounds.height(),
+            },
+            None => Rectangle {
+                x,
+                y,
+                width: 0.0,
+                height: 0.0,
+            },
         }
     }
 
@@ -40,15 +72,32 @@ impl Font {
         encoder: &mut wgpu::CommandEncoder,
         target: &TargetView,
         transformation: Transformation,
+        scissor: Option<Rectangle<u32>>,
     ) {
-        self.glyphs
-            .draw_queued_with_transform(
+        let result = match scissor {
+            Some(scissor) => {
+                self.glyphs.draw_queued_with_transform_and_scissoring(
+                    device,
+                    encoder,
+                    target.view(),
+                    transformation.into(),
+                    wgpu_glyph::Region {
+                        x: scissor.x,
+                        y: scissor.y,
+                        width: scissor.width,
+                        height: scissor.height,
+                    },
+                )
+            }
+            None => self.glyphs.draw_queued_with_transform(
                 device,
                 encoder,
-                target,
+                target.view(),
                 transformation.into(),
-            )
-            .expect("Draw font");
+            ),
+        };
+
+        result.expect("Draw font");
     }
 }
 
@@ -63,7 +112,9 @@ impl<'a> From<Text<'a>> for wgpu_glyph::Section<'a> {
         };
 
         let y = match text.vertical_alignment {
-            VerticalAlignment::Top => text.position.y,
+            VerticalAlignment::Top | VerticalAlignment::Baseline => {
+                text.position.y
+            }
             VerticalAlignment::Center => text.position.y + text.bounds.1 / 2.0,
             VerticalAlignment::Bottom => text.position.y + text.bounds.1,
         };
@@ -77,6 +128,7 @@ impl<'a> From<Text<'a>> for wgpu_glyph::Section<'a> {
             },
             color: text.color.into_linear(),
             bounds: text.bounds,
+            font_id: wgpu_glyph::FontId(text.face.index()),
             layout: wgpu_glyph::Layout::default()
                 .h_align(text.horizontal_alignment.into())
                 .v_align(text.vertical_alignment.into()),
@@ -101,6 +153,7 @@ impl From<VerticalAlignment> for wgpu_glyph::VerticalAlign {
             VerticalAlignment::Top => wgpu_glyph::VerticalAlign::Top,
             VerticalAlignment::Center => wgpu_glyph::VerticalAlign::Center,
             VerticalAlignment::Bottom => wgpu_glyph::VerticalAlign::Bottom,
+            VerticalAlignment::Baseline => wgpu_glyph::VerticalAlign::Top,
         }
     }
 }
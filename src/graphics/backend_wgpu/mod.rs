@@ -1,49 +1,78 @@
 mod font;
+mod multisample;
 mod quad;
 mod surface;
-pub mod texture;
+mod texture;
 mod triangle;
 mod types;
 
 pub use font::Font;
-pub use quad::Quad;
+pub use quad::InstanceBuffer;
 pub use surface::Surface;
-pub use texture::Texture;
-pub use triangle::Vertex;
-pub use types::TargetView;
+pub use texture::{Drawable, Texture};
+pub use types::{DepthView, TargetView};
 
-use crate::graphics::{Color, Transformation};
+use crate::graphics::gpu::{Quad, Renderer, Vertex};
+use crate::graphics::{
+    AdapterInfo, Backend, BlendMode, Color, ImageSettings, Limits,
+    PowerPreference, Rectangle, ResourceStats, Transformation,
+};
 use crate::{Error, Result};
 
-#[allow(missing_debug_implementations)]
-#[allow(missing_docs)]
+// The maximum width and height of a texture, in pixels, that every `wgpu`
+// backend supports
+const MAX_TEXTURE_SIZE: u16 = 8192;
+
+// The maximum amount of layers of a texture array that every `wgpu` backend
+// supports
+const MAX_TEXTURE_LAYERS: u16 = 256;
+
 pub struct Gpu {
     device: wgpu::Device,
     queue: wgpu::Queue,
     quad_pipeline: quad::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    multisample: multisample::Multisample,
     encoder: wgpu::CommandEncoder,
+    info: AdapterInfo,
 }
 
 impl Gpu {
     pub(super) fn for_window(
         builder: winit::window::WindowBuilder,
         event_loop: &winit::event_loop::EventLoop<()>,
+        backend: Backend,
+        power_preference: PowerPreference,
+        multisampling: u16,
+        vsync: bool,
     ) -> Result<(Gpu, Surface)> {
-        let window = builder
-            .build(event_loop)
-            .map_err(|error| Error::WindowCreation(error.to_string()))?;
-
-        let (mut device, queue) = futures::executor::block_on(async {
-            let adapter = wgpu::Adapter::request(
-                &wgpu::RequestAdapterOptions {
-                    power_preference: wgpu::PowerPreference::HighPerformance,
-                    compatible_surface: None,
+        let (mut device, queue, info) = futures::executor::block_on(async {
+            let options = wgpu::RequestAdapterOptions {
+                power_preference: match power_preference {
+                    PowerPreference::Low => wgpu::PowerPreference::LowPower,
+                    PowerPreference::High => {
+                        wgpu::PowerPreference::HighPerformance
+                    }
                 },
-                wgpu::BackendBit::all(),
-            )
-            .await
-            .expect("Request adapter");
+                compatible_surface: None,
+            };
+
+            let mut adapter = None;
+
+            // Fall back to the other backends, in order, if the preferred one
+            // is not supported
+            for bits in std::iter::once(backend_bits(backend))
+                .chain(FALLBACK_BACKENDS.iter().cloned())
+            {
+                adapter = wgpu::Adapter::request(&options, bits).await;
+
+                if adapter.is_some() {
+                    break;
+                }
+            }
+
+            let adapter = adapter.ok_or(Error::AdapterNotFound)?;
+            let info = adapter_info(&adapter.get_info());
 
             let (device, queue) = adapter
                 .request_device(&wgpu::DeviceDescriptor {
@@ -54,13 +83,25 @@ impl Gpu {
                 })
                 .await;
 
-            (device, queue)
-        });
+            Ok::<_, Error>((device, queue, info))
+        })?;
 
-        let surface = Surface::new(window, &device);
+        // The window is only built once an adapter has been found, so the
+        // runtime can fall back to another backend otherwise
+        let window = builder
+            .build(event_loop)
+            .map_err(|error| Error::WindowCreation(error.to_string()))?;
+
+        let surface = Surface::new(
+            window,
+            &device,
+            vsync,
+            u32::from(multisampling.max(1)),
+        );
 
         let quad_pipeline = quad::Pipeline::new(&mut device);
         let triangle_pipeline = triangle::Pipeline::new(&mut device);
+        let multisample = multisample::Multisample::new(&device);
 
         let encoder =
             device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
@@ -73,18 +114,78 @@ impl Gpu {
                 queue,
                 quad_pipeline,
                 triangle_pipeline,
+                multisample,
                 encoder,
+                info,
             },
             surface,
         ))
     }
 
-    pub(super) fn clear(&mut self, view: &TargetView, color: Color) {
+    // Replaces the current encoder with a new one and returns it, so its
+    // commands can be submitted.
+    //
+    // The instances queued by the draw calls of the encoder are uploaded
+    // first.
+    pub(super) fn take_encoder(&mut self) -> wgpu::CommandEncoder {
+        if let Some(upload) = self.quad_pipeline.flush(&mut self.device) {
+            self.queue.submit(&[upload]);
+        }
+
+        let new_encoder = self.device.create_command_encoder(
+            &wgpu::CommandEncoderDescriptor {
+                label: Some("coffee::backend encoder"),
+            },
+        );
+
+        std::mem::replace(&mut self.encoder, new_encoder)
+    }
+}
+
+impl Renderer for Gpu {
+    type Texture = Texture;
+    type Drawable = Drawable;
+    type Font = Font;
+    type InstanceBuffer = InstanceBuffer;
+    type TargetView = TargetView;
+    type DepthView = DepthView;
+
+    // The pixels of a target start at its top-left corner
+    fn scissor(
+        &self,
+        region: &Rectangle<f32>,
+        width: f32,
+        height: f32,
+    ) -> Rectangle<u32> {
+        let left = ((region.x + 1.0) / 2.0 * width).round().max(0.0).min(width);
+        let right = ((region.x + region.width + 1.0) / 2.0 * width)
+            .round()
+            .max(left)
+            .min(width);
+
+        let top = ((1.0 - region.y - region.height) / 2.0 * height)
+            .round()
+            .max(0.0)
+            .min(height);
+        let bottom = ((1.0 - region.y) / 2.0 * height)
+            .round()
+            .max(top)
+            .min(height);
+
+        Rectangle {
+            x: left as u32,
+            y: top as u32,
+            width: (right - left) as u32,
+            height: (bottom - top) as u32,
+        }
+    }
+
+    fn clear(&mut self, view: &TargetView, color: Color) {
         let [r, g, b, a] = color.into_linear();
 
         let _ = self.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
             color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
-                attachment: &view,
+                attachment: view.view(),
                 resolve_target: None,
                 load_op: wgpu::LoadOp::Clear,
                 store_op: wgpu::StoreOp::Store,
@@ -99,98 +200,412 @@ impl Gpu {
         });
     }
 
-    pub(super) fn upload_texture(
+    fn clear_depth(&mut self, depth: &DepthView) {
+        let _ = self.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[],
+            depth_stencil_attachment: Some(
+                wgpu::RenderPassDepthStencilAttachmentDescriptor {
+                    attachment: depth,
+                    depth_load_op: wgpu::LoadOp::Clear,
+                    depth_store_op: wgpu::StoreOp::Store,
+                    clear_depth: 1.0,
+                    stencil_load_op: wgpu::LoadOp::Clear,
+                    stencil_store_op: wgpu::StoreOp::Store,
+                    clear_stencil: 0,
+                },
+            ),
+        });
+    }
+
+    fn info(&self) -> &AdapterInfo {
+        &self.info
+    }
+
+    // wgpu does not expose timestamp queries yet
+    fn frame_time(&self) -> Option<std::time::Duration> {
+        None
+    }
+
+    // The pending work is submitted and waited for, so every dropped texture
+    // is reclaimed right away
+    fn collect(&mut self) -> ResourceStats {
+        let encoder = self.take_encoder();
+
+        self.queue.submit(&[encoder.finish()]);
+        self.device.poll(wgpu::Maintain::Wait);
+
+        texture::collect_garbage();
+
+        ResourceStats::take()
+    }
+
+    // wgpu does not expose the texture limits of an adapter yet, so these are
+    // the minimums guaranteed by every supported backend
+    fn limits(&self) -> Limits {
+        Limits {
+            max_texture_size: MAX_TEXTURE_SIZE,
+            max_texture_layers: MAX_TEXTURE_LAYERS,
+        }
+    }
+
+    // The shaders are precompiled, so they cannot be reloaded
+    fn reload_shaders(&mut self) -> Option<std::result::Result<(), String>> {
+        None
+    }
+
+    fn upload_texture(
         &mut self,
         image: &image::DynamicImage,
+        settings: ImageSettings,
     ) -> Texture {
-        Texture::new(&mut self.device, &self.queue, &self.quad_pipeline, image)
+        Texture::new(
+            &mut self.device,
+            &self.queue,
+            &self.quad_pipeline,
+            image,
+            settings,
+        )
     }
 
-    pub(super) fn upload_texture_array(
+    fn upload_texture_array(
         &mut self,
         layers: &[image::DynamicImage],
+        mipmaps: bool,
+        settings: ImageSettings,
     ) -> Texture {
         Texture::new_array(
             &mut self.device,
             &self.queue,
             &self.quad_pipeline,
             layers,
+            mipmaps,
+            settings,
         )
     }
 
-    pub(super) fn create_drawable_texture(
+    fn create_texture(
         &mut self,
         width: u16,
         height: u16,
-    ) -> texture::Drawable {
-        texture::Drawable::new(
+        settings: ImageSettings,
+    ) -> Texture {
+        Texture::new_empty(
             &mut self.device,
             &self.queue,
             &self.quad_pipeline,
             width,
             height,
+            settings,
         )
     }
 
-    pub(super) fn read_drawable_texture_pixels(
+    fn update_texture(&mut self, texture: &Texture, y: u16, rgba: &[u8]) {
+        texture.update(&mut self.device, &mut self.encoder, y, rgba);
+    }
+
+    fn create_drawable_texture(
         &mut self,
-        drawable: &texture::Drawable,
-    ) -> image::DynamicImage {
-        let new_encoder = self.device.create_command_encoder(
-            &wgpu::CommandEncoderDescriptor {
-                label: Some("coffee::backend encoder"),
-            },
-        );
+        width: u16,
+        height: u16,
+        has_depth: bool,
+        samples: u16,
+    ) -> Drawable {
+        Drawable::new(
+            &mut self.device,
+            &self.queue,
+            &self.quad_pipeline,
+            width,
+            height,
+            has_depth,
+            u32::from(samples.max(1)),
+        )
+    }
 
-        let encoder = std::mem::replace(&mut self.encoder, new_encoder);
+    fn read_texture_pixels(
+        &mut self,
+        texture: &Texture,
+    ) -> image::DynamicImage {
+        let encoder = self.take_encoder();
 
-        drawable.read_pixels(&mut self.device, &self.queue, encoder)
+        texture.read_pixels(&mut self.device, &self.queue, encoder)
     }
 
-    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
+    fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
         Font::from_bytes(&mut self.device, bytes)
     }
 
-    pub(super) fn draw_triangles(
+    fn draw_triangles(
         &mut self,
         vertices: &[Vertex],
         indices: &[u32],
         view: &TargetView,
         transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
+        let target = self.multisample.begin(
+            &self.device,
+            &mut self.encoder,
+            view,
+            blend_mode,
+        );
+
         self.triangle_pipeline.draw(
             &mut self.device,
             &mut self.encoder,
             vertices,
             indices,
             transformation,
-            view,
+            target,
+            view.samples(),
+            scissor,
+            blend_mode,
         );
+
+        self.multisample.end(&mut self.encoder, view, blend_mode);
     }
 
-    pub(super) fn draw_texture_quads(
+    fn draw_texture_quads(
         &mut self,
         texture: &Texture,
         instances: &[Quad],
         view: &TargetView,
+        depth: Option<&DepthView>,
         transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
+        let target = self.multisample.begin(
+            &self.device,
+            &mut self.encoder,
+            view,
+            blend_mode,
+        );
+
         self.quad_pipeline.draw_textured(
             &mut self.device,
             &mut self.encoder,
             texture.binding(),
+            texture.sampler(),
             instances,
             transformation,
+            target,
+            view.samples(),
+            depth,
+            scissor,
+            blend_mode,
+        );
+
+        self.multisample.end(&mut self.encoder, view, blend_mode);
+    }
+
+    fn draw_distance_field_quads(
+        &mut self,
+        texture: &Texture,
+        instances: &[Quad],
+        view: &TargetView,
+        depth: Option<&DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        let target = self.multisample.begin(
+            &self.device,
+            &mut self.encoder,
+            view,
+            blend_mode,
+        );
+
+        self.quad_pipeline.draw_distance_field(
+            &mut self.device,
+            &mut self.encoder,
+            texture.binding(),
+            texture.sampler(),
+            instances,
+            transformation,
+            target,
+            view.samples(),
+            depth,
+            scissor,
+            blend_mode,
+        );
+
+        self.multisample.end(&mut self.encoder, view, blend_mode);
+    }
+
+    fn upload_instances(&mut self, instances: &[Quad]) -> InstanceBuffer {
+        InstanceBuffer::new(&mut self.device, instances)
+    }
+
+    fn draw_instance_buffer(
+        &mut self,
+        texture: &Texture,
+        instances: &InstanceBuffer,
+        view: &TargetView,
+        depth: Option<&DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        let target = self.multisample.begin(
+            &self.device,
+            &mut self.encoder,
             view,
+            blend_mode,
+        );
+
+        self.quad_pipeline.draw_instance_buffer(
+            &mut self.device,
+            &mut self.encoder,
+            texture.binding(),
+            texture.sampler(),
+            instances,
+            transformation,
+            target,
+            view.samples(),
+            depth,
+            scissor,
+            blend_mode,
         );
+
+        self.multisample.end(&mut self.encoder, view, blend_mode);
     }
 
-    pub(super) fn draw_font(
+    fn draw_font(
         &mut self,
         font: &mut Font,
         target: &TargetView,
         transformation: Transformation,
+        scissor: Option<Rectangle<u32>>,
     ) {
-        font.draw(&mut self.device, &mut self.encoder, target, transformation);
+        font.draw(
+            &mut self.device,
+            &mut self.encoder,
+            target,
+            transformation,
+            scissor,
+        );
+    }
+}
+
+// The backends tried, in order, when the preferred one has no adapter.
+//
+// OpenGL is the last stage of the chain, but `wgpu` cannot draw with it yet.
+// When none of these backends has an adapter, `Error::AdapterNotFound` is
+// returned and `graphics::Gpu` falls back to the `gfx` backend, as long as the
+// `opengl` feature is enabled.
+const FALLBACK_BACKENDS: [wgpu::BackendBit; 4] = [
+    wgpu::BackendBit::VULKAN,
+    wgpu::BackendBit::DX12,
+    wgpu::BackendBit::METAL,
+    wgpu::BackendBit::DX11,
+];
+
+fn adapter_info(info: &wgpu::AdapterInfo) -> AdapterInfo {
+    let backend = match info.backend {
+        wgpu::Backend::Vulkan => Backend::Vulkan,
+        wgpu::Backend::Metal => Backend::Metal,
+        wgpu::Backend::Dx12 => Backend::Dx12,
+        wgpu::Backend::Dx11 => Backend::Dx11,
+        wgpu::Backend::Gl => Backend::OpenGl,
+        _ => Backend::Auto,
+    };
+
+    // Adapters report the PCI identifier of their vendor
+    let vendor = match info.vendor {
+        0x1002 => String::from("AMD"),
+        0x106B => String::from("Apple"),
+        0x10DE => String::from("NVIDIA"),
+        0x13B5 => String::from("ARM"),
+        0x5143 => String::from("Qualcomm"),
+        0x8086 => String::from("Intel"),
+        id => format!("{:#06x}", id),
+    };
+
+    AdapterInfo {
+        name: info.name.clone(),
+        backend,
+        vendor,
+    }
+}
+
+fn backend_bits(backend: Backend) -> wgpu::BackendBit {
+    match backend {
+        // OpenGL is drawn with `gfx` instead, so it is never requested here
+        Backend::Auto | Backend::OpenGl => wgpu::BackendBit::PRIMARY,
+        Backend::Vulkan => wgpu::BackendBit::VULKAN,
+        Backend::Metal => wgpu::BackendBit::METAL,
+        Backend::Dx11 => wgpu::BackendBit::DX11,
+        Backend::Dx12 => wgpu::BackendBit::DX12,
+    }
+}
+
+// Maps the depth of the clip space used by the transformations, which goes
+// from -1 to 1 like in OpenGL, to the range wgpu expects, from 0 to 1.
+fn clip_space(transformation: &Transformation) -> Transformation {
+    #[rustfmt::skip]
+    let correction = nalgebra::Matrix4::new(
+        1.0, 0.0, 0.0, 0.0,
+        0.0, 1.0, 0.0, 0.0,
+        0.0, 0.0, 0.5, 0.5,
+        0.0, 0.0, 0.0, 1.0,
+    );
+
+    Transformation::from(correction) * *transformation
+}
+
+// The color and alpha blending of the pipelines drawing with the given blend
+// mode. The alpha channel of the view is kept when adding or multiplying
+// colors.
+fn blend(
+    blend_mode: BlendMode,
+) -> (wgpu::BlendDescriptor, wgpu::BlendDescriptor) {
+    let keep_alpha = wgpu::BlendDescriptor {
+        src_factor: wgpu::BlendFactor::Zero,
+        dst_factor: wgpu::BlendFactor::One,
+        operation: wgpu::BlendOperation::Add,
+    };
+
+    match blend_mode {
+        BlendMode::Alpha => (
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+        ),
+        BlendMode::Additive => (
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::SrcAlpha,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+            keep_alpha,
+        ),
+        BlendMode::Multiply => (
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::DstColor,
+                dst_factor: wgpu::BlendFactor::Zero,
+                operation: wgpu::BlendOperation::Add,
+            },
+            keep_alpha,
+        ),
+        BlendMode::Premultiplied => (
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                operation: wgpu::BlendOperation::Add,
+            },
+        ),
     }
 }
@@ -0,0 +1,378 @@
+use std::collections::HashMap;
+
+use super::types::TargetView;
+use crate::graphics::BlendMode;
+
+// Draws on views with multisample anti-aliasing.
+//
+// `wgpu_glyph` cannot draw on multisampled attachments, so views keep a
+// single sample per pixel. Instead, every draw call on a multisampled view is
+// rendered on a layer with as many samples and the same size. The layer is
+// resolved and blended on the view afterwards.
+//
+// Multisampled views have no depth buffer, so layers do not have one either.
+pub struct Multisample {
+    // The layers are keyed by width, height, and samples per pixel
+    layers: HashMap<(u32, u32, u32), Layer>,
+    layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    pipelines: HashMap<BlendMode, wgpu::RenderPipeline>,
+}
+
+impl Multisample {
+    pub fn new(device: &wgpu::Device) -> Multisample {
+        let layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("coffee::backend::multisample layer"),
+                bindings: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::SampledTexture {
+                            multisampled: false,
+                            dimension: wgpu::TextureViewDimension::D2,
+                            component_type: wgpu::TextureComponentType::Float,
+                        },
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStage::FRAGMENT,
+                        ty: wgpu::BindingType::Sampler { comparison: false },
+                    },
+                ],
+            });
+
+        // The layer is fetched pixel by pixel, so the sampler is never used
+        // to filter
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            lod_min_clamp: 0.0,
+            lod_max_clamp: 0.0,
+            compare: wgpu::CompareFunction::Always,
+        });
+
+        let pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                bind_group_layouts: &[&layout],
+            });
+
+        let vs = include_bytes!("shader/multisample.vert.spv");
+        let vs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&vs[..]))
+                .expect("Read multisample vertex shader as SPIR-V"),
+        );
+
+        let fs = include_bytes!("shader/multisample.frag.spv");
+        let fs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&fs[..]))
+                .expect("Read multisample fragment shader as SPIR-V"),
+        );
+
+        // A layer is blended on its view like the draw call it contains
+        let pipelines = BlendMode::ALL
+            .iter()
+            .map(|mode| {
+                let pipeline = create_pipeline(
+                    device,
+                    &pipeline_layout,
+                    &vs_module,
+                    &fs_module,
+                    *mode,
+                );
+
+                (*mode, pipeline)
+            })
+            .collect();
+
+        Multisample {
+            layers: HashMap::new(),
+            layout,
+            sampler,
+            pipelines,
+        }
+    }
+
+    /// Returns the view where a draw call on the given view must be rendered.
+    ///
+    /// The layer of a multisampled view is cleared first, and the draw call
+    /// must use as many samples per pixel as the view.
+    pub fn begin<'a>(
+        &'a mut self,
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &'a TargetView,
+        blend_mode: BlendMode,
+    ) -> &'a wgpu::TextureView {
+        if view.samples() <= 1 {
+            return view.view();
+        }
+
+        let Multisample {
+            layers,
+            layout,
+            sampler,
+            ..
+        } = self;
+
+        let key = (view.width(), view.height(), view.samples());
+
+        let layer = layers
+            .entry(key)
+            .or_insert_with(|| Layer::new(device, layout, sampler, key));
+
+        layer.is_used = true;
+
+        // Multiplying by white keeps the colors of the view where the draw
+        // call does not cover it
+        let clear_color = match blend_mode {
+            BlendMode::Multiply => wgpu::Color {
+                r: 1.0,
+                g: 1.0,
+                b: 1.0,
+                a: 1.0,
+            },
+            _ => wgpu::Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.0,
+            },
+        };
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: &layer.attachment,
+                resolve_target: None,
+                load_op: wgpu::LoadOp::Clear,
+                store_op: wgpu::StoreOp::Store,
+                clear_color,
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        &layer.attachment
+    }
+
+    /// Resolves the layer of the given view and blends it on the view, if it
+    /// is multisampled.
+    pub fn end(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        view: &TargetView,
+        blend_mode: BlendMode,
+    ) {
+        if view.samples() <= 1 {
+            return;
+        }
+
+        let layer =
+            &self.layers[&(view.width(), view.height(), view.samples())];
+
+        let _ = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            color_attachments: &[wgpu::RenderPassColorAttachmentDescriptor {
+                attachment: &layer.attachment,
+                resolve_target: Some(&layer.resolved),
+                load_op: wgpu::LoadOp::Load,
+                store_op: wgpu::StoreOp::Store,
+                clear_color: wgpu::Color {
+                    r: 0.0,
+                    g: 0.0,
+                    b: 0.0,
+                    a: 0.0,
+                },
+            }],
+            depth_stencil_attachment: None,
+        });
+
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: view.view(),
+                        resolve_target: None,
+                        load_op: wgpu::LoadOp::Load,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color: wgpu::Color {
+                            r: 0.0,
+                            g: 0.0,
+                            b: 0.0,
+                            a: 0.0,
+                        },
+                    },
+                ],
+                depth_stencil_attachment: None,
+            });
+
+        render_pass.set_pipeline(&self.pipelines[&blend_mode]);
+        render_pass.set_bind_group(0, &layer.binding, &[]);
+        render_pass.draw(0..3, 0..1);
+    }
+
+    /// Frees the layers that have not been used since the last call.
+    ///
+    /// It must only be called once the commands that may use them have been
+    /// submitted.
+    pub fn trim(&mut self) {
+        self.layers.retain(|_, layer| layer.is_used);
+
+        for layer in self.layers.values_mut() {
+            layer.is_used = false;
+        }
+    }
+}
+
+struct Layer {
+    attachment: wgpu::TextureView,
+    resolved: wgpu::TextureView,
+    binding: wgpu::BindGroup,
+    is_used: bool,
+    _textures: [wgpu::Texture; 2],
+}
+
+impl Layer {
+    fn new(
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        (width, height, samples): (u32, u32, u32),
+    ) -> Layer {
+        let attachment = create_texture(
+            device,
+            width,
+            height,
+            samples,
+            wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+        );
+
+        let resolved = create_texture(
+            device,
+            width,
+            height,
+            1,
+            wgpu::TextureUsage::OUTPUT_ATTACHMENT | wgpu::TextureUsage::SAMPLED,
+        );
+
+        let attachment_view = attachment.create_default_view();
+        let resolved_view = resolved.create_default_view();
+
+        let binding = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("coffee::backend::multisample layer"),
+            layout,
+            bindings: &[
+                wgpu::Binding {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(
+                        &resolved_view,
+                    ),
+                },
+                wgpu::Binding {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        });
+
+        Layer {
+            attachment: attachment_view,
+            resolved: resolved_view,
+            binding,
+            is_used: true,
+            _textures: [attachment, resolved],
+        }
+    }
+}
+
+fn create_texture(
+    device: &wgpu::Device,
+    width: u32,
+    height: u32,
+    samples: u32,
+    usage: wgpu::TextureUsage,
+) -> wgpu::Texture {
+    device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("coffee::backend::multisample layer"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth: 1,
+        },
+        array_layer_count: 1,
+        mip_level_count: 1,
+        sample_count: samples,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        usage,
+    })
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    blend_mode: BlendMode,
+) -> wgpu::RenderPipeline {
+    let (color_blend, alpha_blend) = blend(blend_mode);
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            color_blend,
+            alpha_blend,
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffers: &[],
+        },
+        sample_count: 1,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
+
+// The blending of a layer on its view. The colors of a layer drawn with alpha
+// blending are already multiplied by their alpha, as the layer starts
+// transparent, and the colors of a layer drawn additively are added as they
+// are.
+fn blend(
+    blend_mode: BlendMode,
+) -> (wgpu::BlendDescriptor, wgpu::BlendDescriptor) {
+    match blend_mode {
+        BlendMode::Alpha | BlendMode::Premultiplied => {
+            super::blend(BlendMode::Premultiplied)
+        }
+        BlendMode::Additive => (
+            wgpu::BlendDescriptor {
+                src_factor: wgpu::BlendFactor::One,
+                dst_factor: wgpu::BlendFactor::One,
+                operation: wgpu::BlendOperation::Add,
+            },
+            super::blend(BlendMode::Additive).1,
+        ),
+        BlendMode::Multiply => super::blend(BlendMode::Multiply),
+    }
+}
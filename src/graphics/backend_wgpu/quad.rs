@@ -1,32 +1,34 @@
+use std::collections::HashMap;
 use std::mem;
+use std::rc::Rc;
 
-use crate::graphics::{self, Transformation};
+use crate::graphics::gpu::Quad;
+use crate::graphics::{
+    BlendMode, FilterMode, Rectangle, Sampler, Transformation, WrapMode,
+};
 use zerocopy::AsBytes;
 
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    // The pipelines are keyed by blend mode, whether they test depth, and
+    // samples per pixel
+    pipelines: HashMap<(BlendMode, bool, u32), wgpu::RenderPipeline>,
+    distance_field_pipelines:
+        HashMap<(BlendMode, bool, u32), wgpu::RenderPipeline>,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
+    distance_field_fs_module: wgpu::ShaderModule,
     transform: wgpu::Buffer,
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
-    instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    instances: Instances,
+    constant_layout: wgpu::BindGroupLayout,
+    constants: HashMap<Sampler, wgpu::BindGroup>,
     texture_layout: wgpu::BindGroupLayout,
 }
 
 impl Pipeline {
     pub fn new(device: &mut wgpu::Device) -> Pipeline {
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Nearest,
-            min_filter: wgpu::FilterMode::Nearest,
-            mipmap_filter: wgpu::FilterMode::Nearest,
-            lod_min_clamp: -100.0,
-            lod_max_clamp: 100.0,
-            compare: wgpu::CompareFunction::Always,
-        });
-
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("coffee::backend::quad constants"),
@@ -51,24 +53,17 @@ impl Pipeline {
             wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
         );
 
-        let constant_bind_group =
-            device.create_bind_group(&wgpu::BindGroupDescriptor {
-                label: Some("coffee::backend::quad constants"),
-                layout: &constant_layout,
-                bindings: &[
-                    wgpu::Binding {
-                        binding: 0,
-                        resource: wgpu::BindingResource::Buffer {
-                            buffer: &transform_buffer,
-                            range: 0..64,
-                        },
-                    },
-                    wgpu::Binding {
-                        binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
-                    },
-                ],
-            });
+        let mut constants = HashMap::new();
+
+        let _ = constants.insert(
+            Sampler::default(),
+            create_constants(
+                device,
+                &constant_layout,
+                &transform_buffer,
+                Sampler::default(),
+            ),
+        );
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -101,84 +96,12 @@ impl Pipeline {
                 .expect("Read quad fragment shader as SPIR-V"),
         );
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Cw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint16,
-                    vertex_buffers: &[
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Vertex>() as u64,
-                            step_mode: wgpu::InputStepMode::Vertex,
-                            attributes: &[wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            }],
-                        },
-                        wgpu::VertexBufferDescriptor {
-                            stride: mem::size_of::<Quad>() as u64,
-                            step_mode: wgpu::InputStepMode::Instance,
-                            attributes: &[
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 1,
-                                    format: wgpu::VertexFormat::Float4,
-                                    offset: 0,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 2,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * 4,
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 3,
-                                    format: wgpu::VertexFormat::Float2,
-                                    offset: 4 * (4 + 2),
-                                },
-                                wgpu::VertexAttributeDescriptor {
-                                    shader_location: 4,
-                                    format: wgpu::VertexFormat::Uint,
-                                    offset: 4 * (4 + 2 + 2),
-                                },
-                            ],
-                        },
-                    ],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
+        let distance_field_fs =
+            include_bytes!("shader/distance_field.frag.spv");
+        let distance_field_fs_module = device.create_shader_module(
+            &wgpu::read_spirv(std::io::Cursor::new(&distance_field_fs[..]))
+                .expect("Read distance field fragment shader as SPIR-V"),
+        );
 
         let vertices = device.create_buffer_with_data(
             QUAD_VERTS.as_bytes(),
@@ -190,21 +113,70 @@ impl Pipeline {
             wgpu::BufferUsage::INDEX,
         );
 
-        let instances = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("coffee::backend::quad instances"),
-            size: mem::size_of::<Quad>() as u64 * Quad::MAX as u64,
-            usage: wgpu::BufferUsage::VERTEX | wgpu::BufferUsage::COPY_DST,
-        });
-
-        Pipeline {
-            pipeline,
+        let mut pipeline = Pipeline {
+            pipelines: HashMap::new(),
+            distance_field_pipelines: HashMap::new(),
+            layout,
+            vs_module,
+            fs_module,
+            distance_field_fs_module,
             transform: transform_buffer,
             vertices,
             indices,
-            instances,
-            constants: constant_bind_group,
+            instances: Instances::new(),
+            constant_layout,
+            constants,
             texture_layout,
+        };
+
+        // A pipeline is created for every blend mode, with and without a
+        // depth buffer, as these states cannot be changed when drawing.
+        // Multisampled pipelines are only created once they are needed.
+        for mode in BlendMode::ALL.iter() {
+            for has_depth in &[false, true] {
+                pipeline.prepare_pipelines(device, (*mode, *has_depth, 1));
+            }
+        }
+
+        pipeline
+    }
+
+    // Creates the pipelines with the given key, unless they exist already
+    fn prepare_pipelines(
+        &mut self,
+        device: &wgpu::Device,
+        key: (BlendMode, bool, u32),
+    ) {
+        if self.pipelines.contains_key(&key) {
+            return;
         }
+
+        let (blend_mode, has_depth, samples) = key;
+
+        let pipeline = create_pipeline(
+            device,
+            &self.layout,
+            &self.vs_module,
+            &self.fs_module,
+            blend_mode,
+            has_depth,
+            samples,
+        );
+
+        let distance_field_pipeline = create_pipeline(
+            device,
+            &self.layout,
+            &self.vs_module,
+            &self.distance_field_fs_module,
+            blend_mode,
+            has_depth,
+            samples,
+        );
+
+        let _ = self.pipelines.insert(key, pipeline);
+        let _ = self
+            .distance_field_pipelines
+            .insert(key, distance_field_pipeline);
     }
 
     pub fn create_texture_binding(
@@ -229,11 +201,177 @@ impl Pipeline {
         device: &mut wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         texture: &TextureBinding,
+        sampler: Sampler,
+        instances: &[Quad],
+        transformation: &Transformation,
+        target: &wgpu::TextureView,
+        samples: u32,
+        depth: Option<&wgpu::TextureView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        self.draw(
+            device,
+            encoder,
+            texture,
+            sampler,
+            instances,
+            transformation,
+            target,
+            samples,
+            depth,
+            scissor,
+            blend_mode,
+            false,
+        );
+    }
+
+    /// Draws the quads treating the alpha channel of the given texture as a
+    /// distance field, like the glyph atlas of a distance-field font.
+    pub fn draw_distance_field(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &TextureBinding,
+        sampler: Sampler,
+        instances: &[Quad],
+        transformation: &Transformation,
+        target: &wgpu::TextureView,
+        samples: u32,
+        depth: Option<&wgpu::TextureView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        self.draw(
+            device,
+            encoder,
+            texture,
+            sampler,
+            instances,
+            transformation,
+            target,
+            samples,
+            depth,
+            scissor,
+            blend_mode,
+            true,
+        );
+    }
+
+    fn draw(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &TextureBinding,
+        sampler: Sampler,
         instances: &[Quad],
         transformation: &Transformation,
         target: &wgpu::TextureView,
+        samples: u32,
+        depth: Option<&wgpu::TextureView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+        is_distance_field: bool,
+    ) {
+        self.prepare(device, encoder, sampler, transformation);
+
+        let key = (blend_mode, depth.is_some(), samples);
+        self.prepare_pipelines(device, key);
+
+        let pipeline = if is_distance_field {
+            &self.distance_field_pipelines[&key]
+        } else {
+            &self.pipelines[&key]
+        };
+
+        let mut remaining = instances;
+
+        while !remaining.is_empty() {
+            let (chunk, offset, amount) =
+                self.instances.push(device, remaining);
+
+            self.render(
+                encoder,
+                texture,
+                sampler,
+                &self.instances.chunks[chunk].buffer,
+                offset,
+                amount as u32,
+                target,
+                depth,
+                scissor,
+                pipeline,
+            );
+
+            remaining = &remaining[amount..];
+        }
+    }
+
+    /// Uploads the instances queued by the draw calls since the last flush.
+    ///
+    /// The returned commands must be submitted before the commands of the
+    /// draw calls.
+    pub fn flush(
+        &mut self,
+        device: &mut wgpu::Device,
+    ) -> Option<wgpu::CommandBuffer> {
+        self.instances.flush(device)
+    }
+
+    pub fn draw_instance_buffer(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &TextureBinding,
+        sampler: Sampler,
+        instances: &InstanceBuffer,
+        transformation: &Transformation,
+        target: &wgpu::TextureView,
+        samples: u32,
+        depth: Option<&wgpu::TextureView>,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        if let Some(buffer) = &instances.raw {
+            let key = (blend_mode, depth.is_some(), samples);
+
+            self.prepare(device, encoder, sampler, transformation);
+            self.prepare_pipelines(device, key);
+
+            self.render(
+                encoder,
+                texture,
+                sampler,
+                buffer,
+                0,
+                instances.len,
+                target,
+                depth,
+                scissor,
+                &self.pipelines[&key],
+            );
+        }
+    }
+
+    fn prepare(
+        &mut self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        sampler: Sampler,
+        transformation: &Transformation,
     ) {
-        let matrix: [f32; 16] = transformation.clone().into();
+        if !self.constants.contains_key(&sampler) {
+            let constants = create_constants(
+                device,
+                &self.constant_layout,
+                &self.transform,
+                sampler,
+            );
+
+            let _ = self.constants.insert(sampler, constants);
+        }
+
+        let matrix: [f32; 16] = super::clip_space(transformation).into();
 
         let transform_buffer = device.create_buffer_with_data(
             matrix.as_bytes(),
@@ -247,66 +385,378 @@ impl Pipeline {
             0,
             16 * 4,
         );
+    }
 
-        let mut i = 0;
-        let total = instances.len();
+    fn render(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        texture: &TextureBinding,
+        sampler: Sampler,
+        instances: &wgpu::Buffer,
+        offset: usize,
+        amount: u32,
+        target: &wgpu::TextureView,
+        depth: Option<&wgpu::TextureView>,
+        scissor: Option<Rectangle<u32>>,
+        pipeline: &wgpu::RenderPipeline,
+    ) {
+        let mut render_pass =
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                color_attachments: &[
+                    wgpu::RenderPassColorAttachmentDescriptor {
+                        attachment: target,
+                        resolve_target: None,
+                        load_op: wgpu::LoadOp::Load,
+                        store_op: wgpu::StoreOp::Store,
+                        clear_color: wgpu::Color {
+                            r: 0.0,
+                            g: 0.0,
+                            b: 0.0,
+                            a: 0.0,
+                        },
+                    },
+                ],
+                depth_stencil_attachment: depth.map(|depth| {
+                    wgpu::RenderPassDepthStencilAttachmentDescriptor {
+                        attachment: depth,
+                        depth_load_op: wgpu::LoadOp::Load,
+                        depth_store_op: wgpu::StoreOp::Store,
+                        clear_depth: 1.0,
+                        stencil_load_op: wgpu::LoadOp::Load,
+                        stencil_store_op: wgpu::StoreOp::Store,
+                        clear_stencil: 0,
+                    }
+                }),
+            });
 
-        while i < total {
-            let end = (i + Quad::MAX).min(total);
-            let amount = end - i;
+        render_pass.set_pipeline(pipeline);
+        render_pass.set_bind_group(0, &self.constants[&sampler], &[]);
+        render_pass.set_bind_group(1, &texture.0, &[]);
+        render_pass.set_index_buffer(&self.indices, 0, 0);
+        render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
+        render_pass.set_vertex_buffer(
+            1,
+            instances,
+            (mem::size_of::<Quad>() * offset) as u64,
+            0,
+        );
 
-            let instance_buffer = device.create_buffer_with_data(
-                instances[i..end].as_bytes(),
-                wgpu::BufferUsage::COPY_SRC,
+        if let Some(scissor) = scissor {
+            render_pass.set_scissor_rect(
+                scissor.x,
+                scissor.y,
+                scissor.width,
+                scissor.height,
             );
+        }
 
-            encoder.copy_buffer_to_buffer(
-                &instance_buffer,
-                0,
-                &self.instances,
-                0,
-                (mem::size_of::<Quad>() * amount) as u64,
-            );
+        render_pass.draw_indexed(0..QUAD_INDICES.len() as u32, 0, 0..amount);
+    }
+}
+
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    blend_mode: BlendMode,
+    has_depth: bool,
+    samples: u32,
+) -> wgpu::RenderPipeline {
+    let (color_blend, alpha_blend) = super::blend(blend_mode);
+
+    let depth_stencil_state = if has_depth {
+        Some(wgpu::DepthStencilStateDescriptor {
+            format: super::texture::DEPTH_FORMAT,
+            depth_write_enabled: true,
+            depth_compare: wgpu::CompareFunction::LessEqual,
+            stencil_front: wgpu::StencilStateFaceDescriptor::IGNORE,
+            stencil_back: wgpu::StencilStateFaceDescriptor::IGNORE,
+            stencil_read_mask: 0,
+            stencil_write_mask: 0,
+        })
+    } else {
+        None
+    };
+
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Cw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            color_blend,
+            alpha_blend,
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint16,
+            vertex_buffers: &[
+                wgpu::VertexBufferDescriptor {
+                    stride: mem::size_of::<Vertex>() as u64,
+                    step_mode: wgpu::InputStepMode::Vertex,
+                    attributes: &[wgpu::VertexAttributeDescriptor {
+                        shader_location: 0,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    }],
+                },
+                wgpu::VertexBufferDescriptor {
+                    stride: mem::size_of::<Quad>() as u64,
+                    step_mode: wgpu::InputStepMode::Instance,
+                    attributes: &[
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 1,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 0,
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 2,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 4 * 4,
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 3,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 4 * (4 + 2),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 4,
+                            format: wgpu::VertexFormat::Uint,
+                            offset: 4 * (4 + 2 + 2),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 5,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (4 + 2 + 2 + 1),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 6,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (4 + 2 + 2 + 1 + 4),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 7,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (4 + 2 + 2 + 1 + 4 * 2),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 8,
+                            format: wgpu::VertexFormat::Float4,
+                            offset: 4 * (4 + 2 + 2 + 1 + 4 * 3),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 9,
+                            format: wgpu::VertexFormat::Float2,
+                            offset: 4 * (4 + 2 + 2 + 1 + 4 * 4),
+                        },
+                        wgpu::VertexAttributeDescriptor {
+                            shader_location: 10,
+                            format: wgpu::VertexFormat::Float,
+                            offset: 4 * (4 + 2 + 2 + 1 + 4 * 4 + 2),
+                        },
+                    ],
+                },
+            ],
+        },
+        sample_count: samples,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
+}
 
-            {
-                let mut render_pass =
-                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        color_attachments: &[
-                            wgpu::RenderPassColorAttachmentDescriptor {
-                                attachment: target,
-                                resolve_target: None,
-                                load_op: wgpu::LoadOp::Load,
-                                store_op: wgpu::StoreOp::Store,
-                                clear_color: wgpu::Color {
-                                    r: 0.0,
-                                    g: 0.0,
-                                    b: 0.0,
-                                    a: 0.0,
-                                },
-                            },
-                        ],
-                        depth_stencil_attachment: None,
-                    });
-
-                render_pass.set_pipeline(&self.pipeline);
-                render_pass.set_bind_group(0, &self.constants, &[]);
-                render_pass.set_bind_group(1, &texture.0, &[]);
-                render_pass.set_index_buffer(&self.indices, 0, 0);
-                render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
-                render_pass.set_vertex_buffer(1, &self.instances, 0, 0);
-
-                render_pass.draw_indexed(
-                    0..QUAD_INDICES.len() as u32,
+// The capacity of the first chunk of instances
+const INITIAL_CAPACITY: usize = 10_000;
+
+// The instances drawn during a frame are queued and uploaded at once, right
+// before the frame is submitted. They are stored in a list of chunks that is
+// kept between frames, so the GPU buffers are only allocated when a frame
+// draws more instances than ever before. Each new chunk doubles the capacity
+// of the last one.
+struct Instances {
+    pending: Vec<Quad>,
+    chunks: Vec<Chunk>,
+    current: usize,
+}
+
+struct Chunk {
+    buffer: wgpu::Buffer,
+    capacity: usize,
+    used: usize,
+}
+
+impl Instances {
+    fn new() -> Instances {
+        Instances {
+            pending: Vec::new(),
+            chunks: Vec::new(),
+            current: 0,
+        }
+    }
+
+    // Queues as many of the given instances as fit in the current chunk.
+    //
+    // It returns the index of the chunk, the offset of the instances in it,
+    // and the amount of queued instances.
+    fn push(
+        &mut self,
+        device: &mut wgpu::Device,
+        instances: &[Quad],
+    ) -> (usize, usize, usize) {
+        if let Some(chunk) = self.chunks.get(self.current) {
+            if chunk.used == chunk.capacity {
+                self.current += 1;
+            }
+        }
+
+        if self.current == self.chunks.len() {
+            let capacity = self
+                .chunks
+                .last()
+                .map(|chunk| chunk.capacity * 2)
+                .unwrap_or(INITIAL_CAPACITY);
+
+            self.chunks.push(Chunk {
+                buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("coffee::backend::quad instances"),
+                    size: (mem::size_of::<Quad>() * capacity) as u64,
+                    usage: wgpu::BufferUsage::VERTEX
+                        | wgpu::BufferUsage::COPY_DST,
+                }),
+                capacity,
+                used: 0,
+            });
+        }
+
+        let chunk = &mut self.chunks[self.current];
+        let offset = chunk.used;
+        let amount = (chunk.capacity - chunk.used).min(instances.len());
+
+        chunk.used += amount;
+        self.pending.extend_from_slice(&instances[..amount]);
+
+        (self.current, offset, amount)
+    }
+
+    fn flush(
+        &mut self,
+        device: &mut wgpu::Device,
+    ) -> Option<wgpu::CommandBuffer> {
+        if self.pending.is_empty() {
+            return None;
+        }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            instances = self.pending.len(),
+            chunks = self.current + 1,
+            "Uploading quad instances"
+        );
+
+        let staging = device.create_buffer_with_data(
+            self.pending.as_bytes(),
+            wgpu::BufferUsage::COPY_SRC,
+        );
+
+        let mut encoder =
+            device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("coffee::backend::quad upload"),
+            });
+
+        let mut start = 0;
+
+        for chunk in &mut self.chunks[..=self.current] {
+            if chunk.used > 0 {
+                encoder.copy_buffer_to_buffer(
+                    &staging,
+                    (mem::size_of::<Quad>() * start) as u64,
+                    &chunk.buffer,
                     0,
-                    0..amount as u32,
+                    (mem::size_of::<Quad>() * chunk.used) as u64,
                 );
             }
 
-            i += Quad::MAX;
+            start += chunk.used;
+            chunk.used = 0;
         }
+
+        self.pending.clear();
+        self.current = 0;
+
+        Some(encoder.finish())
     }
 }
 
+fn create_constants(
+    device: &mut wgpu::Device,
+    layout: &wgpu::BindGroupLayout,
+    transform: &wgpu::Buffer,
+    sampler: Sampler,
+) -> wgpu::BindGroup {
+    let address_mode = match sampler.wrap {
+        WrapMode::Clamp => wgpu::AddressMode::ClampToEdge,
+        WrapMode::Repeat => wgpu::AddressMode::Repeat,
+        WrapMode::MirrorRepeat => wgpu::AddressMode::MirrorRepeat,
+    };
+
+    let filter = match sampler.filter {
+        FilterMode::Nearest => wgpu::FilterMode::Nearest,
+        FilterMode::Linear => wgpu::FilterMode::Linear,
+    };
+
+    // Only the full-size level is sampled when mipmaps are disabled
+    let (mipmap_filter, lod_max_clamp) = match sampler.mipmap_filter {
+        Some(FilterMode::Nearest) => (wgpu::FilterMode::Nearest, 100.0),
+        Some(FilterMode::Linear) => (wgpu::FilterMode::Linear, 100.0),
+        None => (wgpu::FilterMode::Nearest, 0.0),
+    };
+
+    let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+        address_mode_u: address_mode,
+        address_mode_v: address_mode,
+        address_mode_w: address_mode,
+        mag_filter: filter,
+        min_filter: filter,
+        mipmap_filter,
+        lod_min_clamp: -100.0,
+        lod_max_clamp,
+        compare: wgpu::CompareFunction::Always,
+    });
+
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("coffee::backend::quad constants"),
+        layout,
+        bindings: &[
+            wgpu::Binding {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer {
+                    buffer: transform,
+                    range: 0..64,
+                },
+            },
+            wgpu::Binding {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            },
+        ],
+    })
+}
+
 #[derive(Clone, Copy, AsBytes)]
 #[repr(C)]
 pub struct Vertex {
@@ -330,32 +780,29 @@ const QUAD_VERTS: [Vertex; 4] = [
     },
 ];
 
-#[derive(Debug, Clone, Copy, AsBytes)]
-#[repr(C)]
-pub struct Quad {
-    source: [f32; 4],
-    scale: [f32; 2],
-    translation: [f32; 2],
-    pub layer: u32,
-}
+pub struct TextureBinding(wgpu::BindGroup);
 
-impl Quad {
-    const MAX: usize = 100_000;
+#[derive(Clone)]
+pub struct InstanceBuffer {
+    raw: Option<Rc<wgpu::Buffer>>,
+    len: u32,
 }
 
-impl From<graphics::Quad> for Quad {
-    fn from(quad: graphics::Quad) -> Quad {
-        let source = quad.source;
-        let position = quad.position;
-        let (width, height) = quad.size;
-
-        Quad {
-            source: [source.x, source.y, source.width, source.height],
-            translation: [position.x, position.y],
-            scale: [width, height],
-            layer: 0,
+impl InstanceBuffer {
+    pub fn new(device: &mut wgpu::Device, instances: &[Quad]) -> Self {
+        // Empty buffers cannot be created, but there is nothing to draw
+        let raw = if instances.is_empty() {
+            None
+        } else {
+            Some(Rc::new(device.create_buffer_with_data(
+                instances.as_bytes(),
+                wgpu::BufferUsage::VERTEX,
+            )))
+        };
+
+        InstanceBuffer {
+            raw,
+            len: instances.len() as u32,
         }
     }
 }
-
-pub struct TextureBinding(wgpu::BindGroup);
@@ -0,0 +1,10 @@
+#version 450
+
+layout(set = 0, binding = 0) uniform texture2D u_Layer;
+layout(set = 0, binding = 1) uniform sampler u_Sampler;
+
+layout(location = 0) out vec4 o_Target;
+
+void main() {
+    o_Target = texelFetch(sampler2D(u_Layer, u_Sampler), ivec2(gl_FragCoord.xy), 0);
+}
@@ -5,6 +5,12 @@ layout(location = 1) in vec4 a_Src;
 layout(location = 2) in vec2 a_Scale;
 layout(location = 3) in vec2 a_Translation;
 layout(location = 4) in uint t_Layer;
+layout(location = 5) in vec4 a_ColorTopLeft;
+layout(location = 6) in vec4 a_ColorTopRight;
+layout(location = 7) in vec4 a_ColorBottomRight;
+layout(location = 8) in vec4 a_ColorBottomLeft;
+layout(location = 9) in vec2 a_Origin;
+layout(location = 10) in float a_Rotation;
 
 layout (set = 0, binding = 0) uniform Globals {
     mat4 u_Transform;
@@ -12,17 +18,23 @@ layout (set = 0, binding = 0) uniform Globals {
 
 layout(location = 0) out vec2 v_Uv;
 layout(location = 1) flat out uint v_Layer;
+layout(location = 2) out vec4 v_Color;
 
 void main() {
     v_Uv = a_Pos * a_Src.zw + a_Src.xy;
     v_Layer = t_Layer;
-
-    mat4 a_Transform = mat4(
-        vec4(a_Scale.x, 0.0, 0.0, 0.0),
-        vec4(0.0, a_Scale.y, 0.0, 0.0),
-        vec4(0.0, 0.0, 1.0, 0.0),
-        vec4(a_Translation, 0.0, 1.0)
+    v_Color = mix(
+        mix(a_ColorTopLeft, a_ColorTopRight, a_Pos.x),
+        mix(a_ColorBottomLeft, a_ColorBottomRight, a_Pos.x),
+        a_Pos.y
     );
 
-    gl_Position = u_Transform * a_Transform * vec4(a_Pos, 0.0, 1.0);
+    // The quad is rotated around its origin, relative to its position
+    float c = cos(a_Rotation);
+    float s = sin(a_Rotation);
+
+    vec2 local = a_Pos * a_Scale - a_Origin;
+    vec2 position = mat2(c, s, -s, c) * local + a_Origin + a_Translation;
+
+    gl_Position = u_Transform * vec4(position, 0.0, 1.0);
 }
@@ -1,28 +1,41 @@
-use super::{Gpu, TargetView};
+use super::{texture, Gpu, TargetView};
 
 pub struct Surface {
     window: winit::window::Window,
     surface: wgpu::Surface,
     swap_chain: wgpu::SwapChain,
     extent: wgpu::Extent3d,
-    output: Option<wgpu::SwapChainOutput>,
+    present_mode: wgpu::PresentMode,
+    samples: u32,
+    output: Option<TargetView>,
 }
 
 impl Surface {
     pub fn new(
         window: winit::window::Window,
         device: &wgpu::Device,
+        vsync: bool,
+        samples: u32,
     ) -> Surface {
         let surface = wgpu::Surface::create(&window);
         let size = window.inner_size();
 
-        let (swap_chain, extent) = new_swap_chain(device, &surface, size);
+        let present_mode = if vsync {
+            wgpu::PresentMode::Mailbox
+        } else {
+            wgpu::PresentMode::Immediate
+        };
+
+        let (swap_chain, extent) =
+            new_swap_chain(device, &surface, size, present_mode);
 
         Surface {
             window,
             surface,
             swap_chain,
             extent,
+            present_mode,
+            samples,
             output: None,
         }
     }
@@ -38,10 +51,15 @@ impl Surface {
                 .get_next_texture()
                 .expect("Get next texture");
 
-            self.output = Some(output);
+            self.output = Some(TargetView::frame(
+                output,
+                self.extent.width,
+                self.extent.height,
+                self.samples,
+            ));
         }
 
-        &self.output.as_ref().unwrap().view
+        self.output.as_ref().unwrap()
     }
 
     pub fn resize(
@@ -50,27 +68,26 @@ impl Surface {
         size: winit::dpi::PhysicalSize<u32>,
     ) {
         let (swap_chain, extent) =
-            new_swap_chain(&gpu.device, &self.surface, size);
+            new_swap_chain(&gpu.device, &self.surface, size, self.present_mode);
 
         self.swap_chain = swap_chain;
         self.extent = extent;
         self.output = None;
     }
 
-    pub fn swap_buffers(&mut self, gpu: &mut Gpu) {
-        let new_encoder = gpu.device.create_command_encoder(
-            &wgpu::CommandEncoderDescriptor {
-                label: Some("coffee::backend::surface blit"),
-            },
-        );
-
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> bool {
         // We swap the current decoder by a new one here, so we can finish the
         // current frame
-        let encoder = std::mem::replace(&mut gpu.encoder, new_encoder);
+        let encoder = gpu.take_encoder();
 
         gpu.queue.submit(&[encoder.finish()]);
 
-        self.output = None;
+        // Textures dropped during the frame are no longer needed
+        texture::collect_garbage();
+        gpu.multisample.trim();
+
+        // The frame is only presented if an output was acquired and drawn to
+        self.output.take().is_some()
     }
 
     pub fn request_redraw(&mut self) {
@@ -82,6 +99,7 @@ fn new_swap_chain(
     device: &wgpu::Device,
     surface: &wgpu::Surface,
     size: winit::dpi::PhysicalSize<u32>,
+    present_mode: wgpu::PresentMode,
 ) -> (wgpu::SwapChain, wgpu::Extent3d) {
     let swap_chain = device.create_swap_chain(
         surface,
@@ -90,7 +108,7 @@ fn new_swap_chain(
             format: wgpu::TextureFormat::Bgra8UnormSrgb,
             width: size.width,
             height: size.height,
-            present_mode: wgpu::PresentMode::Mailbox,
+            present_mode,
         },
     );
 
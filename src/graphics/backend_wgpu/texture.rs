@@ -1,9 +1,20 @@
+use std::cell::RefCell;
 use std::fmt;
 use std::rc::Rc;
 
-use super::types::TargetView;
-use crate::graphics::gpu::quad::{self, Pipeline};
-use crate::graphics::Transformation;
+use super::quad::{self, Pipeline};
+use super::types::{DepthView, TargetView};
+use crate::graphics::image_settings::{self, ImageSettings};
+use crate::graphics::mipmap;
+use crate::graphics::resource_stats::Allocation;
+use crate::graphics::{ResourceId, Sampler, Transformation};
+
+thread_local! {
+    // Textures dropped during a frame may still be used by the commands
+    // recorded in the current encoder, so they are kept here until the
+    // frame is submitted
+    static GRAVEYARD: RefCell<Vec<Lifetime>> = RefCell::new(Vec::new());
+}
 
 #[derive(Clone)]
 pub struct Texture {
@@ -13,6 +24,10 @@ pub struct Texture {
     width: u16,
     height: u16,
     layers: u16,
+    sampler: Sampler,
+    premultiplied: bool,
+    id: ResourceId,
+    _lifetime: Rc<Lifetime>,
 }
 
 impl fmt::Debug for Texture {
@@ -26,33 +41,78 @@ impl fmt::Debug for Texture {
 }
 
 impl Texture {
+    fn from_parts(
+        texture: wgpu::Texture,
+        view: wgpu::TextureView,
+        binding: quad::TextureBinding,
+        width: u16,
+        height: u16,
+        layers: u16,
+        samples: u32,
+    ) -> Texture {
+        let raw = Rc::new(texture);
+        let view = Rc::new(TargetView::new(
+            view,
+            u32::from(width),
+            u32::from(height),
+            samples,
+        ));
+        let binding = Rc::new(binding);
+
+        let lifetime = Lifetime {
+            raw: Some(raw.clone()),
+            view: Some(view.clone()),
+            depth: None,
+            binding: Some(binding.clone()),
+            allocation: Some(Allocation::texture(width, height, layers)),
+        };
+
+        Texture {
+            raw,
+            view,
+            binding,
+            width,
+            height,
+            layers,
+            sampler: Sampler::default(),
+            premultiplied: false,
+            id: ResourceId::new(),
+            _lifetime: Rc::new(lifetime),
+        }
+    }
+
     pub(super) fn new(
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
         pipeline: &Pipeline,
         image: &image::DynamicImage,
+        settings: ImageSettings,
     ) -> Texture {
-        let bgra = image.to_bgra();
+        let mut bgra = image.to_bgra();
         let width = bgra.width() as u16;
         let height = bgra.height() as u16;
 
+        if settings.premultiply {
+            image_settings::premultiply(&mut bgra);
+        }
+
         let (texture, view, binding) = create_texture_array(
             device,
             queue,
             pipeline,
             u32::from(width),
             u32::from(height),
+            1,
             Some(&[&bgra.into_raw()[..]]),
-            wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+            format(settings.srgb),
+            wgpu::TextureUsage::COPY_DST
+                | wgpu::TextureUsage::COPY_SRC
+                | wgpu::TextureUsage::SAMPLED,
         );
 
         Texture {
-            raw: Rc::new(texture),
-            view: Rc::new(view),
-            binding: Rc::new(binding),
-            width,
-            height,
-            layers: 1,
+            premultiplied: settings.premultiply,
+            ..Texture::from_parts(texture, view, binding, width, height, 1, 1)
         }
     }
 
@@ -61,14 +121,42 @@ impl Texture {
         queue: &wgpu::Queue,
         pipeline: &Pipeline,
         layers: &[image::DynamicImage],
+        mipmaps: bool,
+        settings: ImageSettings,
     ) -> Texture {
         let first_layer = &layers[0].to_bgra();
         let width = first_layer.width() as u16;
         let height = first_layer.height() as u16;
 
-        let bgra: Vec<Vec<u8>> =
-            layers.iter().map(|i| i.to_bgra().into_raw()).collect();
-
+        // The levels of every layer are provided one after the other
+        let bgra: Vec<Vec<u8>> = layers
+            .iter()
+            .flat_map(|layer| {
+                let mut rgba = layer.to_rgba();
+
+                // Mipmaps are only correct when built from premultiplied
+                // pixels
+                if settings.premultiply {
+                    image_settings::premultiply(&mut rgba);
+                }
+
+                let levels = if mipmaps {
+                    mipmap::chain(&rgba)
+                } else {
+                    vec![rgba]
+                };
+
+                levels
+                    .into_iter()
+                    .map(|level| {
+                        image::DynamicImage::ImageRgba8(level).to_bgra()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .map(|level| level.into_raw())
+            .collect();
+
+        let levels = bgra.len() / layers.len();
         let raw_layers: Vec<&[u8]> = bgra.iter().map(|i| &i[..]).collect();
 
         let (texture, view, binding) = create_texture_array(
@@ -77,91 +165,107 @@ impl Texture {
             pipeline,
             u32::from(width),
             u32::from(height),
+            levels as u32,
             Some(&raw_layers[..]),
-            wgpu::TextureUsage::COPY_DST | wgpu::TextureUsage::SAMPLED,
+            format(settings.srgb),
+            wgpu::TextureUsage::COPY_DST
+                | wgpu::TextureUsage::COPY_SRC
+                | wgpu::TextureUsage::SAMPLED,
         );
 
         Texture {
-            raw: Rc::new(texture),
-            view: Rc::new(view),
-            binding: Rc::new(binding),
-            width,
-            height,
-            layers: layers.len() as u16,
+            premultiplied: settings.premultiply,
+            ..Texture::from_parts(
+                texture,
+                view,
+                binding,
+                width,
+                height,
+                layers.len() as u16,
+                1,
+            )
         }
     }
 
-    pub(super) fn view(&self) -> &TargetView {
-        &self.view
-    }
-
-    pub(super) fn binding(&self) -> &quad::TextureBinding {
-        &self.binding
-    }
-
-    pub fn width(&self) -> u16 {
-        self.width
-    }
-
-    pub fn height(&self) -> u16 {
-        self.height
-    }
-}
-
-#[derive(Clone)]
-pub struct Drawable {
-    texture: Texture,
-}
-
-impl Drawable {
-    pub fn new(
+    pub(super) fn new_empty(
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
         pipeline: &Pipeline,
         width: u16,
         height: u16,
-    ) -> Drawable {
+        settings: ImageSettings,
+    ) -> Texture {
         let (texture, view, binding) = create_texture_array(
             device,
             queue,
             pipeline,
             u32::from(width),
             u32::from(height),
+            1,
             None,
-            wgpu::TextureUsage::OUTPUT_ATTACHMENT
-                | wgpu::TextureUsage::SAMPLED
-                | wgpu::TextureUsage::COPY_SRC,
+            format(settings.srgb),
+            wgpu::TextureUsage::COPY_DST
+                | wgpu::TextureUsage::COPY_SRC
+                | wgpu::TextureUsage::SAMPLED,
         );
 
-        let texture = Texture {
-            raw: Rc::new(texture),
-            view: Rc::new(view),
-            binding: Rc::new(binding),
-            width,
-            height,
-            layers: 1,
-        };
-
-        Drawable { texture }
+        Texture {
+            premultiplied: settings.premultiply,
+            ..Texture::from_parts(texture, view, binding, width, height, 1, 1)
+        }
     }
 
-    pub fn texture(&self) -> &Texture {
-        &self.texture
-    }
+    pub(super) fn update(
+        &self,
+        device: &mut wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+        y: u16,
+        rgba: &[u8],
+    ) {
+        let width = u32::from(self.width);
+        let rows = (rgba.len() / (4 * width.max(1) as usize)) as u32;
 
-    pub fn target(&self) -> &TargetView {
-        self.texture().view()
+        let mut bgra = rgba.to_vec();
+
+        for pixel in bgra.chunks_mut(4) {
+            pixel.swap(0, 2);
+        }
+
+        let staging = device
+            .create_buffer_with_data(&bgra[..], wgpu::BufferUsage::COPY_SRC);
+
+        encoder.copy_buffer_to_texture(
+            wgpu::BufferCopyView {
+                buffer: &staging,
+                offset: 0,
+                bytes_per_row: 4 * width,
+                rows_per_image: rows,
+            },
+            wgpu::TextureCopyView {
+                texture: &self.raw,
+                array_layer: 0,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0,
+                    y: u32::from(y),
+                    z: 0,
+                },
+            },
+            wgpu::Extent3d {
+                width,
+                height: rows,
+                depth: 1,
+            },
+        );
     }
 
-    pub fn read_pixels(
+    pub(super) fn read_pixels(
         &self,
         device: &mut wgpu::Device,
         queue: &wgpu::Queue,
         mut encoder: wgpu::CommandEncoder,
     ) -> image::DynamicImage {
-        let texture = self.texture();
-
-        let buffer_size = 4 * texture.width() as u64 * texture.height() as u64;
+        let buffer_size = 4 * u64::from(self.width) * u64::from(self.height);
 
         let buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::texture pixels"),
@@ -173,7 +277,7 @@ impl Drawable {
 
         encoder.copy_texture_to_buffer(
             wgpu::TextureCopyView {
-                texture: &texture.raw,
+                texture: &self.raw,
                 mip_level: 0,
                 array_layer: 0,
                 origin: wgpu::Origin3d { x: 0, y: 0, z: 0 },
@@ -181,12 +285,12 @@ impl Drawable {
             wgpu::BufferCopyView {
                 buffer: &buffer,
                 offset: 0,
-                bytes_per_row: 4 * u32::from(texture.width()),
-                rows_per_image: u32::from(texture.height()),
+                bytes_per_row: 4 * u32::from(self.width),
+                rows_per_image: u32::from(self.height),
             },
             wgpu::Extent3d {
-                width: u32::from(texture.width()),
-                height: u32::from(texture.height()),
+                width: u32::from(self.width),
+                height: u32::from(self.height),
                 depth: 1,
             },
         );
@@ -204,15 +308,188 @@ impl Drawable {
 
         image::DynamicImage::ImageBgra8(
             image::ImageBuffer::from_raw(
-                texture.width() as u32,
-                texture.height() as u32,
+                u32::from(self.width),
+                u32::from(self.height),
                 bgra,
             )
             .expect("Create BGRA8 image"),
         )
     }
 
-    pub fn render_transformation() -> Transformation {
+    pub(super) fn view(&self) -> &TargetView {
+        &self.view
+    }
+
+    pub(super) fn binding(&self) -> &quad::TextureBinding {
+        &self.binding
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn sampler(&self) -> Sampler {
+        self.sampler
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.sampler = sampler;
+    }
+
+    pub fn is_premultiplied(&self) -> bool {
+        self.premultiplied
+    }
+
+    pub fn id(&self) -> ResourceId {
+        self.id
+    }
+}
+
+// Keeps the GPU resources of a texture alive until every handle is dropped,
+// moving them to the graveyard afterwards
+struct Lifetime {
+    raw: Option<Rc<wgpu::Texture>>,
+    view: Option<Rc<TargetView>>,
+    depth: Option<Rc<DepthView>>,
+    binding: Option<Rc<quad::TextureBinding>>,
+    allocation: Option<Allocation>,
+}
+
+impl Drop for Lifetime {
+    fn drop(&mut self) {
+        if self.allocation.is_none() {
+            return;
+        }
+
+        let lifetime = Lifetime {
+            raw: self.raw.take(),
+            view: self.view.take(),
+            depth: self.depth.take(),
+            binding: self.binding.take(),
+            allocation: self.allocation.take(),
+        };
+
+        // The graveyard may be gone if the thread is exiting, in which case
+        // the resources are freed right away
+        let _ = GRAVEYARD
+            .try_with(|graveyard| graveyard.borrow_mut().push(lifetime));
+    }
+}
+
+/// Frees the textures dropped since the last call.
+///
+/// It must only be called once the commands that may use them have been
+/// submitted.
+pub(super) fn collect_garbage() {
+    let dead = GRAVEYARD.with(|graveyard| graveyard.replace(Vec::new()));
+
+    drop(dead);
+}
+
+#[derive(Clone)]
+pub struct Drawable {
+    texture: Texture,
+    depth: Option<Depth>,
+}
+
+// The depth buffer of a drawable texture
+#[derive(Clone)]
+struct Depth {
+    view: Rc<DepthView>,
+    _lifetime: Rc<Lifetime>,
+}
+
+impl Depth {
+    fn new(device: &mut wgpu::Device, width: u16, height: u16) -> Depth {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("coffee::backend::depth"),
+            size: wgpu::Extent3d {
+                width: u32::from(width),
+                height: u32::from(height),
+                depth: 1,
+            },
+            array_layer_count: 1,
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: DEPTH_FORMAT,
+            usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+        });
+
+        let raw = Rc::new(texture);
+        let view = Rc::new(raw.create_default_view());
+
+        let lifetime = Lifetime {
+            raw: Some(raw),
+            view: None,
+            depth: Some(view.clone()),
+            binding: None,
+            allocation: Some(Allocation::texture(width, height, 1)),
+        };
+
+        Depth {
+            view,
+            _lifetime: Rc::new(lifetime),
+        }
+    }
+}
+
+impl Drawable {
+    pub fn new(
+        device: &mut wgpu::Device,
+        queue: &wgpu::Queue,
+        pipeline: &Pipeline,
+        width: u16,
+        height: u16,
+        has_depth: bool,
+        samples: u32,
+    ) -> Drawable {
+        let (texture, view, binding) = create_texture_array(
+            device,
+            queue,
+            pipeline,
+            u32::from(width),
+            u32::from(height),
+            1,
+            None,
+            format(true),
+            wgpu::TextureUsage::OUTPUT_ATTACHMENT
+                | wgpu::TextureUsage::SAMPLED
+                | wgpu::TextureUsage::COPY_SRC,
+        );
+
+        // The draw calls on the texture are rendered with multisampling when
+        // it has more than one sample per pixel
+        let texture = Texture::from_parts(
+            texture, view, binding, width, height, 1, samples,
+        );
+
+        let depth = if has_depth {
+            Some(Depth::new(device, width, height))
+        } else {
+            None
+        };
+
+        Drawable { texture, depth }
+    }
+
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    pub fn target(&self) -> &TargetView {
+        self.texture().view()
+    }
+
+    pub fn depth(&self) -> Option<&DepthView> {
+        self.depth.as_ref().map(|depth| &*depth.view)
+    }
+
+    pub fn render_transformation(&self) -> Transformation {
         Transformation::identity()
     }
 }
@@ -224,7 +501,9 @@ fn create_texture_array(
     pipeline: &Pipeline,
     width: u32,
     height: u32,
+    levels: u32,
     layers: Option<&[&[u8]]>,
+    format: wgpu::TextureFormat,
     usage: wgpu::TextureUsage,
 ) -> (wgpu::Texture, wgpu::TextureView, quad::TextureBinding) {
     let extent = wgpu::Extent3d {
@@ -233,16 +512,16 @@ fn create_texture_array(
         depth: 1,
     };
 
-    let layer_count = layers.map(|l| l.len()).unwrap_or(1) as u32;
+    let layer_count = layers.map(|l| l.len() as u32 / levels).unwrap_or(1);
 
     let texture = device.create_texture(&wgpu::TextureDescriptor {
         label: Some("coffee::backend::texture array"),
         size: extent,
         array_layer_count: layer_count,
-        mip_level_count: 1,
+        mip_level_count: levels,
         sample_count: 1,
         dimension: wgpu::TextureDimension::D2,
-        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        format,
         usage,
     });
 
@@ -260,31 +539,47 @@ fn create_texture_array(
                 label: Some("coffee::backend::texture upload"),
             });
 
-        encoder.copy_buffer_to_texture(
-            wgpu::BufferCopyView {
-                buffer: &temp_buf,
-                offset: 0,
-                bytes_per_row: 4 * width,
-                rows_per_image: height,
-            },
-            wgpu::TextureCopyView {
-                texture: &texture,
-                array_layer: 0,
-                mip_level: 0,
-                origin: wgpu::Origin3d { x: 0, y: 0, z: 0 },
-            },
-            extent,
-        );
+        let mut offset = 0;
+
+        // The levels of every layer are provided one after the other
+        for (i, data) in layers.iter().enumerate() {
+            let layer = i as u32 / levels;
+            let level = i as u32 % levels;
+            let level_width = (width >> level).max(1);
+            let level_height = (height >> level).max(1);
+
+            encoder.copy_buffer_to_texture(
+                wgpu::BufferCopyView {
+                    buffer: &temp_buf,
+                    offset,
+                    bytes_per_row: 4 * level_width,
+                    rows_per_image: level_height,
+                },
+                wgpu::TextureCopyView {
+                    texture: &texture,
+                    array_layer: layer,
+                    mip_level: level,
+                    origin: wgpu::Origin3d { x: 0, y: 0, z: 0 },
+                },
+                wgpu::Extent3d {
+                    width: level_width,
+                    height: level_height,
+                    depth: 1,
+                },
+            );
+
+            offset += data.len() as u64;
+        }
 
         queue.submit(&[encoder.finish()]);
     }
 
     let view = texture.create_view(&wgpu::TextureViewDescriptor {
-        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+        format,
         dimension: wgpu::TextureViewDimension::D2Array,
         aspect: wgpu::TextureAspect::All,
         base_mip_level: 0,
-        level_count: 1,
+        level_count: levels,
         base_array_layer: 0,
         array_layer_count: layer_count,
     });
@@ -293,3 +588,14 @@ fn create_texture_array(
 
     (texture, view, binding)
 }
+
+pub(super) const DEPTH_FORMAT: wgpu::TextureFormat =
+    wgpu::TextureFormat::Depth24PlusStencil8;
+
+fn format(srgb: bool) -> wgpu::TextureFormat {
+    if srgb {
+        wgpu::TextureFormat::Bgra8UnormSrgb
+    } else {
+        wgpu::TextureFormat::Bgra8Unorm
+    }
+}
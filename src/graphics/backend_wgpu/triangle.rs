@@ -1,10 +1,16 @@
+use std::collections::HashMap;
 use std::mem;
 
-use crate::graphics::Transformation;
+use crate::graphics::gpu::Vertex;
+use crate::graphics::{BlendMode, Rectangle, Transformation};
 use zerocopy::AsBytes;
 
 pub struct Pipeline {
-    pipeline: wgpu::RenderPipeline,
+    // The pipelines are keyed by blend mode and samples per pixel
+    pipelines: HashMap<(BlendMode, u32), wgpu::RenderPipeline>,
+    layout: wgpu::PipelineLayout,
+    vs_module: wgpu::ShaderModule,
+    fs_module: wgpu::ShaderModule,
     transform: wgpu::Buffer,
     constants: wgpu::BindGroup,
     vertices: wgpu::Buffer,
@@ -63,64 +69,6 @@ impl Pipeline {
                 .expect("Read triangle fragment shader as SPIR-V"),
         );
 
-        let pipeline =
-            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-                layout: &layout,
-                vertex_stage: wgpu::ProgrammableStageDescriptor {
-                    module: &vs_module,
-                    entry_point: "main",
-                },
-                fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                    module: &fs_module,
-                    entry_point: "main",
-                }),
-                rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                    front_face: wgpu::FrontFace::Ccw,
-                    cull_mode: wgpu::CullMode::None,
-                    depth_bias: 0,
-                    depth_bias_slope_scale: 0.0,
-                    depth_bias_clamp: 0.0,
-                }),
-                primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                color_states: &[wgpu::ColorStateDescriptor {
-                    format: wgpu::TextureFormat::Bgra8UnormSrgb,
-                    color_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::SrcAlpha,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    alpha_blend: wgpu::BlendDescriptor {
-                        src_factor: wgpu::BlendFactor::One,
-                        dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                        operation: wgpu::BlendOperation::Add,
-                    },
-                    write_mask: wgpu::ColorWrite::ALL,
-                }],
-                depth_stencil_state: None,
-                vertex_state: wgpu::VertexStateDescriptor {
-                    index_format: wgpu::IndexFormat::Uint32,
-                    vertex_buffers: &[wgpu::VertexBufferDescriptor {
-                        stride: mem::size_of::<Vertex>() as u64,
-                        step_mode: wgpu::InputStepMode::Vertex,
-                        attributes: &[
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 0,
-                                format: wgpu::VertexFormat::Float2,
-                                offset: 0,
-                            },
-                            wgpu::VertexAttributeDescriptor {
-                                shader_location: 1,
-                                format: wgpu::VertexFormat::Float4,
-                                offset: 4 * 2,
-                            },
-                        ],
-                    }],
-                },
-                sample_count: 1,
-                sample_mask: !0,
-                alpha_to_coverage_enabled: false,
-            });
-
         let vertices = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("coffee::backend::triangle vertices"),
             size: mem::size_of::<Vertex>() as u64
@@ -135,14 +83,50 @@ impl Pipeline {
             usage: wgpu::BufferUsage::INDEX | wgpu::BufferUsage::COPY_DST,
         });
 
-        Pipeline {
-            pipeline,
+        let mut pipeline = Pipeline {
+            pipelines: HashMap::new(),
+            layout,
+            vs_module,
+            fs_module,
             transform: transform_buffer,
             constants: constant_bind_group,
             vertices,
             indices,
             buffer_size: Self::INITIAL_BUFFER_SIZE,
+        };
+
+        // A pipeline is created for every blend mode, as the blend state
+        // cannot be changed when drawing. Multisampled pipelines are only
+        // created once they are needed.
+        for mode in BlendMode::ALL.iter() {
+            pipeline.prepare_pipeline(device, (*mode, 1));
+        }
+
+        pipeline
+    }
+
+    // Creates the pipeline with the given key, unless it exists already
+    fn prepare_pipeline(
+        &mut self,
+        device: &wgpu::Device,
+        key: (BlendMode, u32),
+    ) {
+        if self.pipelines.contains_key(&key) {
+            return;
         }
+
+        let (blend_mode, samples) = key;
+
+        let pipeline = create_pipeline(
+            device,
+            &self.layout,
+            &self.vs_module,
+            &self.fs_module,
+            blend_mode,
+            samples,
+        );
+
+        let _ = self.pipelines.insert(key, pipeline);
     }
 
     pub fn draw(
@@ -153,12 +137,17 @@ impl Pipeline {
         indices: &[u32],
         transformation: &Transformation,
         target: &wgpu::TextureView,
+        samples: u32,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
     ) {
         if vertices.is_empty() || indices.is_empty() {
             return;
         }
 
-        let matrix: [f32; 16] = transformation.clone().into();
+        self.prepare_pipeline(device, (blend_mode, samples));
+
+        let matrix: [f32; 16] = super::clip_space(transformation).into();
 
         let transform_buffer = device.create_buffer_with_data(
             matrix.as_bytes(),
@@ -239,28 +228,81 @@ impl Pipeline {
                     depth_stencil_attachment: None,
                 });
 
-            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_pipeline(&self.pipelines[&(blend_mode, samples)]);
             render_pass.set_bind_group(0, &self.constants, &[]);
             render_pass.set_index_buffer(&self.indices, 0, 0);
             render_pass.set_vertex_buffer(0, &self.vertices, 0, 0);
 
+            if let Some(scissor) = scissor {
+                render_pass.set_scissor_rect(
+                    scissor.x,
+                    scissor.y,
+                    scissor.width,
+                    scissor.height,
+                );
+            }
+
             render_pass.draw_indexed(0..indices.len() as u32, 0, 0..1);
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, AsBytes)]
-#[repr(C)]
-pub struct Vertex {
-    _position: [f32; 2],
-    _color: [f32; 4],
-}
+fn create_pipeline(
+    device: &wgpu::Device,
+    layout: &wgpu::PipelineLayout,
+    vs_module: &wgpu::ShaderModule,
+    fs_module: &wgpu::ShaderModule,
+    blend_mode: BlendMode,
+    samples: u32,
+) -> wgpu::RenderPipeline {
+    let (color_blend, alpha_blend) = super::blend(blend_mode);
 
-impl Vertex {
-    pub fn new(position: [f32; 2], color: [f32; 4]) -> Vertex {
-        Vertex {
-            _position: position,
-            _color: color,
-        }
-    }
+    device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+        layout,
+        vertex_stage: wgpu::ProgrammableStageDescriptor {
+            module: vs_module,
+            entry_point: "main",
+        },
+        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+            module: fs_module,
+            entry_point: "main",
+        }),
+        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+            front_face: wgpu::FrontFace::Ccw,
+            cull_mode: wgpu::CullMode::None,
+            depth_bias: 0,
+            depth_bias_slope_scale: 0.0,
+            depth_bias_clamp: 0.0,
+        }),
+        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+        color_states: &[wgpu::ColorStateDescriptor {
+            format: wgpu::TextureFormat::Bgra8UnormSrgb,
+            color_blend,
+            alpha_blend,
+            write_mask: wgpu::ColorWrite::ALL,
+        }],
+        depth_stencil_state: None,
+        vertex_state: wgpu::VertexStateDescriptor {
+            index_format: wgpu::IndexFormat::Uint32,
+            vertex_buffers: &[wgpu::VertexBufferDescriptor {
+                stride: mem::size_of::<Vertex>() as u64,
+                step_mode: wgpu::InputStepMode::Vertex,
+                attributes: &[
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 0,
+                        format: wgpu::VertexFormat::Float2,
+                        offset: 0,
+                    },
+                    wgpu::VertexAttributeDescriptor {
+                        shader_location: 1,
+                        format: wgpu::VertexFormat::Float4,
+                        offset: 4 * 2,
+                    },
+                ],
+            }],
+        },
+        sample_count: samples,
+        sample_mask: !0,
+        alpha_to_coverage_enabled: false,
+    })
 }
@@ -1 +1,68 @@
-pub type TargetView = wgpu::TextureView;
+// A view that can be drawn on.
+//
+// Views drawn with multisampling keep a single sample per pixel. Their draw
+// calls are rendered on a multisampled layer first, which is resolved and
+// blended on the view afterwards.
+pub struct TargetView {
+    raw: Raw,
+    width: u32,
+    height: u32,
+    samples: u32,
+}
+
+enum Raw {
+    Texture(wgpu::TextureView),
+    // The frame of a swap chain is presented when dropped
+    Frame(wgpu::SwapChainOutput),
+}
+
+impl TargetView {
+    pub fn new(
+        view: wgpu::TextureView,
+        width: u32,
+        height: u32,
+        samples: u32,
+    ) -> TargetView {
+        TargetView {
+            raw: Raw::Texture(view),
+            width,
+            height,
+            samples,
+        }
+    }
+
+    pub fn frame(
+        output: wgpu::SwapChainOutput,
+        width: u32,
+        height: u32,
+        samples: u32,
+    ) -> TargetView {
+        TargetView {
+            raw: Raw::Frame(output),
+            width,
+            height,
+            samples,
+        }
+    }
+
+    pub fn view(&self) -> &wgpu::TextureView {
+        match &self.raw {
+            Raw::Texture(view) => view,
+            Raw::Frame(output) => &output.view,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn samples(&self) -> u32 {
+        self.samples
+    }
+}
+
+pub type DepthView = wgpu::TextureView;
@@ -1,15 +1,28 @@
 use rayon::prelude::*;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 
 use crate::graphics::gpu;
-use crate::graphics::{Image, IntoQuad, Target};
+use crate::graphics::{Image, IntoQuad, Quad, Rectangle, Target};
 
 /// A collection of quads that will be drawn all at once using the same
 /// [`Image`].
 ///
+/// Quads are drawn in the order they were added. You can change this order by
+/// giving each quad a sort key with [`add_with_key`] and calling
+/// [`sort_by_key`] before drawing. Alternatively, a [`y_sorted`] batch derives
+/// the key of every quad from its bottom edge, which gives you
+/// painter's-algorithm ordering for top-down and isometric games.
+///
 /// [`Image`]: struct.Image.html
+/// [`add_with_key`]: #method.add_with_key
+/// [`sort_by_key`]: #method.sort_by_key
+/// [`y_sorted`]: #method.y_sorted
 pub struct Batch {
     image: Image,
     instances: Vec<gpu::Quad>,
+    keys: Vec<f32>,
+    is_y_sorted: bool,
     x_unit: f32,
     y_unit: f32,
 }
@@ -26,20 +39,119 @@ impl Batch {
         Self {
             image,
             instances: Vec::new(),
+            keys: Vec::new(),
+            is_y_sorted: false,
             x_unit,
             y_unit,
         }
     }
 
+    /// Makes the [`Batch`] derive the sort key of every added quad from the
+    /// position of its bottom edge.
+    ///
+    /// Call [`sort_by_key`] before drawing to draw the quads from top to
+    /// bottom.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`sort_by_key`]: #method.sort_by_key
+    pub fn y_sorted(mut self) -> Self {
+        self.is_y_sorted = true;
+        self
+    }
+
     /// Adds a quad to the [`Batch`].
     ///
+    /// If the [`Batch`] is [`y_sorted`], the sort key of the quad is the
+    /// position of its bottom edge. Otherwise, it is `0.0`.
+    ///
     /// [`Batch`]: struct.Batch.html
+    /// [`y_sorted`]: #method.y_sorted
     #[inline]
     pub fn add<Q: IntoQuad>(&mut self, quad: Q) {
-        let instance =
-            gpu::Quad::from(quad.into_quad(self.x_unit, self.y_unit));
+        let quad = quad.into_quad(self.x_unit, self.y_unit);
+        let key = sort_key(&quad, self.is_y_sorted);
+
+        self.push(quad, key);
+    }
+
+    /// Adds a quad to the [`Batch`] with the given sort key.
+    ///
+    /// The key is only used by [`sort_by_key`].
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`sort_by_key`]: #method.sort_by_key
+    #[inline]
+    pub fn add_with_key<Q: IntoQuad>(&mut self, quad: Q, key: f32) {
+        let quad = quad.into_quad(self.x_unit, self.y_unit);
+
+        self.push(quad, key);
+    }
+
+    /// Adds a quad to the [`Batch`] only if it overlaps the given bounds.
+    ///
+    /// Use it with [`Target::visible_bounds`] to skip the quads that would
+    /// be drawn off-screen. Rotated quads are checked using the area they
+    /// cover once rotated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::graphics::{Batch, Point, Sprite, Target};
+    ///
+    /// fn draw_world(
+    ///     batch: &mut Batch,
+    ///     trees: &[Point],
+    ///     camera: &mut Target<'_>,
+    /// ) {
+    ///     batch.clear();
+    ///
+    ///     if let Some(bounds) = camera.visible_bounds() {
+    ///         for tree in trees {
+    ///             batch.add_culled(
+    ///                 Sprite {
+    ///                     position: *tree,
+    ///                     ..Sprite::default()
+    ///                 },
+    ///                 &bounds,
+    ///             );
+    ///         }
+    ///     }
+    ///
+    ///     batch.draw(camera);
+    /// }
+    /// ```
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`Target::visible_bounds`]: struct.Target.html#method.visible_bounds
+    #[inline]
+    pub fn add_culled<Q: IntoQuad>(
+        &mut self,
+        quad: Q,
+        bounds: &Rectangle<f32>,
+    ) {
+        let quad = quad.into_quad(self.x_unit, self.y_unit);
+
+        if quad.bounds().intersects(bounds) {
+            let key = sort_key(&quad, self.is_y_sorted);
+
+            self.push(quad, key);
+        }
+    }
+
+    fn push(&mut self, quad: Quad, key: f32) {
+        let count = self.image.push_instances(quad, &mut self.instances);
 
-        self.instances.push(instance);
+        self.keys.extend(std::iter::repeat(key).take(count));
+    }
+
+    /// Sorts the quads of the [`Batch`] by their sort key, in ascending order.
+    ///
+    /// Quads with lower keys are drawn first. The sort is stable: quads with
+    /// equal keys keep the order they were added in.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    pub fn sort_by_key(&mut self) {
+        sort(&mut self.keys, &mut self.instances);
     }
 
     /// Draws the [`Batch`] on the given [`Target`].
@@ -50,6 +162,20 @@ impl Batch {
         target.draw_texture_quads(&self.image.texture, &self.instances[..]);
     }
 
+    /// Freezes the [`Batch`], turning it into a [`FrozenBatch`].
+    ///
+    /// Use this for quads that rarely change, like static tile layers and
+    /// backgrounds.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`FrozenBatch`]: struct.FrozenBatch.html
+    pub fn freeze(self) -> FrozenBatch {
+        FrozenBatch {
+            batch: self,
+            buffer: RefCell::new(None),
+        }
+    }
+
     /// Clears the [`Batch`] contents.
     ///
     /// This is useful to avoid creating a new batch every frame and
@@ -58,6 +184,144 @@ impl Batch {
     /// [`Batch`]: struct.Batch.html
     pub fn clear(&mut self) {
         self.instances.clear();
+        self.keys.clear();
+    }
+}
+
+/// A [`Batch`] whose quads are uploaded to the GPU only once.
+///
+/// Drawing a [`Batch`] uploads all of its quads every time. A [`FrozenBatch`]
+/// uploads them the first time it is drawn and reuses them afterwards, until
+/// it is modified.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::{Batch, FrozenBatch, Image, Point, Sprite, Target};
+///
+/// fn build_background(tileset: Image) -> FrozenBatch {
+///     let mut batch = Batch::new(tileset);
+///
+///     for i in 0..100 {
+///         let x = (i % 10) as f32 * 32.0;
+///         let y = (i / 10) as f32 * 32.0;
+///
+///         batch.add(Sprite {
+///             position: Point::new(x, y),
+///             ..Sprite::default()
+///         });
+///     }
+///
+///     batch.freeze()
+/// }
+///
+/// fn draw_background(background: &FrozenBatch, target: &mut Target<'_>) {
+///     // The tiles are only uploaded the first time
+///     background.draw(target);
+/// }
+/// ```
+///
+/// [`Batch`]: struct.Batch.html
+/// [`FrozenBatch`]: struct.FrozenBatch.html
+pub struct FrozenBatch {
+    batch: Batch,
+    buffer: RefCell<Option<gpu::InstanceBuffer>>,
+}
+
+impl FrozenBatch {
+    /// Returns the frozen [`Batch`].
+    ///
+    /// [`Batch`]: struct.Batch.html
+    pub fn batch(&self) -> &Batch {
+        &self.batch
+    }
+
+    /// Modifies the frozen [`Batch`] with the given function.
+    ///
+    /// The quads will be uploaded again the next time the [`FrozenBatch`] is
+    /// drawn.
+    ///
+    /// [`Batch`]: struct.Batch.html
+    /// [`FrozenBatch`]: struct.FrozenBatch.html
+    pub fn modify<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut Batch),
+    {
+        f(&mut self.batch);
+
+        *self.buffer.get_mut() = None;
+    }
+
+    /// Turns the [`FrozenBatch`] back into a [`Batch`].
+    ///
+    /// [`FrozenBatch`]: struct.FrozenBatch.html
+    /// [`Batch`]: struct.Batch.html
+    pub fn thaw(self) -> Batch {
+        self.batch
+    }
+
+    /// Draws the [`FrozenBatch`] on the given [`Target`].
+    ///
+    /// [`FrozenBatch`]: struct.FrozenBatch.html
+    /// [`Target`]: struct.Target.html
+    pub fn draw(&self, target: &mut Target<'_>) {
+        let mut buffer = self.buffer.borrow_mut();
+        let instances = &self.batch.instances[..];
+
+        let texture = &self.batch.image.texture;
+
+        let buffer = buffer.get_or_insert_with(|| {
+            if texture.is_premultiplied() {
+                let premultiplied: Vec<gpu::Quad> = instances
+                    .iter()
+                    .map(|instance| {
+                        let mut instance = *instance;
+                        instance.premultiply();
+                        instance
+                    })
+                    .collect();
+
+                target.upload_instances(&premultiplied)
+            } else {
+                target.upload_instances(instances)
+            }
+        });
+
+        target.draw_instance_buffer(texture, buffer, instances);
+    }
+}
+
+impl std::fmt::Debug for FrozenBatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FrozenBatch {{ batch: {:?}, is_uploaded: {} }}",
+            self.batch,
+            self.buffer.borrow().is_some()
+        )
+    }
+}
+
+fn sort_key(quad: &Quad, is_y_sorted: bool) -> f32 {
+    if is_y_sorted {
+        quad.position.y + quad.size.1
+    } else {
+        0.0
+    }
+}
+
+// Sorts the instances by their keys, keeping the order of equal keys
+fn sort(keys: &mut Vec<f32>, instances: &mut Vec<gpu::Quad>) {
+    let mut entries: Vec<(f32, gpu::Quad)> =
+        keys.drain(..).zip(instances.drain(..)).collect();
+
+    entries.par_sort_by(|(a, _), (b, _)| {
+        a.partial_cmp(b).unwrap_or(Ordering::Equal)
+    });
+
+    for (key, instance) in entries {
+        keys.push(key);
+        instances.push(instance);
     }
 }
 
@@ -75,10 +339,14 @@ impl<Q: IntoQuad> Extend<Q> for Batch {
         let iter = iter.into_iter();
         let x_unit = self.x_unit;
         let y_unit = self.y_unit;
+        let is_y_sorted = self.is_y_sorted;
 
-        self.instances.extend(
-            iter.map(|quad| gpu::Quad::from(quad.into_quad(x_unit, y_unit))),
-        );
+        for quad in iter {
+            let quad = quad.into_quad(x_unit, y_unit);
+            let key = sort_key(&quad, is_y_sorted);
+
+            self.push(quad, key);
+        }
     }
 }
 
@@ -97,10 +365,103 @@ impl<Q: IntoQuad + Send> ParallelExtend<Q> for Batch {
         let par_iter = par_iter.into_par_iter();
         let x_unit = self.x_unit;
         let y_unit = self.y_unit;
+        let is_y_sorted = self.is_y_sorted;
+
+        // Every quad is a single instance with the default key, so we can
+        // write the instances directly
+        if self.image.tiling.is_none() && !is_y_sorted {
+            self.instances.par_extend(
+                par_iter.map(|quad| {
+                    gpu::Quad::from(quad.into_quad(x_unit, y_unit))
+                }),
+            );
+            self.keys.resize(self.instances.len(), 0.0);
+
+            return;
+        }
+
+        let mut entries = Vec::new();
+
+        entries.par_extend(par_iter.map(|quad| {
+            let quad = quad.into_quad(x_unit, y_unit);
+
+            (sort_key(&quad, is_y_sorted), quad)
+        }));
+
+        for (key, quad) in entries {
+            self.push(quad, key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphics::Point;
+
+    fn quad(x: f32, y: f32, height: f32) -> Quad {
+        Quad {
+            position: Point::new(x, y),
+            size: (10.0, height),
+            ..Quad::default()
+        }
+    }
 
-        self.instances.par_extend(
-            par_iter
-                .map(|quad| gpu::Quad::from(quad.into_quad(x_unit, y_unit))),
+    fn positions(instances: &[gpu::Quad]) -> Vec<[f32; 2]> {
+        instances
+            .iter()
+            .map(|instance| instance.translation)
+            .collect()
+    }
+
+    #[test]
+    fn sort_orders_by_ascending_key() {
+        let mut keys = vec![3.0, 1.0, 2.0];
+        let mut instances: Vec<gpu::Quad> = vec![
+            quad(3.0, 0.0, 10.0).into(),
+            quad(1.0, 0.0, 10.0).into(),
+            quad(2.0, 0.0, 10.0).into(),
+        ];
+
+        sort(&mut keys, &mut instances);
+
+        assert_eq!(keys, vec![1.0, 2.0, 3.0]);
+        assert_eq!(
+            positions(&instances),
+            vec![[1.0, 0.0], [2.0, 0.0], [3.0, 0.0]]
         );
     }
+
+    #[test]
+    fn sort_is_stable() {
+        let mut keys = vec![1.0, 0.0, 1.0, 0.0];
+        let mut instances: Vec<gpu::Quad> =
+            (0..4).map(|i| quad(i as f32, 0.0, 10.0).into()).collect();
+
+        sort(&mut keys, &mut instances);
+
+        assert_eq!(keys, vec![0.0, 0.0, 1.0, 1.0]);
+        assert_eq!(
+            positions(&instances),
+            vec![[1.0, 0.0], [3.0, 0.0], [0.0, 0.0], [2.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn y_sorted_keys_use_the_bottom_edge() {
+        // A tall quad starting higher can end lower than a short one
+        let tall = quad(0.0, 0.0, 50.0);
+        let short = quad(1.0, 20.0, 10.0);
+
+        assert_eq!(sort_key(&tall, true), 50.0);
+        assert_eq!(sort_key(&short, true), 30.0);
+        assert_eq!(sort_key(&tall, false), 0.0);
+
+        let mut keys = vec![sort_key(&tall, true), sort_key(&short, true)];
+        let mut instances = vec![tall.into(), short.into()];
+
+        sort(&mut keys, &mut instances);
+
+        assert_eq!(positions(&instances), vec![[1.0, 20.0], [0.0, 0.0]]);
+    }
 }
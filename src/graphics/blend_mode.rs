@@ -0,0 +1,48 @@
+/// The way the colors drawn on a [`Target`] are combined with its contents.
+///
+/// [`Target`]: struct.Target.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    /// Colors are drawn on top of the contents, using their alpha channel to
+    /// mix with them.
+    ///
+    /// This is the default.
+    Alpha,
+
+    /// Colors are added to the contents, weighted by their alpha channel.
+    ///
+    /// Overlapping draws get brighter, which is useful for lights, fire and
+    /// glow effects.
+    Additive,
+
+    /// The contents are multiplied by the colors.
+    ///
+    /// Drawing white leaves the contents untouched, while drawing black
+    /// darkens them completely. Useful for shadows and light maps.
+    Multiply,
+
+    /// Like [`Alpha`], but the colors are expected to be already multiplied
+    /// by their alpha channel.
+    ///
+    /// Images uploaded with [`ImageSettings::premultiply`] are drawn with
+    /// this mode automatically instead of [`Alpha`].
+    ///
+    /// [`Alpha`]: #variant.Alpha
+    /// [`ImageSettings::premultiply`]: struct.ImageSettings.html#structfield.premultiply
+    Premultiplied,
+}
+
+impl BlendMode {
+    pub(crate) const ALL: [BlendMode; 4] = [
+        BlendMode::Alpha,
+        BlendMode::Additive,
+        BlendMode::Multiply,
+        BlendMode::Premultiplied,
+    ];
+}
+
+impl Default for BlendMode {
+    fn default() -> BlendMode {
+        BlendMode::Alpha
+    }
+}
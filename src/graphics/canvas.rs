@@ -1,16 +1,27 @@
-use crate::graphics::gpu::{self, texture, Gpu};
-use crate::graphics::{IntoQuad, Target};
+use std::path::Path;
+
+use crate::graphics::gpu::{self, Drawable, Gpu};
+use crate::graphics::{
+    DrawParameters, IntoQuad, ResourceId, Sampler, Target, Transformation,
+};
 use crate::load::Task;
-use crate::Result;
+use crate::{Error, Result};
 
 /// An off-screen rendering target.
 ///
 /// It can be used both as a [`Target`] and as a resource.
 ///
+/// A [`Canvas`] is not multisampled, even if
+/// [`WindowSettings::multisampling`] is enabled. Use
+/// [`Canvas::with_multisampling`] to create one that is.
+///
+/// [`Canvas`]: struct.Canvas.html
+/// [`WindowSettings::multisampling`]: struct.WindowSettings.html#structfield.multisampling
+/// [`Canvas::with_multisampling`]: struct.Canvas.html#method.with_multisampling
 /// [`Target`]: struct.Target.html
 #[derive(Clone)]
 pub struct Canvas {
-    drawable: texture::Drawable,
+    drawable: Drawable,
 }
 
 impl Canvas {
@@ -18,8 +29,73 @@ impl Canvas {
     ///
     /// [`Canvas`]: struct.Canvas.html
     pub fn new(gpu: &mut Gpu, width: u16, height: u16) -> Result<Canvas> {
+        Canvas::create(gpu, width, height, false, 0)
+    }
+
+    /// Creates a new [`Canvas`] with the given size and a depth buffer.
+    ///
+    /// The quads drawn on a [`Target::with_projection`] of the [`Canvas`] are
+    /// tested against the depth buffer, so the ones behind others are hidden
+    /// regardless of the drawing order. It is cleared along with the
+    /// [`Canvas`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target::with_projection`]: struct.Target.html#method.with_projection
+    pub fn with_depth(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+    ) -> Result<Canvas> {
+        Canvas::create(gpu, width, height, true, 0)
+    }
+
+    /// Creates a new [`Canvas`] with the given size that is drawn with
+    /// multisample anti-aliasing (MSAA), using the given amount of samples per
+    /// pixel.
+    ///
+    /// It smooths the edges of meshes and rotated images drawn on the
+    /// [`Canvas`]. The supported amounts of samples are `2`, `4`, and `8`,
+    /// while `0` and `1` disable multisampling.
+    ///
+    /// Keep in mind that multisampling multiplies the memory used by the
+    /// [`Canvas`] and the work needed to draw on it.
+    ///
+    /// # Errors
+    /// It fails with [`Error::MultisamplingNotSupported`] when the amount of
+    /// samples is not supported.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Error::MultisamplingNotSupported`]: ../enum.Error.html#variant.MultisamplingNotSupported
+    pub fn with_multisampling(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+        samples: u16,
+    ) -> Result<Canvas> {
+        Canvas::create(gpu, width, height, false, samples)
+    }
+
+    fn create(
+        gpu: &mut Gpu,
+        width: u16,
+        height: u16,
+        has_depth: bool,
+        samples: u16,
+    ) -> Result<Canvas> {
+        let max = gpu.max_texture_size();
+
+        if width > max || height > max {
+            return Err(Error::TextureTooLarge { max });
+        }
+
+        match samples {
+            0 | 1 | 2 | 4 | 8 => {}
+            _ => return Err(Error::MultisamplingNotSupported(samples)),
+        }
+
         Ok(Canvas {
-            drawable: gpu.create_drawable_texture(width, height),
+            drawable: gpu
+                .create_drawable_texture(width, height, has_depth, samples),
         })
     }
 
@@ -45,6 +121,33 @@ impl Canvas {
         self.drawable.texture().height()
     }
 
+    /// Returns the [`ResourceId`] of the [`Canvas`].
+    ///
+    /// [`ResourceId`]: struct.ResourceId.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn id(&self) -> ResourceId {
+        self.drawable.texture().id()
+    }
+
+    /// Returns the [`Sampler`] used to draw the [`Canvas`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn sampler(&self) -> Sampler {
+        self.drawable.texture().sampler()
+    }
+
+    /// Sets the [`Sampler`] used to draw the [`Canvas`].
+    ///
+    /// Only this handle is affected. Other clones of the [`Canvas`] keep their
+    /// own [`Sampler`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.drawable.set_sampler(sampler);
+    }
+
     /// Views the [`Canvas`] as a [`Target`].
     ///
     /// [`Canvas`]: struct.Canvas.html
@@ -57,8 +160,9 @@ impl Canvas {
             self.drawable.target(),
             f32::from(texture.width()),
             f32::from(texture.height()),
-            texture::Drawable::render_transformation(),
+            self.drawable.render_transformation(),
         )
+        .with_depth(self.drawable.depth())
     }
 
     /// Renders the [`Canvas`] on the given [`Target`].
@@ -75,6 +179,25 @@ impl Canvas {
         );
     }
 
+    /// Renders the [`Canvas`] on the given [`Target`] with the given
+    /// [`DrawParameters`], applying a [`Transformation`] first.
+    ///
+    /// The [`Target`] can be another [`Canvas`], which allows you to compose
+    /// post-processing steps or picture-in-picture views.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Target`]: struct.Target.html
+    /// [`DrawParameters`]: struct.DrawParameters.html
+    /// [`Transformation`]: struct.Transformation.html
+    pub fn draw_with(
+        &self,
+        parameters: DrawParameters,
+        transformation: Transformation,
+        target: &mut Target<'_>,
+    ) {
+        self.draw(parameters, &mut target.transform(transformation));
+    }
+
     /// Reads the pixels of the [`Canvas`].
     ///
     /// _Note:_ This is a very slow operation.
@@ -83,6 +206,31 @@ impl Canvas {
     pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
         gpu.read_drawable_texture_pixels(&self.drawable)
     }
+
+    /// Saves the contents of the [`Canvas`] to the given path.
+    ///
+    /// The format of the file is inferred from the extension of the path.
+    ///
+    /// _Note:_ This is a very slow operation.
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn save<P: AsRef<Path>>(&self, gpu: &mut Gpu, path: P) -> Result<()> {
+        crate::graphics::image::write(&self.read_pixels(gpu), path)
+    }
+}
+
+impl PartialEq for Canvas {
+    fn eq(&self, other: &Canvas) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Canvas {}
+
+impl std::hash::Hash for Canvas {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
 }
 
 impl std::fmt::Debug for Canvas {
@@ -0,0 +1,746 @@
+use std::collections::HashMap;
+
+use crate::graphics::font::decorations;
+use crate::graphics::gpu::{self, Texture};
+use crate::graphics::{
+    Color, FilterMode, FontMetrics, Gpu, HorizontalAlignment, ImageSettings,
+    Mesh, Point, Quad, Rectangle, ResourceId, Sampler, Shape, Target, Text,
+    VerticalAlignment,
+};
+use crate::load::Task;
+use crate::{Error, Result};
+
+// The size the glyphs are rasterized at before computing their distance
+// field, in pixels
+const BASE_SIZE: f32 = 48.0;
+
+// The distance from the edges of the glyphs covered by the distance field, in
+// pixels at the base size
+const SPREAD: u32 = 6;
+
+// The space left between the glyphs of the atlas, in pixels
+const GAP: u32 = 1;
+
+/// A font rendered from a signed distance field, which stays crisp at any
+/// scale.
+///
+/// A [`Font`] rasterizes its glyphs for every text size it draws, so text
+/// drawn with a scaling [`Transformation`], like a name tag above a unit in a
+/// zoomed world, looks blurry. A [`DistanceFieldFont`] stores the distance to
+/// the edges of its glyphs in an atlas generated once, when it is loaded, and
+/// reconstructs their edges in a shader. This way, its text stays sharp when
+/// zoomed in or out, without rasterizing the glyphs again.
+///
+/// Only the characters given when loading it are available. The rest are
+/// drawn as a `?`, if available, or skipped otherwise.
+///
+/// Compared to [`Font`], it has a single face and the `face` field of
+/// [`Text`] is ignored. Glyphs outside of the `bounds` of some [`Text`] are
+/// not clipped either. Very thin strokes and sharp corners lose some detail,
+/// so prefer a [`Font`] for small text drawn at its natural size.
+///
+/// # Example
+///
+/// ```no_run
+/// use coffee::graphics::{
+///     Color, DistanceFieldFont, Gpu, HorizontalAlignment, Point, Target,
+///     Text,
+/// };
+/// use coffee::Result;
+///
+/// fn draw_name_tag(
+///     gpu: &mut Gpu,
+///     bytes: &'static [u8],
+///     unit: Point,
+///     world: &mut Target<'_>,
+/// ) -> Result<()> {
+///     let mut font = DistanceFieldFont::from_bytes(gpu, bytes)?;
+///
+///     font.add(Text {
+///         content: "Knight",
+///         position: Point::new(unit.x - 50.0, unit.y - 20.0),
+///         bounds: (100.0, 20.0),
+///         size: 12.0,
+///         color: Color::WHITE,
+///         horizontal_alignment: HorizontalAlignment::Center,
+///         ..Text::default()
+///     });
+///
+///     // The target can be zoomed in by a camera transformation
+///     font.draw(world);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Font`]: struct.Font.html
+/// [`Transformation`]: struct.Transformation.html
+/// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+/// [`Text`]: struct.Text.html
+pub struct DistanceFieldFont {
+    font: rusttype::Font<'static>,
+    texture: Texture,
+    glyphs: HashMap<char, Glyph>,
+    instances: Vec<gpu::Quad>,
+    backgrounds: Vec<(Rectangle<f32>, Color)>,
+}
+
+impl DistanceFieldFont {
+    /// The characters available in a [`DistanceFieldFont`] loaded with
+    /// [`from_bytes`]: printable ASCII and Latin-1 characters.
+    ///
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    /// [`from_bytes`]: #method.from_bytes
+    pub const DEFAULT_CHARACTERS: &'static str = concat!(
+        " !\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`",
+        "abcdefghijklmnopqrstuvwxyz{|}~",
+        "¡¢£¤¥¦§¨©ª«¬®¯°±²³´µ¶·¸¹º»¼½¾¿ÀÁÂÃÄÅÆÇÈÉÊËÌÍÎÏÐÑÒÓÔÕÖ×ØÙÚÛÜÝÞß",
+        "àáâãäåæçèéêëìíîïðñòóôõö÷øùúûüýþÿ",
+    );
+
+    /// Loads a [`DistanceFieldFont`] from raw data, generating the distance
+    /// field of the [`DEFAULT_CHARACTERS`].
+    ///
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    /// [`DEFAULT_CHARACTERS`]: #associatedconstant.DEFAULT_CHARACTERS
+    pub fn from_bytes(
+        gpu: &mut Gpu,
+        bytes: &'static [u8],
+    ) -> Result<DistanceFieldFont> {
+        DistanceFieldFont::from_bytes_with_characters(
+            gpu,
+            bytes,
+            DistanceFieldFont::DEFAULT_CHARACTERS,
+        )
+    }
+
+    /// Loads a [`DistanceFieldFont`] from raw data, generating the distance
+    /// field of the given characters.
+    ///
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    pub fn from_bytes_with_characters(
+        gpu: &mut Gpu,
+        bytes: &'static [u8],
+        characters: &str,
+    ) -> Result<DistanceFieldFont> {
+        let font = rusttype::Font::from_bytes(bytes)
+            .map_err(|error| Error::Font(error.to_string()))?;
+
+        let (atlas, glyphs) =
+            generate_atlas(&font, characters, gpu.limits().max_texture_size)?;
+
+        let mut texture = gpu.upload_texture(&atlas, ImageSettings::default());
+
+        // The distance field needs to be interpolated to find the edges of
+        // the glyphs
+        texture.set_sampler(Sampler {
+            filter: FilterMode::Linear,
+            ..Sampler::default()
+        });
+
+        Ok(DistanceFieldFont {
+            font,
+            texture,
+            glyphs,
+            instances: Vec::new(),
+            backgrounds: Vec::new(),
+        })
+    }
+
+    /// Creates a [`Task`] that loads a [`DistanceFieldFont`] from raw data.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    pub fn load_from_bytes(bytes: &'static [u8]) -> Task<DistanceFieldFont> {
+        Task::using_gpu(move |gpu| DistanceFieldFont::from_bytes(gpu, bytes))
+    }
+
+    /// Returns the [`ResourceId`] of the [`DistanceFieldFont`].
+    ///
+    /// [`ResourceId`]: struct.ResourceId.html
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    pub fn id(&self) -> ResourceId {
+        self.texture.id()
+    }
+
+    /// Adds [`Text`] to this [`DistanceFieldFont`].
+    ///
+    /// Its outline, shadow, and background are queued as well, if any.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    pub fn add(&mut self, text: Text<'_>) {
+        let text = self.align_baseline(text);
+
+        if let Some((color, padding)) = text.background {
+            let bounds = self.bounds(text.clone());
+
+            self.backgrounds.push((
+                Rectangle {
+                    x: bounds.x - padding,
+                    y: bounds.y - padding,
+                    width: bounds.width + padding * 2.0,
+                    height: bounds.height + padding * 2.0,
+                },
+                color,
+            ));
+        }
+
+        for copy in decorations(&text) {
+            self.queue(&copy);
+        }
+
+        self.queue(&text);
+    }
+
+    /// Computes the layout bounds of the given [`Text`].
+    ///
+    /// [`Text`]: struct.Text.html
+    pub fn measure(&self, text: Text<'_>) -> (f32, f32) {
+        let bounds = self.bounds(text);
+
+        (bounds.width, bounds.height)
+    }
+
+    /// Computes the area covered by the given [`Text`] once drawn.
+    ///
+    /// It works like [`Font::bounds`].
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`Font::bounds`]: struct.Font.html#method.bounds
+    pub fn bounds(&self, text: Text<'_>) -> Rectangle<f32> {
+        let text = self.align_baseline(text);
+
+        if text.content.is_empty() {
+            return Rectangle {
+                x: text.position.x,
+                y: text.position.y,
+                width: 0.0,
+                height: 0.0,
+            };
+        }
+
+        let lines = self.lines(&text);
+        let metrics = self.metrics(text.size);
+        let top = self.top(&text, &lines, &metrics);
+
+        let left = lines
+            .iter()
+            .map(|line| line_left(&text, line.width))
+            .fold(std::f32::INFINITY, f32::min);
+
+        let right = lines
+            .iter()
+            .map(|line| line_left(&text, line.width) + line.width)
+            .fold(std::f32::NEG_INFINITY, f32::max);
+
+        Rectangle {
+            x: left,
+            y: top,
+            width: right - left,
+            height: text_height(&lines, &metrics),
+        }
+    }
+
+    /// Returns the vertical [`Metrics`] of the [`DistanceFieldFont`] for the
+    /// given text size.
+    ///
+    /// [`Metrics`]: struct.FontMetrics.html
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    pub fn metrics(&self, size: f32) -> FontMetrics {
+        let metrics = self.font.v_metrics(rusttype::Scale::uniform(size));
+
+        FontMetrics {
+            ascent: metrics.ascent,
+            descent: metrics.descent,
+            line_gap: metrics.line_gap,
+        }
+    }
+
+    /// Renders and flushes all the text added to this [`DistanceFieldFont`].
+    ///
+    /// [`DistanceFieldFont`]: struct.DistanceFieldFont.html
+    pub fn draw(&mut self, target: &mut Target<'_>) {
+        if !self.backgrounds.is_empty() {
+            let mut mesh = Mesh::new();
+
+            for (rectangle, color) in self.backgrounds.drain(..) {
+                mesh.fill(Shape::Rectangle(rectangle), color);
+            }
+
+            target.draw_text_mesh(&mesh);
+        }
+
+        if !self.instances.is_empty() {
+            target.draw_distance_field_quads(&self.texture, &self.instances);
+            self.instances.clear();
+        }
+    }
+
+    fn queue(&mut self, text: &Text<'_>) {
+        let lines = self.lines(text);
+        let scale = text.size / BASE_SIZE;
+        let metrics = self.metrics(text.size);
+        let mut baseline = self.top(text, &lines, &metrics) + metrics.ascent;
+
+        for line in &lines {
+            let left = line_left(text, line.width);
+
+            for (character, x) in &line.glyphs {
+                if character.is_whitespace() {
+                    continue;
+                }
+
+                if let Some(region) =
+                    self.glyph(*character).and_then(|glyph| glyph.region)
+                {
+                    let quad = Quad {
+                        source: region.source,
+                        position: Point::new(
+                            left + x + region.offset.0 * scale,
+                            baseline + region.offset.1 * scale,
+                        ),
+                        size: (region.size.0 * scale, region.size.1 * scale),
+                        colors: [text.color; 4],
+                        ..Quad::default()
+                    };
+
+                    self.instances.push(gpu::Quad::from(quad));
+                }
+            }
+
+            baseline += metrics.line_height();
+        }
+    }
+
+    // Breaks the text into lines, wrapping words that do not fit in its
+    // bounds
+    fn lines(&self, text: &Text<'_>) -> Vec<Line> {
+        let scale = text.size / BASE_SIZE;
+        let kerning = rusttype::Scale::uniform(text.size);
+        let max_width = text.bounds.0;
+
+        let mut lines = Vec::new();
+
+        for paragraph in text.content.split('\n') {
+            let mut line = Line::default();
+            let mut x = 0.0;
+            let mut word_start = 0;
+            let mut width_before_word = 0.0;
+            let mut previous: Option<&Glyph> = None;
+
+            for character in paragraph.chars() {
+                let glyph = match self.glyph(character) {
+                    Some(glyph) => glyph,
+                    None => continue,
+                };
+
+                if let Some(previous) = previous {
+                    x += self.font.pair_kerning(kerning, previous.id, glyph.id);
+                }
+
+                previous = Some(glyph);
+
+                let advance = glyph.advance * scale;
+
+                if character.is_whitespace() {
+                    line.glyphs.push((character, x));
+                    x += advance;
+                    word_start = line.glyphs.len();
+                    width_before_word = line.width;
+                    continue;
+                }
+
+                // The current word is moved to a new line if it does not fit
+                if x + advance > max_width && word_start > 0 {
+                    let word = line.glyphs.split_off(word_start);
+                    let offset = word.first().map(|(_, x)| *x).unwrap_or(x);
+
+                    line.width = width_before_word;
+                    lines.push(line);
+
+                    line = Line {
+                        glyphs: word
+                            .into_iter()
+                            .map(|(character, x)| (character, x - offset))
+                            .collect(),
+                        width: 0.0,
+                    };
+
+                    x -= offset;
+                    word_start = 0;
+                }
+
+                line.glyphs.push((character, x));
+                x += advance;
+                line.width = x;
+            }
+
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    fn glyph(&self, character: char) -> Option<&Glyph> {
+        self.glyphs
+            .get(&character)
+            .or_else(|| self.glyphs.get(&'?'))
+    }
+
+    fn top(
+        &self,
+        text: &Text<'_>,
+        lines: &[Line],
+        metrics: &FontMetrics,
+    ) -> f32 {
+        let height = text_height(lines, metrics);
+
+        match text.vertical_alignment {
+            VerticalAlignment::Top | VerticalAlignment::Baseline => {
+                text.position.y
+            }
+            VerticalAlignment::Center => {
+                text.position.y + (text.bounds.1 - height) / 2.0
+            }
+            VerticalAlignment::Bottom => {
+                text.position.y + text.bounds.1 - height
+            }
+        }
+    }
+
+    fn align_baseline<'a>(&self, text: Text<'a>) -> Text<'a> {
+        match text.vertical_alignment {
+            VerticalAlignment::Baseline => {
+                let ascent = self.metrics(text.size).ascent;
+
+                Text {
+                    position: Point::new(
+                        text.position.x,
+                        text.position.y - ascent,
+                    ),
+                    vertical_alignment: VerticalAlignment::Top,
+                    ..text
+                }
+            }
+            _ => text,
+        }
+    }
+}
+
+impl std::fmt::Debug for DistanceFieldFont {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DistanceFieldFont {{ glyphs: {} }}", self.glyphs.len())
+    }
+}
+
+impl PartialEq for DistanceFieldFont {
+    fn eq(&self, other: &DistanceFieldFont) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for DistanceFieldFont {}
+
+impl std::hash::Hash for DistanceFieldFont {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+// A glyph of the atlas. Its measures are in pixels at the base size.
+struct Glyph {
+    id: rusttype::GlyphId,
+    advance: f32,
+    region: Option<Region>,
+}
+
+// The region of the atlas containing the distance field of a glyph, which is
+// missing for glyphs without an outline, like spaces
+#[derive(Clone, Copy)]
+struct Region {
+    source: Rectangle<f32>,
+    offset: (f32, f32),
+    size: (f32, f32),
+}
+
+// A line of laid out text, with the horizontal position of each of its
+// characters
+#[derive(Default)]
+struct Line {
+    glyphs: Vec<(char, f32)>,
+    width: f32,
+}
+
+fn line_left(text: &Text<'_>, width: f32) -> f32 {
+    match text.horizontal_alignment {
+        HorizontalAlignment::Left => text.position.x,
+        HorizontalAlignment::Center => {
+            text.position.x + (text.bounds.0 - width) / 2.0
+        }
+        HorizontalAlignment::Right => text.position.x + text.bounds.0 - width,
+    }
+}
+
+fn text_height(lines: &[Line], metrics: &FontMetrics) -> f32 {
+    lines.len() as f32 * metrics.line_height() - metrics.line_gap
+}
+
+// Rasterizes the given characters and packs their distance fields in rows
+fn generate_atlas(
+    font: &rusttype::Font<'static>,
+    characters: &str,
+    max_size: u16,
+) -> Result<(image::DynamicImage, HashMap<char, Glyph>)> {
+    let scale = rusttype::Scale::uniform(BASE_SIZE);
+
+    let mut fields = Vec::new();
+    let mut glyphs = HashMap::new();
+
+    for character in characters.chars() {
+        if glyphs.contains_key(&character) {
+            continue;
+        }
+
+        let glyph = font.glyph(character).scaled(scale);
+        let advance = glyph.h_metrics().advance_width;
+        let glyph = glyph.positioned(rusttype::point(0.0, 0.0));
+
+        if let Some(bounds) = glyph.pixel_bounding_box() {
+            let width = bounds.width() as u32 + SPREAD * 2;
+            let height = bounds.height() as u32 + SPREAD * 2;
+
+            let mut coverage = vec![0.0; (width * height) as usize];
+
+            glyph.draw(|x, y, value| {
+                let index = (y + SPREAD) * width + x + SPREAD;
+                coverage[index as usize] = value;
+            });
+
+            fields.push((
+                character,
+                (bounds.min.x - SPREAD as i32, bounds.min.y - SPREAD as i32),
+                width,
+                height,
+                distance_field(&coverage, width as usize, height as usize),
+            ));
+        }
+
+        let _ = glyphs.insert(
+            character,
+            Glyph {
+                id: glyph.id(),
+                advance,
+                region: None,
+            },
+        );
+    }
+
+    // Taller glyphs are packed first, so rows waste less space
+    fields.sort_by(|a, b| b.3.cmp(&a.3));
+
+    let area: u32 = fields
+        .iter()
+        .map(|(_, _, width, height, _)| (width + GAP) * (height + GAP))
+        .sum();
+
+    let atlas_width = ((area as f32).sqrt().ceil() as u32)
+        .next_power_of_two()
+        .max(fields.iter().map(|field| field.2 + GAP).max().unwrap_or(1));
+
+    // Find the position of every glyph first, to know the atlas height
+    let mut positions = Vec::with_capacity(fields.len());
+    let (mut x, mut y, mut row_height) = (0, 0, 0);
+
+    for (_, _, width, height, _) in &fields {
+        if x + width > atlas_width {
+            x = 0;
+            y += row_height + GAP;
+            row_height = 0;
+        }
+
+        positions.push((x, y));
+
+        x += width + GAP;
+        row_height = row_height.max(*height);
+    }
+
+    let atlas_height = (y + row_height).max(1);
+
+    if atlas_width > u32::from(max_size) || atlas_height > u32::from(max_size) {
+        return Err(Error::TextureTooLarge { max: max_size });
+    }
+
+    let mut atlas = image::RgbaImage::from_pixel(
+        atlas_width,
+        atlas_height,
+        image::Rgba([255, 255, 255, 0]),
+    );
+
+    for ((character, offset, width, height, field), (x, y)) in
+        fields.into_iter().zip(positions)
+    {
+        for row in 0..height {
+            for column in 0..width {
+                let distance = field[(row * width + column) as usize];
+
+                atlas.get_pixel_mut(x + column, y + row).data[3] = distance;
+            }
+        }
+
+        if let Some(glyph) = glyphs.get_mut(&character) {
+            glyph.region = Some(Region {
+                source: Rectangle {
+                    x: x as f32 / atlas_width as f32,
+                    y: y as f32 / atlas_height as f32,
+                    width: width as f32 / atlas_width as f32,
+                    height: height as f32 / atlas_height as f32,
+                },
+                offset: (offset.0 as f32, offset.1 as f32),
+                size: (width as f32, height as f32),
+            });
+        }
+    }
+
+    Ok((image::DynamicImage::ImageRgba8(atlas), glyphs))
+}
+
+// The squared distance used for pixels that are infinitely far from an edge
+const INFINITY: f32 = 1e20;
+
+// Computes the signed distance field of the given coverage of a glyph.
+//
+// The distances to the inside and the outside of the glyph are computed with
+// the squared Euclidean distance transform by Felzenszwalb and Huttenlocher,
+// starting from the subpixel distances estimated from the coverage of the
+// pixels on the edges. The distance at the edges is stored as `128`, and it
+// increases towards the inside of the glyph, reaching the limits at `SPREAD`
+// pixels away.
+fn distance_field(coverage: &[f32], width: usize, height: usize) -> Vec<u8> {
+    let mut outer = Vec::with_capacity(coverage.len());
+    let mut inner = Vec::with_capacity(coverage.len());
+
+    for value in coverage {
+        if *value >= 1.0 {
+            outer.push(0.0);
+            inner.push(INFINITY);
+        } else if *value <= 0.0 {
+            outer.push(INFINITY);
+            inner.push(0.0);
+        } else {
+            let distance = 0.5 - value;
+
+            outer.push(if distance > 0.0 {
+                distance * distance
+            } else {
+                0.0
+            });
+            inner.push(if distance < 0.0 {
+                distance * distance
+            } else {
+                0.0
+            });
+        }
+    }
+
+    let mut transform = Transform::new(width.max(height));
+
+    transform.run(&mut outer, width, height);
+    transform.run(&mut inner, width, height);
+
+    outer
+        .iter()
+        .zip(inner.iter())
+        .map(|(outer, inner)| {
+            let distance = outer.sqrt() - inner.sqrt();
+            let value = 0.5 - distance / (SPREAD as f32 * 2.0);
+
+            (value.max(0.0).min(1.0) * 255.0).round() as u8
+        })
+        .collect()
+}
+
+// The buffers of a one-dimensional distance transform, reused for every row
+// and column
+struct Transform {
+    f: Vec<f32>,
+    v: Vec<usize>,
+    z: Vec<f32>,
+}
+
+impl Transform {
+    fn new(length: usize) -> Transform {
+        Transform {
+            f: vec![0.0; length],
+            v: vec![0; length],
+            z: vec![0.0; length + 1],
+        }
+    }
+
+    fn run(&mut self, grid: &mut [f32], width: usize, height: usize) {
+        for x in 0..width {
+            self.run_1d(grid, x, width, height);
+        }
+
+        for y in 0..height {
+            self.run_1d(grid, y * width, 1, width);
+        }
+    }
+
+    fn run_1d(
+        &mut self,
+        grid: &mut [f32],
+        offset: usize,
+        stride: usize,
+        length: usize,
+    ) {
+        let Transform { f, v, z } = self;
+
+        for q in 0..length {
+            f[q] = grid[offset + q * stride];
+        }
+
+        v[0] = 0;
+        z[0] = -INFINITY;
+        z[1] = INFINITY;
+
+        let mut k = 0;
+
+        // Find the lower envelope of the parabolas rooted at every pixel
+        for q in 1..length {
+            let mut s;
+
+            loop {
+                let r = v[k];
+
+                s = (f[q] - f[r] + (q * q) as f32 - (r * r) as f32)
+                    / (q - r) as f32
+                    / 2.0;
+
+                if k > 0 && s <= z[k] {
+                    k -= 1;
+                } else {
+                    break;
+                }
+            }
+
+            k += 1;
+            v[k] = q;
+            z[k] = s;
+            z[k + 1] = INFINITY;
+        }
+
+        k = 0;
+
+        for q in 0..length {
+            while z[k + 1] < q as f32 {
+                k += 1;
+            }
+
+            let r = v[k];
+            let distance = q as f32 - r as f32;
+
+            grid[offset + q * stride] = f[r] + distance * distance;
+        }
+    }
+}
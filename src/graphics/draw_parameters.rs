@@ -0,0 +1,212 @@
+use crate::graphics::quad::inset_source;
+use crate::graphics::{Color, IntoQuad, Point, Quad, Rectangle};
+
+/// A builder describing how to draw a resource.
+///
+/// [`DrawParameters`] unifies [`Quad`] and [`Sprite`]: the region of the
+/// resource to draw can be given in pixels or in normalized coordinates, and
+/// it is converted using the size of the resource when drawn. The size of the
+/// drawn quad is the size of that region in pixels, multiplied by the scale.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::{DrawParameters, Image, Point, Rectangle, Target};
+///
+/// fn draw_player(sheet: &Image, target: &mut Target<'_>) {
+///     sheet.draw(
+///         DrawParameters::new()
+///             .source_px(Rectangle {
+///                 x: 32,
+///                 y: 0,
+///                 width: 32,
+///                 height: 48,
+///             })
+///             .position(Point::new(100.0, 200.0))
+///             .scale(2.0, 2.0)
+///             .rotation(0.25),
+///         target,
+///     );
+/// }
+/// ```
+///
+/// [`DrawParameters`]: struct.DrawParameters.html
+/// [`Quad`]: struct.Quad.html
+/// [`Sprite`]: struct.Sprite.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrawParameters {
+    source: Source,
+    position: Point,
+    scale: (f32, f32),
+    rotation: f32,
+    origin: Point,
+    clamp_source: bool,
+    colors: [Color; 4],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Source {
+    Pixels(Rectangle<u16>),
+    Normalized(Rectangle<f32>),
+}
+
+impl DrawParameters {
+    /// Creates new [`DrawParameters`] that draw the whole resource at the
+    /// origin, with its original size.
+    ///
+    /// [`DrawParameters`]: struct.DrawParameters.html
+    pub fn new() -> DrawParameters {
+        DrawParameters {
+            source: Source::Normalized(Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 1.0,
+                height: 1.0,
+            }),
+            position: Point::new(0.0, 0.0),
+            scale: (1.0, 1.0),
+            rotation: 0.0,
+            origin: Point::new(0.0, 0.0),
+            clamp_source: false,
+            colors: [Color::WHITE; 4],
+        }
+    }
+
+    /// Sets the region of the resource to draw, in pixels.
+    pub fn source_px(mut self, source: Rectangle<u16>) -> DrawParameters {
+        self.source = Source::Pixels(source);
+        self
+    }
+
+    /// Sets the region of the resource to draw, in normalized coordinates:
+    /// [0.0, 1.0].
+    pub fn source(mut self, source: Rectangle<f32>) -> DrawParameters {
+        self.source = Source::Normalized(source);
+        self
+    }
+
+    /// Sets the position where the resource should be drawn.
+    pub fn position(mut self, position: Point) -> DrawParameters {
+        self.position = position;
+        self
+    }
+
+    /// Sets the scale to apply to the size of the source region.
+    ///
+    /// A negative scale mirrors the resource along its axis. The drawn quad
+    /// keeps its position and covers the same area.
+    pub fn scale(mut self, x: f32, y: f32) -> DrawParameters {
+        self.scale = (x, y);
+        self
+    }
+
+    /// Sets the rotation to apply, in radians.
+    ///
+    /// The resource is rotated around its [`origin`].
+    ///
+    /// [`origin`]: #method.origin
+    pub fn rotation(mut self, rotation: f32) -> DrawParameters {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the point the resource rotates around, relative to its
+    /// position.
+    ///
+    /// By default, it is the top-left corner of the drawn quad.
+    pub fn origin(mut self, origin: Point) -> DrawParameters {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets whether only the texels inside the source region should be
+    /// sampled.
+    ///
+    /// Enable it to avoid bleeding from adjacent cells of an atlas when the
+    /// resource is scaled or drawn at subpixel positions. The outer half of
+    /// the border texels is not drawn.
+    ///
+    /// By default, it is disabled.
+    pub fn clamp_source(mut self, clamp_source: bool) -> DrawParameters {
+        self.clamp_source = clamp_source;
+        self
+    }
+
+    /// Sets the color of each corner of the drawn quad, clockwise from the
+    /// top-left one.
+    ///
+    /// The colors are interpolated across the quad and multiplied with the
+    /// resource. Draw a white resource to get a plain gradient:
+    ///
+    /// ```
+    /// use coffee::graphics::{Color, DrawParameters, Frame, Image};
+    ///
+    /// // `pixel` is a 1x1 white image
+    /// fn draw_sky(pixel: &Image, frame: &mut Frame<'_>) {
+    ///     let top = Color::from_rgb(40, 80, 160);
+    ///     let horizon = Color::from_rgb(250, 180, 120);
+    ///
+    ///     pixel.draw(
+    ///         DrawParameters::new()
+    ///             .scale(frame.width(), frame.height() / 2.0)
+    ///             .corner_colors([top, top, horizon, horizon]),
+    ///         &mut frame.as_target(),
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// By default, every corner is white.
+    pub fn corner_colors(mut self, colors: [Color; 4]) -> DrawParameters {
+        self.colors = colors;
+        self
+    }
+}
+
+impl Default for DrawParameters {
+    fn default() -> DrawParameters {
+        DrawParameters::new()
+    }
+}
+
+impl IntoQuad for DrawParameters {
+    fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        let mut source = match self.source {
+            Source::Pixels(source) => Rectangle {
+                x: f32::from(source.x) * x_unit,
+                y: f32::from(source.y) * y_unit,
+                width: f32::from(source.width) * x_unit,
+                height: f32::from(source.height) * y_unit,
+            },
+            Source::Normalized(source) => source,
+        };
+
+        let size = (
+            (source.width / x_unit).abs() * self.scale.0.abs(),
+            (source.height / y_unit).abs() * self.scale.1.abs(),
+        );
+
+        if self.clamp_source {
+            source = inset_source(source, x_unit, y_unit);
+        }
+
+        // Negative scales flip the texture coordinates
+        if self.scale.0 < 0.0 {
+            source.x += source.width;
+            source.width = -source.width;
+        }
+
+        if self.scale.1 < 0.0 {
+            source.y += source.height;
+            source.height = -source.height;
+        }
+
+        Quad {
+            source,
+            position: self.position,
+            size,
+            colors: self.colors,
+            rotation: self.rotation,
+            origin: self.origin,
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::graphics::{
+    Canvas, Image, ImageHandle, IntoQuad, SubImage, Target, Transformation,
+};
+
+/// A resource that can be drawn on a [`Target`] using quads.
+///
+/// It is implemented by [`Image`], [`SubImage`], [`ImageHandle`], and
+/// [`Canvas`]. Use it to write code that works with any of them, like
+/// post-processing steps that draw a [`Canvas`] exactly like a sprite.
+///
+/// [`Target`]: struct.Target.html
+/// [`Image`]: struct.Image.html
+/// [`SubImage`]: struct.SubImage.html
+/// [`ImageHandle`]: struct.ImageHandle.html
+/// [`Canvas`]: struct.Canvas.html
+pub trait Drawable {
+    /// Returns the width of the [`Drawable`] in pixels.
+    ///
+    /// [`Drawable`]: trait.Drawable.html
+    fn width(&self) -> u16;
+
+    /// Returns the height of the [`Drawable`] in pixels.
+    ///
+    /// [`Drawable`]: trait.Drawable.html
+    fn height(&self) -> u16;
+
+    /// Draws the [`Drawable`] on the given [`Target`].
+    ///
+    /// [`Drawable`]: trait.Drawable.html
+    /// [`Target`]: struct.Target.html
+    fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>);
+
+    /// Draws the [`Drawable`] on the given [`Target`], applying the given
+    /// [`Transformation`] first.
+    ///
+    /// [`Drawable`]: trait.Drawable.html
+    /// [`Target`]: struct.Target.html
+    /// [`Transformation`]: struct.Transformation.html
+    fn draw_transformed<Q: IntoQuad>(
+        &self,
+        quad: Q,
+        transformation: Transformation,
+        target: &mut Target<'_>,
+    ) {
+        self.draw(quad, &mut target.transform(transformation));
+    }
+
+    /// Draws the [`Drawable`] once for every quad produced by the given
+    /// iterator, batching consecutive quads into a single draw call.
+    ///
+    /// See [`Target::batched`].
+    ///
+    /// [`Drawable`]: trait.Drawable.html
+    /// [`Target::batched`]: struct.Target.html#method.batched
+    fn draw_all<I>(&self, quads: I, target: &mut Target<'_>)
+    where
+        I: IntoIterator,
+        I::Item: IntoQuad,
+    {
+        target.batched(|target| {
+            for quad in quads {
+                self.draw(quad, target);
+            }
+        });
+    }
+}
+
+impl Drawable for Image {
+    fn width(&self) -> u16 {
+        Image::width(self)
+    }
+
+    fn height(&self) -> u16 {
+        Image::height(self)
+    }
+
+    fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        Image::draw(self, quad, target);
+    }
+}
+
+impl Drawable for SubImage {
+    fn width(&self) -> u16 {
+        SubImage::width(self)
+    }
+
+    fn height(&self) -> u16 {
+        SubImage::height(self)
+    }
+
+    fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        SubImage::draw(self, quad, target);
+    }
+}
+
+impl Drawable for ImageHandle {
+    fn width(&self) -> u16 {
+        self.image().width()
+    }
+
+    fn height(&self) -> u16 {
+        self.image().height()
+    }
+
+    fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        ImageHandle::draw(self, quad, target);
+    }
+}
+
+impl Drawable for Canvas {
+    fn width(&self) -> u16 {
+        Canvas::width(self)
+    }
+
+    fn height(&self) -> u16 {
+        Canvas::height(self)
+    }
+
+    fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        Canvas::draw(self, quad, target);
+    }
+}
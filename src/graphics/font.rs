@@ -1,11 +1,71 @@
 use crate::graphics::gpu;
-use crate::graphics::{Gpu, Target, Text};
+use crate::graphics::{
+    Color, Gpu, Mesh, Point, Rectangle, ResourceId, Shape, Target, Text,
+    Vector, VerticalAlignment,
+};
 use crate::load::Task;
 use crate::Result;
 
+// The amount of copies of the glyphs drawn to outline text
+const OUTLINE_SAMPLES: usize = 8;
+
 /// A collection of text with the same font.
+///
+/// A [`Font`] can contain multiple faces, added with [`add_face`]. All the
+/// faces of a [`Font`] share the same glyph cache and are drawn together, in
+/// the order the [`Text`] was added. Select the face of some [`Text`] with
+/// its `face` field.
+///
+/// Every [`Font`] keeps its own glyph cache and [`draw`] only flushes the text
+/// added to it. Therefore, you can use as many fonts as you need in the same
+/// frame.
+///
+/// # Example
+///
+/// ```no_run
+/// use coffee::graphics::{Color, Font, Gpu, Point, Target, Text};
+/// use coffee::Result;
+///
+/// fn draw_title(
+///     gpu: &mut Gpu,
+///     regular: &'static [u8],
+///     bold: &'static [u8],
+///     target: &mut Target<'_>,
+/// ) -> Result<()> {
+///     let mut font = Font::from_bytes(gpu, regular)?;
+///     let bold = font.add_face(bold);
+///
+///     font.add(Text {
+///         content: "Coffee",
+///         position: Point::new(10.0, 10.0),
+///         color: Color::WHITE,
+///         face: bold,
+///         ..Text::default()
+///     });
+///
+///     font.add(Text {
+///         content: "An opinionated 2D game engine",
+///         position: Point::new(10.0, 40.0),
+///         color: Color::WHITE,
+///         ..Text::default()
+///     });
+///
+///     font.draw(target);
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// [`Font`]: struct.Font.html
+/// [`add_face`]: #method.add_face
+/// [`Text`]: struct.Text.html
+/// [`draw`]: #method.draw
 #[allow(missing_debug_implementations)]
-pub struct Font(gpu::Font);
+pub struct Font {
+    raw: gpu::Font,
+    id: ResourceId,
+    backgrounds: Vec<(Rectangle<f32>, Color)>,
+}
 
 impl Font {
     pub(crate) const DEFAULT: &'static [u8] =
@@ -15,7 +75,11 @@ impl Font {
     ///
     /// [`Font`]: struct.Font.html
     pub fn from_bytes(gpu: &mut Gpu, bytes: &'static [u8]) -> Result<Font> {
-        Ok(Font(gpu.upload_font(bytes)))
+        Ok(Font {
+            raw: gpu.upload_font(bytes),
+            id: ResourceId::new(),
+            backgrounds: Vec::new(),
+        })
     }
 
     /// Creates a [`Task`] that loads a [`Font`] from raw data.
@@ -26,26 +90,228 @@ impl Font {
         Task::using_gpu(move |gpu| Font::from_bytes(gpu, bytes))
     }
 
+    /// Returns the [`ResourceId`] of the [`Font`].
+    ///
+    /// [`ResourceId`]: struct.ResourceId.html
+    /// [`Font`]: struct.Font.html
+    pub fn id(&self) -> ResourceId {
+        self.id
+    }
+
+    /// Adds a new face to the [`Font`] from raw data and returns it.
+    ///
+    /// The new face shares the glyph cache of the [`Font`].
+    ///
+    /// [`Font`]: struct.Font.html
+    pub fn add_face(&mut self, bytes: &'static [u8]) -> Face {
+        Face(self.raw.add_face(bytes))
+    }
+
     /// Adds [`Text`] to this [`Font`].
     ///
+    /// Its outline, shadow, and background are queued as well, if any.
+    ///
     /// [`Text`]: struct.Text.html
     /// [`Font`]: struct.Font.html
     pub fn add(&mut self, text: Text<'_>) {
-        self.0.add(text)
+        let text = self.align_baseline(text);
+
+        if let Some((color, padding)) = text.background {
+            let bounds = self.raw.bounds(text.clone());
+
+            self.backgrounds.push((
+                Rectangle {
+                    x: bounds.x - padding,
+                    y: bounds.y - padding,
+                    width: bounds.width + padding * 2.0,
+                    height: bounds.height + padding * 2.0,
+                },
+                color,
+            ));
+        }
+
+        // Shadows and outlines are drawn as copies of the glyphs queued
+        // before the text itself, so they end up behind it
+        for copy in decorations(&text) {
+            self.raw.add(copy);
+        }
+
+        self.raw.add(text)
     }
 
     /// Computes the layout bounds of the given [`Text`].
     ///
     /// [`Text`]: struct.Text.html
     pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
-        self.0.measure(text)
+        let text = self.align_baseline(text);
+
+        self.raw.measure(text)
+    }
+
+    /// Computes the area covered by the given [`Text`] once drawn.
+    ///
+    /// Unlike [`measure`], the returned [`Rectangle`] takes the position and
+    /// the alignment of the [`Text`] into account. This is useful to draw
+    /// backgrounds behind text, or to check if the mouse is over it.
+    ///
+    /// No [`Frame`] is needed, so you can lay out text before drawing.
+    ///
+    /// [`Text`]: struct.Text.html
+    /// [`measure`]: #method.measure
+    /// [`Rectangle`]: struct.Rectangle.html
+    /// [`Frame`]: struct.Frame.html
+    pub fn bounds(&mut self, text: Text<'_>) -> Rectangle<f32> {
+        let text = self.align_baseline(text);
+
+        self.raw.bounds(text)
+    }
+
+    /// Returns the vertical [`Metrics`] of the [`Font`] for the given text
+    /// size.
+    ///
+    /// This is useful to align text with other graphics, like an icon next to
+    /// a score.
+    ///
+    /// [`Metrics`]: struct.FontMetrics.html
+    /// [`Font`]: struct.Font.html
+    pub fn metrics(&self, size: f32) -> Metrics {
+        self.raw.metrics(0, size)
+    }
+
+    /// Returns the vertical [`Metrics`] of the given [`Face`] of the [`Font`]
+    /// for the given text size.
+    ///
+    /// [`Metrics`]: struct.FontMetrics.html
+    /// [`Face`]: struct.FontFace.html
+    /// [`Font`]: struct.Font.html
+    pub fn face_metrics(&self, face: Face, size: f32) -> Metrics {
+        self.raw.metrics(face.0, size)
     }
 
     /// Renders and flushes all the text added to this [`Font`].
     ///
     /// [`Font`]: struct.Font.html
-    #[inline]
     pub fn draw(&mut self, target: &mut Target<'_>) {
-        target.draw_font(&mut self.0)
+        if !self.backgrounds.is_empty() {
+            let mut mesh = Mesh::new();
+
+            for (rectangle, color) in self.backgrounds.drain(..) {
+                mesh.fill(Shape::Rectangle(rectangle), color);
+            }
+
+            target.draw_text_mesh(&mesh);
+        }
+
+        target.draw_font(&mut self.raw)
+    }
+
+    fn align_baseline<'a>(&self, text: Text<'a>) -> Text<'a> {
+        match text.vertical_alignment {
+            VerticalAlignment::Baseline => {
+                let ascent = self.face_metrics(text.face, text.size).ascent;
+
+                Text {
+                    position: Point::new(
+                        text.position.x,
+                        text.position.y - ascent,
+                    ),
+                    vertical_alignment: VerticalAlignment::Top,
+                    ..text
+                }
+            }
+            _ => text,
+        }
+    }
+}
+
+impl PartialEq for Font {
+    fn eq(&self, other: &Font) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Font {}
+
+impl std::hash::Hash for Font {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+// Returns the copies of the given text that draw its shadow and its outline,
+// in drawing order
+pub(super) fn decorations<'a>(text: &Text<'a>) -> Vec<Text<'a>> {
+    let mut copies = Vec::new();
+
+    if let Some((offset, color)) = text.shadow {
+        copies.push(Text {
+            position: text.position + offset,
+            color,
+            ..text.clone()
+        });
+    }
+
+    if let Some((color, width)) = text.outline {
+        for i in 0..OUTLINE_SAMPLES {
+            let angle =
+                i as f32 / OUTLINE_SAMPLES as f32 * std::f32::consts::PI * 2.0;
+
+            copies.push(Text {
+                position: text.position
+                    + Vector::new(angle.cos(), angle.sin()) * width,
+                color,
+                ..text.clone()
+            });
+        }
+    }
+
+    copies
+}
+
+/// A face of a [`Font`].
+///
+/// The default [`Face`] is the one the [`Font`] was created with.
+///
+/// [`Font`]: struct.Font.html
+/// [`Face`]: struct.FontFace.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Face(usize);
+
+impl Face {
+    pub(in crate::graphics) fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// The vertical metrics of a [`Font`] for a specific text size.
+///
+/// All the values are in pixels and relative to the baseline, with the
+/// y axis pointing up.
+///
+/// [`Font`]: struct.Font.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Metrics {
+    /// The distance from the baseline to the highest point of any glyph.
+    pub ascent: f32,
+
+    /// The distance from the baseline to the lowest point of any glyph.
+    ///
+    /// It is usually negative.
+    pub descent: f32,
+
+    /// The recommended gap between the descent of a line and the ascent of
+    /// the next one.
+    pub line_gap: f32,
+}
+
+impl Metrics {
+    /// Returns the distance between the baselines of two consecutive lines.
+    pub fn line_height(&self) -> f32 {
+        self.ascent - self.descent + self.line_gap
+    }
+
+    /// Returns the offset of the baseline from the top of a line.
+    pub fn baseline(&self) -> f32 {
+        self.ascent
     }
 }
@@ -0,0 +1,216 @@
+//! Declare multi-pass rendering with dependencies between passes.
+use crate::graphics::{Canvas, Frame, Target};
+
+/// A set of rendering passes that draw into canvases and into the [`Frame`].
+///
+/// Each pass declares the [`Canvas`] it draws to and the canvases it reads
+/// from. When drawn, a [`FrameGraph`] runs every pass after all the passes
+/// that produce its inputs, and the passes drawing to the [`Frame`] last.
+///
+/// This is useful to build post-processing chains, like drawing the world on
+/// a [`Canvas`] and compositing it with the user interface on the [`Frame`].
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::frame_graph::{FrameGraph, Output};
+/// use coffee::graphics::{Canvas, Color, Frame, Gpu, Quad};
+/// use coffee::Result;
+///
+/// struct World;
+///
+/// fn build(gpu: &mut Gpu) -> Result<FrameGraph<World>> {
+///     let mut graph = FrameGraph::new();
+///
+///     let scene = graph.canvas(Canvas::new(gpu, 640, 480)?);
+///
+///     // Composite the scene on the frame...
+///     graph.pass(Output::Frame, &[scene], |_world, inputs, target| {
+///         target.clear(Color::BLACK);
+///         inputs[0].draw(Quad::default(), target);
+///     });
+///
+///     // ...once it has been drawn, even if declared later
+///     graph.pass(Output::Canvas(scene), &[], |_world, _inputs, target| {
+///         target.clear(Color::WHITE);
+///     });
+///
+///     Ok(graph)
+/// }
+///
+/// fn draw(graph: &mut FrameGraph<World>, world: &World, frame: &mut Frame) {
+///     graph.draw(world, frame);
+/// }
+/// ```
+///
+/// [`Frame`]: ../struct.Frame.html
+/// [`Canvas`]: ../struct.Canvas.html
+/// [`FrameGraph`]: struct.FrameGraph.html
+#[allow(missing_debug_implementations)]
+pub struct FrameGraph<State> {
+    canvases: Vec<Canvas>,
+    passes: Vec<Pass<State>>,
+}
+
+struct Pass<State> {
+    output: Output,
+    inputs: Vec<CanvasId>,
+    draw: Box<dyn Fn(&State, &[&Canvas], &mut Target<'_>)>,
+}
+
+/// An identifier of a [`Canvas`] in a [`FrameGraph`].
+///
+/// [`Canvas`]: ../struct.Canvas.html
+/// [`FrameGraph`]: struct.FrameGraph.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CanvasId(usize);
+
+/// The target of a pass in a [`FrameGraph`].
+///
+/// [`FrameGraph`]: struct.FrameGraph.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Output {
+    /// A [`Canvas`] of the [`FrameGraph`].
+    ///
+    /// [`Canvas`]: ../struct.Canvas.html
+    /// [`FrameGraph`]: struct.FrameGraph.html
+    Canvas(CanvasId),
+
+    /// The [`Frame`] being drawn.
+    ///
+    /// [`Frame`]: ../struct.Frame.html
+    Frame,
+}
+
+impl<State> FrameGraph<State> {
+    /// Creates an empty [`FrameGraph`].
+    ///
+    /// [`FrameGraph`]: struct.FrameGraph.html
+    pub fn new() -> FrameGraph<State> {
+        FrameGraph {
+            canvases: Vec::new(),
+            passes: Vec::new(),
+        }
+    }
+
+    /// Adds a [`Canvas`] to the [`FrameGraph`] and returns its identifier.
+    ///
+    /// [`Canvas`]: ../struct.Canvas.html
+    /// [`FrameGraph`]: struct.FrameGraph.html
+    pub fn canvas(&mut self, canvas: Canvas) -> CanvasId {
+        self.canvases.push(canvas);
+
+        CanvasId(self.canvases.len() - 1)
+    }
+
+    /// Returns the [`Canvas`] with the given identifier.
+    ///
+    /// [`Canvas`]: ../struct.Canvas.html
+    pub fn get(&self, id: CanvasId) -> &Canvas {
+        &self.canvases[id.0]
+    }
+
+    /// Adds a pass to the [`FrameGraph`].
+    ///
+    /// The pass draws to the given [`Output`] and reads from the given
+    /// inputs, which are provided to the draw function in the same order.
+    ///
+    /// # Panics
+    /// Panics if the pass reads from the same [`Canvas`] it draws to.
+    ///
+    /// [`FrameGraph`]: struct.FrameGraph.html
+    /// [`Output`]: enum.Output.html
+    /// [`Canvas`]: ../struct.Canvas.html
+    pub fn pass<F>(&mut self, output: Output, inputs: &[CanvasId], draw: F)
+    where
+        F: 'static + Fn(&State, &[&Canvas], &mut Target<'_>),
+    {
+        assert!(
+            inputs.iter().all(|input| Output::Canvas(*input) != output),
+            "A pass cannot read from the canvas it draws to"
+        );
+
+        self.passes.push(Pass {
+            output,
+            inputs: inputs.to_vec(),
+            draw: Box::new(draw),
+        });
+    }
+
+    /// Runs all the passes of the [`FrameGraph`] with the given state.
+    ///
+    /// Passes run after the passes that draw to their inputs. Independent
+    /// passes run in the order they were added. If the passes have a cyclic
+    /// dependency, the passes in the cycle run in the order they were added.
+    ///
+    /// [`FrameGraph`]: struct.FrameGraph.html
+    pub fn draw(&mut self, state: &State, frame: &mut Frame<'_>) {
+        for index in self.schedule() {
+            let pass = &self.passes[index];
+
+            let inputs: Vec<&Canvas> =
+                pass.inputs.iter().map(|id| &self.canvases[id.0]).collect();
+
+            match pass.output {
+                Output::Canvas(id) => {
+                    let mut canvas = self.canvases[id.0].clone();
+                    let mut target = canvas.as_target(frame.gpu());
+
+                    (pass.draw)(state, &inputs, &mut target);
+                }
+                Output::Frame => {
+                    (pass.draw)(state, &inputs, &mut frame.as_target());
+                }
+            }
+        }
+    }
+
+    fn schedule(&self) -> Vec<usize> {
+        let mut order = Vec::with_capacity(self.passes.len());
+        let mut is_scheduled = vec![false; self.passes.len()];
+
+        while order.len() < self.passes.len() {
+            let is_pending = |index: &usize| !is_scheduled[*index];
+
+            // A pass is ready when no pending pass draws to its inputs
+            let is_ready = |index: &usize| {
+                let pass = &self.passes[*index];
+
+                pass.inputs.iter().all(|input| {
+                    self.passes
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| is_pending(i))
+                        .all(|(_, other)| {
+                            other.output != Output::Canvas(*input)
+                        })
+                })
+            };
+
+            // Frame passes wait for every canvas pass to be scheduled
+            let is_frame_ready = (0..self.passes.len())
+                .filter(is_pending)
+                .all(|i| self.passes[i].output == Output::Frame);
+
+            let next = (0..self.passes.len())
+                .filter(is_pending)
+                .filter(|i| {
+                    is_frame_ready || self.passes[*i].output != Output::Frame
+                })
+                .find(is_ready)
+                .or_else(|| (0..self.passes.len()).find(is_pending))
+                .expect("Find pending pass");
+
+            is_scheduled[next] = true;
+            order.push(next);
+        }
+
+        order
+    }
+}
+
+impl<State> Default for FrameGraph<State> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
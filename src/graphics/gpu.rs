@@ -0,0 +1,718 @@
+//! The graphics backends behind a shared interface.
+//!
+//! Both backends can be compiled in at the same time. The [`Renderer`] trait
+//! describes what every backend needs to provide, while the types of this
+//! module wrap the resources of the backend chosen at startup and dispatch
+//! every operation to it.
+//!
+//! [`Renderer`]: trait.Renderer.html
+
+// Evaluates the given expression with the resource of the backend behind a
+// shared type
+macro_rules! dispatch {
+    ($type:ident, $value:expr, $raw:ident => $body:expr) => {
+        match $value {
+            #[cfg(feature = "opengl")]
+            $type::Gfx($raw) => $body,
+            #[cfg(any(
+                feature = "vulkan",
+                feature = "metal",
+                feature = "dx11",
+                feature = "dx12",
+            ))]
+            $type::Wgpu($raw) => $body,
+        }
+    };
+}
+
+// Defines a shared type wrapping a resource of every compiled backend. The
+// backend resource has the same name, unless a different one is given.
+macro_rules! resource {
+    ($(#[$meta:meta])* $name:ident) => {
+        resource!($(#[$meta])* $name = $name);
+    };
+    ($(#[$meta:meta])* $name:ident = $raw:ident) => {
+        $(#[$meta])*
+        pub enum $name {
+            #[cfg(feature = "opengl")]
+            Gfx(backend_gfx::$raw),
+            #[cfg(any(
+                feature = "vulkan",
+                feature = "metal",
+                feature = "dx11",
+                feature = "dx12",
+            ))]
+            Wgpu(backend_wgpu::$raw),
+        }
+
+        #[cfg(feature = "opengl")]
+        impl Raw<backend_gfx::$raw> for $name {
+            #[allow(unreachable_patterns)]
+            fn raw(&self) -> &backend_gfx::$raw {
+                match self {
+                    $name::Gfx(raw) => raw,
+                    _ => mismatch(),
+                }
+            }
+        }
+
+        #[cfg(feature = "opengl")]
+        impl RawMut<backend_gfx::$raw> for $name {
+            #[allow(unreachable_patterns)]
+            fn raw_mut(&mut self) -> &mut backend_gfx::$raw {
+                match self {
+                    $name::Gfx(raw) => raw,
+                    _ => mismatch(),
+                }
+            }
+        }
+
+        #[cfg(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        ))]
+        impl Raw<backend_wgpu::$raw> for $name {
+            #[allow(unreachable_patterns)]
+            fn raw(&self) -> &backend_wgpu::$raw {
+                match self {
+                    $name::Wgpu(raw) => raw,
+                    _ => mismatch(),
+                }
+            }
+        }
+
+        #[cfg(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        ))]
+        impl RawMut<backend_wgpu::$raw> for $name {
+            #[allow(unreachable_patterns)]
+            fn raw_mut(&mut self) -> &mut backend_wgpu::$raw {
+                match self {
+                    $name::Wgpu(raw) => raw,
+                    _ => mismatch(),
+                }
+            }
+        }
+
+        #[cfg(feature = "opengl")]
+        impl From<backend_gfx::$raw> for $name {
+            fn from(raw: backend_gfx::$raw) -> $name {
+                $name::Gfx(raw)
+            }
+        }
+
+        #[cfg(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        ))]
+        impl From<backend_wgpu::$raw> for $name {
+            fn from(raw: backend_wgpu::$raw) -> $name {
+                $name::Wgpu(raw)
+            }
+        }
+    };
+}
+
+mod font;
+mod quad;
+mod surface;
+mod texture;
+mod vertex;
+
+pub use font::Font;
+pub use quad::{InstanceBuffer, Quad};
+pub use surface::Surface;
+pub use texture::{DepthView, Drawable, TargetView, Texture};
+pub use vertex::Vertex;
+
+#[cfg(feature = "opengl")]
+use super::backend_gfx;
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+use super::backend_wgpu;
+
+use crate::graphics::{
+    AdapterInfo, Backend, BlendMode, Color, ImageSettings, Limits,
+    PowerPreference, Rectangle, ResourceStats, Transformation,
+};
+use crate::Result;
+
+/// The operations every graphics backend provides.
+///
+/// The resources of a backend are only ever used with the backend that
+/// created them.
+pub trait Renderer {
+    type Texture;
+    type Drawable;
+    type Font;
+    type InstanceBuffer;
+    type TargetView;
+    type DepthView;
+
+    fn info(&self) -> &AdapterInfo;
+
+    fn limits(&self) -> Limits;
+
+    fn frame_time(&self) -> Option<std::time::Duration>;
+
+    fn collect(&mut self) -> ResourceStats;
+
+    fn reload_shaders(&mut self) -> Option<std::result::Result<(), String>>;
+
+    /// Converts a region in normalized device coordinates to the pixels of a
+    /// target with the given size.
+    fn scissor(
+        &self,
+        region: &Rectangle<f32>,
+        width: f32,
+        height: f32,
+    ) -> Rectangle<u32>;
+
+    fn clear(&mut self, view: &Self::TargetView, color: Color);
+
+    fn clear_depth(&mut self, depth: &Self::DepthView);
+
+    fn upload_texture(
+        &mut self,
+        image: &image::DynamicImage,
+        settings: ImageSettings,
+    ) -> Self::Texture;
+
+    fn upload_texture_array(
+        &mut self,
+        layers: &[image::DynamicImage],
+        mipmaps: bool,
+        settings: ImageSettings,
+    ) -> Self::Texture;
+
+    fn create_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+        settings: ImageSettings,
+    ) -> Self::Texture;
+
+    fn update_texture(&mut self, texture: &Self::Texture, y: u16, rgba: &[u8]);
+
+    /// Creates a texture that can be drawn on, with multisample anti-aliasing
+    /// when more than one sample per pixel is requested.
+    fn create_drawable_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+        has_depth: bool,
+        samples: u16,
+    ) -> Self::Drawable;
+
+    fn read_texture_pixels(
+        &mut self,
+        texture: &Self::Texture,
+    ) -> image::DynamicImage;
+
+    fn upload_font(&mut self, bytes: &'static [u8]) -> Self::Font;
+
+    fn draw_triangles(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: &Self::TargetView,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    );
+
+    fn draw_texture_quads(
+        &mut self,
+        texture: &Self::Texture,
+        instances: &[Quad],
+        view: &Self::TargetView,
+        depth: Option<&Self::DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    );
+
+    fn draw_distance_field_quads(
+        &mut self,
+        texture: &Self::Texture,
+        instances: &[Quad],
+        view: &Self::TargetView,
+        depth: Option<&Self::DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    );
+
+    fn upload_instances(&mut self, instances: &[Quad]) -> Self::InstanceBuffer;
+
+    fn draw_instance_buffer(
+        &mut self,
+        texture: &Self::Texture,
+        instances: &Self::InstanceBuffer,
+        view: &Self::TargetView,
+        depth: Option<&Self::DepthView>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    );
+
+    fn draw_font(
+        &mut self,
+        font: &mut Self::Font,
+        target: &Self::TargetView,
+        transformation: Transformation,
+        scissor: Option<Rectangle<u32>>,
+    );
+}
+
+// Gives access to the resource of a specific backend behind a shared type
+trait Raw<T> {
+    fn raw(&self) -> &T;
+}
+
+trait RawMut<T>: Raw<T> {
+    fn raw_mut(&mut self) -> &mut T;
+}
+
+fn mismatch() -> ! {
+    unreachable!("A resource was used with a backend that did not create it")
+}
+
+/// A link between your game and a graphics processor.
+///
+/// It is necessary to perform any kind of graphical operation, like loading
+/// resources and drawing.
+///
+/// A [`Gpu`] can be obtained from a [`Window`] or a [`Frame`].
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Window`]: struct.Window.html
+/// [`Frame`]: struct.Frame.html
+#[allow(missing_debug_implementations)]
+pub struct Gpu {
+    device: Device,
+}
+
+// The backend chosen when creating the window
+enum Device {
+    #[cfg(feature = "opengl")]
+    Gfx(backend_gfx::Gpu),
+    #[cfg(any(
+        feature = "vulkan",
+        feature = "metal",
+        feature = "dx11",
+        feature = "dx12",
+    ))]
+    Wgpu(backend_wgpu::Gpu),
+}
+
+impl Gpu {
+    /// Creates the window along with the [`Gpu`] of the given [`Backend`].
+    ///
+    /// The `wgpu` backends are preferred, unless OpenGL is chosen explicitly.
+    /// When no adapter supports them, the runtime falls back to OpenGL, as
+    /// long as it has been compiled in.
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    /// [`Backend`]: ../enum.Backend.html
+    #[cfg_attr(
+        not(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        )),
+        allow(unused_variables)
+    )]
+    pub(super) fn for_window(
+        builder: winit::window::WindowBuilder,
+        event_loop: &winit::event_loop::EventLoop<()>,
+        backend: Backend,
+        power_preference: PowerPreference,
+        multisampling: u16,
+        vsync: bool,
+    ) -> Result<(Gpu, Surface)> {
+        #[cfg(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        ))]
+        {
+            if backend != Backend::OpenGl {
+                let result = backend_wgpu::Gpu::for_window(
+                    builder.clone(),
+                    event_loop,
+                    backend,
+                    power_preference,
+                    multisampling,
+                    vsync,
+                );
+
+                match result {
+                    Ok((gpu, surface)) => {
+                        return Ok((
+                            Gpu {
+                                device: Device::Wgpu(gpu),
+                            },
+                            Surface::from(surface),
+                        ));
+                    }
+                    #[cfg(feature = "opengl")]
+                    Err(crate::Error::AdapterNotFound) => {
+                        log::warn!(
+                            "No adapter supports the {:?} backend, falling \
+                             back to OpenGL",
+                            backend
+                        );
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        #[cfg(feature = "opengl")]
+        {
+            let (gpu, surface) = backend_gfx::Gpu::for_window(
+                builder,
+                event_loop,
+                power_preference,
+                multisampling,
+                vsync,
+            )?;
+
+            Ok((
+                Gpu {
+                    device: Device::Gfx(gpu),
+                },
+                Surface::from(surface),
+            ))
+        }
+
+        // OpenGL is only chosen explicitly when it has been compiled in
+        #[cfg(not(feature = "opengl"))]
+        Err(crate::Error::AdapterNotFound)
+    }
+
+    /// Returns information about the graphics adapter used by the [`Gpu`].
+    ///
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn info(&self) -> &AdapterInfo {
+        dispatch!(Device, &self.device, gpu => gpu.info())
+    }
+
+    /// Frees the GPU resources that are no longer in use and returns
+    /// statistics about them.
+    ///
+    /// Dropped textures are normally freed once the frame using them has been
+    /// presented. This method submits the pending work and reclaims them
+    /// right away. It can be useful after unloading a level, for instance.
+    pub fn collect(&mut self) -> ResourceStats {
+        dispatch!(Device, &mut self.device, gpu => gpu.collect())
+    }
+
+    /// Returns the [`Limits`] of the [`Gpu`].
+    ///
+    /// OpenGL guarantees texture arrays of at least 256 layers. The `wgpu`
+    /// backends do not expose the texture limits of an adapter yet, so they
+    /// report the minimums guaranteed by all of them: `8192x8192` pixels and
+    /// 256 layers. Larger images are split into tiles.
+    ///
+    /// [`Limits`]: struct.Limits.html
+    /// [`Gpu`]: struct.Gpu.html
+    pub fn limits(&self) -> Limits {
+        dispatch!(Device, &self.device, gpu => gpu.limits())
+    }
+
+    /// Returns the maximum width and height of a texture, in pixels.
+    pub fn max_texture_size(&self) -> u16 {
+        self.limits().max_texture_size
+    }
+
+    /// Rebuilds the built-in pipelines if the sources of their shaders have
+    /// changed on disk.
+    ///
+    /// It returns `None` when nothing changed or when the backend uses
+    /// precompiled shaders. On failure, the previous pipelines are kept.
+    pub(crate) fn reload_shaders(
+        &mut self,
+    ) -> Option<std::result::Result<(), String>> {
+        dispatch!(Device, &mut self.device, gpu => gpu.reload_shaders())
+    }
+
+    pub(super) fn frame_time(&self) -> Option<std::time::Duration> {
+        dispatch!(Device, &self.device, gpu => gpu.frame_time())
+    }
+
+    pub(super) fn scissor(
+        &self,
+        region: &Rectangle<f32>,
+        width: f32,
+        height: f32,
+    ) -> Rectangle<u32> {
+        dispatch!(Device, &self.device, gpu => gpu.scissor(region, width, height))
+    }
+
+    pub(super) fn clear(&mut self, view: TargetView<'_>, color: Color) {
+        dispatch!(Device, &mut self.device, gpu => gpu.clear(view.raw(), color))
+    }
+
+    pub(super) fn clear_depth(&mut self, depth: DepthView<'_>) {
+        dispatch!(Device, &mut self.device, gpu => gpu.clear_depth(depth.raw()))
+    }
+
+    pub(super) fn upload_texture(
+        &mut self,
+        image: &image::DynamicImage,
+        settings: ImageSettings,
+    ) -> Texture {
+        dispatch!(Device, &mut self.device, gpu => {
+            Texture::from(gpu.upload_texture(image, settings))
+        })
+    }
+
+    pub(super) fn upload_texture_array(
+        &mut self,
+        layers: &[image::DynamicImage],
+        mipmaps: bool,
+        settings: ImageSettings,
+    ) -> Texture {
+        dispatch!(Device, &mut self.device, gpu => {
+            Texture::from(gpu.upload_texture_array(layers, mipmaps, settings))
+        })
+    }
+
+    pub(super) fn create_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+        settings: ImageSettings,
+    ) -> Texture {
+        dispatch!(Device, &mut self.device, gpu => {
+            Texture::from(gpu.create_texture(width, height, settings))
+        })
+    }
+
+    pub(super) fn update_texture(
+        &mut self,
+        texture: &Texture,
+        y: u16,
+        rgba: &[u8],
+    ) {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.update_texture(texture.raw(), y, rgba)
+        })
+    }
+
+    pub(super) fn create_drawable_texture(
+        &mut self,
+        width: u16,
+        height: u16,
+        has_depth: bool,
+        samples: u16,
+    ) -> Drawable {
+        dispatch!(Device, &mut self.device, gpu => {
+            Drawable::from(gpu.create_drawable_texture(
+                width, height, has_depth, samples,
+            ))
+        })
+    }
+
+    pub(super) fn read_drawable_texture_pixels(
+        &mut self,
+        drawable: &Drawable,
+    ) -> image::DynamicImage {
+        self.read_texture_pixels(drawable.texture())
+    }
+
+    pub(super) fn read_texture_pixels(
+        &mut self,
+        texture: &Texture,
+    ) -> image::DynamicImage {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.read_texture_pixels(texture.raw())
+        })
+    }
+
+    pub(super) fn upload_font(&mut self, bytes: &'static [u8]) -> Font {
+        dispatch!(Device, &mut self.device, gpu => {
+            Font::from(gpu.upload_font(bytes))
+        })
+    }
+
+    pub(super) fn draw_triangles(
+        &mut self,
+        vertices: &[Vertex],
+        indices: &[u32],
+        view: TargetView<'_>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.draw_triangles(
+                vertices,
+                indices,
+                view.raw(),
+                transformation,
+                scissor,
+                blend_mode,
+            )
+        })
+    }
+
+    pub(super) fn draw_texture_quads(
+        &mut self,
+        texture: &Texture,
+        instances: &[Quad],
+        view: TargetView<'_>,
+        depth: Option<DepthView<'_>>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.draw_texture_quads(
+                texture.raw(),
+                instances,
+                view.raw(),
+                depth.as_ref().map(Raw::raw),
+                transformation,
+                scissor,
+                blend_mode,
+            )
+        })
+    }
+
+    pub(super) fn draw_distance_field_quads(
+        &mut self,
+        texture: &Texture,
+        instances: &[Quad],
+        view: TargetView<'_>,
+        depth: Option<DepthView<'_>>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.draw_distance_field_quads(
+                texture.raw(),
+                instances,
+                view.raw(),
+                depth.as_ref().map(Raw::raw),
+                transformation,
+                scissor,
+                blend_mode,
+            )
+        })
+    }
+
+    pub(super) fn upload_instances(
+        &mut self,
+        instances: &[Quad],
+    ) -> InstanceBuffer {
+        dispatch!(Device, &mut self.device, gpu => {
+            InstanceBuffer::from(gpu.upload_instances(instances))
+        })
+    }
+
+    pub(super) fn draw_instance_buffer(
+        &mut self,
+        texture: &Texture,
+        instances: &InstanceBuffer,
+        view: TargetView<'_>,
+        depth: Option<DepthView<'_>>,
+        transformation: &Transformation,
+        scissor: Option<Rectangle<u32>>,
+        blend_mode: BlendMode,
+    ) {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.draw_instance_buffer(
+                texture.raw(),
+                instances.raw(),
+                view.raw(),
+                depth.as_ref().map(Raw::raw),
+                transformation,
+                scissor,
+                blend_mode,
+            )
+        })
+    }
+
+    pub(super) fn draw_font(
+        &mut self,
+        font: &mut Font,
+        target: TargetView<'_>,
+        transformation: Transformation,
+        scissor: Option<Rectangle<u32>>,
+    ) {
+        dispatch!(Device, &mut self.device, gpu => {
+            gpu.draw_font(font.raw_mut(), target.raw(), transformation, scissor)
+        })
+    }
+}
+
+#[cfg(feature = "opengl")]
+impl Raw<backend_gfx::Gpu> for Gpu {
+    #[allow(unreachable_patterns)]
+    fn raw(&self) -> &backend_gfx::Gpu {
+        match &self.device {
+            Device::Gfx(gpu) => gpu,
+            _ => mismatch(),
+        }
+    }
+}
+
+#[cfg(feature = "opengl")]
+impl RawMut<backend_gfx::Gpu> for Gpu {
+    #[allow(unreachable_patterns)]
+    fn raw_mut(&mut self) -> &mut backend_gfx::Gpu {
+        match &mut self.device {
+            Device::Gfx(gpu) => gpu,
+            _ => mismatch(),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+impl Raw<backend_wgpu::Gpu> for Gpu {
+    #[allow(unreachable_patterns)]
+    fn raw(&self) -> &backend_wgpu::Gpu {
+        match &self.device {
+            Device::Wgpu(gpu) => gpu,
+            _ => mismatch(),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+impl RawMut<backend_wgpu::Gpu> for Gpu {
+    #[allow(unreachable_patterns)]
+    fn raw_mut(&mut self) -> &mut backend_wgpu::Gpu {
+        match &mut self.device {
+            Device::Wgpu(gpu) => gpu,
+            _ => mismatch(),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "opengl")]
+use super::backend_gfx;
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+use super::backend_wgpu;
+use super::{mismatch, Raw, RawMut};
+use crate::graphics::{FontMetrics, Rectangle, Text};
+
+resource!(Font);
+
+impl Font {
+    pub fn add_face(&mut self, bytes: &'static [u8]) -> usize {
+        dispatch!(Font, self, raw => raw.add_face(bytes))
+    }
+
+    pub fn add(&mut self, text: Text<'_>) {
+        dispatch!(Font, self, raw => raw.add(text))
+    }
+
+    pub fn metrics(&self, face: usize, size: f32) -> FontMetrics {
+        dispatch!(Font, self, raw => raw.metrics(face, size))
+    }
+
+    pub fn measure(&mut self, text: Text<'_>) -> (f32, f32) {
+        dispatch!(Font, self, raw => raw.measure(text))
+    }
+
+    pub fn bounds(&mut self, text: Text<'_>) -> Rectangle<f32> {
+        dispatch!(Font, self, raw => raw.bounds(text))
+    }
+}
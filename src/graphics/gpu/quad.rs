@@ -0,0 +1,102 @@
+#[cfg(feature = "opengl")]
+use super::backend_gfx;
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+use super::backend_wgpu;
+use super::{mismatch, Raw, RawMut};
+use crate::graphics::{self, Transformation};
+
+/// The instance data of a textured quad, shared by every backend.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    any(
+        feature = "vulkan",
+        feature = "metal",
+        feature = "dx11",
+        feature = "dx12",
+    ),
+    derive(zerocopy::AsBytes)
+)]
+#[repr(C)]
+pub struct Quad {
+    pub source: [f32; 4],
+    pub scale: [f32; 2],
+    pub translation: [f32; 2],
+    pub layer: u32,
+    pub colors: [[f32; 4]; 4],
+    pub origin: [f32; 2],
+    pub rotation: f32,
+}
+
+impl Quad {
+    pub fn flip_vertically(&mut self) {
+        self.source[1] += self.source[3];
+        self.source[3] = -self.source[3];
+    }
+
+    /// Rounds the position of the quad to whole pixels of the target, given
+    /// the transformations to and from target pixels.
+    pub fn snap(
+        &mut self,
+        to_pixels: &Transformation,
+        from_pixels: &Transformation,
+    ) {
+        let position = to_pixels.transform_point(graphics::Point::new(
+            self.translation[0],
+            self.translation[1],
+        ));
+
+        let snapped = from_pixels.transform_point(graphics::Point::new(
+            position.x.round(),
+            position.y.round(),
+        ));
+
+        self.translation = [snapped.x, snapped.y];
+    }
+
+    /// Multiplies the colors of the quad by their alpha channel, matching
+    /// textures with premultiplied alpha.
+    pub fn premultiply(&mut self) {
+        for color in &mut self.colors {
+            let alpha = color[3];
+
+            color[0] *= alpha;
+            color[1] *= alpha;
+            color[2] *= alpha;
+        }
+    }
+}
+
+impl From<graphics::Quad> for Quad {
+    fn from(quad: graphics::Quad) -> Quad {
+        let source = quad.source;
+        let position = quad.position;
+        let (width, height) = quad.size;
+
+        let [top_left, top_right, bottom_right, bottom_left] = quad.colors;
+
+        Quad {
+            source: [source.x, source.y, source.width, source.height],
+            translation: [position.x, position.y],
+            scale: [width, height],
+            layer: 0,
+            colors: [
+                top_left.into_linear(),
+                top_right.into_linear(),
+                bottom_right.into_linear(),
+                bottom_left.into_linear(),
+            ],
+            origin: [quad.origin.x, quad.origin.y],
+            rotation: quad.rotation,
+        }
+    }
+}
+
+resource!(
+    #[derive(Clone)]
+    InstanceBuffer
+);
@@ -0,0 +1,38 @@
+#[cfg(feature = "opengl")]
+use super::backend_gfx;
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+use super::backend_wgpu;
+use super::{mismatch, Gpu, Raw, RawMut, TargetView};
+
+resource!(Surface);
+
+impl Surface {
+    pub fn window(&self) -> &winit::window::Window {
+        dispatch!(Surface, self, raw => raw.window())
+    }
+
+    pub fn target(&mut self) -> TargetView<'_> {
+        dispatch!(Surface, self, raw => TargetView::from(raw.target()))
+    }
+
+    pub fn resize(
+        &mut self,
+        gpu: &mut Gpu,
+        size: winit::dpi::PhysicalSize<u32>,
+    ) {
+        dispatch!(Surface, self, raw => raw.resize(gpu.raw_mut(), size))
+    }
+
+    pub fn request_redraw(&mut self) {
+        dispatch!(Surface, self, raw => raw.request_redraw())
+    }
+
+    pub fn swap_buffers(&mut self, gpu: &mut Gpu) -> bool {
+        dispatch!(Surface, self, raw => raw.swap_buffers(gpu.raw_mut()))
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "opengl")]
+use super::backend_gfx;
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+use super::backend_wgpu;
+use super::{mismatch, Raw, RawMut};
+use crate::graphics::{ResourceId, Sampler, Transformation};
+
+resource!(
+    #[derive(Clone, Debug)]
+    Texture
+);
+
+impl Texture {
+    pub fn width(&self) -> u16 {
+        dispatch!(Texture, self, raw => raw.width())
+    }
+
+    pub fn height(&self) -> u16 {
+        dispatch!(Texture, self, raw => raw.height())
+    }
+
+    pub fn sampler(&self) -> Sampler {
+        dispatch!(Texture, self, raw => raw.sampler())
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        dispatch!(Texture, self, raw => raw.set_sampler(sampler))
+    }
+
+    pub fn is_premultiplied(&self) -> bool {
+        dispatch!(Texture, self, raw => raw.is_premultiplied())
+    }
+
+    pub fn id(&self) -> ResourceId {
+        dispatch!(Texture, self, raw => raw.id())
+    }
+}
+
+resource!(
+    // The render target and depth buffer of a drawable texture
+    #[derive(Clone)]
+    Attachments = Drawable
+);
+
+/// A texture that can be drawn on.
+#[derive(Clone)]
+pub struct Drawable {
+    texture: Texture,
+    attachments: Attachments,
+}
+
+impl Drawable {
+    pub fn texture(&self) -> &Texture {
+        &self.texture
+    }
+
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.texture.set_sampler(sampler);
+    }
+
+    pub fn target(&self) -> TargetView<'_> {
+        dispatch!(Attachments, &self.attachments, raw => {
+            TargetView::from(raw.target())
+        })
+    }
+
+    pub fn depth(&self) -> Option<DepthView<'_>> {
+        dispatch!(Attachments, &self.attachments, raw => {
+            raw.depth().map(DepthView::from)
+        })
+    }
+
+    /// Returns the transformation needed to draw on the texture, which
+    /// depends on how the backend lays out its pixels.
+    pub fn render_transformation(&self) -> Transformation {
+        dispatch!(Attachments, &self.attachments, raw => {
+            raw.render_transformation()
+        })
+    }
+}
+
+#[cfg(feature = "opengl")]
+impl From<backend_gfx::Drawable> for Drawable {
+    fn from(raw: backend_gfx::Drawable) -> Drawable {
+        Drawable {
+            texture: Texture::from(raw.texture().clone()),
+            attachments: Attachments::from(raw),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "vulkan",
+    feature = "metal",
+    feature = "dx11",
+    feature = "dx12",
+))]
+impl From<backend_wgpu::Drawable> for Drawable {
+    fn from(raw: backend_wgpu::Drawable) -> Drawable {
+        Drawable {
+            texture: Texture::from(raw.texture().clone()),
+            attachments: Attachments::from(raw),
+        }
+    }
+}
+
+// Defines a shared view borrowing a view of every compiled backend
+macro_rules! view {
+    ($name:ident) => {
+        #[derive(Clone, Copy)]
+        pub enum $name<'a> {
+            #[cfg(feature = "opengl")]
+            Gfx(&'a backend_gfx::$name),
+            #[cfg(any(
+                feature = "vulkan",
+                feature = "metal",
+                feature = "dx11",
+                feature = "dx12",
+            ))]
+            Wgpu(&'a backend_wgpu::$name),
+        }
+
+        #[cfg(feature = "opengl")]
+        impl Raw<backend_gfx::$name> for $name<'_> {
+            #[allow(unreachable_patterns)]
+            fn raw(&self) -> &backend_gfx::$name {
+                match self {
+                    $name::Gfx(raw) => raw,
+                    _ => mismatch(),
+                }
+            }
+        }
+
+        #[cfg(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        ))]
+        impl Raw<backend_wgpu::$name> for $name<'_> {
+            #[allow(unreachable_patterns)]
+            fn raw(&self) -> &backend_wgpu::$name {
+                match self {
+                    $name::Wgpu(raw) => raw,
+                    _ => mismatch(),
+                }
+            }
+        }
+
+        #[cfg(feature = "opengl")]
+        impl<'a> From<&'a backend_gfx::$name> for $name<'a> {
+            fn from(raw: &'a backend_gfx::$name) -> $name<'a> {
+                $name::Gfx(raw)
+            }
+        }
+
+        #[cfg(any(
+            feature = "vulkan",
+            feature = "metal",
+            feature = "dx11",
+            feature = "dx12",
+        ))]
+        impl<'a> From<&'a backend_wgpu::$name> for $name<'a> {
+            fn from(raw: &'a backend_wgpu::$name) -> $name<'a> {
+                $name::Wgpu(raw)
+            }
+        }
+    };
+}
+
+view!(TargetView);
+view!(DepthView);
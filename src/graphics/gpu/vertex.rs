@@ -0,0 +1,22 @@
+/// A colored vertex of a triangle, shared by every backend.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(
+    any(
+        feature = "vulkan",
+        feature = "metal",
+        feature = "dx11",
+        feature = "dx12",
+    ),
+    derive(zerocopy::AsBytes)
+)]
+#[repr(C)]
+pub struct Vertex {
+    pub position: [f32; 2],
+    pub color: [f32; 4],
+}
+
+impl Vertex {
+    pub fn new(position: [f32; 2], color: [f32; 4]) -> Vertex {
+        Vertex { position, color }
+    }
+}
@@ -2,10 +2,16 @@ use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
+use image::GenericImageView;
+
 use crate::graphics::gpu::{self, Texture};
-use crate::graphics::{Color, Gpu, IntoQuad, Target};
+use crate::graphics::image_settings;
+use crate::graphics::{
+    Color, Gpu, ImageHandle, ImageSettings, IntoQuad, Point, Quad, Rectangle,
+    ResourceId, Sampler, SubImage, Target,
+};
 use crate::load::Task;
-use crate::Result;
+use crate::{Error, Result};
 
 /// A loaded image.
 ///
@@ -14,25 +20,64 @@ use crate::Result;
 /// Cloning an [`Image`] is cheap, it only clones a handle. It does not
 /// create a new copy of the image on the GPU.
 ///
+/// Images larger than the [`Limits`] of the [`Gpu`] are split into tiles and
+/// stored in the layers of a single texture. Drawing them works the same way,
+/// but quads with a `source` outside of the image do not repeat it and seams
+/// may be visible between tiles when using linear filtering.
+///
 /// [`Image`]: struct.Image.html
+/// [`Limits`]: struct.Limits.html
+/// [`Gpu`]: struct.Gpu.html
 #[derive(Clone)]
 pub struct Image {
     pub(super) texture: Texture,
+    pub(super) tiling: Option<Tiling>,
 }
 
 impl Image {
     /// Loads an [`Image`] from the given path.
     ///
+    /// The color channels of the file are expected to be independent of its
+    /// alpha channel, which is always the case for PNG files. Use
+    /// [`new_premultiplied`] for images exported with premultiplied alpha.
+    ///
+    /// The [`Image`] is uploaded with the default [`ImageSettings`].
+    ///
     /// [`Image`]: struct.Image.html
+    /// [`new_premultiplied`]: #method.new_premultiplied
+    /// [`ImageSettings`]: struct.ImageSettings.html
     pub fn new<P: AsRef<Path>>(gpu: &mut Gpu, path: P) -> Result<Image> {
-        let image = {
-            let mut buf = Vec::new();
-            let mut reader = File::open(path)?;
-            let _ = reader.read_to_end(&mut buf)?;
-            image::load_from_memory(&buf)?
-        };
+        Image::with_settings(gpu, path, ImageSettings::default())
+    }
 
-        Image::from_image(gpu, &image)
+    /// Loads an [`Image`] from the given path using the given
+    /// [`ImageSettings`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`ImageSettings`]: struct.ImageSettings.html
+    pub fn with_settings<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+        settings: ImageSettings,
+    ) -> Result<Image> {
+        let image = read(path)?;
+
+        Image::from_image_with_settings(gpu, &image, settings)
+    }
+
+    /// Loads an [`Image`] with premultiplied alpha from the given path.
+    ///
+    /// The image is converted to straight alpha when loaded, avoiding dark
+    /// fringes around its transparent edges when drawn.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn new_premultiplied<P: AsRef<Path>>(
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<Image> {
+        let image = read(path)?;
+
+        Image::from_premultiplied_image(gpu, &image)
     }
 
     /// Creates a [`Task`] that loads an [`Image`] from the given path.
@@ -45,6 +90,47 @@ impl Image {
         Task::using_gpu(move |gpu| Image::new(gpu, &p))
     }
 
+    /// Creates a [`Task`] that loads an [`Image`] from the given path using
+    /// the given [`ImageSettings`].
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: struct.Image.html
+    /// [`ImageSettings`]: struct.ImageSettings.html
+    pub fn load_with_settings<P: Into<PathBuf>>(
+        path: P,
+        settings: ImageSettings,
+    ) -> Task<Image> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| Image::with_settings(gpu, &p, settings))
+    }
+
+    /// Creates a [`Task`] that loads an [`Image`] with premultiplied alpha
+    /// from the given path.
+    ///
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Image`]: struct.Image.html
+    pub fn load_premultiplied<P: Into<PathBuf>>(path: P) -> Task<Image> {
+        let p = path.into();
+
+        Task::using_gpu(move |gpu| Image::new_premultiplied(gpu, &p))
+    }
+
+    /// Starts loading an [`Image`] from the given path in the background.
+    ///
+    /// The file is decoded in a separate thread and uploaded to the GPU
+    /// progressively, avoiding frame hitches when loading big images during
+    /// gameplay. Check out [`ImageHandle`] for more details.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`ImageHandle`]: struct.ImageHandle.html
+    pub fn load_in_background<P: Into<PathBuf>>(
+        gpu: &mut Gpu,
+        path: P,
+    ) -> Result<ImageHandle> {
+        ImageHandle::new(gpu, path.into())
+    }
+
     /// Creates an [`Image`] from a [`DynamicImage`] of the [`image` crate].
     ///
     /// [`Image`]: struct.Image.html
@@ -54,9 +140,98 @@ impl Image {
         gpu: &mut Gpu,
         image: &image::DynamicImage,
     ) -> Result<Image> {
-        let texture = gpu.upload_texture(&image);
+        Image::from_image_with_settings(gpu, image, ImageSettings::default())
+    }
+
+    /// Creates an [`Image`] from a [`DynamicImage`] using the given
+    /// [`ImageSettings`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`DynamicImage`]: https://docs.rs/image/0.21.1/image/enum.DynamicImage.html
+    /// [`ImageSettings`]: struct.ImageSettings.html
+    pub fn from_image_with_settings(
+        gpu: &mut Gpu,
+        image: &image::DynamicImage,
+        settings: ImageSettings,
+    ) -> Result<Image> {
+        let limits = gpu.limits();
+        let max = u32::from(limits.max_texture_size);
+        let (width, height) = image.dimensions();
+
+        if width <= max && height <= max {
+            let texture = gpu.upload_texture(&image, settings);
+
+            return Ok(Image {
+                texture,
+                tiling: None,
+            });
+        }
+
+        let too_large = Error::TextureTooLarge {
+            max: limits.max_texture_size,
+        };
+
+        if width > u32::from(u16::max_value())
+            || height > u32::from(u16::max_value())
+        {
+            return Err(too_large);
+        }
+
+        let columns = (width + max - 1) / max;
+        let rows = (height + max - 1) / max;
+
+        if columns * rows > u32::from(limits.max_texture_layers) {
+            return Err(too_large);
+        }
+
+        // Tiles are the same size, so they can be stored in a texture array
+        let tile_width = (width + columns - 1) / columns;
+        let tile_height = (height + rows - 1) / rows;
+
+        let rgba = image.to_rgba();
+        let mut layers = Vec::with_capacity((columns * rows) as usize);
+
+        for row in 0..rows {
+            for column in 0..columns {
+                layers.push(image::DynamicImage::ImageRgba8(crop(
+                    &rgba,
+                    column * tile_width,
+                    row * tile_height,
+                    tile_width,
+                    tile_height,
+                )));
+            }
+        }
+
+        let texture = gpu.upload_texture_array(&layers, false, settings);
+
+        Ok(Image {
+            texture,
+            tiling: Some(Tiling {
+                width: width as u16,
+                height: height as u16,
+                tile_width: tile_width as u16,
+                tile_height: tile_height as u16,
+                columns: columns as u16,
+                rows: rows as u16,
+            }),
+        })
+    }
+
+    /// Creates an [`Image`] from a [`DynamicImage`] with premultiplied alpha.
+    ///
+    /// The image is converted to straight alpha before being uploaded.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`DynamicImage`]: https://docs.rs/image/0.21.1/image/enum.DynamicImage.html
+    pub fn from_premultiplied_image(
+        gpu: &mut Gpu,
+        image: &image::DynamicImage,
+    ) -> Result<Image> {
+        let mut rgba = image.to_rgba();
+        image_settings::unpremultiply(&mut rgba);
 
-        Ok(Image { texture })
+        Image::from_image(gpu, &image::DynamicImage::ImageRgba8(rgba))
     }
 
     /// Creates an [`Image`] representing a color palette.
@@ -86,14 +261,97 @@ impl Image {
     ///
     /// [`Image`]: struct.Image.html
     pub fn width(&self) -> u16 {
-        self.texture.width()
+        match &self.tiling {
+            Some(tiling) => tiling.width,
+            None => self.texture.width(),
+        }
     }
 
     /// Returns the height of the [`Image`].
     ///
     /// [`Image`]: struct.Image.html
     pub fn height(&self) -> u16 {
-        self.texture.height()
+        match &self.tiling {
+            Some(tiling) => tiling.height,
+            None => self.texture.height(),
+        }
+    }
+
+    /// Returns the [`ResourceId`] of the [`Image`].
+    ///
+    /// Clones of an [`Image`] share the same [`ResourceId`], even if they use
+    /// a different [`Sampler`].
+    ///
+    /// [`ResourceId`]: struct.ResourceId.html
+    /// [`Image`]: struct.Image.html
+    /// [`Sampler`]: struct.Sampler.html
+    pub fn id(&self) -> ResourceId {
+        self.texture.id()
+    }
+
+    /// Returns the [`Sampler`] used to draw the [`Image`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Image`]: struct.Image.html
+    pub fn sampler(&self) -> Sampler {
+        self.texture.sampler()
+    }
+
+    /// Sets the [`Sampler`] used to draw the [`Image`].
+    ///
+    /// Only this handle is affected. Other clones of the [`Image`] keep their
+    /// own [`Sampler`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Image`]: struct.Image.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.texture.set_sampler(sampler);
+    }
+
+    /// Returns a [`SubImage`] covering the given region of the [`Image`].
+    ///
+    /// The region is in absolute coordinates and it is clamped to the bounds
+    /// of the [`Image`]. This is useful to draw the different sprites of an
+    /// atlas.
+    ///
+    /// [`SubImage`]: struct.SubImage.html
+    /// [`Image`]: struct.Image.html
+    pub fn slice(&self, region: Rectangle<u16>) -> SubImage {
+        SubImage::new(self.clone(), region)
+    }
+
+    /// Reads the pixels of the [`Image`] back from the GPU.
+    ///
+    /// If the [`Image`] was split into tiles, only the first tile is read.
+    /// Premultiplied pixels are converted back to straight alpha.
+    ///
+    /// _Note:_ This is a very slow operation.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn read_pixels(&self, gpu: &mut Gpu) -> image::DynamicImage {
+        let pixels = gpu.read_texture_pixels(&self.texture);
+
+        if !self.texture.is_premultiplied() {
+            return pixels;
+        }
+
+        let mut rgba = pixels.to_rgba();
+        image_settings::unpremultiply(&mut rgba);
+
+        image::DynamicImage::ImageRgba8(rgba)
+    }
+
+    /// Saves the [`Image`] to the given path.
+    ///
+    /// The pixels are read back from the GPU and the format of the file is
+    /// inferred from the extension of the path. This is useful to export
+    /// procedurally generated art or to debug the contents of an atlas.
+    ///
+    /// _Note:_ This is a very slow operation.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub fn save<P: AsRef<Path>>(&self, gpu: &mut Gpu, path: P) -> Result<()> {
+        write(&self.read_pixels(gpu), path)
     }
 
     /// Draws the [`Image`] on the given [`Target`].
@@ -102,13 +360,99 @@ impl Image {
     /// [`Target`]: struct.Target.html
     #[inline]
     pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
-        target.draw_texture_quads(
-            &self.texture,
-            &[gpu::Quad::from(quad.into_quad(
-                1.0 / self.width() as f32,
-                1.0 / self.height() as f32,
-            ))],
-        );
+        let quad = quad
+            .into_quad(1.0 / self.width() as f32, 1.0 / self.height() as f32);
+
+        match &self.tiling {
+            None => {
+                target.draw_texture_quads(
+                    &self.texture,
+                    &[gpu::Quad::from(quad)],
+                );
+            }
+            Some(tiling) => {
+                let mut instances = Vec::new();
+                let _ = tiling.split(quad, &mut instances);
+
+                target.draw_texture_quads(&self.texture, &instances[..]);
+            }
+        }
+    }
+
+    /// Draws every quad produced by the given iterator on the given
+    /// [`Target`], using the [`Image`].
+    ///
+    /// The quads are merged into a single draw call, so you can feed your own
+    /// [`IntoQuad`] types straight from your game state, without collecting
+    /// them into a `Vec` or managing a [`Batch`] every frame.
+    ///
+    /// ```
+    /// use coffee::graphics::{Image, Point, Rectangle, Sprite, Target};
+    ///
+    /// fn draw_coins(coins: &[Point], coin: &Image, target: &mut Target<'_>) {
+    ///     coin.draw_all(
+    ///         coins.iter().map(|position| Sprite {
+    ///             source: Rectangle {
+    ///                 x: 0,
+    ///                 y: 0,
+    ///                 width: 16,
+    ///                 height: 16,
+    ///             },
+    ///             position: *position,
+    ///             ..Sprite::default()
+    ///         }),
+    ///         target,
+    ///     );
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Image`]: struct.Image.html
+    /// [`IntoQuad`]: trait.IntoQuad.html
+    /// [`Batch`]: struct.Batch.html
+    pub fn draw_all<I>(&self, quads: I, target: &mut Target<'_>)
+    where
+        I: IntoIterator,
+        I::Item: IntoQuad,
+    {
+        target.batched(|target| {
+            for quad in quads {
+                self.draw(quad, target);
+            }
+        });
+    }
+
+    /// Pushes the instances needed to draw the given [`Quad`] and returns
+    /// how many were pushed.
+    ///
+    /// [`Quad`]: struct.Quad.html
+    #[inline]
+    pub(super) fn push_instances(
+        &self,
+        quad: Quad,
+        instances: &mut Vec<gpu::Quad>,
+    ) -> usize {
+        match &self.tiling {
+            None => {
+                instances.push(gpu::Quad::from(quad));
+                1
+            }
+            Some(tiling) => tiling.split(quad, instances),
+        }
+    }
+}
+
+impl PartialEq for Image {
+    fn eq(&self, other: &Image) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Image {}
+
+impl std::hash::Hash for Image {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
     }
 }
 
@@ -122,3 +466,298 @@ impl std::fmt::Debug for Image {
         )
     }
 }
+
+pub(super) fn read<P: AsRef<Path>>(path: P) -> Result<image::DynamicImage> {
+    let path = path.as_ref();
+    let mut buf = Vec::new();
+
+    let _ = File::open(path)
+        .and_then(|mut reader| reader.read_to_end(&mut buf))
+        .map_err(|error| Error::File(PathBuf::from(path), error))?;
+
+    Ok(image::load_from_memory(&buf)?)
+}
+
+pub(super) fn write<P: AsRef<Path>>(
+    image: &image::DynamicImage,
+    path: P,
+) -> Result<()> {
+    let path = path.as_ref();
+
+    // Encoders write BGRA pixels as if they were RGBA, and the wgpu backends
+    // read textures back as BGRA
+    let rgba = image::DynamicImage::ImageRgba8(image.to_rgba());
+
+    rgba.save(path)
+        .map_err(|error| Error::File(PathBuf::from(path), error))
+}
+
+/// The layout of an [`Image`] split into the layers of a texture array.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Tiling {
+    width: u16,
+    height: u16,
+    tile_width: u16,
+    tile_height: u16,
+    columns: u16,
+    rows: u16,
+}
+
+impl Tiling {
+    // Splits a quad covering the whole image into a quad per visible tile
+    fn split(&self, quad: Quad, instances: &mut Vec<gpu::Quad>) -> usize {
+        let width = f32::from(self.width);
+        let height = f32::from(self.height);
+        let tile_width = f32::from(self.tile_width);
+        let tile_height = f32::from(self.tile_height);
+
+        let mut count = 0;
+
+        for row in 0..self.rows {
+            let top = f32::from(row) * tile_height;
+            let bottom = (top + tile_height).min(height);
+
+            let (y0, y1) = match overlap(
+                quad.source.y,
+                quad.source.height,
+                top / height,
+                bottom / height,
+            ) {
+                Some(range) => range,
+                None => continue,
+            };
+
+            for column in 0..self.columns {
+                let left = f32::from(column) * tile_width;
+                let right = (left + tile_width).min(width);
+
+                let (x0, x1) = match overlap(
+                    quad.source.x,
+                    quad.source.width,
+                    left / width,
+                    right / width,
+                ) {
+                    Some(range) => range,
+                    None => continue,
+                };
+
+                let x_scale = width / tile_width;
+                let y_scale = height / tile_height;
+
+                let mut instance = gpu::Quad::from(Quad {
+                    source: Rectangle {
+                        x: (quad.source.x + x0 * quad.source.width) * x_scale
+                            - f32::from(column),
+                        y: (quad.source.y + y0 * quad.source.height) * y_scale
+                            - f32::from(row),
+                        width: (x1 - x0) * quad.source.width * x_scale,
+                        height: (y1 - y0) * quad.source.height * y_scale,
+                    },
+                    position: Point::new(
+                        quad.position.x + x0 * quad.size.0,
+                        quad.position.y + y0 * quad.size.1,
+                    ),
+                    size: ((x1 - x0) * quad.size.0, (y1 - y0) * quad.size.1),
+                    colors: colors_between(&quad.colors, (x0, x1), (y0, y1)),
+                    // Every tile rotates around the origin of the whole quad
+                    rotation: quad.rotation,
+                    origin: Point::new(
+                        quad.origin.x - x0 * quad.size.0,
+                        quad.origin.y - y0 * quad.size.1,
+                    ),
+                });
+
+                instance.layer = u32::from(row) * u32::from(self.columns)
+                    + u32::from(column);
+
+                instances.push(instance);
+                count += 1;
+            }
+        }
+
+        count
+    }
+}
+
+// Interpolates the corner colors of a quad for the given ranges of it,
+// relative to its size
+fn colors_between(
+    colors: &[Color; 4],
+    (x0, x1): (f32, f32),
+    (y0, y1): (f32, f32),
+) -> [Color; 4] {
+    fn lerp(a: Color, b: Color, t: f32) -> Color {
+        Color {
+            r: a.r + (b.r - a.r) * t,
+            g: a.g + (b.g - a.g) * t,
+            b: a.b + (b.b - a.b) * t,
+            a: a.a + (b.a - a.a) * t,
+        }
+    }
+
+    let at = |x: f32, y: f32| {
+        let top = lerp(colors[0], colors[1], x);
+        let bottom = lerp(colors[3], colors[2], x);
+
+        lerp(top, bottom, y)
+    };
+
+    [at(x0, y0), at(x1, y0), at(x1, y1), at(x0, y1)]
+}
+
+// Returns the range of a quad, relative to its size, that samples the given
+// range of the source. A negative length means the source is flipped.
+fn overlap(start: f32, length: f32, min: f32, max: f32) -> Option<(f32, f32)> {
+    if length == 0.0 {
+        return None;
+    }
+
+    let a = (min - start) / length;
+    let b = (max - start) / length;
+
+    let from = a.min(b).max(0.0);
+    let to = a.max(b).min(1.0);
+
+    if from < to {
+        Some((from, to))
+    } else {
+        None
+    }
+}
+
+fn crop(
+    image: &image::RgbaImage,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> image::RgbaImage {
+    let source: &[u8] = image;
+    let source_row = 4 * image.width() as usize;
+    let row = 4 * width as usize;
+
+    // Tiles on the edges are padded with transparent pixels
+    let columns = 4 * width.min(image.width().saturating_sub(x)) as usize;
+    let rows = height.min(image.height().saturating_sub(y)) as usize;
+
+    let mut pixels = vec![0; row * height as usize];
+
+    for i in 0..rows {
+        let start = (y as usize + i) * source_row + 4 * x as usize;
+
+        pixels[i * row..i * row + columns]
+            .copy_from_slice(&source[start..start + columns]);
+    }
+
+    image::RgbaImage::from_raw(width, height, pixels).expect("Crop image tile")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlap_is_relative_to_the_quad() {
+        assert_eq!(overlap(0.0, 1.0, 0.0, 0.5), Some((0.0, 0.5)));
+        assert_eq!(overlap(0.25, 0.5, 0.0, 0.5), Some((0.0, 0.5)));
+        assert_eq!(overlap(0.25, 0.5, 0.5, 1.0), Some((0.5, 1.0)));
+    }
+
+    #[test]
+    fn overlap_of_flipped_sources() {
+        assert_eq!(overlap(1.0, -1.0, 0.0, 0.25), Some((0.75, 1.0)));
+        assert_eq!(overlap(1.0, -1.0, 0.75, 1.0), Some((0.0, 0.25)));
+    }
+
+    #[test]
+    fn overlap_outside_of_the_source() {
+        assert_eq!(overlap(0.0, 0.5, 0.5, 1.0), None);
+        assert_eq!(overlap(0.5, 0.5, 0.0, 0.5), None);
+        assert_eq!(overlap(0.0, 0.0, 0.0, 1.0), None);
+    }
+
+    #[test]
+    fn crop_copies_the_region() {
+        let image = image::RgbaImage::from_fn(4, 4, |x, y| {
+            image::Rgba([x as u8, y as u8, 0, 255])
+        });
+
+        let tile = crop(&image, 1, 2, 2, 2);
+
+        assert_eq!(tile.dimensions(), (2, 2));
+        assert_eq!(tile.get_pixel(0, 0), &image::Rgba([1, 2, 0, 255]));
+        assert_eq!(tile.get_pixel(1, 1), &image::Rgba([2, 3, 0, 255]));
+    }
+
+    #[test]
+    fn crop_pads_the_edges() {
+        let image = image::RgbaImage::from_pixel(3, 3, image::Rgba([255; 4]));
+
+        let tile = crop(&image, 2, 2, 2, 2);
+        let transparent = image::Rgba([0, 0, 0, 0]);
+
+        assert_eq!(tile.dimensions(), (2, 2));
+        assert_eq!(tile.get_pixel(0, 0), &image::Rgba([255; 4]));
+        assert_eq!(tile.get_pixel(1, 0), &transparent);
+        assert_eq!(tile.get_pixel(0, 1), &transparent);
+        assert_eq!(tile.get_pixel(1, 1), &transparent);
+    }
+
+    #[test]
+    fn split_draws_the_visible_tiles() {
+        let tiling = Tiling {
+            width: 300,
+            height: 100,
+            tile_width: 100,
+            tile_height: 100,
+            columns: 3,
+            rows: 1,
+        };
+
+        // The right half of the image, drawn in a 150x100 quad
+        let quad = Quad {
+            source: Rectangle {
+                x: 0.5,
+                y: 0.0,
+                width: 0.5,
+                height: 1.0,
+            },
+            size: (150.0, 100.0),
+            ..Quad::default()
+        };
+
+        let mut instances = Vec::new();
+
+        assert_eq!(tiling.split(quad, &mut instances), 2);
+
+        let layers: Vec<u32> =
+            instances.iter().map(|instance| instance.layer).collect();
+
+        assert_eq!(layers, vec![1, 2]);
+
+        // The second half of the middle tile
+        assert_close(&instances[0].source, &[0.5, 0.0, 0.5, 1.0]);
+        assert_close(&instances[0].translation, &[0.0, 0.0]);
+        assert_close(&instances[0].scale, &[50.0, 100.0]);
+
+        // The whole last tile
+        assert_close(&instances[1].source, &[0.0, 0.0, 1.0, 1.0]);
+        assert_close(&instances[1].translation, &[50.0, 0.0]);
+        assert_close(&instances[1].scale, &[100.0, 100.0]);
+    }
+
+    fn assert_close(values: &[f32], expected: &[f32]) {
+        assert_eq!(values.len(), expected.len());
+
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 1e-4,
+                "{:?} is not close to {:?}",
+                values,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+
+use crate::graphics::gpu::Texture;
+use crate::graphics::image_settings::{self, ImageSettings};
+use crate::graphics::{image, Color, Gpu, Image, IntoQuad, Target};
+use crate::{Error, Result};
+
+/// The amount of bytes uploaded to the GPU on every call to
+/// [`ImageHandle::update`].
+///
+/// [`ImageHandle::update`]: struct.ImageHandle.html#method.update
+const UPLOAD_BUDGET: usize = 256 * 1024;
+
+/// An [`Image`] being loaded in the background.
+///
+/// You can obtain an [`ImageHandle`] using [`Image::load_in_background`]. The
+/// file is decoded in a separate thread and then uploaded to the GPU in
+/// slices, a few rows every time [`update`] is called. This way, loading big
+/// images during gameplay does not stall any frame.
+///
+/// Until the upload is complete, an [`ImageHandle`] holds a transparent
+/// placeholder pixel.
+///
+/// # Example
+/// Call [`update`] once per frame, before drawing:
+///
+/// ```
+/// use coffee::graphics::{Frame, ImageHandle, Quad};
+///
+/// fn draw(background: &mut ImageHandle, frame: &mut Frame) {
+///     background.update(frame.gpu()).expect("Load background");
+///     background.draw(Quad::default(), &mut frame.as_target());
+/// }
+/// ```
+///
+/// [`Image`]: struct.Image.html
+/// [`ImageHandle`]: struct.ImageHandle.html
+/// [`Image::load_in_background`]: struct.Image.html#method.load_in_background
+/// [`update`]: #method.update
+pub struct ImageHandle {
+    image: Image,
+    state: State,
+}
+
+enum State {
+    Decoding(mpsc::Receiver<Result<::image::RgbaImage>>),
+    Uploading {
+        texture: Texture,
+        pixels: ::image::RgbaImage,
+        next_row: u32,
+    },
+    Ready,
+    Failed,
+}
+
+impl ImageHandle {
+    pub(super) fn new(gpu: &mut Gpu, path: PathBuf) -> Result<ImageHandle> {
+        let transparent = Color {
+            a: 0.0,
+            ..Color::BLACK
+        };
+
+        let placeholder = Image::from_colors(gpu, &[transparent])?;
+        let (sender, receiver) = mpsc::channel();
+
+        let _ = thread::spawn(move || {
+            // Pixels are premultiplied here to keep the work off the main
+            // thread, as the default `ImageSettings` require
+            let result = image::read(&path).map(|image| {
+                let mut rgba = image.to_rgba();
+                image_settings::premultiply(&mut rgba);
+
+                rgba
+            });
+
+            // The handle may have been dropped already
+            let _ = sender.send(result);
+        });
+
+        Ok(ImageHandle {
+            image: placeholder,
+            state: State::Decoding(receiver),
+        })
+    }
+
+    /// Makes progress on the loading of the [`ImageHandle`].
+    ///
+    /// It uploads, at most, a fixed amount of rows to the GPU. Once all the
+    /// rows are uploaded, the placeholder is replaced by the loaded
+    /// [`Image`].
+    ///
+    /// An error is returned only once, the first time it happens. Afterwards,
+    /// the [`ImageHandle`] keeps its placeholder.
+    ///
+    /// [`ImageHandle`]: struct.ImageHandle.html
+    /// [`Image`]: struct.Image.html
+    pub fn update(&mut self, gpu: &mut Gpu) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Failed) {
+            State::Decoding(receiver) => match receiver.try_recv() {
+                Ok(Ok(pixels)) => {
+                    let max = gpu.max_texture_size();
+
+                    if pixels.width() > u32::from(max)
+                        || pixels.height() > u32::from(max)
+                    {
+                        return Err(Error::TextureTooLarge { max });
+                    }
+
+                    let texture = gpu.create_texture(
+                        pixels.width() as u16,
+                        pixels.height() as u16,
+                        ImageSettings::default(),
+                    );
+
+                    self.state = State::Uploading {
+                        texture,
+                        pixels,
+                        next_row: 0,
+                    };
+
+                    self.update(gpu)
+                }
+                Ok(Err(error)) => Err(error),
+                Err(mpsc::TryRecvError::Empty) => {
+                    self.state = State::Decoding(receiver);
+
+                    Ok(())
+                }
+                Err(mpsc::TryRecvError::Disconnected) => {
+                    Err(Error::IO(std::io::Error::new(
+                        std::io::ErrorKind::Other,
+                        "The decoding thread stopped unexpectedly",
+                    )))
+                }
+            },
+            State::Uploading {
+                texture,
+                pixels,
+                next_row,
+            } => {
+                let row_size = 4 * pixels.width().max(1) as usize;
+                let rows = (UPLOAD_BUDGET / row_size).max(1) as u32;
+                let end = (next_row + rows).min(pixels.height());
+
+                let raw: &[u8] = &pixels;
+                let slice =
+                    &raw[next_row as usize * row_size..end as usize * row_size];
+
+                gpu.update_texture(&texture, next_row as u16, slice);
+
+                if end == pixels.height() {
+                    self.image = Image {
+                        texture,
+                        tiling: None,
+                    };
+                    self.state = State::Ready;
+                } else {
+                    self.state = State::Uploading {
+                        texture,
+                        pixels,
+                        next_row: end,
+                    };
+                }
+
+                Ok(())
+            }
+            State::Ready => {
+                self.state = State::Ready;
+
+                Ok(())
+            }
+            State::Failed => Ok(()),
+        }
+    }
+
+    /// Returns true if the [`ImageHandle`] has finished loading.
+    ///
+    /// [`ImageHandle`]: struct.ImageHandle.html
+    pub fn is_ready(&self) -> bool {
+        match self.state {
+            State::Ready => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the current [`Image`] of the [`ImageHandle`].
+    ///
+    /// It is a transparent placeholder pixel until [`is_ready`] returns true.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`ImageHandle`]: struct.ImageHandle.html
+    /// [`is_ready`]: #method.is_ready
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Draws the current [`Image`] of the [`ImageHandle`] on the given
+    /// [`Target`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`ImageHandle`]: struct.ImageHandle.html
+    /// [`Target`]: struct.Target.html
+    #[inline]
+    pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        self.image.draw(quad, target);
+    }
+}
+
+impl std::fmt::Debug for ImageHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ImageHandle {{ image: {:?}, is_ready: {} }}",
+            self.image,
+            self.is_ready()
+        )
+    }
+}
@@ -0,0 +1,73 @@
+/// The settings used to upload an [`Image`] to the GPU.
+///
+/// The defaults are correct for PNG files, whose color channels are in the
+/// sRGB color space and independent of their alpha channel.
+///
+/// [`Image`]: struct.Image.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImageSettings {
+    /// Whether to multiply the color channels by the alpha channel when
+    /// uploading the [`Image`].
+    ///
+    /// Filtering an image with straight alpha blends the color of its
+    /// transparent pixels, usually black, into its edges. This shows up as
+    /// dark fringes when the image is scaled or rotated. Premultiplied images
+    /// are drawn with [`BlendMode::Premultiplied`] and do not have this
+    /// problem.
+    ///
+    /// By default, it is `true`.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`BlendMode::Premultiplied`]: enum.BlendMode.html#variant.Premultiplied
+    pub premultiply: bool,
+
+    /// Whether the color channels of the [`Image`] are in the sRGB color
+    /// space.
+    ///
+    /// Disable it for images holding data instead of colors, like normal maps
+    /// or noise.
+    ///
+    /// By default, it is `true`.
+    ///
+    /// [`Image`]: struct.Image.html
+    pub srgb: bool,
+}
+
+impl Default for ImageSettings {
+    fn default() -> ImageSettings {
+        ImageSettings {
+            premultiply: true,
+            srgb: true,
+        }
+    }
+}
+
+// Multiplies the color channels of the given RGBA or BGRA pixels by their
+// alpha channel
+pub(crate) fn premultiply(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_mut(4) {
+        let alpha = u32::from(pixel[3]);
+
+        if alpha < 255 {
+            for channel in &mut pixel[0..3] {
+                *channel = ((u32::from(*channel) * alpha + 127) / 255) as u8;
+            }
+        }
+    }
+}
+
+// Divides the color channels of the given RGBA or BGRA pixels by their alpha
+// channel
+pub(crate) fn unpremultiply(pixels: &mut [u8]) {
+    for pixel in pixels.chunks_mut(4) {
+        let alpha = u32::from(pixel[3]);
+
+        if alpha > 0 && alpha < 255 {
+            for channel in &mut pixel[0..3] {
+                let value = (u32::from(*channel) * 255 + alpha / 2) / alpha;
+
+                *channel = value.min(255) as u8;
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+//! Light up your scenes with 2D lights.
+use std::f32::consts::PI;
+
+use crate::graphics::gpu::{self, Gpu};
+use crate::graphics::{
+    BlendMode, Canvas, Color, Point, Quad, Target, Transformation,
+};
+use crate::Result;
+
+/// An off-screen map of the light reaching every pixel of a scene.
+///
+/// A [`LightMap`] is filled with an ambient [`Color`]. Then, every [`Light`]
+/// is added on top of it. Finally, the map is composited over the scene,
+/// multiplying its colors. Pixels that no light reaches keep the ambient
+/// color.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::lighting::{Light, LightMap};
+/// use coffee::graphics::{Color, Frame, Point};
+///
+/// fn draw_cave(lights: &mut LightMap, torch: Point, frame: &mut Frame<'_>) {
+///     // Draw the scene as usual
+///     frame.clear(Color::WHITE);
+///
+///     lights.clear();
+///     lights.add(Light::Point {
+///         position: torch,
+///         radius: 200.0,
+///         color: Color::from_rgb(255, 200, 150),
+///         falloff: 2.0,
+///     });
+///
+///     lights.render(frame.gpu());
+///     lights.composite(&mut frame.as_target());
+/// }
+/// ```
+///
+/// [`LightMap`]: struct.LightMap.html
+/// [`Color`]: ../struct.Color.html
+/// [`Light`]: enum.Light.html
+#[derive(Debug, Clone)]
+pub struct LightMap {
+    canvas: Canvas,
+    ambient: Color,
+    vertices: Vec<gpu::Vertex>,
+    indices: Vec<u32>,
+}
+
+impl LightMap {
+    /// Creates a new [`LightMap`] with the given size.
+    ///
+    /// The ambient [`Color`] is black by default, so only the lights show
+    /// the scene.
+    ///
+    /// [`LightMap`]: struct.LightMap.html
+    /// [`Color`]: ../struct.Color.html
+    pub fn new(gpu: &mut Gpu, width: u16, height: u16) -> Result<LightMap> {
+        Ok(LightMap {
+            canvas: Canvas::new(gpu, width, height)?,
+            ambient: Color::BLACK,
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        })
+    }
+
+    /// Returns the width of the [`LightMap`].
+    ///
+    /// [`LightMap`]: struct.LightMap.html
+    pub fn width(&self) -> u16 {
+        self.canvas.width()
+    }
+
+    /// Returns the height of the [`LightMap`].
+    ///
+    /// [`LightMap`]: struct.LightMap.html
+    pub fn height(&self) -> u16 {
+        self.canvas.height()
+    }
+
+    /// Returns the ambient [`Color`] of the [`LightMap`].
+    ///
+    /// [`Color`]: ../struct.Color.html
+    /// [`LightMap`]: struct.LightMap.html
+    pub fn ambient(&self) -> Color {
+        self.ambient
+    }
+
+    /// Sets the ambient [`Color`] of the [`LightMap`].
+    ///
+    /// It is the color of the pixels that no light reaches.
+    ///
+    /// [`Color`]: ../struct.Color.html
+    /// [`LightMap`]: struct.LightMap.html
+    pub fn set_ambient(&mut self, color: Color) {
+        self.ambient = color;
+    }
+
+    /// Adds a [`Light`] to the [`LightMap`].
+    ///
+    /// The [`Light`] shows up the next time the [`LightMap`] is rendered.
+    ///
+    /// [`Light`]: enum.Light.html
+    /// [`LightMap`]: struct.LightMap.html
+    pub fn add(&mut self, light: Light) {
+        match light {
+            Light::Point {
+                position,
+                radius,
+                color,
+                falloff,
+            } => self.add_arc(position, radius, color, falloff, 0.0, 2.0 * PI),
+            Light::Cone {
+                position,
+                radius,
+                color,
+                falloff,
+                direction,
+                angle,
+            } => self.add_arc(
+                position,
+                radius,
+                color,
+                falloff,
+                direction - angle / 2.0,
+                angle.max(0.0).min(2.0 * PI),
+            ),
+        }
+    }
+
+    /// Removes all the lights of the [`LightMap`].
+    ///
+    /// [`LightMap`]: struct.LightMap.html
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.indices.clear();
+    }
+
+    /// Renders the lights of the [`LightMap`] on top of its ambient
+    /// [`Color`].
+    ///
+    /// Overlapping lights are added together.
+    ///
+    /// [`LightMap`]: struct.LightMap.html
+    /// [`Color`]: ../struct.Color.html
+    pub fn render(&mut self, gpu: &mut Gpu) {
+        let mut target = self.canvas.as_target(gpu);
+
+        target.clear(self.ambient);
+
+        if !self.indices.is_empty() {
+            target.blend_mode(BlendMode::Additive);
+            target.draw_triangles(&self.vertices, &self.indices);
+        }
+    }
+
+    /// Composites the [`LightMap`] over the contents of the given [`Target`],
+    /// multiplying their colors.
+    ///
+    /// The [`LightMap`] is drawn at the origin of the [`Target`] with its own
+    /// size. Transform the [`Target`] to stretch it.
+    ///
+    /// [`LightMap`]: struct.LightMap.html
+    /// [`Target`]: ../struct.Target.html
+    pub fn composite(&self, target: &mut Target<'_>) {
+        let mut target = target.transform(Transformation::identity());
+
+        target.blend_mode(BlendMode::Multiply);
+
+        self.canvas.draw(
+            Quad {
+                position: Point::new(0.0, 0.0),
+                size: (f32::from(self.width()), f32::from(self.height())),
+                ..Quad::default()
+            },
+            &mut target,
+        );
+    }
+
+    // Adds a light shaped like a circular sector as a set of rings, fading
+    // to transparent at its radius
+    fn add_arc(
+        &mut self,
+        center: Point,
+        radius: f32,
+        color: Color,
+        falloff: f32,
+        start: f32,
+        angle: f32,
+    ) {
+        if radius <= 0.0 || angle <= 0.0 {
+            return;
+        }
+
+        let [r, g, b, a] = color.into_linear();
+        let segments =
+            ((SEGMENTS as f32 * angle / (2.0 * PI)).ceil() as u32).max(1);
+
+        let base = self.vertices.len() as u32;
+
+        self.vertices
+            .push(gpu::Vertex::new([center.x, center.y], [r, g, b, a]));
+
+        for ring in 1..=RINGS {
+            let distance = ring as f32 / RINGS as f32;
+            let intensity = (1.0 - distance).powf(falloff.max(0.0));
+
+            for segment in 0..=segments {
+                let theta = start + angle * segment as f32 / segments as f32;
+
+                self.vertices.push(gpu::Vertex::new(
+                    [
+                        center.x + theta.cos() * radius * distance,
+                        center.y + theta.sin() * radius * distance,
+                    ],
+                    [r, g, b, a * intensity],
+                ));
+            }
+        }
+
+        let ring_start = |ring: u32| base + 1 + (ring - 1) * (segments + 1);
+
+        for segment in 0..segments {
+            let first = ring_start(1) + segment;
+
+            self.indices.extend_from_slice(&[base, first, first + 1]);
+        }
+
+        for ring in 2..=RINGS {
+            let inner = ring_start(ring - 1);
+            let outer = ring_start(ring);
+
+            for segment in 0..segments {
+                let (i, o) = (inner + segment, outer + segment);
+
+                self.indices
+                    .extend_from_slice(&[i, o, o + 1, i, o + 1, i + 1]);
+            }
+        }
+    }
+}
+
+/// A source of light.
+///
+/// Its intensity decreases from its position to its radius, where it
+/// vanishes. The `falloff` controls how fast: `1.0` fades linearly, while
+/// higher values concentrate the light around its position.
+///
+/// The alpha channel of its [`Color`] scales its intensity.
+///
+/// [`Color`]: ../struct.Color.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Light {
+    /// A light shining in every direction, like a torch.
+    Point {
+        /// The position of the light
+        position: Point,
+
+        /// The distance reached by the light
+        radius: f32,
+
+        /// The color of the light
+        color: Color,
+
+        /// The exponent of the fading of the light
+        falloff: f32,
+    },
+
+    /// A light shining in a direction, like a flashlight.
+    Cone {
+        /// The position of the light
+        position: Point,
+
+        /// The distance reached by the light
+        radius: f32,
+
+        /// The color of the light
+        color: Color,
+
+        /// The exponent of the fading of the light
+        falloff: f32,
+
+        /// The direction of the light, in radians
+        direction: f32,
+
+        /// The width of the cone, in radians
+        angle: f32,
+    },
+}
+
+// The amount of rings approximating the falloff of a light
+const RINGS: u32 = 16;
+
+// The amount of segments approximating a full circle
+const SEGMENTS: u32 = 48;
@@ -0,0 +1,16 @@
+/// The limits of a [`Gpu`].
+///
+/// You can obtain them using [`Gpu::limits`].
+///
+/// [`Gpu`]: struct.Gpu.html
+/// [`Gpu::limits`]: struct.Gpu.html#method.limits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum width and height of a texture, in pixels.
+    pub max_texture_size: u16,
+
+    /// The maximum amount of layers of a [`TextureArray`].
+    ///
+    /// [`TextureArray`]: texture_array/struct.TextureArray.html
+    pub max_texture_layers: u16,
+}
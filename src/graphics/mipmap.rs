@@ -0,0 +1,26 @@
+// Generates the mip chain of an image, starting with the image itself.
+//
+// Every level halves the size of the previous one until it reaches a single
+// pixel.
+pub(crate) fn chain(image: &image::RgbaImage) -> Vec<image::RgbaImage> {
+    let mut levels = vec![image.clone()];
+    let (mut width, mut height) = image.dimensions();
+
+    while width > 1 || height > 1 {
+        width = (width / 2).max(1);
+        height = (height / 2).max(1);
+
+        let previous = levels.last().expect("Previous mip level");
+
+        let level = image::imageops::resize(
+            previous,
+            width,
+            height,
+            image::FilterType::Triangle,
+        );
+
+        levels.push(level);
+    }
+
+    levels
+}
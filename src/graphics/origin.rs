@@ -0,0 +1,22 @@
+/// The corner of a [`Target`] where its coordinate system starts.
+///
+/// [`Target`]: struct.Target.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Origin {
+    /// The origin is at the top-left corner and the y axis points down.
+    ///
+    /// This is the default.
+    TopLeft,
+
+    /// The origin is at the bottom-left corner and the y axis points up.
+    ///
+    /// Useful when porting games that use the conventions of most physics
+    /// engines.
+    BottomLeft,
+}
+
+impl Default for Origin {
+    fn default() -> Origin {
+        Origin::TopLeft
+    }
+}
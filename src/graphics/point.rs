@@ -1,4 +1,59 @@
 use nalgebra;
 
 /// A 2D point.
+///
+/// It is a [`nalgebra::Point2`], so you can use all of its operations. Along
+/// with [`PointExt`], it supports:
+///
+/// ```
+/// use coffee::graphics::{Point, PointExt, Vector};
+///
+/// let a = Point::new(0.0, 0.0);
+/// let b = Point::from([3.0, 4.0]);
+///
+/// assert_eq!(a.distance(b), 5.0);
+/// assert_eq!(a.lerp(b, 0.5), Point::new(1.5, 2.0));
+/// assert_eq!(a + Vector::new(3.0, 4.0), b);
+/// assert_eq!(b - a, Vector::new(3.0, 4.0));
+/// ```
+///
+/// When the `mint` feature is enabled, it can be converted from and into a
+/// `mint::Point2<f32>`, which allows you to exchange points with other math
+/// and physics crates.
+///
+/// [`nalgebra::Point2`]: https://docs.rs/nalgebra/0.18/nalgebra/geometry/type.Point2.html
+/// [`PointExt`]: trait.PointExt.html
 pub type Point = nalgebra::Point2<f32>;
+
+/// Convenience methods for a [`Point`].
+///
+/// [`Point`]: type.Point.html
+pub trait PointExt {
+    /// Returns the distance between two points.
+    fn distance(&self, other: Point) -> f32;
+
+    /// Linearly interpolates between two points.
+    ///
+    /// A `t` of `0.0` produces this point, while a `t` of `1.0` produces the
+    /// `other` point.
+    fn lerp(&self, other: Point, t: f32) -> Point;
+
+    /// Creates a [`Point`] from a tuple of coordinates.
+    ///
+    /// [`Point`]: type.Point.html
+    fn from_tuple(coordinates: (f32, f32)) -> Point;
+}
+
+impl PointExt for Point {
+    fn distance(&self, other: Point) -> f32 {
+        nalgebra::distance(self, &other)
+    }
+
+    fn lerp(&self, other: Point, t: f32) -> Point {
+        *self + (other - *self) * t
+    }
+
+    fn from_tuple((x, y): (f32, f32)) -> Point {
+        Point::new(x, y)
+    }
+}
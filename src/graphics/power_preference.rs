@@ -0,0 +1,24 @@
+/// The kind of graphics adapter a game prefers.
+///
+/// Systems with more than one graphics processor, like laptops with
+/// integrated and dedicated GPUs, use it to choose the adapter to draw with.
+///
+/// _Note:_ Only the `wgpu` backends honor it. OpenGL offers no portable way to
+/// choose an adapter, so the OpenGL backend always uses the one chosen by the
+/// system, even when the runtime falls back to it. Most drivers let players
+/// choose the adapter of a game in their settings, like the NVIDIA Control
+/// Panel, or with environment variables, like `DRI_PRIME=1` with Mesa.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerPreference {
+    /// Prefer an adapter that saves power, like an integrated GPU.
+    Low,
+
+    /// Prefer the adapter with the best performance, like a dedicated GPU.
+    High,
+}
+
+impl Default for PowerPreference {
+    fn default() -> PowerPreference {
+        PowerPreference::High
+    }
+}
@@ -1,3 +1,4 @@
+use crate::graphics::color::Color;
 use crate::graphics::point::Point;
 use crate::graphics::rectangle::Rectangle;
 
@@ -6,6 +7,12 @@ use crate::graphics::rectangle::Rectangle;
 pub struct Quad {
     /// The region of the resource that should be shown on the quad, in relative
     /// coordinates: [0.0, 1.0].
+    ///
+    /// Coordinates outside of this range repeat the resource when it uses a
+    /// [`Sampler`] with [`WrapMode::Repeat`].
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`WrapMode::Repeat`]: enum.WrapMode.html#variant.Repeat
     pub source: Rectangle<f32>,
 
     /// The position where the quad should be drawn.
@@ -13,6 +20,61 @@ pub struct Quad {
 
     /// The size of the quad.
     pub size: (f32, f32),
+
+    /// The color of each corner of the quad, clockwise from the top-left
+    /// one.
+    ///
+    /// The colors are interpolated across the quad and multiplied with the
+    /// resource. Use them to draw cheap gradients, like a sky or a lighting
+    /// falloff. By default, every corner is white, which leaves the resource
+    /// unchanged.
+    pub colors: [Color; 4],
+
+    /// The rotation of the quad, in radians.
+    ///
+    /// The quad is rotated around its [`origin`].
+    ///
+    /// [`origin`]: #structfield.origin
+    pub rotation: f32,
+
+    /// The point the quad rotates around, relative to its [`position`].
+    ///
+    /// By default, it is the top-left corner of the quad.
+    ///
+    /// [`position`]: #structfield.position
+    pub origin: Point,
+}
+
+impl Quad {
+    // The area covered by the quad, once rotated
+    pub(super) fn bounds(&self) -> Rectangle<f32> {
+        let (width, height) = self.size;
+        let (sin, cos) = self.rotation.sin_cos();
+
+        let corners =
+            [(0.0, 0.0), (width, 0.0), (width, height), (0.0, height)];
+
+        let (mut left, mut top) = (f32::INFINITY, f32::INFINITY);
+        let (mut right, mut bottom) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for (x, y) in corners.iter() {
+            let (x, y) = (x - self.origin.x, y - self.origin.y);
+            let rotated_x = x * cos - y * sin + self.origin.x;
+            let rotated_y = x * sin + y * cos + self.origin.y;
+
+            left = left.min(rotated_x);
+            right = right.max(rotated_x);
+            top = top.min(rotated_y);
+            bottom = bottom.max(rotated_y);
+        }
+
+        Rectangle {
+            x: self.position.x + left,
+            y: self.position.y + top,
+            width: right - left,
+            height: bottom - top,
+        }
+    }
 }
 
 impl Default for Quad {
@@ -26,6 +88,9 @@ impl Default for Quad {
             },
             position: Point::new(0.0, 0.0),
             size: (1.0, 1.0),
+            colors: [Color::WHITE; 4],
+            rotation: 0.0,
+            origin: Point::new(0.0, 0.0),
         }
     }
 }
@@ -34,6 +99,54 @@ impl Default for Quad {
 ///
 /// Most methods accept generic types that can be turned into quads. This allows
 /// you to use your own quad-based type.
+///
+/// Implement it for your tiles, particles or UI primitives to feed them
+/// straight into [`Image::draw_all`] or [`Batch::extend`], without building
+/// intermediate [`Sprite`] or [`Quad`] collections every frame.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::{Image, IntoQuad, Point, Quad, Rectangle, Target};
+///
+/// struct Particle {
+///     position: Point,
+///     size: f32,
+///     rotation: f32,
+/// }
+///
+/// impl IntoQuad for &Particle {
+///     fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+///         Quad {
+///             // The particle sprite is at (0, 0) and measures 8x8 pixels
+///             source: Rectangle {
+///                 x: 0.0,
+///                 y: 0.0,
+///                 width: 8.0 * x_unit,
+///                 height: 8.0 * y_unit,
+///             },
+///             position: Point::new(
+///                 self.position.x - self.size / 2.0,
+///                 self.position.y - self.size / 2.0,
+///             ),
+///             size: (self.size, self.size),
+///             // The particle spins around its center
+///             rotation: self.rotation,
+///             origin: Point::new(self.size / 2.0, self.size / 2.0),
+///             ..Quad::default()
+///         }
+///     }
+/// }
+///
+/// fn draw(particles: &[Particle], image: &Image, target: &mut Target<'_>) {
+///     image.draw_all(particles, target);
+/// }
+/// ```
+///
+/// [`Image::draw_all`]: struct.Image.html#method.draw_all
+/// [`Batch::extend`]: struct.Batch.html#impl-Extend%3CQ%3E
+/// [`Sprite`]: struct.Sprite.html
+/// [`Quad`]: struct.Quad.html
 pub trait IntoQuad {
     /// Turns the implementor into a quad.
     ///
@@ -50,3 +163,21 @@ impl IntoQuad for Quad {
         self
     }
 }
+
+// Shrinks the given source by half a texel on every side, so sampling at its
+// edges never reads texels outside of it
+pub(super) fn inset_source(
+    source: Rectangle<f32>,
+    x_unit: f32,
+    y_unit: f32,
+) -> Rectangle<f32> {
+    let inset_x = (x_unit / 2.0).min(source.width.abs() / 2.0);
+    let inset_y = (y_unit / 2.0).min(source.height.abs() / 2.0);
+
+    Rectangle {
+        x: source.x + inset_x * source.width.signum(),
+        y: source.y + inset_y * source.height.signum(),
+        width: source.width - inset_x * 2.0 * source.width.signum(),
+        height: source.height - inset_y * 2.0 * source.height.signum(),
+    }
+}
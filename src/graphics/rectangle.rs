@@ -38,4 +38,128 @@ impl Rectangle<f32> {
             self.y + self.height / 2.0,
         )
     }
+    /// Returns true if the given [`Rectangle`] overlaps this one.
+    ///
+    /// Rectangles that only share an edge do not overlap.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn intersects(&self, other: &Rectangle<f32>) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+
+    /// Returns the area shared by this [`Rectangle`] and the given one, if
+    /// they overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::graphics::Rectangle;
+    ///
+    /// let a = Rectangle { x: 0.0, y: 0.0, width: 10.0, height: 10.0 };
+    /// let b = Rectangle { x: 5.0, y: 5.0, width: 10.0, height: 10.0 };
+    ///
+    /// assert_eq!(
+    ///     a.intersection(&b),
+    ///     Some(Rectangle { x: 5.0, y: 5.0, width: 5.0, height: 5.0 })
+    /// );
+    /// ```
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn intersection(
+        &self,
+        other: &Rectangle<f32>,
+    ) -> Option<Rectangle<f32>> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+
+        Some(Rectangle {
+            x,
+            y,
+            width: (self.x + self.width).min(other.x + other.width) - x,
+            height: (self.y + self.height).min(other.y + other.height) - y,
+        })
+    }
+
+    /// Returns the smallest [`Rectangle`] containing both this [`Rectangle`]
+    /// and the given one.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn union(&self, other: &Rectangle<f32>) -> Rectangle<f32> {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+
+        Rectangle {
+            x,
+            y,
+            width: (self.x + self.width).max(other.x + other.width) - x,
+            height: (self.y + self.height).max(other.y + other.height) - y,
+        }
+    }
+
+    /// Grows the [`Rectangle`] by the given amount on every side, keeping its
+    /// center.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn expand(&self, amount: f32) -> Rectangle<f32> {
+        Rectangle {
+            x: self.x - amount,
+            y: self.y - amount,
+            width: self.width + amount * 2.0,
+            height: self.height + amount * 2.0,
+        }
+    }
+
+    /// Shrinks the [`Rectangle`] by the given amount on every side, keeping
+    /// its center.
+    ///
+    /// The size never becomes negative.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn shrink(&self, amount: f32) -> Rectangle<f32> {
+        let amount_x = amount.min(self.width / 2.0);
+        let amount_y = amount.min(self.height / 2.0);
+
+        Rectangle {
+            x: self.x + amount_x,
+            y: self.y + amount_y,
+            width: self.width - amount_x * 2.0,
+            height: self.height - amount_y * 2.0,
+        }
+    }
+
+    /// Rounds the [`Rectangle`] to whole pixels.
+    ///
+    /// Coordinates outside of the range of `u16` are clamped.
+    ///
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn round(&self) -> Rectangle<u16> {
+        let round = |value: f32| {
+            value.round().max(0.0).min(f32::from(u16::max_value())) as u16
+        };
+
+        Rectangle {
+            x: round(self.x),
+            y: round(self.y),
+            width: round(self.width),
+            height: round(self.height),
+        }
+    }
+}
+
+impl From<Rectangle<u16>> for Rectangle<f32> {
+    fn from(rectangle: Rectangle<u16>) -> Rectangle<f32> {
+        Rectangle {
+            x: f32::from(rectangle.x),
+            y: f32::from(rectangle.y),
+            width: f32::from(rectangle.width),
+            height: f32::from(rectangle.height),
+        }
+    }
 }
@@ -0,0 +1,21 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+static NEXT: AtomicU64 = AtomicU64::new(0);
+
+/// A unique identifier of a resource stored in the GPU.
+///
+/// Cheap clones of a resource, like the ones of an [`Image`], share the same
+/// [`ResourceId`]. Identifiers are never reused while the game is running.
+///
+/// It can be used to group draw calls by texture, or as the key of a map.
+///
+/// [`Image`]: struct.Image.html
+/// [`ResourceId`]: struct.ResourceId.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ResourceId(u64);
+
+impl ResourceId {
+    pub(crate) fn new() -> ResourceId {
+        ResourceId(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
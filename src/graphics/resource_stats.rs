@@ -0,0 +1,102 @@
+use std::cell::Cell;
+
+thread_local! {
+    static LIVE: Cell<Usage> = Cell::new(Usage::default());
+    static FREED: Cell<Usage> = Cell::new(Usage::default());
+}
+
+/// Statistics about the GPU resources of a game.
+///
+/// You can obtain them using [`Gpu::collect`].
+///
+/// [`Gpu::collect`]: struct.Gpu.html#method.collect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ResourceStats {
+    /// The amount of textures alive, including the ones used by a
+    /// [`Canvas`] or a [`TextureArray`].
+    ///
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub textures: usize,
+
+    /// The approximate amount of memory used by the textures alive, in
+    /// bytes.
+    pub texture_bytes: u64,
+
+    /// The amount of textures freed since the last collection.
+    pub freed_textures: usize,
+
+    /// The approximate amount of memory freed since the last collection, in
+    /// bytes.
+    pub freed_bytes: u64,
+}
+
+impl ResourceStats {
+    /// Returns the current statistics and resets the freed counters.
+    pub(crate) fn take() -> ResourceStats {
+        let live = LIVE.with(Cell::get);
+        let freed = FREED.with(|freed| freed.replace(Usage::default()));
+
+        ResourceStats {
+            textures: live.textures,
+            texture_bytes: live.bytes,
+            freed_textures: freed.textures,
+            freed_bytes: freed.bytes,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Usage {
+    textures: usize,
+    bytes: u64,
+}
+
+/// The memory of a texture, accounted for until it is dropped.
+#[derive(Debug)]
+pub(crate) struct Allocation {
+    bytes: u64,
+}
+
+impl Allocation {
+    pub fn texture(width: u16, height: u16, layers: u16) -> Allocation {
+        let bytes =
+            u64::from(width) * u64::from(height) * u64::from(layers.max(1)) * 4;
+
+        LIVE.with(|live| {
+            let usage = live.get();
+
+            live.set(Usage {
+                textures: usage.textures + 1,
+                bytes: usage.bytes + bytes,
+            })
+        });
+
+        Allocation { bytes }
+    }
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        let bytes = self.bytes;
+
+        // Textures may outlive the counters when the thread is exiting
+        let _ = LIVE.try_with(|live| {
+            let usage = live.get();
+
+            live.set(Usage {
+                textures: usage.textures.saturating_sub(1),
+                bytes: usage.bytes.saturating_sub(bytes),
+            })
+        });
+
+        let _ = FREED.try_with(|freed| {
+            let usage = freed.get();
+
+            freed.set(Usage {
+                textures: usage.textures + 1,
+                bytes: usage.bytes + bytes,
+            })
+        });
+    }
+}
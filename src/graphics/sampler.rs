@@ -0,0 +1,74 @@
+/// The sampling configuration of a texture.
+///
+/// It controls how the pixels of an [`Image`], a [`TextureArray`], or a
+/// [`Canvas`] are read when drawn with a different size or outside of their
+/// bounds.
+///
+/// The default [`Sampler`] uses [`FilterMode::Nearest`] and
+/// [`WrapMode::Clamp`], which keeps pixel art crisp.
+///
+/// [`Image`]: struct.Image.html
+/// [`TextureArray`]: texture_array/struct.TextureArray.html
+/// [`Canvas`]: struct.Canvas.html
+/// [`Sampler`]: struct.Sampler.html
+/// [`FilterMode::Nearest`]: enum.FilterMode.html#variant.Nearest
+/// [`WrapMode::Clamp`]: enum.WrapMode.html#variant.Clamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sampler {
+    /// The filter used when the texture is magnified or minified.
+    pub filter: FilterMode,
+
+    /// The behavior when sampling outside of the texture.
+    pub wrap: WrapMode,
+
+    /// The maximum anisotropy level.
+    ///
+    /// Values greater than `1` enable anisotropic filtering, which implies
+    /// linear filtering.
+    ///
+    /// Anisotropic filtering is only supported by the OpenGL backend. The
+    /// `wgpu` backends ignore this setting.
+    pub anisotropy: u8,
+
+    /// The filter used between the mipmap levels of the texture, if any.
+    ///
+    /// When `None`, only the full-size level is sampled. Mipmap levels are
+    /// only available in a [`TextureArray`] built with mipmaps.
+    ///
+    /// [`TextureArray`]: texture_array/struct.TextureArray.html
+    pub mipmap_filter: Option<FilterMode>,
+}
+
+impl Default for Sampler {
+    fn default() -> Sampler {
+        Sampler {
+            filter: FilterMode::Nearest,
+            wrap: WrapMode::Clamp,
+            anisotropy: 0,
+            mipmap_filter: None,
+        }
+    }
+}
+
+/// The filter used to sample a texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMode {
+    /// Use the nearest pixel. Ideal for pixel art.
+    Nearest,
+
+    /// Interpolate linearly between the nearest pixels.
+    Linear,
+}
+
+/// The behavior when sampling a texture outside of its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WrapMode {
+    /// Use the color of the closest edge.
+    Clamp,
+
+    /// Repeat the texture.
+    Repeat,
+
+    /// Repeat the texture, mirroring it every time.
+    MirrorRepeat,
+}
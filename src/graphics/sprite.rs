@@ -1,3 +1,4 @@
+use crate::graphics::quad::inset_source;
 use crate::graphics::{IntoQuad, Point, Quad, Rectangle};
 
 /// A quad describing the portion of a resource in absolute coordinates.
@@ -17,7 +18,27 @@ pub struct Sprite {
     pub position: Point,
 
     /// The scale to apply to the sprite.
+    ///
+    /// A negative scale mirrors the sprite along its axis, like `flip_x` and
+    /// `flip_y` do.
     pub scale: (f32, f32),
+
+    /// Whether the sprite should be mirrored horizontally.
+    ///
+    /// The sprite keeps its position, only its contents are flipped.
+    pub flip_x: bool,
+
+    /// Whether the sprite should be mirrored vertically.
+    ///
+    /// The sprite keeps its position, only its contents are flipped.
+    pub flip_y: bool,
+
+    /// Whether the sprite should only sample texels inside its source.
+    ///
+    /// Enable it to avoid bleeding from adjacent cells of an atlas when the
+    /// sprite is scaled or drawn at subpixel positions. The outer half of the
+    /// border texels is not drawn.
+    pub clamp_source: bool,
 }
 
 impl Default for Sprite {
@@ -32,24 +53,45 @@ impl Default for Sprite {
             },
             position: Point::new(0.0, 0.0),
             scale: (1.0, 1.0),
+            flip_x: false,
+            flip_y: false,
+            clamp_source: false,
         }
     }
 }
 
 impl IntoQuad for Sprite {
     fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        let mut source = Rectangle {
+            x: self.source.x as f32 * x_unit,
+            y: self.source.y as f32 * y_unit,
+            width: self.source.width as f32 * x_unit,
+            height: self.source.height as f32 * y_unit,
+        };
+
+        if self.clamp_source {
+            source = inset_source(source, x_unit, y_unit);
+        }
+
+        // Flipping is achieved by swapping the texture coordinates
+        if self.flip_x != (self.scale.0 < 0.0) {
+            source.x += source.width;
+            source.width = -source.width;
+        }
+
+        if self.flip_y != (self.scale.1 < 0.0) {
+            source.y += source.height;
+            source.height = -source.height;
+        }
+
         Quad {
-            source: Rectangle {
-                x: self.source.x as f32 * x_unit,
-                y: self.source.y as f32 * y_unit,
-                width: self.source.width as f32 * x_unit,
-                height: self.source.height as f32 * y_unit,
-            },
+            source,
             position: self.position,
             size: (
-                self.source.width as f32 * self.scale.0,
-                self.source.height as f32 * self.scale.1,
+                self.source.width as f32 * self.scale.0.abs(),
+                self.source.height as f32 * self.scale.1.abs(),
             ),
+            ..Quad::default()
         }
     }
 }
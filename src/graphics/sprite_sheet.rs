@@ -0,0 +1,375 @@
+//! Load sprite sheets exported by [Aseprite] and [TexturePacker].
+//!
+//! This module is only available when the `sprite-sheet` feature is enabled.
+//!
+//! [Aseprite]: https://www.aseprite.org
+//! [TexturePacker]: https://www.codeandweb.com/texturepacker
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::graphics::{Gpu, Image, Rectangle, SubImage};
+use crate::load::Task;
+use crate::{Error, Result};
+
+/// The duration of the frames of a sprite sheet that does not specify one.
+pub const DEFAULT_FRAME_DURATION: Duration = Duration::from_millis(100);
+
+/// An [`Image`] with named frames and animations.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::sprite_sheet::SpriteSheet;
+/// use coffee::graphics::{Point, Quad, Target};
+/// use coffee::load::Task;
+/// use std::time::Duration;
+///
+/// fn load_player() -> Task<SpriteSheet> {
+///     SpriteSheet::from_aseprite("resources/player.json")
+/// }
+///
+/// fn draw_player(
+///     sheet: &SpriteSheet,
+///     elapsed: Duration,
+///     position: Point,
+///     target: &mut Target<'_>,
+/// ) {
+///     if let Some(walk) = sheet.animation("walk") {
+///         let frame = walk.frame_at(elapsed);
+///
+///         frame.image.draw(
+///             Quad {
+///                 position,
+///                 size: (
+///                     f32::from(frame.image.width()),
+///                     f32::from(frame.image.height()),
+///                 ),
+///                 ..Quad::default()
+///             },
+///             target,
+///         );
+///     }
+/// }
+/// ```
+///
+/// [`Image`]: ../struct.Image.html
+#[derive(Debug, Clone)]
+pub struct SpriteSheet {
+    image: Image,
+    frames: Vec<Frame>,
+    animations: HashMap<String, Animation>,
+}
+
+/// A frame of a [`SpriteSheet`].
+///
+/// [`SpriteSheet`]: struct.SpriteSheet.html
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The name of the frame.
+    pub name: String,
+
+    /// The region of the [`SpriteSheet`] image covered by the frame.
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub image: SubImage,
+
+    /// The amount of time the frame is shown when animated.
+    pub duration: Duration,
+}
+
+/// A sequence of frames of a [`SpriteSheet`].
+///
+/// [`SpriteSheet`]: struct.SpriteSheet.html
+#[derive(Debug, Clone)]
+pub struct Animation {
+    frames: Vec<Frame>,
+    duration: Duration,
+}
+
+impl SpriteSheet {
+    /// Creates a [`Task`] that loads a [`SpriteSheet`] exported by
+    /// [Aseprite] as JSON.
+    ///
+    /// Both the `Hash` and the `Array` layouts are supported. Every frame
+    /// tag becomes an [`Animation`] with the same name, honoring its
+    /// direction. The image is loaded from the path in the metadata, relative
+    /// to the JSON file.
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [`Animation`]: struct.Animation.html
+    /// [Aseprite]: https://www.aseprite.org
+    pub fn from_aseprite<P: Into<PathBuf>>(path: P) -> Task<SpriteSheet> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| SpriteSheet::new(gpu, &path))
+    }
+
+    /// Creates a [`Task`] that loads a [`SpriteSheet`] exported by
+    /// [TexturePacker] as JSON.
+    ///
+    /// Both the `Hash` and the `Array` layouts are supported. If the file
+    /// contains an `animations` object, like the one generated for PixiJS,
+    /// every entry becomes an [`Animation`]. As TexturePacker does not export
+    /// frame durations, every frame lasts [`DEFAULT_FRAME_DURATION`].
+    ///
+    /// [`Task`]: ../../load/struct.Task.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    /// [`Animation`]: struct.Animation.html
+    /// [`DEFAULT_FRAME_DURATION`]: constant.DEFAULT_FRAME_DURATION.html
+    /// [TexturePacker]: https://www.codeandweb.com/texturepacker
+    pub fn from_texture_packer<P: Into<PathBuf>>(path: P) -> Task<SpriteSheet> {
+        let path = path.into();
+
+        Task::using_gpu(move |gpu| SpriteSheet::new(gpu, &path))
+    }
+
+    /// Returns the [`Image`] of the [`SpriteSheet`].
+    ///
+    /// [`Image`]: ../struct.Image.html
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns all the frames of the [`SpriteSheet`], in the order they
+    /// were exported.
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the frame with the given name, if any.
+    pub fn frame(&self, name: &str) -> Option<&Frame> {
+        self.frames.iter().find(|frame| frame.name == name)
+    }
+
+    /// Returns the [`Animation`] with the given name, if any.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn animation(&self, name: &str) -> Option<&Animation> {
+        self.animations.get(name)
+    }
+
+    /// Returns the names of the animations of the [`SpriteSheet`].
+    ///
+    /// [`SpriteSheet`]: struct.SpriteSheet.html
+    pub fn animation_names(&self) -> impl Iterator<Item = &str> {
+        self.animations.keys().map(String::as_str)
+    }
+
+    // Both formats share the same structure for frames and metadata, so a
+    // single parser handles them
+    fn new(gpu: &mut Gpu, path: &Path) -> Result<SpriteSheet> {
+        let contents = fs::read(path)
+            .map_err(|error| Error::File(PathBuf::from(path), error))?;
+
+        let json: Value = serde_json::from_slice(&contents)
+            .map_err(|error| invalid(path, &error.to_string()))?;
+
+        let image_path = json["meta"]["image"]
+            .as_str()
+            .ok_or_else(|| invalid(path, "Missing image path in metadata"))?;
+
+        let image = Image::new(
+            gpu,
+            path.parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(image_path),
+        )?;
+
+        let frames = match &json["frames"] {
+            Value::Array(frames) => frames
+                .iter()
+                .enumerate()
+                .map(|(i, frame)| {
+                    let name = frame["filename"]
+                        .as_str()
+                        .map(String::from)
+                        .unwrap_or_else(|| i.to_string());
+
+                    parse_frame(path, &image, name, frame)
+                })
+                .collect::<Result<Vec<_>>>()?,
+            Value::Object(frames) => frames
+                .iter()
+                .map(|(name, frame)| {
+                    parse_frame(path, &image, name.clone(), frame)
+                })
+                .collect::<Result<Vec<_>>>()?,
+            _ => return Err(invalid(path, "Missing frames")),
+        };
+
+        let mut animations = HashMap::new();
+
+        // Aseprite frame tags
+        if let Some(tags) = json["meta"]["frameTags"].as_array() {
+            for tag in tags {
+                let name = tag["name"]
+                    .as_str()
+                    .ok_or_else(|| invalid(path, "Missing tag name"))?;
+
+                let from = tag["from"].as_u64().unwrap_or(0) as usize;
+                let to = tag["to"].as_u64().unwrap_or(0) as usize;
+
+                if from > to || to >= frames.len() {
+                    return Err(invalid(path, "Tag out of bounds"));
+                }
+
+                let mut sequence: Vec<Frame> = frames[from..=to].to_vec();
+
+                match tag["direction"].as_str() {
+                    Some("reverse") => sequence.reverse(),
+                    Some("pingpong") => {
+                        let back: Vec<Frame> = sequence
+                            .iter()
+                            .rev()
+                            .skip(1)
+                            .take(sequence.len().saturating_sub(2))
+                            .cloned()
+                            .collect();
+
+                        sequence.extend(back);
+                    }
+                    _ => {}
+                }
+
+                let animation = Animation::new(sequence);
+
+                let _ = animations.insert(String::from(name), animation);
+            }
+        }
+
+        // TexturePacker animations, listed by frame name
+        if let Some(entries) = json["animations"].as_object() {
+            for (name, names) in entries {
+                let sequence = names
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|frame| {
+                        frame
+                            .as_str()
+                            .and_then(|frame| {
+                                frames.iter().find(|f| f.name == frame)
+                            })
+                            .cloned()
+                            .ok_or_else(|| invalid(path, "Unknown frame"))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                if !sequence.is_empty() {
+                    let animation = Animation::new(sequence);
+
+                    let _ = animations.insert(name.clone(), animation);
+                }
+            }
+        }
+
+        Ok(SpriteSheet {
+            image,
+            frames,
+            animations,
+        })
+    }
+}
+
+impl Animation {
+    fn new(frames: Vec<Frame>) -> Animation {
+        let duration = frames.iter().map(|frame| frame.duration).sum();
+
+        Animation { frames, duration }
+    }
+
+    /// Returns the frames of the [`Animation`], in order.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the total duration of the [`Animation`].
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Returns the frame shown after the given amount of time has elapsed
+    /// since the [`Animation`] started.
+    ///
+    /// The [`Animation`] loops forever.
+    ///
+    /// [`Animation`]: struct.Animation.html
+    pub fn frame_at(&self, elapsed: Duration) -> &Frame {
+        let total = self.duration.as_nanos();
+
+        if total == 0 {
+            return &self.frames[0];
+        }
+
+        let mut remaining = elapsed.as_nanos() % total;
+
+        for frame in &self.frames {
+            let duration = frame.duration.as_nanos();
+
+            if remaining < duration {
+                return frame;
+            }
+
+            remaining -= duration;
+        }
+
+        &self.frames[self.frames.len() - 1]
+    }
+}
+
+fn parse_frame(
+    path: &Path,
+    image: &Image,
+    name: String,
+    frame: &Value,
+) -> Result<Frame> {
+    if frame["rotated"].as_bool().unwrap_or(false) {
+        return Err(invalid(path, "Rotated frames are not supported"));
+    }
+
+    let coordinate = |key: &str| {
+        frame["frame"][key]
+            .as_u64()
+            .filter(|value| *value <= u64::from(u16::max_value()))
+            .map(|value| value as u16)
+            .ok_or_else(|| invalid(path, "Invalid frame region"))
+    };
+
+    let region = Rectangle {
+        x: coordinate("x")?,
+        y: coordinate("y")?,
+        width: coordinate("w")?,
+        height: coordinate("h")?,
+    };
+
+    let duration = frame["duration"]
+        .as_u64()
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_FRAME_DURATION);
+
+    Ok(Frame {
+        name,
+        image: image.slice(region),
+        duration,
+    })
+}
+
+fn invalid(path: &Path, message: &str) -> Error {
+    Error::File(
+        PathBuf::from(path),
+        io::Error::new(io::ErrorKind::InvalidData, message),
+    )
+}
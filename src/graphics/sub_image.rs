@@ -0,0 +1,131 @@
+use crate::graphics::{Image, IntoQuad, Quad, Rectangle, Target};
+
+/// A region of an [`Image`].
+///
+/// You can obtain a [`SubImage`] using [`Image::slice`]. It keeps a handle to
+/// the whole [`Image`] and the region it covers, so you can draw the sprites of
+/// an atlas without carrying their source rectangles around.
+///
+/// Cloning a [`SubImage`] is cheap, like cloning an [`Image`].
+///
+/// [`Image`]: struct.Image.html
+/// [`SubImage`]: struct.SubImage.html
+/// [`Image::slice`]: struct.Image.html#method.slice
+#[derive(Debug, Clone)]
+pub struct SubImage {
+    image: Image,
+    region: Rectangle<u16>,
+}
+
+impl SubImage {
+    pub(super) fn new(image: Image, region: Rectangle<u16>) -> SubImage {
+        let x = region.x.min(image.width());
+        let y = region.y.min(image.height());
+
+        SubImage {
+            region: Rectangle {
+                x,
+                y,
+                width: region.width.min(image.width() - x),
+                height: region.height.min(image.height() - y),
+            },
+            image,
+        }
+    }
+
+    /// Returns the [`Image`] containing the [`SubImage`].
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`SubImage`]: struct.SubImage.html
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    /// Returns the region of the [`Image`] covered by the [`SubImage`], in
+    /// absolute coordinates.
+    ///
+    /// [`Image`]: struct.Image.html
+    /// [`SubImage`]: struct.SubImage.html
+    pub fn region(&self) -> Rectangle<u16> {
+        self.region
+    }
+
+    /// Returns the width of the [`SubImage`].
+    ///
+    /// [`SubImage`]: struct.SubImage.html
+    pub fn width(&self) -> u16 {
+        self.region.width
+    }
+
+    /// Returns the height of the [`SubImage`].
+    ///
+    /// [`SubImage`]: struct.SubImage.html
+    pub fn height(&self) -> u16 {
+        self.region.height
+    }
+
+    /// Creates a new [`SubImage`] covering a region of this one.
+    ///
+    /// The given region is relative to the top-left corner of the
+    /// [`SubImage`].
+    ///
+    /// [`SubImage`]: struct.SubImage.html
+    pub fn slice(&self, region: Rectangle<u16>) -> SubImage {
+        let x = region.x.min(self.region.width);
+        let y = region.y.min(self.region.height);
+
+        SubImage {
+            image: self.image.clone(),
+            region: Rectangle {
+                x: self.region.x + x,
+                y: self.region.y + y,
+                width: region.width.min(self.region.width - x),
+                height: region.height.min(self.region.height - y),
+            },
+        }
+    }
+
+    /// Draws the [`SubImage`] on the given [`Target`].
+    ///
+    /// The `source` of the given quad is relative to the [`SubImage`]. For
+    /// instance, the default `source` of a [`Quad`] covers the whole
+    /// [`SubImage`], and the `source` of a [`Sprite`] is in absolute
+    /// coordinates starting at its top-left corner.
+    ///
+    /// [`SubImage`]: struct.SubImage.html
+    /// [`Target`]: struct.Target.html
+    /// [`Quad`]: struct.Quad.html
+    /// [`Sprite`]: struct.Sprite.html
+    #[inline]
+    pub fn draw<Q: IntoQuad>(&self, quad: Q, target: &mut Target<'_>) {
+        let mut instances = Vec::with_capacity(1);
+        let _ = self
+            .image
+            .push_instances(self.to_image_quad(quad), &mut instances);
+
+        target.draw_texture_quads(&self.image.texture, &instances[..]);
+    }
+
+    fn to_image_quad<Q: IntoQuad>(&self, quad: Q) -> Quad {
+        let width = self.image.width() as f32;
+        let height = self.image.height() as f32;
+
+        let quad = quad.into_quad(
+            1.0 / self.region.width.max(1) as f32,
+            1.0 / self.region.height.max(1) as f32,
+        );
+
+        let x_scale = self.region.width as f32 / width;
+        let y_scale = self.region.height as f32 / height;
+
+        Quad {
+            source: Rectangle {
+                x: self.region.x as f32 / width + quad.source.x * x_scale,
+                y: self.region.y as f32 / height + quad.source.y * y_scale,
+                width: quad.source.width * x_scale,
+                height: quad.source.height * y_scale,
+            },
+            ..quad
+        }
+    }
+}
@@ -1,5 +1,10 @@
-use crate::graphics::gpu::{self, Font, Gpu, TargetView, Texture, Vertex};
-use crate::graphics::{Color, Transformation};
+use crate::graphics::gpu::{
+    self, DepthView, Font, Gpu, TargetView, Texture, Vertex,
+};
+use crate::graphics::{
+    BlendMode, Color, Mesh, Origin, Point, Rectangle, Shape,
+    Transformation, Vector,
+};
 
 /// A rendering target.
 ///
@@ -8,43 +13,111 @@ use crate::graphics::{Color, Transformation};
 ///
 /// The default coordinate system of a [`Target`] has the origin `(0, 0)` at
 /// its top-left corner and `(Target::width, Target::height)` at its bottom-right
-/// corner.
+/// corner. Use [`Frame::as_target_with_origin`] to make the y axis point up.
 ///
 /// [`Target`]: struct.Target.html
 /// [`Frame`]: struct.Frame.html
 /// [`Canvas`]: struct.Canvas.html
+/// [`Frame::as_target_with_origin`]: struct.Frame.html#method.as_target_with_origin
 pub struct Target<'a> {
     gpu: &'a mut Gpu,
-    view: &'a TargetView,
+    view: TargetView<'a>,
+    depth: Option<DepthView<'a>>,
+    base: Transformation,
     transformation: Transformation,
+    local: Transformation,
+    flip: Option<Transformation>,
+    pixel_snap: bool,
+    blend_mode: BlendMode,
+    size: (f32, f32),
+    clip: Option<Rectangle<f32>>,
+    deferred: Option<&'a mut Deferred>,
 }
 
 impl<'a> Target<'a> {
     pub(super) fn new(
         gpu: &'a mut Gpu,
-        view: &'a TargetView,
+        view: TargetView<'a>,
         width: f32,
         height: f32,
     ) -> Self {
         Target {
             gpu,
             view,
+            depth: None,
+            base: Transformation::identity(),
             transformation: Transformation::orthographic(width, height),
+            local: Transformation::identity(),
+            flip: None,
+            pixel_snap: false,
+            blend_mode: BlendMode::Alpha,
+            size: (width, height),
+            clip: None,
+            deferred: None,
         }
     }
 
     pub(super) fn with_transformation(
         gpu: &'a mut Gpu,
-        view: &'a TargetView,
+        view: TargetView<'a>,
         width: f32,
         height: f32,
         transformation: Transformation,
     ) -> Self {
         let mut target = Self::new(gpu, view, width, height);
+        target.base = transformation;
         target.transformation = transformation * target.transformation;
         target
     }
 
+    pub(super) fn with_depth(mut self, depth: Option<DepthView<'a>>) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    pub(super) fn with_origin(mut self, origin: Origin, height: f32) -> Self {
+        match origin {
+            Origin::TopLeft => {}
+            Origin::BottomLeft => {
+                let flip = Transformation::translate(Vector::new(0.0, height))
+                    * Transformation::nonuniform_scale(Vector::new(1.0, -1.0));
+
+                self.transformation = self.transformation * flip;
+                self.local = self.local * flip;
+                self.flip = Some(flip);
+            }
+        }
+
+        self
+    }
+
+    pub(super) fn with_viewport(mut self, region: Rectangle<f32>) -> Self {
+        let translation =
+            Transformation::translate(Vector::new(region.x, region.y));
+
+        // The region is clipped in normalized device coordinates, so it does
+        // not depend on how the backend lays out the pixels of the view
+        let a = self
+            .transformation
+            .transform_point(Point::new(region.x, region.y));
+
+        let b = self.transformation.transform_point(Point::new(
+            region.x + region.width,
+            region.y + region.height,
+        ));
+
+        self.clip = Some(Rectangle {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+            width: (a.x - b.x).abs(),
+            height: (a.y - b.y).abs(),
+        });
+
+        self.transformation = self.transformation * translation;
+        self.local = self.local * translation;
+        self
+    }
+
     /// Creates a new [`Target`] applying the given transformation.
     ///
     /// This is equivalent to multiplying the current [`Target`] transform by
@@ -83,16 +156,315 @@ impl<'a> Target<'a> {
         Target {
             gpu: self.gpu,
             view: self.view,
+            depth: self.depth,
+            base: self.base,
             transformation: self.transformation * transformation,
+            local: self.local * transformation,
+            flip: self.flip,
+            pixel_snap: self.pixel_snap,
+            blend_mode: self.blend_mode,
+            size: self.size,
+            clip: self.clip,
+            deferred: self.deferred.as_mut().map(|deferred| &mut **deferred),
+        }
+    }
+
+    /// Creates a new [`Target`] replacing its orthographic projection with
+    /// the given one.
+    ///
+    /// Use it with [`Transformation::perspective`] to draw quads in 3D. The y
+    /// axis points up and images are not drawn upside down. Quads are tested
+    /// against the depth buffer of a [`Canvas`] created with
+    /// [`Canvas::with_depth`], so the ones behind others are hidden
+    /// regardless of the drawing order. Meshes and text are drawn without
+    /// depth testing.
+    ///
+    /// The transformations of the current [`Target`] are not kept. If it is a
+    /// viewport, drawing is still clipped to its region, but the projection
+    /// covers the whole [`Target`].
+    ///
+    /// ```
+    /// use coffee::graphics::{Canvas, Color, Gpu, Transformation};
+    ///
+    /// fn draw_floor(canvas: &mut Canvas, gpu: &mut Gpu) {
+    ///     let aspect = f32::from(canvas.width()) / f32::from(canvas.height());
+    ///
+    ///     let mut target = canvas.as_target(gpu);
+    ///     target.clear(Color::BLACK);
+    ///
+    ///     let mut floor = target.with_projection(
+    ///         Transformation::perspective(1.0, aspect, 0.1, 100.0)
+    ///             * Transformation::translate_z(-10.0)
+    ///             * Transformation::rotate_x(-1.2),
+    ///     );
+    ///
+    ///     // Draw the tiles of the floor on `floor` here
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Transformation::perspective`]: struct.Transformation.html#method.perspective
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Canvas::with_depth`]: struct.Canvas.html#method.with_depth
+    pub fn with_projection(
+        &mut self,
+        projection: Transformation,
+    ) -> Target<'_> {
+        let (width, height) = self.size;
+
+        // Maps normalized device coordinates back to the pixels of the target
+        let to_pixels =
+            Transformation::translate(Vector::new(width / 2.0, height / 2.0))
+                * Transformation::nonuniform_scale(Vector::new(
+                    width / 2.0,
+                    -height / 2.0,
+                ));
+
+        Target {
+            gpu: self.gpu,
+            view: self.view,
+            depth: self.depth,
+            base: self.base,
+            transformation: self.base * projection,
+            local: to_pixels * projection,
+            flip: Some(Transformation::nonuniform_scale(Vector::new(
+                1.0, -1.0,
+            ))),
+            pixel_snap: self.pixel_snap,
+            blend_mode: self.blend_mode,
+            size: self.size,
+            clip: self.clip,
+            deferred: self.deferred.as_mut().map(|deferred| &mut **deferred),
+        }
+    }
+
+    /// Enables or disables pixel snapping for the [`Target`].
+    ///
+    /// When enabled, the position of every textured quad is rounded to whole
+    /// pixels of the [`Target`], after applying its transformation. This
+    /// removes the shimmering of pixel art when a camera moves at subpixel
+    /// speeds.
+    ///
+    /// It is disabled by default and inherited by transformed targets.
+    ///
+    /// [`Target`]: struct.Target.html
+    pub fn pixel_snap(&mut self, is_enabled: bool) {
+        self.pixel_snap = is_enabled;
+    }
+
+    /// Sets the [`BlendMode`] used to draw on the [`Target`].
+    ///
+    /// It is [`BlendMode::Alpha`] by default and inherited by transformed
+    /// targets. Clearing a [`Target`] is not affected by it.
+    ///
+    /// [`BlendMode`]: enum.BlendMode.html
+    /// [`BlendMode::Alpha`]: enum.BlendMode.html#variant.Alpha
+    /// [`Target`]: struct.Target.html
+    pub fn blend_mode(&mut self, blend_mode: BlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Draws with the given function, batching consecutive quads that share
+    /// the same texture.
+    ///
+    /// The quads drawn inside the function are recorded instead of being
+    /// drawn right away. Consecutive quads using the same texture, [`Sampler`],
+    /// transformation and blend mode are merged into a single draw call. This
+    /// way, you can draw sprites one by one without managing a [`Batch`]
+    /// yourself.
+    ///
+    /// Drawing order is preserved: meshes, text and clears submit the recorded
+    /// quads before being drawn. Any remaining quads are submitted when the
+    /// function returns.
+    ///
+    /// ```
+    /// use coffee::graphics::{Frame, Image, Point, Quad};
+    ///
+    /// fn draw_trees(trees: &[Point], image: &Image, frame: &mut Frame<'_>) {
+    ///     let mut target = frame.as_target();
+    ///
+    ///     // Every tree is drawn with a single draw call
+    ///     target.batched(|target| {
+    ///         for tree in trees {
+    ///             image.draw(
+    ///                 Quad {
+    ///                     position: *tree,
+    ///                     size: (32.0, 32.0),
+    ///                     ..Quad::default()
+    ///                 },
+    ///                 target,
+    ///             );
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    ///
+    /// [`Sampler`]: struct.Sampler.html
+    /// [`Batch`]: struct.Batch.html
+    pub fn batched<F>(&mut self, draw: F)
+    where
+        F: FnOnce(&mut Target<'_>),
+    {
+        if self.deferred.is_some() {
+            draw(self);
+            return;
+        }
+
+        let mut deferred = Deferred::new();
+
+        let mut target = Target {
+            gpu: self.gpu,
+            view: self.view,
+            depth: self.depth,
+            base: self.base,
+            transformation: self.transformation,
+            local: self.local,
+            flip: self.flip,
+            pixel_snap: self.pixel_snap,
+            blend_mode: self.blend_mode,
+            size: self.size,
+            clip: self.clip,
+            deferred: Some(&mut deferred),
+        };
+
+        draw(&mut target);
+        target.flush();
+    }
+
+    /// Converts a [`Point`] in the coordinate system of the [`Target`] to
+    /// pixel coordinates.
+    ///
+    /// This applies all the transformations of the [`Target`].
+    ///
+    /// [`Point`]: type.Point.html
+    /// [`Target`]: struct.Target.html
+    pub fn project(&self, point: Point) -> Point {
+        self.local.transform_point(point)
+    }
+
+    /// Converts a [`Point`] in pixel coordinates to the coordinate system of
+    /// the [`Target`].
+    ///
+    /// You can use this to find out where the mouse cursor is in your world
+    /// when drawing with a camera transformation. It returns `None` if the
+    /// transformations of the [`Target`] cannot be inverted, like when
+    /// scaling by zero.
+    ///
+    /// [`Point`]: type.Point.html
+    /// [`Target`]: struct.Target.html
+    pub fn unproject(&self, point: Point) -> Option<Point> {
+        self.local
+            .inverse()
+            .map(|inverse| inverse.transform_point(point))
+    }
+
+    /// Returns the region of the coordinate system of the [`Target`] that is
+    /// visible.
+    ///
+    /// Anything drawn outside of it does not show up, so you can use it to
+    /// skip off-screen objects when drawing a large world with a camera
+    /// transformation. If the [`Target`] is rotated, the region is the
+    /// smallest [`Rectangle`] containing the visible area. It returns `None`
+    /// if the transformations of the [`Target`] cannot be inverted.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Rectangle`]: struct.Rectangle.html
+    pub fn visible_bounds(&self) -> Option<Rectangle<f32>> {
+        let inverse = self.transformation.inverse()?;
+
+        let visible = self.clip.unwrap_or(Rectangle {
+            x: -1.0,
+            y: -1.0,
+            width: 2.0,
+            height: 2.0,
+        });
+
+        let corners = [
+            Point::new(visible.x, visible.y),
+            Point::new(visible.x + visible.width, visible.y),
+            Point::new(visible.x, visible.y + visible.height),
+            Point::new(visible.x + visible.width, visible.y + visible.height),
+        ];
+
+        let mut min = Point::new(std::f32::INFINITY, std::f32::INFINITY);
+        let mut max = Point::new(-std::f32::INFINITY, -std::f32::INFINITY);
+
+        for corner in corners.iter() {
+            let point = inverse.transform_point(*corner);
+
+            min = Point::new(min.x.min(point.x), min.y.min(point.y));
+            max = Point::new(max.x.max(point.x), max.y.max(point.y));
         }
+
+        Some(Rectangle {
+            x: min.x,
+            y: min.y,
+            width: max.x - min.x,
+            height: max.y - min.y,
+        })
     }
 
     /// Clears the [`Target`] with the given [`Color`].
     ///
+    /// If the [`Target`] is a viewport, only its region is cleared. In that
+    /// case, translucent colors are blended with the current contents.
+    ///
+    /// The depth buffer of a [`Canvas`] created with [`Canvas::with_depth`]
+    /// is cleared too, unless the [`Target`] is a viewport.
+    ///
     /// [`Target`]: struct.Target.html
     /// [`Color`]: struct.Color.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Canvas::with_depth`]: struct.Canvas.html#method.with_depth
     pub fn clear(&mut self, color: Color) {
-        self.gpu.clear(&self.view, color);
+        self.flush();
+
+        // Viewports are filled instead, as clears ignore the scissor
+        // rectangle
+        if self.clip.is_some() {
+            let color = color.into_linear();
+
+            let vertices = [
+                Vertex::new([-1.0, -1.0], color),
+                Vertex::new([1.0, -1.0], color),
+                Vertex::new([1.0, 1.0], color),
+                Vertex::new([-1.0, 1.0], color),
+            ];
+
+            let scissor = self.scissor();
+
+            self.gpu.draw_triangles(
+                &vertices,
+                &[0, 1, 2, 0, 2, 3],
+                self.view,
+                &Transformation::identity(),
+                scissor,
+                BlendMode::Alpha,
+            );
+        } else {
+            self.gpu.clear(self.view, color);
+
+            if let Some(depth) = self.depth {
+                self.gpu.clear_depth(depth);
+            }
+        }
+    }
+
+    /// Fills the given region of the [`Target`] with the given [`Color`].
+    ///
+    /// The region is affected by the transformations of the [`Target`].
+    /// Unlike [`clear`], the region is drawn on top of the current contents,
+    /// so translucent colors are blended with them.
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Color`]: struct.Color.html
+    /// [`clear`]: #method.clear
+    pub fn clear_region(&mut self, region: Rectangle<f32>, color: Color) {
+        let mut mesh = Mesh::new();
+
+        mesh.fill(Shape::Rectangle(region), color);
+        mesh.draw(self);
     }
 
     pub(super) fn draw_triangles(
@@ -100,11 +472,17 @@ impl<'a> Target<'a> {
         vertices: &[Vertex],
         indices: &[u32],
     ) {
+        self.flush();
+
+        let scissor = self.scissor();
+
         self.gpu.draw_triangles(
             vertices,
             indices,
-            &self.view,
+            self.view,
             &self.transformation,
+            scissor,
+            self.blend_mode,
         );
     }
 
@@ -113,16 +491,268 @@ impl<'a> Target<'a> {
         texture: &Texture,
         instances: &[gpu::Quad],
     ) {
-        self.gpu.draw_texture_quads(
+        let snap = if self.pixel_snap {
+            self.local.inverse().map(|inverse| (self.local, inverse))
+        } else {
+            None
+        };
+
+        let blend_mode = self.texture_blend_mode(texture);
+        let premultiply = blend_mode == BlendMode::Premultiplied;
+
+        let adjusted: Vec<gpu::Quad>;
+
+        let instances = if self.flip.is_some() || snap.is_some() || premultiply
+        {
+            adjusted = instances
+                .iter()
+                .map(|instance| {
+                    let mut instance = *instance;
+
+                    // Textures are flipped back when the y axis points up, so
+                    // images are not drawn upside down
+                    if self.flip.is_some() {
+                        instance.flip_vertically();
+                    }
+
+                    if let Some((to_pixels, from_pixels)) = &snap {
+                        instance.snap(to_pixels, from_pixels);
+                    }
+
+                    if premultiply {
+                        instance.premultiply();
+                    }
+
+                    instance
+                })
+                .collect();
+
+            &adjusted[..]
+        } else {
+            instances
+        };
+
+        let transformation = self.transformation;
+        let accepts = self.deferred.as_ref().map(|deferred| {
+            deferred.accepts(texture, &transformation, blend_mode)
+        });
+
+        match accepts {
+            Some(true) => {
+                if let Some(deferred) = &mut self.deferred {
+                    deferred.instances.extend_from_slice(instances);
+                }
+            }
+            Some(false) => {
+                self.flush();
+
+                if let Some(deferred) = &mut self.deferred {
+                    deferred.texture = Some(texture.clone());
+                    deferred.transformation = transformation;
+                    deferred.blend_mode = blend_mode;
+                    deferred.instances.extend_from_slice(instances);
+                }
+            }
+            None => {
+                let scissor = self.scissor();
+
+                self.gpu.draw_texture_quads(
+                    texture,
+                    instances,
+                    self.view,
+                    self.depth,
+                    &self.transformation,
+                    scissor,
+                    blend_mode,
+                );
+            }
+        }
+    }
+
+    pub(super) fn upload_instances(
+        &mut self,
+        instances: &[gpu::Quad],
+    ) -> gpu::InstanceBuffer {
+        self.gpu.upload_instances(instances)
+    }
+
+    pub(super) fn draw_instance_buffer(
+        &mut self,
+        texture: &Texture,
+        buffer: &gpu::InstanceBuffer,
+        instances: &[gpu::Quad],
+    ) {
+        let blend_mode = self.texture_blend_mode(texture);
+
+        // Uploaded instances cannot be flipped nor snapped, so they are drawn
+        // again. The same goes for their colors, which are only premultiplied
+        // for premultiplied textures.
+        if self.flip.is_some()
+            || self.pixel_snap
+            || (blend_mode == BlendMode::Premultiplied)
+                != texture.is_premultiplied()
+        {
+            self.draw_texture_quads(texture, instances);
+            return;
+        }
+
+        self.flush();
+
+        let scissor = self.scissor();
+
+        self.gpu.draw_instance_buffer(
             texture,
-            instances,
-            &self.view,
+            buffer,
+            self.view,
+            self.depth,
             &self.transformation,
+            scissor,
+            blend_mode,
         );
     }
 
+    // Premultiplied textures replace the default blend mode, as alpha
+    // blending them would darken their translucent pixels twice
+    fn texture_blend_mode(&self, texture: &Texture) -> BlendMode {
+        match self.blend_mode {
+            BlendMode::Alpha if texture.is_premultiplied() => {
+                BlendMode::Premultiplied
+            }
+            blend_mode => blend_mode,
+        }
+    }
+
     pub(in crate::graphics) fn draw_font(&mut self, font: &mut Font) {
-        self.gpu.draw_font(font, &self.view, self.transformation);
+        self.flush();
+
+        // Text is always laid out from the top-left corner
+        let transformation = match self.flip {
+            Some(flip) => self.transformation * flip,
+            None => self.transformation,
+        };
+
+        let scissor = self.scissor();
+
+        self.gpu.draw_font(font, self.view, transformation, scissor);
+    }
+
+    pub(in crate::graphics) fn draw_distance_field_quads(
+        &mut self,
+        texture: &Texture,
+        instances: &[gpu::Quad],
+    ) {
+        self.flush();
+
+        // Text is always laid out from the top-left corner
+        let transformation = match self.flip {
+            Some(flip) => self.transformation * flip,
+            None => self.transformation,
+        };
+
+        // The shader scales every channel by the coverage of the glyphs, so
+        // the colors need to be premultiplied when blending them like alpha
+        let blend_mode = self.texture_blend_mode(texture);
+        let premultiplied: Vec<gpu::Quad>;
+
+        let instances = if blend_mode == BlendMode::Premultiplied {
+            premultiplied = instances
+                .iter()
+                .map(|instance| {
+                    let mut instance = *instance;
+                    instance.premultiply();
+                    instance
+                })
+                .collect();
+
+            &premultiplied[..]
+        } else {
+            instances
+        };
+
+        let scissor = self.scissor();
+
+        self.gpu.draw_distance_field_quads(
+            texture,
+            instances,
+            self.view,
+            self.depth,
+            &transformation,
+            scissor,
+            blend_mode,
+        );
+    }
+
+    // Draws a mesh in the coordinate system of text, which is always laid
+    // out from the top-left corner
+    pub(in crate::graphics) fn draw_text_mesh(&mut self, mesh: &Mesh) {
+        match self.flip {
+            Some(flip) => mesh.draw(&mut self.transform(flip)),
+            None => mesh.draw(self),
+        }
+    }
+
+    fn scissor(&self) -> Option<Rectangle<u32>> {
+        let (width, height) = self.size;
+
+        self.clip
+            .as_ref()
+            .map(|clip| self.gpu.scissor(clip, width, height))
+    }
+
+    fn flush(&mut self) {
+        let scissor = self.scissor();
+
+        if let Some(deferred) = &mut self.deferred {
+            if let Some(texture) = deferred.texture.take() {
+                self.gpu.draw_texture_quads(
+                    &texture,
+                    &deferred.instances,
+                    self.view,
+                    self.depth,
+                    &deferred.transformation,
+                    scissor,
+                    deferred.blend_mode,
+                );
+
+                deferred.instances.clear();
+            }
+        }
+    }
+}
+
+// The quads recorded by a batched target, waiting to be drawn
+struct Deferred {
+    texture: Option<Texture>,
+    transformation: Transformation,
+    blend_mode: BlendMode,
+    instances: Vec<gpu::Quad>,
+}
+
+impl Deferred {
+    fn new() -> Deferred {
+        Deferred {
+            texture: None,
+            transformation: Transformation::identity(),
+            blend_mode: BlendMode::Alpha,
+            instances: Vec::new(),
+        }
+    }
+
+    fn accepts(
+        &self,
+        texture: &Texture,
+        transformation: &Transformation,
+        blend_mode: BlendMode,
+    ) -> bool {
+        match &self.texture {
+            Some(current) => {
+                current.id() == texture.id()
+                    && current.sampler() == texture.sampler()
+                    && self.transformation == *transformation
+                    && self.blend_mode == blend_mode
+            }
+            None => false,
+        }
     }
 }
 
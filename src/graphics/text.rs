@@ -1,6 +1,6 @@
 use std::f32;
 
-use crate::graphics::{Color, Point};
+use crate::graphics::{Color, FontFace, Point, Vector};
 
 /// A section of text.
 #[derive(Clone, PartialEq, Debug)]
@@ -25,6 +25,57 @@ pub struct Text<'a> {
 
     /// Text vertical alignment
     pub vertical_alignment: VerticalAlignment,
+
+    /// The face of the [`Font`] used to draw the text
+    ///
+    /// [`Font`]: struct.Font.html
+    pub face: FontFace,
+
+    /// The color and width of an outline drawn around the glyphs, if any
+    pub outline: Option<(Color, f32)>,
+
+    /// The offset and color of a shadow drawn behind the glyphs, if any
+    pub shadow: Option<(Vector, Color)>,
+
+    /// The color and padding of a box drawn behind the text, if any
+    ///
+    /// The boxes of all the text added to a [`Font`] are drawn before any
+    /// text.
+    ///
+    /// [`Font`]: struct.Font.html
+    pub background: Option<(Color, f32)>,
+}
+
+impl<'a> Text<'a> {
+    /// Draws an outline of the given [`Color`] and width around the glyphs
+    /// of the [`Text`].
+    ///
+    /// [`Color`]: struct.Color.html
+    /// [`Text`]: struct.Text.html
+    pub fn outline(mut self, color: Color, width: f32) -> Self {
+        self.outline = Some((color, width));
+        self
+    }
+
+    /// Draws a shadow of the given [`Color`] behind the glyphs of the
+    /// [`Text`], displaced by the given offset.
+    ///
+    /// [`Color`]: struct.Color.html
+    /// [`Text`]: struct.Text.html
+    pub fn shadow(mut self, offset: Vector, color: Color) -> Self {
+        self.shadow = Some((offset, color));
+        self
+    }
+
+    /// Draws a box of the given [`Color`] behind the [`Text`], extending its
+    /// bounds by the given padding.
+    ///
+    /// [`Color`]: struct.Color.html
+    /// [`Text`]: struct.Text.html
+    pub fn background(mut self, color: Color, padding: f32) -> Self {
+        self.background = Some((color, padding));
+        self
+    }
 }
 
 impl Default for Text<'static> {
@@ -38,6 +89,10 @@ impl Default for Text<'static> {
             color: Color::BLACK,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            face: FontFace::default(),
+            outline: None,
+            shadow: None,
+            background: None,
         }
     }
 }
@@ -66,4 +121,7 @@ pub enum VerticalAlignment {
 
     /// Align bottom
     Bottom,
+
+    /// Align the baseline of the first line with the position
+    Baseline,
 }
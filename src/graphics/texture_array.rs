@@ -7,10 +7,13 @@ pub use batch::Batch;
 pub use builder::Builder;
 pub use loader::{Indices, Key, Loader};
 
+use std::collections::HashMap;
 use std::fmt;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::graphics::gpu::Texture;
+use crate::graphics::{FilterMode, Sampler};
 
 /// A collection of different textures with the same size.
 ///
@@ -23,15 +26,130 @@ use crate::graphics::gpu::Texture;
 /// Cloning a [`TextureArray`] is cheap, it only clones a handle. It does not
 /// create new copy of the texture on the GPU.
 ///
+/// The [`Index`] of a texture depends on the order the textures were added.
+/// If you need to reference textures from data that outlives a run, like
+/// level files, add them with a name using [`Builder::add_keyed`] and look
+/// their [`Index`] up with [`get`].
+///
 /// [`TextureArray`]: struct.TextureArray.html
 /// [`Builder`]: struct.Builder.html
 /// [`Loader`]: struct.Loader.html
 /// [`Batch`]: struct.Batch.html
+/// [`Index`]: struct.Index.html
+/// [`Builder::add_keyed`]: struct.Builder.html#method.add_keyed
+/// [`get`]: #method.get
 #[derive(Debug, Clone)]
 pub struct TextureArray {
     texture: Texture,
     x_unit: f32,
     y_unit: f32,
+    keys: Arc<HashMap<String, Index>>,
+}
+
+impl TextureArray {
+    /// Returns the [`Index`] of the texture added with the given name, if
+    /// any.
+    ///
+    /// [`Index`]: struct.Index.html
+    pub fn get(&self, key: &str) -> Option<Index> {
+        self.keys.get(key).cloned()
+    }
+
+    /// Returns the mapping between the names of the textures of the
+    /// [`TextureArray`] and their [`Index`].
+    ///
+    /// When the `serde-serialize` feature is enabled, the mapping can be serialized.
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Index`]: struct.Index.html
+    pub fn keys(&self) -> &HashMap<String, Index> {
+        &self.keys
+    }
+
+    /// Returns the [`Sampler`] used to draw the [`TextureArray`].
+    ///
+    /// [`Sampler`]: ../struct.Sampler.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn sampler(&self) -> Sampler {
+        self.texture.sampler()
+    }
+
+    /// Sets the [`Sampler`] used to draw the [`TextureArray`].
+    ///
+    /// Only this handle is affected. Other clones of the [`TextureArray`]
+    /// keep their own [`Sampler`].
+    ///
+    /// [`Sampler`]: ../struct.Sampler.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    pub fn set_sampler(&mut self, sampler: Sampler) {
+        self.texture.set_sampler(sampler);
+    }
+}
+
+/// The filtering quality of a [`TextureArray`].
+///
+/// Higher qualities generate mipmaps for every layer when the
+/// [`TextureArray`] is built. They take a third more of memory, but keep
+/// zoomed-out tilemaps from shimmering.
+///
+/// [`TextureArray`]: struct.TextureArray.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quality {
+    /// Use the nearest pixel, without mipmaps. Ideal for pixel art.
+    ///
+    /// This is the default.
+    Nearest,
+
+    /// Interpolate linearly between the nearest pixels, without mipmaps.
+    Bilinear,
+
+    /// Interpolate linearly between the nearest pixels and between mipmap
+    /// levels.
+    Trilinear,
+
+    /// Like [`Trilinear`], but sharper on surfaces seen at an angle, up to
+    /// the given anisotropy level.
+    ///
+    /// Anisotropic filtering is only supported by the OpenGL backend. The
+    /// `wgpu` backends fall back to [`Trilinear`].
+    ///
+    /// [`Trilinear`]: #variant.Trilinear
+    Anisotropic(u8),
+}
+
+impl Quality {
+    pub(crate) fn has_mipmaps(self) -> bool {
+        match self {
+            Quality::Nearest | Quality::Bilinear => false,
+            Quality::Trilinear | Quality::Anisotropic(_) => true,
+        }
+    }
+
+    pub(crate) fn sampler(self) -> Sampler {
+        let (filter, mipmap_filter, anisotropy) = match self {
+            Quality::Nearest => (FilterMode::Nearest, None, 0),
+            Quality::Bilinear => (FilterMode::Linear, None, 0),
+            Quality::Trilinear => {
+                (FilterMode::Linear, Some(FilterMode::Linear), 0)
+            }
+            Quality::Anisotropic(level) => {
+                (FilterMode::Linear, Some(FilterMode::Linear), level)
+            }
+        };
+
+        Sampler {
+            filter,
+            mipmap_filter,
+            anisotropy,
+            ..Sampler::default()
+        }
+    }
+}
+
+impl Default for Quality {
+    fn default() -> Quality {
+        Quality::Nearest
+    }
 }
 
 /// An index that identifies a texture in a [`TextureArray`].
@@ -41,12 +159,20 @@ pub struct TextureArray {
 /// [`TextureArray`]: struct.TextureArray.html
 /// [`Batch`]: struct.Batch.html
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Index {
     layer: u16,
     offset: Offset,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 struct Offset {
     x: f32,
     y: f32,
@@ -63,6 +189,9 @@ pub enum Error {
 
     /// A provided image did not fit in a texture array layer.
     ImageIsTooBig(PathBuf),
+
+    /// A texture was added with a name that was already in use.
+    DuplicateKey(String),
 }
 
 impl fmt::Display for Error {
@@ -72,6 +201,7 @@ impl fmt::Display for Error {
             Error::ImageIsTooBig(path) => {
                 write!(f, "Image is too big: {}", path.display())
             }
+            Error::DuplicateKey(key) => write!(f, "Duplicate key: {}", key),
         }
     }
 }
@@ -1,10 +1,11 @@
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use super::{Index, Offset, TextureArray};
-use crate::graphics::Gpu;
+use super::{Index, Offset, Quality, TextureArray};
+use crate::graphics::{Gpu, ImageSettings};
 use crate::{Error, Result};
 
 /// A [`TextureArray`] builder.
@@ -16,6 +17,8 @@ pub struct Builder {
     height: u32,
     layers: Vec<Layer>,
     current: Layer,
+    keys: HashMap<String, Index>,
+    quality: Quality,
 }
 
 impl Builder {
@@ -29,9 +32,25 @@ impl Builder {
             height: height as u32,
             layers: Vec::new(),
             current: Layer::new(width, height),
+            keys: HashMap::new(),
+            quality: Quality::default(),
         }
     }
 
+    /// Sets the filtering [`Quality`] of the produced [`TextureArray`].
+    ///
+    /// Mipmaps are generated for every layer when building, if the
+    /// [`Quality`] needs them. The [`Sampler`] of the [`TextureArray`] is
+    /// chosen accordingly.
+    ///
+    /// [`Quality`]: enum.Quality.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Sampler`]: ../struct.Sampler.html
+    pub fn quality(&mut self, quality: Quality) -> &mut Builder {
+        self.quality = quality;
+        self
+    }
+
     /// Loads a new image from the given path and adds it to the produced
     /// [`TextureArray`].
     ///
@@ -49,8 +68,12 @@ impl Builder {
     pub fn add<P: AsRef<Path>>(&mut self, path: P) -> Result<Index> {
         let img = {
             let mut buf = Vec::new();
-            let mut reader = File::open(&path)?;
-            let _ = reader.read_to_end(&mut buf)?;
+
+            let _ = File::open(&path)
+                .and_then(|mut reader| reader.read_to_end(&mut buf))
+                .map_err(|error| {
+                    Error::File(PathBuf::from(path.as_ref()), error)
+                })?;
             let rgba = image::load_from_memory(&buf)?.to_rgba();
             Arc::new(rgba)
         };
@@ -84,6 +107,35 @@ impl Builder {
         }
     }
 
+    /// Loads a new image from the given path and adds it to the produced
+    /// [`TextureArray`] with the given name.
+    ///
+    /// The [`Index`] of the image can be obtained later using
+    /// [`TextureArray::get`] with the same name. Unlike the [`Index`] itself,
+    /// the name stays valid when other images are added.
+    ///
+    /// It fails if the name is already in use.
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Index`]: struct.Index.html
+    /// [`TextureArray::get`]: struct.TextureArray.html#method.get
+    pub fn add_keyed<K: Into<String>, P: AsRef<Path>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Result<Index> {
+        let key = key.into();
+
+        if self.keys.contains_key(&key) {
+            return Err(Error::TextureArray(super::Error::DuplicateKey(key)));
+        }
+
+        let index = self.add(path)?;
+        let _ = self.keys.insert(key, index);
+
+        Ok(index)
+    }
+
     /// Builds the [`TextureArray`].
     ///
     /// [`TextureArray`]: struct.TextureArray.html
@@ -101,12 +153,19 @@ impl Builder {
             })
             .collect();
 
-        let texture = gpu.upload_texture_array(&images[..]);
+        let mut texture = gpu.upload_texture_array(
+            &images[..],
+            self.quality.has_mipmaps(),
+            ImageSettings::default(),
+        );
+
+        texture.set_sampler(self.quality.sampler());
 
         TextureArray {
             texture,
             x_unit: 1.0 / self.width as f32,
             y_unit: 1.0 / self.height as f32,
+            keys: Arc::new(self.keys.clone()),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::collections::VecDeque;
 use std::path::PathBuf;
 
-use super::{Builder, Index, TextureArray};
+use super::{Builder, Index, Quality, TextureArray};
 use crate::load::Task;
 use crate::{Error, Result};
 
@@ -56,7 +56,8 @@ use crate::{Error, Result};
 pub struct Loader {
     width: u16,
     height: u16,
-    paths: Vec<PathBuf>,
+    paths: Vec<(Option<String>, PathBuf)>,
+    quality: Quality,
 }
 
 impl Loader {
@@ -70,9 +71,22 @@ impl Loader {
             width,
             height,
             paths: Vec::new(),
+            quality: Quality::default(),
         }
     }
 
+    /// Sets the filtering [`Quality`] of the produced [`TextureArray`].
+    ///
+    /// See [`Builder::quality`].
+    ///
+    /// [`Quality`]: enum.Quality.html
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Builder::quality`]: struct.Builder.html#method.quality
+    pub fn quality(&mut self, quality: Quality) -> &mut Loader {
+        self.quality = quality;
+        self
+    }
+
     /// Queues an image to be added to the produced [`TextureArray`] and obtain
     /// a [`Key`] to its [`Index`].
     ///
@@ -80,7 +94,26 @@ impl Loader {
     /// [`Key`]: struct.Key.html
     /// [`Index`]: struct.Index.html
     pub fn add<P: Into<PathBuf>>(&mut self, path: P) -> Key {
-        self.paths.push(path.into());
+        self.paths.push((None, path.into()));
+        Key(self.paths.len() - 1)
+    }
+
+    /// Queues an image to be added to the produced [`TextureArray`] with the
+    /// given name and obtain a [`Key`] to its [`Index`].
+    ///
+    /// The [`Index`] can also be obtained using [`TextureArray::get`] with the
+    /// same name.
+    ///
+    /// [`TextureArray`]: struct.TextureArray.html
+    /// [`Key`]: struct.Key.html
+    /// [`Index`]: struct.Index.html
+    /// [`TextureArray::get`]: struct.TextureArray.html#method.get
+    pub fn add_keyed<K: Into<String>, P: Into<PathBuf>>(
+        &mut self,
+        key: K,
+        path: P,
+    ) -> Key {
+        self.paths.push((Some(key.into()), path.into()));
         Key(self.paths.len() - 1)
     }
 
@@ -99,11 +132,16 @@ impl Loader {
 
         Task::sequence(total_work, move |task| {
             let mut builder = Builder::new(self.width, self.height);
+            let _ = builder.quality(self.quality);
             let mut work_todo = VecDeque::from(self.paths.clone());
             let mut indices = Vec::new();
 
-            while let Some(next) = work_todo.pop_front() {
-                let index = builder.add(next)?;
+            while let Some((key, path)) = work_todo.pop_front() {
+                let index = match key {
+                    Some(key) => builder.add_keyed(key, path)?,
+                    None => builder.add(path)?,
+                };
+
                 indices.push(index);
 
                 task.notify_progress(1);
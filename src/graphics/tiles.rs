@@ -0,0 +1,84 @@
+use crate::graphics::{IntoQuad, Point, Quad, Rectangle, Vector};
+
+/// A quad that repeats a resource across its whole area.
+///
+/// A single quad is drawn, no matter how many times the resource is repeated.
+/// This is useful to draw scrolling backgrounds or large areas of ground.
+///
+/// The resource needs to use a [`Sampler`] with [`WrapMode::Repeat`] or
+/// [`WrapMode::MirrorRepeat`]. Otherwise, the edges of the resource are
+/// stretched. Tiling repeats the whole texture, so it does not work with a
+/// [`SubImage`] of an atlas.
+///
+/// # Example
+/// ```
+/// use coffee::graphics::{
+///     Frame, Image, Point, Sampler, Tiles, Vector, WrapMode,
+/// };
+///
+/// fn draw_background(background: &mut Image, scroll: f32, frame: &mut Frame) {
+///     background.set_sampler(Sampler {
+///         wrap: WrapMode::Repeat,
+///         ..Sampler::default()
+///     });
+///
+///     background.draw(
+///         Tiles {
+///             position: Point::new(0.0, 0.0),
+///             size: (frame.width(), frame.height()),
+///             offset: Vector::new(scroll, 0.0),
+///             ..Tiles::default()
+///         },
+///         &mut frame.as_target(),
+///     );
+/// }
+/// ```
+///
+/// [`Sampler`]: struct.Sampler.html
+/// [`WrapMode::Repeat`]: enum.WrapMode.html#variant.Repeat
+/// [`WrapMode::MirrorRepeat`]: enum.WrapMode.html#variant.MirrorRepeat
+/// [`SubImage`]: struct.SubImage.html
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tiles {
+    /// The position where the quad should be drawn.
+    pub position: Point,
+
+    /// The size of the quad.
+    pub size: (f32, f32),
+
+    /// The scale to apply to every tile.
+    pub scale: (f32, f32),
+
+    /// The offset of the tiles, in pixels of the resource.
+    ///
+    /// Change it over time to scroll the tiles.
+    pub offset: Vector,
+}
+
+impl Default for Tiles {
+    #[inline]
+    fn default() -> Tiles {
+        Tiles {
+            position: Point::new(0.0, 0.0),
+            size: (1.0, 1.0),
+            scale: (1.0, 1.0),
+            offset: Vector::new(0.0, 0.0),
+        }
+    }
+}
+
+impl IntoQuad for Tiles {
+    fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        Quad {
+            source: Rectangle {
+                x: self.offset.x * x_unit,
+                y: self.offset.y * y_unit,
+                width: self.size.0 / self.scale.0 * x_unit,
+                height: self.size.1 / self.scale.1 * y_unit,
+            },
+            position: self.position,
+            size: self.size,
+            ..Quad::default()
+        }
+    }
+}
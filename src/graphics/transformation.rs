@@ -1,20 +1,28 @@
-use nalgebra::Matrix3;
+use nalgebra::{Matrix3, Matrix4, Perspective3, Vector3};
 use std::ops::Mul;
 
-use crate::graphics::Vector;
+use crate::graphics::{Point, Vector};
 
-/// A 2D transformation matrix.
+/// A transformation matrix.
 ///
 /// It can be used to apply a transformation to a [`Target`].
 ///
+/// Most transformations work on the 2D plane where quads are drawn. A
+/// [`perspective`] projection, together with [`translate_z`], [`rotate_x`],
+/// and [`rotate_y`], places them in 3D.
+///
 /// [`Target`]: struct.Target.html
+/// [`perspective`]: #method.perspective
+/// [`translate_z`]: #method.translate_z
+/// [`rotate_x`]: #method.rotate_x
+/// [`rotate_y`]: #method.rotate_y
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Transformation(Matrix3<f32>);
+pub struct Transformation(Matrix4<f32>);
 
 impl Transformation {
     /// Get the identity transformation.
     pub fn identity() -> Transformation {
-        Transformation(Matrix3::identity())
+        Transformation(Matrix4::identity())
     }
 
     /// Creates an orthographic projection.
@@ -25,7 +33,7 @@ impl Transformation {
     /// [`Target`]: struct.Target.html
     #[rustfmt::skip]
     pub fn orthographic(width: f32, height: f32) -> Transformation {
-        Transformation(nalgebra::Matrix3::new(
+        Transformation::from(nalgebra::Matrix3::new(
             2.0 / width, 0.0, -1.0,
             0.0, -2.0 / height, 1.0,
             0.0, 0.0, 1.0
@@ -36,7 +44,7 @@ impl Transformation {
     ///
     /// You can use this to pan your camera, for example.
     pub fn translate(translation: Vector) -> Transformation {
-        Transformation(Matrix3::new_translation(&Vector::new(
+        Transformation::from(Matrix3::new_translation(&Vector::new(
             translation.x,
             translation.y,
         )))
@@ -46,7 +54,7 @@ impl Transformation {
     ///
     /// You can use this to zoom your camera, for example.
     pub fn scale(scale: f32) -> Transformation {
-        Transformation(Matrix3::new_scaling(scale))
+        Transformation::from(Matrix3::new_scaling(scale))
     }
 
     /// Creates a non-uniform scale transformation.
@@ -54,14 +62,105 @@ impl Transformation {
     /// It allows you to scale each axis independently. You should rarely need
     /// this.
     pub fn nonuniform_scale(scale: Vector) -> Transformation {
-        Transformation(Matrix3::new_nonuniform_scaling(&scale))
+        Transformation::from(Matrix3::new_nonuniform_scaling(&scale))
     }
 
     /// Creates a rotation transformation (in radians).
     ///
     /// You can use this to rotate your camera, for example.
     pub fn rotate(rotation: f32) -> Transformation {
-        Transformation(Matrix3::new_rotation(rotation))
+        Transformation::from(Matrix3::new_rotation(rotation))
+    }
+
+    /// Creates a perspective projection.
+    ///
+    /// The camera is placed at the origin, looking towards the negative z
+    /// axis, with the y axis pointing up. `fov` is the vertical field of view
+    /// (in radians), `aspect` is the width of the view divided by its height,
+    /// and `near` and `far` are the distances to the clipping planes.
+    ///
+    /// Use it with [`Target::with_projection`] and combine it with
+    /// [`translate_z`], [`rotate_x`], and [`rotate_y`] to place your quads in
+    /// 3D, like a _mode 7_ floor or a rotating card. A [`Canvas`] created
+    /// with [`Canvas::with_depth`] draws them using depth testing.
+    ///
+    /// ```
+    /// use coffee::graphics::Transformation;
+    ///
+    /// let projection =
+    ///     Transformation::perspective(std::f32::consts::PI / 3.0, 1.5, 0.1, 100.0);
+    ///
+    /// // Half a turn of a card placed 5 units in front of the camera
+    /// let card = projection
+    ///     * Transformation::translate_z(-5.0)
+    ///     * Transformation::rotate_y(std::f32::consts::PI);
+    /// ```
+    ///
+    /// [`Target::with_projection`]: struct.Target.html#method.with_projection
+    /// [`translate_z`]: #method.translate_z
+    /// [`rotate_x`]: #method.rotate_x
+    /// [`rotate_y`]: #method.rotate_y
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`Canvas::with_depth`]: struct.Canvas.html#method.with_depth
+    pub fn perspective(
+        fov: f32,
+        aspect: f32,
+        near: f32,
+        far: f32,
+    ) -> Transformation {
+        Transformation(Perspective3::new(aspect, fov, near, far).into_inner())
+    }
+
+    /// Creates a translation along the z axis.
+    ///
+    /// It moves a quad away from the camera of a [`perspective`] projection
+    /// when negative.
+    ///
+    /// [`perspective`]: #method.perspective
+    pub fn translate_z(distance: f32) -> Transformation {
+        Transformation(Matrix4::new_translation(&Vector3::new(
+            0.0, 0.0, distance,
+        )))
+    }
+
+    /// Creates a rotation around the x axis (in radians).
+    ///
+    /// It tilts quads in 3D, which is only noticeable with a [`perspective`]
+    /// projection.
+    ///
+    /// [`perspective`]: #method.perspective
+    pub fn rotate_x(rotation: f32) -> Transformation {
+        Transformation(Matrix4::from_axis_angle(&Vector3::x_axis(), rotation))
+    }
+
+    /// Creates a rotation around the y axis (in radians).
+    ///
+    /// It turns quads in 3D, which is only noticeable with a [`perspective`]
+    /// projection.
+    ///
+    /// [`perspective`]: #method.perspective
+    pub fn rotate_y(rotation: f32) -> Transformation {
+        Transformation(Matrix4::from_axis_angle(&Vector3::y_axis(), rotation))
+    }
+
+    /// Returns the inverse of the transformation, if it exists.
+    pub fn inverse(&self) -> Option<Transformation> {
+        self.0.try_inverse().map(Transformation)
+    }
+
+    /// Applies the transformation to the given [`Point`].
+    ///
+    /// The [`Point`] is considered to lie on the plane where quads are drawn.
+    ///
+    /// [`Point`]: type.Point.html
+    pub fn transform_point(&self, point: Point) -> Point {
+        let m = &self.0;
+        let w = m[(3, 0)] * point.x + m[(3, 1)] * point.y + m[(3, 3)];
+
+        Point::new(
+            (m[(0, 0)] * point.x + m[(0, 1)] * point.y + m[(0, 3)]) / w,
+            (m[(1, 0)] * point.x + m[(1, 1)] * point.y + m[(1, 3)]) / w,
+        )
     }
 }
 
@@ -74,37 +173,55 @@ impl Mul for Transformation {
 }
 
 impl From<Transformation> for [[f32; 4]; 4] {
-    #[rustfmt::skip]
     fn from(t: Transformation) -> [[f32; 4]; 4] {
-        [
-            [t.0[0], t.0[1], 0.0, t.0[2]],
-            [t.0[3], t.0[4], 0.0, t.0[5]],
-            [0.0, 0.0, -1.0, 0.0],
-            [t.0[6], t.0[7], 0.0, t.0[8]],
-        ]
+        t.0.into()
     }
 }
 
 impl From<Transformation> for [f32; 16] {
-    #[rustfmt::skip]
     fn from(t: Transformation) -> [f32; 16] {
-        [
-            t.0[0], t.0[1], 0.0, t.0[2],
-            t.0[3], t.0[4], 0.0, t.0[5],
-            0.0, 0.0, -1.0, 0.0,
-            t.0[6], t.0[7], 0.0, t.0[8]
-        ]
+        let mut matrix = [0.0; 16];
+        matrix.copy_from_slice(t.0.as_slice());
+        matrix
     }
 }
 
+/// Embeds a 2D projective transformation in the plane where quads are drawn.
 impl From<Matrix3<f32>> for Transformation {
-    fn from(matrix: Matrix3<f32>) -> Self {
-        Transformation(matrix)
+    #[rustfmt::skip]
+    fn from(m: Matrix3<f32>) -> Self {
+        Transformation(Matrix4::new(
+            m[(0, 0)], m[(0, 1)], 0.0, m[(0, 2)],
+            m[(1, 0)], m[(1, 1)], 0.0, m[(1, 2)],
+            0.0, 0.0, 1.0, 0.0,
+            m[(2, 0)], m[(2, 1)], 0.0, m[(2, 2)],
+        ))
     }
 }
 
+/// Returns the 2D projective transformation of the plane where quads are
+/// drawn. The depth of the quads is discarded.
 impl Into<Matrix3<f32>> for Transformation {
+    #[rustfmt::skip]
     fn into(self) -> Matrix3<f32> {
+        let m = &self.0;
+
+        Matrix3::new(
+            m[(0, 0)], m[(0, 1)], m[(0, 3)],
+            m[(1, 0)], m[(1, 1)], m[(1, 3)],
+            m[(3, 0)], m[(3, 1)], m[(3, 3)],
+        )
+    }
+}
+
+impl From<Matrix4<f32>> for Transformation {
+    fn from(matrix: Matrix4<f32>) -> Self {
+        Transformation(matrix)
+    }
+}
+
+impl Into<Matrix4<f32>> for Transformation {
+    fn into(self) -> Matrix4<f32> {
         self.0
     }
 }
@@ -0,0 +1,332 @@
+//! Animate transitions between scenes.
+use crate::graphics::{
+    Canvas, Color, Frame, Mesh, Point, Quad, Rectangle, Shape, Target,
+};
+use crate::Result;
+
+/// An animated transition from a captured scene to the next one.
+///
+/// A [`Transition`] draws the outgoing scene into a [`Canvas`] once, when it
+/// is created. Then, it animates an [`Effect`] over the incoming scene during
+/// a fixed amount of ticks.
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::transition::{Effect, Transition};
+/// use coffee::graphics::{Color, Frame, Target};
+/// use coffee::Result;
+///
+/// enum Scene {
+///     Menu,
+///     Level,
+/// }
+///
+/// impl Scene {
+///     fn draw(&self, target: &mut Target<'_>) {
+///         match self {
+///             Scene::Menu => target.clear(Color::BLACK),
+///             Scene::Level => target.clear(Color::WHITE),
+///         }
+///     }
+/// }
+///
+/// struct MyGame {
+///     scene: Scene,
+///     transition: Option<Transition>,
+/// }
+///
+/// impl MyGame {
+///     fn start_level(&mut self, frame: &mut Frame<'_>) -> Result<()> {
+///         let scene = &self.scene;
+///
+///         // Capture the menu before switching scenes
+///         self.transition = Some(Transition::new(
+///             frame,
+///             Effect::Fade(Color::BLACK),
+///             60,
+///             |target| scene.draw(target),
+///         )?);
+///
+///         self.scene = Scene::Level;
+///
+///         Ok(())
+///     }
+///
+///     // Call this from `Game::update`
+///     fn update(&mut self) {
+///         if let Some(transition) = &mut self.transition {
+///             transition.update();
+///
+///             if transition.is_finished() {
+///                 self.transition = None;
+///             }
+///         }
+///     }
+///
+///     // Call this from `Game::draw`
+///     fn draw(&mut self, frame: &mut Frame<'_>) {
+///         self.scene.draw(&mut frame.as_target());
+///
+///         if let Some(transition) = &self.transition {
+///             transition.draw(frame);
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`Transition`]: struct.Transition.html
+/// [`Canvas`]: ../struct.Canvas.html
+/// [`Effect`]: enum.Effect.html
+#[derive(Debug, Clone)]
+pub struct Transition {
+    canvas: Canvas,
+    effect: Effect,
+    ticks: u16,
+    elapsed: u16,
+}
+
+/// The visual effect of a [`Transition`].
+///
+/// [`Transition`]: struct.Transition.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Effect {
+    /// Fade the outgoing scene to the given [`Color`] during the first half
+    /// of the [`Transition`] and fade the incoming scene in from it during the
+    /// second half.
+    ///
+    /// [`Color`]: ../struct.Color.html
+    /// [`Transition`]: struct.Transition.html
+    Fade(Color),
+
+    /// Blend the outgoing scene into the incoming one, fading it out over
+    /// the whole [`Transition`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    Crossfade,
+
+    /// Reveal the incoming scene with an edge moving in the given
+    /// [`Direction`].
+    ///
+    /// [`Direction`]: enum.Direction.html
+    Wipe(Direction),
+}
+
+/// The direction of a [`Effect::Wipe`].
+///
+/// [`Effect::Wipe`]: enum.Effect.html#variant.Wipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// From right to left.
+    Left,
+
+    /// From left to right.
+    Right,
+
+    /// From bottom to top.
+    Up,
+
+    /// From top to bottom.
+    Down,
+}
+
+impl Transition {
+    /// Creates a [`Transition`] with the given [`Effect`] lasting the given
+    /// amount of ticks.
+    ///
+    /// The outgoing scene is captured by drawing it with the provided
+    /// function on a [`Canvas`] of the size of the [`Frame`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Effect`]: enum.Effect.html
+    /// [`Canvas`]: ../struct.Canvas.html
+    /// [`Frame`]: ../struct.Frame.html
+    pub fn new<F>(
+        frame: &mut Frame<'_>,
+        effect: Effect,
+        ticks: u16,
+        draw: F,
+    ) -> Result<Transition>
+    where
+        F: FnOnce(&mut Target<'_>),
+    {
+        let width = frame.width().round() as u16;
+        let height = frame.height().round() as u16;
+
+        let mut canvas = Canvas::new(frame.gpu(), width, height)?;
+
+        draw(&mut canvas.as_target(frame.gpu()));
+
+        Ok(Transition {
+            canvas,
+            effect,
+            ticks,
+            elapsed: 0,
+        })
+    }
+
+    /// Creates a [`Transition`] like [`new`], but captures the outgoing scene
+    /// by drawing it with the provided function on a [`Frame`].
+    ///
+    /// Use it when the outgoing scene can only be drawn on a [`Frame`], like
+    /// a [`Scene`]. Nothing is drawn on the given [`Frame`] itself.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`new`]: #method.new
+    /// [`Frame`]: ../struct.Frame.html
+    /// [`Scene`]: ../../scene/trait.Scene.html
+    pub fn capture<F>(
+        frame: &mut Frame<'_>,
+        effect: Effect,
+        ticks: u16,
+        draw: F,
+    ) -> Result<Transition>
+    where
+        F: FnOnce(&mut Frame<'_>),
+    {
+        let canvas = frame.capture(draw)?;
+
+        Ok(Transition {
+            canvas,
+            effect,
+            ticks,
+            elapsed: 0,
+        })
+    }
+
+    /// Advances the [`Transition`] by a tick.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn update(&mut self) {
+        self.elapsed = (self.elapsed + 1).min(self.ticks);
+    }
+
+    /// Returns the progress of the [`Transition`], from `0.0` to `1.0`.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn progress(&self) -> f32 {
+        if self.ticks == 0 {
+            1.0
+        } else {
+            f32::from(self.elapsed) / f32::from(self.ticks)
+        }
+    }
+
+    /// Returns true if the [`Transition`] has finished.
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.ticks
+    }
+
+    /// Draws the [`Transition`] on top of the incoming scene, which should
+    /// already be drawn on the given [`Frame`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    /// [`Frame`]: ../struct.Frame.html
+    pub fn draw(&self, frame: &mut Frame<'_>) {
+        let progress = self.progress();
+        let width = frame.width();
+        let height = frame.height();
+        let mut target = frame.as_target();
+
+        match self.effect {
+            Effect::Fade(color) => {
+                let opacity = if progress < 0.5 {
+                    self.canvas.draw(
+                        Quad {
+                            size: (width, height),
+                            ..Quad::default()
+                        },
+                        &mut target,
+                    );
+
+                    progress * 2.0
+                } else {
+                    (1.0 - progress) * 2.0
+                };
+
+                let mut mesh = Mesh::new();
+
+                mesh.fill(
+                    Shape::Rectangle(Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width,
+                        height,
+                    }),
+                    Color {
+                        a: color.a * opacity,
+                        ..color
+                    },
+                );
+
+                mesh.draw(&mut target);
+            }
+            Effect::Crossfade => {
+                let color = Color {
+                    a: 1.0 - progress,
+                    ..Color::WHITE
+                };
+
+                self.canvas.draw(
+                    Quad {
+                        size: (width, height),
+                        colors: [color; 4],
+                        ..Quad::default()
+                    },
+                    &mut target,
+                );
+            }
+            Effect::Wipe(direction) => {
+                // The part of the outgoing scene that is still visible
+                let remaining = 1.0 - progress;
+
+                let source = match direction {
+                    Direction::Left => Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width: remaining,
+                        height: 1.0,
+                    },
+                    Direction::Right => Rectangle {
+                        x: progress,
+                        y: 0.0,
+                        width: remaining,
+                        height: 1.0,
+                    },
+                    Direction::Up => Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width: 1.0,
+                        height: remaining,
+                    },
+                    Direction::Down => Rectangle {
+                        x: 0.0,
+                        y: progress,
+                        width: 1.0,
+                        height: remaining,
+                    },
+                };
+
+                if remaining > 0.0 {
+                    self.canvas.draw(
+                        Quad {
+                            source,
+                            position: Point::new(
+                                source.x * width,
+                                source.y * height,
+                            ),
+                            size: (
+                                source.width * width,
+                                source.height * height,
+                            ),
+                            ..Quad::default()
+                        },
+                        &mut target,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,23 @@
 use nalgebra;
 
 /// A 2D vector.
+///
+/// It is a [`nalgebra::Vector2`], so you can use all of its operations:
+///
+/// ```
+/// use coffee::graphics::Vector;
+///
+/// let velocity = Vector::from([3.0, 4.0]);
+///
+/// assert_eq!(velocity.norm(), 5.0);
+/// assert_eq!(velocity.normalize(), Vector::new(0.6, 0.8));
+/// assert_eq!(velocity.dot(&Vector::new(1.0, 0.0)), 3.0);
+/// assert_eq!(velocity.lerp(&Vector::zeros(), 0.5), Vector::new(1.5, 2.0));
+/// ```
+///
+/// When the `mint` feature is enabled, it can be converted from and into a
+/// `mint::Vector2<f32>`, which allows you to exchange vectors with other math
+/// and physics crates.
+///
+/// [`nalgebra::Vector2`]: https://docs.rs/nalgebra/0.18/nalgebra/base/type.Vector2.html
 pub type Vector = nalgebra::Vector2<f32>;
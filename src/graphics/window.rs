@@ -1,15 +1,28 @@
+#[cfg(feature = "capture")]
+mod capture;
+#[cfg(feature = "clipboard")]
+mod clipboard;
 mod cursor_icon;
 mod frame;
+mod monitor;
+mod presentation;
 mod settings;
+mod update_mode;
 
 pub(crate) use winit;
 
 pub use cursor_icon::CursorIcon;
 pub use frame::Frame;
+pub use monitor::{Fullscreen, Monitor, VideoMode};
+pub use presentation::PresentationStats;
 pub use settings::Settings;
+pub use update_mode::UpdateMode;
 
 use crate::graphics::gpu::{self, Gpu};
-use crate::Result;
+use crate::graphics::{Canvas, Point, Quad, Target};
+use crate::input::window::NewSize;
+use crate::rand::Rng;
+use crate::{Error, Result};
 
 /// An open window.
 ///
@@ -22,7 +35,17 @@ pub struct Window {
     width: f32,
     height: f32,
     is_fullscreen: bool,
+    is_display_changed: bool,
+    pending_size: Option<winit::dpi::PhysicalSize<u32>>,
     cursor_icon: Option<winit::window::CursorIcon>,
+    presentation: presentation::Tracker,
+    preserve_frame: bool,
+    offscreen: Option<Canvas>,
+    seed: u64,
+    #[cfg(feature = "clipboard")]
+    clipboard: clipboard::Clipboard,
+    #[cfg(feature = "capture")]
+    capture: capture::Capture,
 }
 
 impl Window {
@@ -31,21 +54,89 @@ impl Window {
         event_loop: &winit::event_loop::EventLoop<()>,
     ) -> Result<Window> {
         let (width, height) = settings.size;
-        let is_fullscreen = settings.fullscreen;
+        let is_fullscreen = settings.fullscreen.is_some();
+        let monitor = settings.monitor;
+        let backend = settings.backend;
+        let power_preference = settings.power_preference;
+        let multisampling = settings.multisampling;
+        let vsync = settings.vsync;
+        let preserve_frame = settings.preserve_frame;
+        let seed = settings.seed.unwrap_or_else(|| Rng::from_entropy().seed());
 
-        let (gpu, surface) =
-            Gpu::for_window(settings.into_builder(event_loop), event_loop)?;
+        if !backend.is_compiled() {
+            return Err(Error::BackendNotCompiled(backend));
+        }
+
+        match multisampling {
+            0 | 1 | 2 | 4 | 8 => {}
+            _ => return Err(Error::MultisamplingNotSupported(multisampling)),
+        }
+
+        // Log the seed, so runs can be reproduced
+        log::info!("Random seed: {}", seed);
+
+        let (mut gpu, surface) = Gpu::for_window(
+            settings.into_builder(event_loop),
+            event_loop,
+            backend,
+            power_preference,
+            multisampling,
+            vsync,
+        )?;
+
+        if let (false, Some(index)) = (is_fullscreen, monitor) {
+            move_to_monitor(surface.window(), index, (width, height));
+        }
+
+        let offscreen = create_offscreen(
+            &mut gpu,
+            preserve_frame,
+            width as f32,
+            height as f32,
+        )?;
 
         Ok(Window {
             is_fullscreen,
+            is_display_changed: false,
+            pending_size: None,
             gpu,
             surface,
             width: width as f32,
             height: height as f32,
             cursor_icon: Some(winit::window::CursorIcon::Default),
+            presentation: presentation::Tracker::new(),
+            preserve_frame,
+            offscreen,
+            seed,
+            #[cfg(feature = "clipboard")]
+            clipboard: clipboard::Clipboard::new(),
+            #[cfg(feature = "capture")]
+            capture: capture::Capture::new(),
         })
     }
 
+    /// Returns the random seed of the current run.
+    ///
+    /// It is the seed in the [`WindowSettings`] if set, or a different one
+    /// on every run otherwise.
+    ///
+    /// [`WindowSettings`]: struct.WindowSettings.html
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Creates a new [`Rng`] seeded with the random seed of the current run.
+    ///
+    /// Every call returns an [`Rng`] producing the same numbers. You will
+    /// normally create it in [`Game::load`] and keep it in your game state,
+    /// forking streams as needed.
+    ///
+    /// [`Rng`]: ../rand/struct.Rng.html
+    /// [`Game::load`]: ../trait.Game.html#tymethod.load
+    pub fn rng(&self) -> Rng {
+        Rng::new(self.seed)
+    }
+
     /// Returns the [`Gpu`] linked to the [`Window`].
     ///
     /// [`Gpu`]: struct.Gpu.html
@@ -60,20 +151,78 @@ impl Window {
 
     /// Toggles the [`Window`]'s fullscreen state.
     ///
+    /// The [`Window`] becomes a borderless window covering the monitor it is
+    /// in. Device resources, like your [`Canvas`] instances and the glyph
+    /// cache of your fonts, are not affected. The current frame is kept
+    /// when [`WindowSettings::preserve_frame`] is enabled.
+    ///
+    /// A [`window::Event::DisplayChanged`] is produced once the change is
+    /// applied, so you can lay out your game again.
+    ///
     /// [`Window`]: struct.Window.html
+    /// [`Canvas`]: struct.Canvas.html
+    /// [`WindowSettings::preserve_frame`]: struct.WindowSettings.html#structfield.preserve_frame
+    /// [`window::Event::DisplayChanged`]: ../input/window/enum.Event.html#variant.DisplayChanged
     pub fn toggle_fullscreen(&mut self) {
         let window = self.surface.window();
 
         let monitor = if self.is_fullscreen {
             None
         } else {
-            Some(window.primary_monitor())
+            Some(window.current_monitor())
         };
 
         window
             .set_fullscreen(monitor.map(winit::window::Fullscreen::Borderless));
 
         self.is_fullscreen = !self.is_fullscreen;
+        self.is_display_changed = true;
+    }
+
+    /// Sets the [`Fullscreen`] mode of the [`Window`] in its current
+    /// monitor.
+    ///
+    /// Use `None` to go back to windowed mode. Like with
+    /// [`toggle_fullscreen`], a [`window::Event::DisplayChanged`] is produced
+    /// once the change is applied.
+    ///
+    /// [`Fullscreen`]: enum.Fullscreen.html
+    /// [`Window`]: struct.Window.html
+    /// [`toggle_fullscreen`]: #method.toggle_fullscreen
+    /// [`window::Event::DisplayChanged`]: ../input/window/enum.Event.html#variant.DisplayChanged
+    pub fn set_fullscreen(&mut self, fullscreen: Option<Fullscreen>) {
+        let window = self.surface.window();
+        let monitor = window.current_monitor();
+
+        window.set_fullscreen(
+            fullscreen.map(|fullscreen| fullscreen.into_winit(monitor)),
+        );
+
+        self.is_fullscreen = fullscreen.is_some();
+        self.is_display_changed = true;
+    }
+
+    /// Returns `true` if the [`Window`] is in fullscreen mode.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn is_fullscreen(&self) -> bool {
+        self.is_fullscreen
+    }
+
+    /// Returns the monitors connected to the system.
+    ///
+    /// The index of a [`Monitor`] in the list can be used in
+    /// [`WindowSettings::monitor`] to choose where the [`Window`] opens.
+    ///
+    /// [`Monitor`]: struct.Monitor.html
+    /// [`WindowSettings::monitor`]: struct.WindowSettings.html#structfield.monitor
+    /// [`Window`]: struct.Window.html
+    pub fn available_monitors(&self) -> Vec<Monitor> {
+        self.surface
+            .window()
+            .available_monitors()
+            .map(|handle| Monitor::from_handle(&handle))
+            .collect()
     }
 
     /// Returns the width of the [`Window`].
@@ -90,19 +239,304 @@ impl Window {
         self.height
     }
 
+    /// Returns the DPI factor of the [`Window`].
+    ///
+    /// It is the ratio between physical pixels and logical pixels of the
+    /// monitor where the [`Window`] is. For instance, it is usually `2.0` in
+    /// high DPI displays.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub fn dpi(&self) -> f64 {
+        self.surface.window().scale_factor()
+    }
+
+    /// Grabs or releases the mouse cursor.
+    ///
+    /// A grabbed cursor cannot leave the [`Window`]. Combined with a hidden
+    /// cursor and [`mouse::Event::Motion`], it lets you control a camera or
+    /// aim with the mouse. It returns `false` if the platform does not
+    /// support it.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`mouse::Event::Motion`]: ../input/mouse/enum.Event.html#variant.Motion
+    pub fn set_cursor_grab(&mut self, grab: bool) -> bool {
+        self.surface.window().set_cursor_grab(grab).is_ok()
+    }
+
+    /// Moves the mouse cursor to the given position of the [`Window`], in
+    /// pixels.
+    ///
+    /// A [`mouse::Event::CursorMoved`] is produced in the next frame. It
+    /// returns `false` if the platform does not support it.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`mouse::Event::CursorMoved`]: ../input/mouse/enum.Event.html#variant.CursorMoved
+    pub fn set_cursor_position(&mut self, position: Point) -> bool {
+        self.surface
+            .window()
+            .set_cursor_position(winit::dpi::PhysicalPosition::new(
+                f64::from(position.x),
+                f64::from(position.y),
+            ))
+            .is_ok()
+    }
+
+    /// Returns the [`PresentationStats`] of the [`Window`] during the last
+    /// second.
+    ///
+    /// [`PresentationStats`]: struct.PresentationStats.html
+    /// [`Window`]: struct.Window.html
+    pub fn presentation_stats(&self) -> PresentationStats {
+        self.presentation.stats()
+    }
+
+    /// Returns the text contents of the clipboard of the platform, if any.
+    ///
+    /// This method is only available when the `clipboard` feature is enabled.
+    #[cfg(feature = "clipboard")]
+    pub fn read_clipboard(&mut self) -> Option<String> {
+        self.clipboard.read()
+    }
+
+    /// Replaces the contents of the clipboard of the platform with the given
+    /// text.
+    ///
+    /// Errors are ignored, as the clipboard may not be available in some
+    /// platforms.
+    ///
+    /// This method is only available when the `clipboard` feature is enabled.
+    #[cfg(feature = "clipboard")]
+    pub fn write_clipboard(&mut self, contents: &str) {
+        self.clipboard.write(contents);
+    }
+
+    /// Saves the last frame presented in the [`Window`] as an image file.
+    ///
+    /// The format of the image is inferred from the extension of the path.
+    /// The frame can only be read back if it was drawn off-screen, which
+    /// happens while recording or when [`WindowSettings::preserve_frame`] is
+    /// enabled. It fails otherwise.
+    ///
+    /// This method is only available when the `capture` feature is enabled.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`WindowSettings::preserve_frame`]: struct.WindowSettings.html#structfield.preserve_frame
+    #[cfg(feature = "capture")]
+    pub fn capture_screenshot<P: AsRef<std::path::Path>>(
+        &mut self,
+        path: P,
+    ) -> Result<()> {
+        capture::Capture::screenshot(
+            &mut self.gpu,
+            self.offscreen.as_ref(),
+            path.as_ref(),
+        )
+    }
+
+    /// Starts recording the frames presented in the [`Window`], so they can
+    /// be saved with [`record_gif`].
+    ///
+    /// Frames are recorded at 20 frames per second, scaled down to fit in
+    /// 640x640 pixels and reduced to 256 colors. Only the last 10 seconds are
+    /// kept.
+    ///
+    /// Keep in mind that recording draws every frame off-screen and reads it
+    /// back from the GPU, which is slow. Stop recording with
+    /// [`stop_recording`] once you are done.
+    ///
+    /// This method is only available when the `capture` feature is enabled.
+    ///
+    /// # Errors
+    /// It fails when the off-screen frame cannot be created.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`record_gif`]: #method.record_gif
+    /// [`stop_recording`]: #method.stop_recording
+    #[cfg(feature = "capture")]
+    pub fn start_recording(&mut self) -> Result<()> {
+        if self.offscreen.is_none() {
+            self.offscreen =
+                create_offscreen(&mut self.gpu, true, self.width, self.height)?;
+        }
+
+        self.capture.start();
+
+        Ok(())
+    }
+
+    /// Stops recording the frames presented in the [`Window`].
+    ///
+    /// The recorded frames are kept, so they can still be saved with
+    /// [`record_gif`].
+    ///
+    /// This method is only available when the `capture` feature is enabled.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`record_gif`]: #method.record_gif
+    #[cfg(feature = "capture")]
+    pub fn stop_recording(&mut self) {
+        self.capture.stop();
+
+        if !self.preserve_frame {
+            self.offscreen = None;
+        }
+    }
+
+    /// Returns `true` while the frames presented in the [`Window`] are being
+    /// recorded.
+    ///
+    /// This method is only available when the `capture` feature is enabled.
+    ///
+    /// [`Window`]: struct.Window.html
+    #[cfg(feature = "capture")]
+    pub fn is_recording(&self) -> bool {
+        self.capture.is_recording()
+    }
+
+    /// Saves the frames recorded in the [`Window`] during the given amount
+    /// of seconds as an animated GIF.
+    ///
+    /// Frames are only recorded after calling [`start_recording`].
+    ///
+    /// This method is only available when the `capture` feature is enabled.
+    ///
+    /// [`Window`]: struct.Window.html
+    /// [`start_recording`]: #method.start_recording
+    #[cfg(feature = "capture")]
+    pub fn record_gif<P: AsRef<std::path::Path>>(
+        &self,
+        seconds: f32,
+        path: P,
+    ) -> Result<()> {
+        self.capture.gif(
+            std::time::Duration::from_secs_f32(seconds.max(0.0)),
+            path.as_ref(),
+        )
+    }
+
     pub(crate) fn swap_buffers(&mut self) {
-        self.surface.swap_buffers(&mut self.gpu);
+        if let Some(offscreen) = &self.offscreen {
+            let view = self.surface.target();
+
+            offscreen.draw(
+                Quad {
+                    size: (self.width, self.height),
+                    ..Quad::default()
+                },
+                &mut Target::new(&mut self.gpu, view, self.width, self.height),
+            );
+
+            #[cfg(feature = "capture")]
+            self.capture.record(&mut self.gpu, offscreen);
+        }
+
+        let is_presented = self.surface.swap_buffers(&mut self.gpu);
+        let is_hidden = self.width < 1.0 || self.height < 1.0;
+
+        self.presentation.record(is_presented && !is_hidden);
+    }
+
+    // The time the GPU spent on the latest measured frame, if the backend
+    // supports timestamp queries
+    pub(crate) fn gpu_time(&self) -> Option<std::time::Duration> {
+        self.gpu.frame_time()
     }
 
     pub(crate) fn request_redraw(&mut self) {
         self.surface.request_redraw();
     }
 
-    pub(crate) fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
+    // Resize events come in storms while the window is dragged, so only the
+    // last size is applied once per frame
+    pub(crate) fn queue_resize(
+        &mut self,
+        new_size: winit::dpi::PhysicalSize<u32>,
+    ) {
+        self.pending_size = Some(new_size);
+    }
+
+    // Applies the last queued size, if any, and returns it
+    pub(crate) fn apply_resize(&mut self) -> Option<NewSize> {
+        let new_size = self.pending_size.take()?;
+
+        if new_size.width as f32 == self.width
+            && new_size.height as f32 == self.height
+        {
+            return None;
+        }
+
+        self.resize(new_size);
+
+        let dpi = self.dpi();
+
+        Some(NewSize {
+            logical_width: (f64::from(new_size.width) / dpi) as f32,
+            logical_height: (f64::from(new_size.height) / dpi) as f32,
+            physical_width: new_size.width,
+            physical_height: new_size.height,
+            dpi,
+        })
+    }
+
+    fn resize(&mut self, new_size: winit::dpi::PhysicalSize<u32>) {
         self.surface.resize(&mut self.gpu, new_size);
 
         self.width = new_size.width as f32;
         self.height = new_size.height as f32;
+        self.is_display_changed = true;
+
+        let previous = self.offscreen.take();
+        let is_needed = self.needs_offscreen();
+
+        self.offscreen = match create_offscreen(
+            &mut self.gpu,
+            is_needed,
+            self.width,
+            self.height,
+        ) {
+            Ok(offscreen) => offscreen,
+            Err(error) => {
+                // The frames are drawn on the window directly instead
+                log::error!("Could not create the off-screen frame: {}", error);
+
+                None
+            }
+        };
+
+        // Keep the preserved frame at its original size, instead of
+        // stretching it or losing it
+        if let (Some(previous), Some(offscreen)) =
+            (previous, &mut self.offscreen)
+        {
+            previous.draw(
+                Quad {
+                    size: (
+                        f32::from(previous.width()),
+                        f32::from(previous.height()),
+                    ),
+                    ..Quad::default()
+                },
+                &mut offscreen.as_target(&mut self.gpu),
+            );
+        }
+    }
+
+    // Frames are drawn off-screen while they need to outlive the swap chain
+    fn needs_offscreen(&self) -> bool {
+        #[cfg(feature = "capture")]
+        {
+            if self.capture.is_recording() {
+                return true;
+            }
+        }
+
+        self.preserve_frame
+    }
+
+    // Returns `true` once after the size or the fullscreen mode of the window
+    // changes
+    pub(crate) fn take_display_change(&mut self) -> bool {
+        std::mem::replace(&mut self.is_display_changed, false)
     }
 
     pub(crate) fn update_cursor(
@@ -130,3 +564,36 @@ impl std::fmt::Debug for Window {
         )
     }
 }
+
+// Windows are created in the primary monitor, so we center them in the chosen
+// one afterwards
+fn move_to_monitor(
+    window: &winit::window::Window,
+    index: usize,
+    (width, height): (u32, u32),
+) {
+    if let Some(monitor) = window.available_monitors().nth(index) {
+        let position = monitor.position();
+        let size = monitor.size();
+
+        window.set_outer_position(winit::dpi::PhysicalPosition {
+            x: position.x + (size.width as i32 - width as i32).max(0) / 2,
+            y: position.y + (size.height as i32 - height as i32).max(0) / 2,
+        });
+    }
+}
+
+// Frames are drawn off-screen when they need to outlive the swap chain, and
+// copied to the window when presented
+fn create_offscreen(
+    gpu: &mut Gpu,
+    is_needed: bool,
+    width: f32,
+    height: f32,
+) -> Result<Option<Canvas>> {
+    if !is_needed || width < 1.0 || height < 1.0 {
+        return Ok(None);
+    }
+
+    Canvas::new(gpu, width as u16, height as u16).map(Some)
+}
@@ -0,0 +1,174 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::graphics::{Canvas, Gpu};
+use crate::{Error, Result};
+
+// Frames are recorded at 20 frames per second, which GIF can represent
+// exactly, during the last 10 seconds at most
+const RECORDING_INTERVAL: Duration = Duration::from_millis(50);
+const MAX_RECORDING: Duration = Duration::from_secs(10);
+
+// Recorded frames are scaled down to fit this size, in pixels, and reduced to
+// the 256 colors of a GIF right away, so a full recording takes tens of
+// megabytes instead of gigabytes
+const MAX_RECORDING_SIZE: u32 = 640;
+
+// Records the frames of the window, which are drawn off-screen while
+// recording so they can be read back once presented
+pub struct Capture {
+    frames: VecDeque<Recorded>,
+    is_recording: bool,
+}
+
+struct Recorded {
+    frame: gif::Frame<'static>,
+    time: Instant,
+}
+
+impl Capture {
+    pub fn new() -> Capture {
+        Capture {
+            frames: VecDeque::new(),
+            is_recording: false,
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+
+    pub fn start(&mut self) {
+        self.is_recording = true;
+    }
+
+    pub fn stop(&mut self) {
+        self.is_recording = false;
+    }
+
+    pub fn record(&mut self, gpu: &mut Gpu, canvas: &Canvas) {
+        if !self.is_recording {
+            return;
+        }
+
+        let now = Instant::now();
+
+        let is_due = self
+            .frames
+            .back()
+            .map(|last| now - last.time >= RECORDING_INTERVAL)
+            .unwrap_or(true);
+
+        if is_due {
+            self.frames.push_back(Recorded {
+                frame: quantize(canvas.read_pixels(gpu).to_rgba()),
+                time: now,
+            });
+
+            while self
+                .frames
+                .front()
+                .map(|first| now - first.time > MAX_RECORDING)
+                .unwrap_or(false)
+            {
+                let _ = self.frames.pop_front();
+            }
+        }
+    }
+
+    pub fn screenshot(
+        gpu: &mut Gpu,
+        canvas: Option<&Canvas>,
+        path: &Path,
+    ) -> Result<()> {
+        let canvas = canvas.ok_or_else(|| {
+            unavailable(path, "The last frame was not drawn off-screen")
+        })?;
+
+        // Pixels may be read back as BGRA, which encoders do not swap
+        image::DynamicImage::ImageRgba8(canvas.read_pixels(gpu).to_rgba())
+            .save(path)
+            .map_err(|error| Error::File(PathBuf::from(path), error))
+    }
+
+    pub fn gif(&self, duration: Duration, path: &Path) -> Result<()> {
+        let last = self.frames.back().ok_or_else(|| {
+            unavailable(path, "No frame has been recorded yet")
+        })?;
+        let (width, height) = (last.frame.width, last.frame.height);
+        let start = last.time - duration.min(MAX_RECORDING);
+
+        // Frames recorded before the last resize are skipped
+        let frames: Vec<&Recorded> = self
+            .frames
+            .iter()
+            .filter(|frame| frame.time >= start)
+            .filter(|recorded| {
+                (recorded.frame.width, recorded.frame.height) == (width, height)
+            })
+            .collect();
+
+        let to_error = |error| Error::File(PathBuf::from(path), error);
+
+        let file = File::create(path).map_err(to_error)?;
+
+        let mut encoder =
+            gif::Encoder::new(file, width, height, &[]).map_err(to_error)?;
+
+        {
+            use gif::SetParameter;
+
+            encoder.set(gif::Repeat::Infinite).map_err(to_error)?;
+        }
+
+        for (i, recorded) in frames.iter().enumerate() {
+            let next = match frames.get(i + 1) {
+                Some(next) => next.time,
+                None => recorded.time + RECORDING_INTERVAL,
+            };
+
+            let mut frame = recorded.frame.clone();
+
+            // GIF delays are measured in hundredths of a second
+            frame.delay = ((next - recorded.time).as_millis() / 10) as u16;
+
+            encoder.write_frame(&frame).map_err(to_error)?;
+        }
+
+        Ok(())
+    }
+}
+
+// Scales down the given frame to fit the maximum recording size and reduces
+// its colors to a palette
+fn quantize(image: image::RgbaImage) -> gif::Frame<'static> {
+    let (width, height) = image.dimensions();
+    let scale = MAX_RECORDING_SIZE as f32 / width.max(height) as f32;
+
+    let image = if scale < 1.0 {
+        image::imageops::resize(
+            &image,
+            ((width as f32 * scale).round() as u32).max(1),
+            ((height as f32 * scale).round() as u32).max(1),
+            image::FilterType::Triangle,
+        )
+    } else {
+        image
+    };
+
+    let (width, height) = image.dimensions();
+    let mut pixels = image.into_raw();
+
+    // The fastest quantization is good enough for quick recordings, and it
+    // keeps the frames from stalling the game
+    gif::Frame::from_rgba_speed(width as u16, height as u16, &mut pixels, 30)
+}
+
+fn unavailable(path: &Path, reason: &str) -> Error {
+    Error::File(
+        PathBuf::from(path),
+        std::io::Error::new(std::io::ErrorKind::Other, reason),
+    )
+}
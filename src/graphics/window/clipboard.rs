@@ -0,0 +1,30 @@
+use clipboard::{ClipboardContext, ClipboardProvider};
+
+// The platform clipboard, connected lazily on first use
+pub struct Clipboard {
+    context: Option<ClipboardContext>,
+}
+
+impl Clipboard {
+    pub fn new() -> Clipboard {
+        Clipboard { context: None }
+    }
+
+    pub fn read(&mut self) -> Option<String> {
+        self.context()?.get_contents().ok()
+    }
+
+    pub fn write(&mut self, contents: &str) {
+        if let Some(context) = self.context() {
+            let _ = context.set_contents(String::from(contents));
+        }
+    }
+
+    fn context(&mut self) -> Option<&mut ClipboardContext> {
+        if self.context.is_none() {
+            self.context = ClipboardProvider::new().ok();
+        }
+
+        self.context.as_mut()
+    }
+}
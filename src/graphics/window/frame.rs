@@ -1,6 +1,7 @@
 use super::Window;
 
-use crate::graphics::{Color, Gpu, Target};
+use crate::graphics::{Canvas, Color, Gpu, Origin, Rectangle, Target};
+use crate::Result;
 
 /// The next frame of your game.
 ///
@@ -53,18 +54,120 @@ impl<'a> Frame<'a> {
             gpu,
             width,
             height,
+            offscreen,
             ..
         } = &mut self.window;
 
+        // Frames are drawn off-screen when they need to be preserved
+        if let Some(canvas) = offscreen {
+            return canvas.as_target(gpu);
+        }
+
         let view = surface.target();
 
         Target::new(gpu, view, *width, *height)
     }
 
+    /// See the frame as a [`Target`] with its coordinate system starting at
+    /// the given [`Origin`].
+    ///
+    /// With [`Origin::BottomLeft`], the y axis points up. The `position` of a
+    /// [`Quad`] becomes the bottom-left corner of the drawn image, which is
+    /// not flipped. Text keeps being laid out from the top-left corner, so it
+    /// is not drawn upside down either.
+    ///
+    /// ```
+    /// use coffee::graphics::{Color, Frame, Mesh, Origin, Rectangle, Shape};
+    ///
+    /// fn draw_ground(frame: &mut Frame<'_>) {
+    ///     let mut target = frame.as_target_with_origin(Origin::BottomLeft);
+    ///     let mut mesh = Mesh::new();
+    ///
+    ///     // A strip along the bottom of the frame
+    ///     mesh.fill(
+    ///         Shape::Rectangle(Rectangle {
+    ///             x: 0.0,
+    ///             y: 0.0,
+    ///             width: 800.0,
+    ///             height: 50.0,
+    ///         }),
+    ///         Color::WHITE,
+    ///     );
+    ///
+    ///     mesh.draw(&mut target);
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Origin`]: enum.Origin.html
+    /// [`Origin::BottomLeft`]: enum.Origin.html#variant.BottomLeft
+    /// [`Quad`]: struct.Quad.html
+    pub fn as_target_with_origin(&mut self, origin: Origin) -> Target<'_> {
+        let height = self.height();
+
+        self.as_target().with_origin(origin, height)
+    }
+
+    /// See a region of the frame as a [`Target`].
+    ///
+    /// The region is given in pixels. Drawing is restricted to it and the
+    /// origin of the coordinate system of the [`Target`] is moved to its
+    /// top-left corner. This way, you can draw split-screen views or editor
+    /// panels without using an intermediate [`Canvas`].
+    ///
+    /// ```
+    /// use coffee::graphics::{Color, Frame, Rectangle};
+    ///
+    /// fn draw_split_screen(frame: &mut Frame<'_>) {
+    ///     let half = frame.width() / 2.0;
+    ///     let height = frame.height();
+    ///
+    ///     for (i, color) in [Color::RED, Color::BLUE].iter().enumerate() {
+    ///         let mut player = frame.viewport(Rectangle {
+    ///             x: half * i as f32,
+    ///             y: 0.0,
+    ///             width: half,
+    ///             height,
+    ///         });
+    ///
+    ///         player.clear(*color);
+    ///
+    ///         // Draw the view of each player here
+    ///         // ...
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Target`]: struct.Target.html
+    /// [`Canvas`]: struct.Canvas.html
+    pub fn viewport(&mut self, region: Rectangle<f32>) -> Target<'_> {
+        self.as_target().with_viewport(region)
+    }
+
     /// Clear the frame with the given [`Color`].
     ///
     /// [`Color`]: struct.Color.html
     pub fn clear(&mut self, color: Color) {
         self.as_target().clear(color);
     }
+
+    // Draws on a new `Canvas` of the size of the frame, instead of the frame
+    // itself, while running the given function
+    pub(crate) fn capture<F>(&mut self, draw: F) -> Result<Canvas>
+    where
+        F: FnOnce(&mut Frame<'_>),
+    {
+        let width = self.width().round() as u16;
+        let height = self.height().round() as u16;
+
+        let canvas = Canvas::new(self.gpu(), width, height)?;
+        let previous =
+            std::mem::replace(&mut self.window.offscreen, Some(canvas));
+
+        draw(&mut Frame::new(self.window));
+
+        let canvas = std::mem::replace(&mut self.window.offscreen, previous);
+
+        Ok(canvas.expect("Captured canvas"))
+    }
 }
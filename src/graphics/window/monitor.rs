@@ -0,0 +1,134 @@
+use super::winit;
+
+/// A monitor connected to the system.
+///
+/// You can list the available monitors using [`Window::available_monitors`].
+///
+/// [`Window::available_monitors`]: struct.Window.html#method.available_monitors
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    /// The name of the monitor, if available.
+    pub name: Option<String>,
+
+    /// The resolution of the monitor, in physical pixels.
+    pub size: (u32, u32),
+
+    /// The position of the top-left corner of the monitor in the desktop,
+    /// in physical pixels.
+    pub position: (i32, i32),
+
+    /// The DPI factor of the monitor.
+    pub dpi: f64,
+
+    /// The [`VideoMode`] list supported by the monitor in exclusive
+    /// fullscreen.
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    pub video_modes: Vec<VideoMode>,
+}
+
+impl Monitor {
+    pub(super) fn from_handle(
+        handle: &winit::monitor::MonitorHandle,
+    ) -> Monitor {
+        let size = handle.size();
+        let position = handle.position();
+
+        Monitor {
+            name: handle.name(),
+            size: (size.width, size.height),
+            position: (position.x, position.y),
+            dpi: handle.scale_factor(),
+            video_modes: handle
+                .video_modes()
+                .map(|mode| VideoMode::from_winit(&mode))
+                .collect(),
+        }
+    }
+}
+
+/// A video mode of a [`Monitor`].
+///
+/// [`Monitor`]: struct.Monitor.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VideoMode {
+    /// The resolution of the video mode, in physical pixels.
+    pub size: (u32, u32),
+
+    /// The bit depth of the video mode.
+    pub bit_depth: u16,
+
+    /// The refresh rate of the video mode, in hertz.
+    pub refresh_rate: u16,
+}
+
+impl VideoMode {
+    fn from_winit(mode: &winit::monitor::VideoMode) -> VideoMode {
+        let size = mode.size();
+
+        VideoMode {
+            size: (size.width, size.height),
+            bit_depth: mode.bit_depth(),
+            refresh_rate: mode.refresh_rate(),
+        }
+    }
+
+    // Sizes are compared first, then refresh rates and bit depths
+    fn distance(&self, other: &VideoMode) -> (u32, u32, u32) {
+        (
+            difference(self.size.0, other.size.0)
+                + difference(self.size.1, other.size.1),
+            difference(
+                u32::from(self.refresh_rate),
+                u32::from(other.refresh_rate),
+            ),
+            difference(u32::from(self.bit_depth), u32::from(other.bit_depth)),
+        )
+    }
+}
+
+fn difference(a: u32, b: u32) -> u32 {
+    a.max(b) - a.min(b)
+}
+
+/// A fullscreen mode of a [`Window`].
+///
+/// [`Window`]: struct.Window.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Fullscreen {
+    /// Take exclusive control of the monitor and change its [`VideoMode`].
+    ///
+    /// The closest [`VideoMode`] supported by the monitor is used.
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    Exclusive(VideoMode),
+
+    /// Cover the whole monitor with a borderless window, keeping its
+    /// current [`VideoMode`].
+    ///
+    /// [`VideoMode`]: struct.VideoMode.html
+    Borderless,
+}
+
+impl Fullscreen {
+    pub(super) fn into_winit(
+        self,
+        monitor: winit::monitor::MonitorHandle,
+    ) -> winit::window::Fullscreen {
+        match self {
+            Fullscreen::Exclusive(video_mode) => {
+                let closest = monitor.video_modes().min_by_key(|mode| {
+                    VideoMode::from_winit(mode).distance(&video_mode)
+                });
+
+                match closest {
+                    Some(mode) => winit::window::Fullscreen::Exclusive(mode),
+                    None => winit::window::Fullscreen::Borderless(monitor),
+                }
+            }
+            Fullscreen::Borderless => {
+                winit::window::Fullscreen::Borderless(monitor)
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Presentation statistics of a [`Window`] during the last second.
+///
+/// They can be obtained using [`Window::presentation_stats`] and are useful
+/// to diagnose stutter in QA builds.
+///
+/// [`Window`]: struct.Window.html
+/// [`Window::presentation_stats`]: struct.Window.html#method.presentation_stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PresentationStats {
+    /// The amount of frames presented on the [`Window`].
+    ///
+    /// [`Window`]: struct.Window.html
+    pub frames_presented: u32,
+
+    /// The amount of frames that were drawn but could not be presented.
+    ///
+    /// A frame is dropped when the swap chain fails to provide or present an
+    /// image, or when the [`Window`] is hidden. Failures to present are also
+    /// logged as errors.
+    ///
+    /// [`Window`]: struct.Window.html
+    pub frames_dropped: u32,
+
+    /// The longest time spent without presenting a frame.
+    pub longest_stall: Duration,
+}
+
+pub(crate) struct Tracker {
+    frames: VecDeque<Frame>,
+    last_presented: Instant,
+}
+
+struct Frame {
+    time: Instant,
+    is_presented: bool,
+    stall: Duration,
+}
+
+const PERIOD: Duration = Duration::from_secs(1);
+
+impl Tracker {
+    pub fn new() -> Tracker {
+        Tracker {
+            frames: VecDeque::new(),
+            last_presented: Instant::now(),
+        }
+    }
+
+    pub fn record(&mut self, is_presented: bool) {
+        let now = Instant::now();
+        let stall = now - self.last_presented;
+
+        if is_presented {
+            self.last_presented = now;
+        }
+
+        self.frames.push_back(Frame {
+            time: now,
+            is_presented,
+            stall,
+        });
+
+        while let Some(frame) = self.frames.front() {
+            if now - frame.time > PERIOD {
+                let _ = self.frames.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn stats(&self) -> PresentationStats {
+        let now = Instant::now();
+
+        let mut stats = PresentationStats {
+            longest_stall: (now - self.last_presented).min(PERIOD),
+            ..PresentationStats::default()
+        };
+
+        for frame in self.frames.iter().filter(|f| now - f.time <= PERIOD) {
+            if frame.is_presented {
+                stats.frames_presented += 1;
+                stats.longest_stall = stats.longest_stall.max(frame.stall);
+            } else {
+                stats.frames_dropped += 1;
+            }
+        }
+
+        stats
+    }
+}
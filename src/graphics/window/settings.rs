@@ -1,4 +1,5 @@
-use super::winit;
+use super::{winit, Fullscreen, UpdateMode};
+use crate::graphics::{Backend, PowerPreference};
 
 /// A window configuration.
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -12,23 +13,291 @@ pub struct Settings {
     /// Defines whether or not the window should be resizable.
     pub resizable: bool,
 
-    /// Defines whether or not the window should start in fullscreen mode.
-    pub fullscreen: bool,
+    /// The [`Fullscreen`] mode the window should start in, if any.
+    ///
+    /// [`Fullscreen`]: ../enum.Fullscreen.html
+    pub fullscreen: Option<Fullscreen>,
+
+    /// The index of the monitor where the window should open, as listed by
+    /// [`Window::available_monitors`].
+    ///
+    /// By default, or if the monitor is not available, the window opens in
+    /// the primary monitor.
+    ///
+    /// [`Window::available_monitors`]: ../struct.Window.html#method.available_monitors
+    pub monitor: Option<usize>,
 
     /// Defines whether or not the window should start maximized.
     pub maximized: bool,
+
+    /// Defines whether or not the window should be transparent.
+    ///
+    /// When enabled, the window is composited using the alpha channel of the
+    /// [`Frame`], so clearing it with a translucent [`Color`] lets the
+    /// desktop show through. This is useful for overlays and widget-style
+    /// tools.
+    ///
+    /// _Note:_ Support depends on the platform and its compositor. The
+    /// `wgpu` backends may ignore the alpha channel in some platforms.
+    ///
+    /// [`Frame`]: ../struct.Frame.html
+    /// [`Color`]: ../struct.Color.html
+    pub transparent: bool,
+
+    /// Defines whether or not the window should stay on top of other windows.
+    pub always_on_top: bool,
+
+    /// Defines whether or not the window should have borders and a title
+    /// bar.
+    pub decorations: bool,
+
+    /// The graphics [`Backend`] to use.
+    ///
+    /// The window creation fails if it has not been compiled in.
+    ///
+    /// [`Backend`]: ../enum.Backend.html
+    pub backend: Backend,
+
+    /// The [`PowerPreference`] used to choose a graphics adapter.
+    ///
+    /// It is ignored by the OpenGL backend.
+    ///
+    /// [`PowerPreference`]: ../enum.PowerPreference.html
+    pub power_preference: PowerPreference,
+
+    /// The amount of samples per pixel to use for multisample anti-aliasing
+    /// (MSAA) when drawing on the window.
+    ///
+    /// The supported values are `2`, `4`, and `8`, while `0` disables it. The
+    /// window creation fails with [`Error::MultisamplingNotSupported`] for
+    /// other values. A [`Canvas`] is only multisampled when created with
+    /// [`Canvas::with_multisampling`].
+    ///
+    /// [`Error::MultisamplingNotSupported`]: ../../enum.Error.html#variant.MultisamplingNotSupported
+    /// [`Canvas`]: ../struct.Canvas.html
+    /// [`Canvas::with_multisampling`]: ../struct.Canvas.html#method.with_multisampling
+    pub multisampling: u16,
+
+    /// Defines whether or not presenting a frame should wait for the
+    /// vertical blank of the monitor.
+    ///
+    /// It avoids tearing and caps the frame rate to the refresh rate of the
+    /// monitor. Disable it to measure how fast your game can draw.
+    pub vsync: bool,
+
+    /// Defines whether or not the contents of a [`Frame`] should be kept
+    /// between frames.
+    ///
+    /// Coffee never clears a [`Frame`] automatically. However, the contents
+    /// of the window are undefined once presented. When enabled, frames are
+    /// drawn off-screen and copied to the window instead, so you can draw on
+    /// top of the previous frame. This is useful for trail effects or to
+    /// only redraw the regions that changed.
+    ///
+    /// The contents are lost when the window is resized.
+    ///
+    /// [`Frame`]: ../struct.Frame.html
+    pub preserve_frame: bool,
+
+    /// The [`UpdateMode`] of the window, which defines when new frames are
+    /// drawn.
+    ///
+    /// [`UpdateMode`]: ../enum.UpdateMode.html
+    pub updates: UpdateMode,
+
+    /// The seed of the random numbers of the game, which can be obtained
+    /// with [`Window::seed`].
+    ///
+    /// By default, a different seed is chosen on every run. Set it to
+    /// reproduce a previous run, like a replay or a bug report.
+    ///
+    /// [`Window::seed`]: ../struct.Window.html#method.seed
+    pub seed: Option<u64>,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            title: String::from("Coffee"),
+            size: (1280, 1024),
+            resizable: true,
+            fullscreen: None,
+            monitor: None,
+            maximized: false,
+            transparent: false,
+            always_on_top: false,
+            decorations: true,
+            backend: Backend::Auto,
+            power_preference: PowerPreference::High,
+            multisampling: 0,
+            vsync: true,
+            preserve_frame: false,
+            updates: UpdateMode::Continuous,
+            seed: None,
+        }
+    }
 }
 
 impl Settings {
+    /// Creates the default [`Settings`] overridden by the environment.
+    ///
+    /// This is equivalent to `Settings::default().overridden_by_env()`. See
+    /// [`overridden_by_env`].
+    ///
+    /// [`Settings`]: struct.Settings.html
+    /// [`overridden_by_env`]: #method.overridden_by_env
+    pub fn from_env() -> Settings {
+        Settings::default().overridden_by_env()
+    }
+
+    /// Overrides the [`Settings`] with the environment variables and the
+    /// command-line arguments of the process.
+    ///
+    /// This lets testers tweak the display options without recompiling the
+    /// game. The following options are recognized:
+    ///
+    ///   * `--windowed` or `COFFEE_WINDOWED=1` opens a window.
+    ///   * `--fullscreen` or `COFFEE_FULLSCREEN=1` opens a borderless
+    ///   fullscreen window.
+    ///   * `--width=<pixels>` or `COFFEE_WIDTH=<pixels>` sets the width.
+    ///   * `--height=<pixels>` or `COFFEE_HEIGHT=<pixels>` sets the height.
+    ///   * `--vsync=on|off` or `COFFEE_VSYNC=on|off` enables or disables
+    ///   [`vsync`].
+    ///   * `--backend=<name>` or `COFFEE_BACKEND=<name>` chooses the
+    ///   [`Backend`]. The name can be `auto`, `gfx`, `opengl`, `wgpu`,
+    ///   `vulkan`, `metal`, `dx11` or `dx12`.
+    ///
+    /// Command-line arguments take precedence over environment variables.
+    /// Values can also be passed as the next argument, like `--width 800`.
+    /// Any other argument, or any invalid value, is ignored, so your game
+    /// can parse its own arguments.
+    ///
+    /// ```no_run
+    /// use coffee::graphics::WindowSettings;
+    ///
+    /// let settings = WindowSettings {
+    ///     title: String::from("My game"),
+    ///     size: (1280, 720),
+    ///     ..WindowSettings::default()
+    /// }
+    /// .overridden_by_env();
+    /// ```
+    ///
+    /// [`Settings`]: struct.Settings.html
+    /// [`vsync`]: #structfield.vsync
+    /// [`Backend`]: ../enum.Backend.html
+    pub fn overridden_by_env(self) -> Settings {
+        let mut settings = self;
+
+        for (variable, option) in ENVIRONMENT.iter() {
+            if let Ok(value) = std::env::var(variable) {
+                settings.apply(option, &value);
+            }
+        }
+
+        settings.overridden_by_args(std::env::args().skip(1))
+    }
+
+    /// Overrides the [`Settings`] with the given command-line arguments.
+    ///
+    /// The arguments should not include the name of the program. See
+    /// [`overridden_by_env`] for the recognized options.
+    ///
+    /// [`Settings`]: struct.Settings.html
+    /// [`overridden_by_env`]: #method.overridden_by_env
+    pub fn overridden_by_args<I, S>(self, args: I) -> Settings
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut settings = self;
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+
+            if !arg.starts_with("--") {
+                continue;
+            }
+
+            let option = &arg[2..];
+
+            match option.find('=') {
+                Some(i) => settings.apply(&option[..i], &option[i + 1..]),
+                None if FLAGS.contains(&option) => settings.apply(option, "1"),
+                None => {
+                    let value = args.peek().and_then(|next| {
+                        if next.as_ref().starts_with("--") {
+                            None
+                        } else {
+                            Some(String::from(next.as_ref()))
+                        }
+                    });
+
+                    if let Some(value) = value {
+                        let _ = args.next();
+
+                        settings.apply(option, &value);
+                    }
+                }
+            }
+        }
+
+        settings
+    }
+
+    // Applies a single option, ignoring invalid values
+    fn apply(&mut self, option: &str, value: &str) {
+        let value = value.trim();
+        let is_enabled = match value.to_lowercase().as_str() {
+            "1" | "on" | "true" | "yes" => Some(true),
+            "0" | "off" | "false" | "no" => Some(false),
+            _ => None,
+        };
+
+        match option {
+            "windowed" => {
+                if is_enabled == Some(true) {
+                    self.fullscreen = None;
+                }
+            }
+            "fullscreen" => match is_enabled {
+                Some(true) => self.fullscreen = Some(Fullscreen::Borderless),
+                Some(false) => self.fullscreen = None,
+                None => {}
+            },
+            "width" => {
+                if let Ok(width) = value.parse() {
+                    self.size.0 = width;
+                }
+            }
+            "height" => {
+                if let Ok(height) = value.parse() {
+                    self.size.1 = height;
+                }
+            }
+            "vsync" => {
+                if let Some(vsync) = is_enabled {
+                    self.vsync = vsync;
+                }
+            }
+            "backend" => {
+                if let Some(backend) = parse_backend(value) {
+                    self.backend = backend;
+                }
+            }
+            _ => {}
+        }
+    }
+
     pub(super) fn into_builder(
         self,
         events_loop: &winit::event_loop::EventLoop<()>,
     ) -> winit::window::WindowBuilder {
-        let monitor = if self.fullscreen {
-            Some(events_loop.primary_monitor())
-        } else {
-            None
-        };
+        let monitor = self
+            .monitor
+            .and_then(|index| events_loop.available_monitors().nth(index))
+            .unwrap_or_else(|| events_loop.primary_monitor());
 
         winit::window::WindowBuilder::new()
             .with_title(self.title)
@@ -37,7 +306,39 @@ impl Settings {
                 height: self.size.1,
             })
             .with_resizable(self.resizable)
-            .with_fullscreen(monitor.map(winit::window::Fullscreen::Borderless))
+            .with_fullscreen(
+                self.fullscreen
+                    .map(|fullscreen| fullscreen.into_winit(monitor)),
+            )
             .with_maximized(self.maximized)
+            .with_transparent(self.transparent)
+            .with_always_on_top(self.always_on_top)
+            .with_decorations(self.decorations)
+    }
+}
+
+// The options that can be set with environment variables
+const ENVIRONMENT: [(&str, &str); 6] = [
+    ("COFFEE_WINDOWED", "windowed"),
+    ("COFFEE_FULLSCREEN", "fullscreen"),
+    ("COFFEE_WIDTH", "width"),
+    ("COFFEE_HEIGHT", "height"),
+    ("COFFEE_VSYNC", "vsync"),
+    ("COFFEE_BACKEND", "backend"),
+];
+
+// The options that do not take a value in the command line
+const FLAGS: [&str; 2] = ["windowed", "fullscreen"];
+
+fn parse_backend(name: &str) -> Option<Backend> {
+    match name.to_lowercase().as_str() {
+        "auto" => Some(Backend::Auto),
+        "gfx" | "opengl" | "gl" => Some(Backend::OpenGl),
+        // Any other wgpu backend is tried if Vulkan is not supported
+        "wgpu" | "vulkan" => Some(Backend::Vulkan),
+        "metal" => Some(Backend::Metal),
+        "dx11" => Some(Backend::Dx11),
+        "dx12" => Some(Backend::Dx12),
+        _ => None,
     }
 }
@@ -0,0 +1,27 @@
+/// Defines when a [`Window`] draws new frames.
+///
+/// [`Window`]: struct.Window.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UpdateMode {
+    /// Draw new frames continuously, as fast as possible.
+    ///
+    /// This is the default and what most games want.
+    Continuous,
+
+    /// Only draw a new frame when input events are received or the deadline
+    /// returned by [`Game::control_flow`] is reached.
+    ///
+    /// Tools and board games that only change on user input can use this
+    /// mode to greatly reduce CPU and GPU usage. Keep in mind that
+    /// [`Game::update`] only runs when a frame is drawn.
+    ///
+    /// [`Game::control_flow`]: ../trait.Game.html#method.control_flow
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    OnEvent,
+}
+
+impl Default for UpdateMode {
+    fn default() -> UpdateMode {
+        UpdateMode::Continuous
+    }
+}
@@ -1,18 +1,23 @@
 //! Allow players to interact with your game.
 
+pub mod axis;
 pub mod gamepad;
 pub mod keyboard;
 pub mod mouse;
+pub mod touch;
 pub mod window;
 
 mod event;
 mod keyboard_and_mouse;
+mod recording;
 
 pub use crate::graphics::window::winit::event::ElementState as ButtonState;
 pub use event::Event;
 pub use keyboard::Keyboard;
 pub use keyboard_and_mouse::KeyboardAndMouse;
 pub use mouse::Mouse;
+pub use recording::{Recorder, Recording, ReplayInput};
+pub use touch::Touches;
 
 /// The input of your [`Game`].
 ///
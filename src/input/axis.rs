@@ -0,0 +1,319 @@
+//! Turn analog sticks and digital keys into consistent movement.
+//!
+//! Gamepad sticks never rest exactly at the center and keyboard keys can only
+//! be pressed or released. An [`Axis2D`] combines both into a movement
+//! [`Vector`] with a length between `0.0` and `1.0`, applying a [`DeadZone`]
+//! and a [`ResponseCurve`] to analog input and normalizing diagonals of
+//! digital input.
+//!
+//! # Example
+//!
+//! ```
+//! use coffee::input::axis::{Axis2D, DeadZone, Keys};
+//! use coffee::input::gamepad::{self, Axis};
+//! use coffee::input::Keyboard;
+//!
+//! let movement = Axis2D::new().dead_zone(DeadZone::radial(0.2));
+//!
+//! // When a gamepad stick moves...
+//! fn on_gamepad_event(movement: &mut Axis2D, event: gamepad::Event) {
+//!     match event {
+//!         gamepad::Event::AxisChanged(Axis::LeftStickX, x) => {
+//!             movement.set_x(x)
+//!         }
+//!         gamepad::Event::AxisChanged(Axis::LeftStickY, y) => {
+//!             // Sticks point up, while the screen points down
+//!             movement.set_y(-y)
+//!         }
+//!         _ => {}
+//!     }
+//! }
+//!
+//! // Or, when using the keyboard...
+//! fn on_keyboard(movement: &mut Axis2D, keyboard: &Keyboard) {
+//!     movement.set_keys(keyboard, Keys::WASD);
+//! }
+//!
+//! let velocity = movement.value() * 5.0;
+//! ```
+//!
+//! [`Axis2D`]: struct.Axis2D.html
+//! [`Vector`]: ../../graphics/type.Vector.html
+//! [`DeadZone`]: struct.DeadZone.html
+//! [`ResponseCurve`]: enum.ResponseCurve.html
+use crate::graphics::Vector;
+use crate::input::keyboard::{KeyCode, Keyboard};
+
+/// The region around the center of an analog stick where input is ignored.
+///
+/// It is radial: the distance to the center is used, instead of each axis
+/// separately. This keeps diagonal movement smooth. The remaining range is
+/// rescaled, so values start at `0.0` right outside the dead zone.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeadZone {
+    inner: f32,
+    outer: f32,
+}
+
+impl DeadZone {
+    /// A [`DeadZone`] that does not ignore any input.
+    ///
+    /// [`DeadZone`]: struct.DeadZone.html
+    pub const NONE: DeadZone = DeadZone {
+        inner: 0.0,
+        outer: 1.0,
+    };
+
+    /// Creates a radial [`DeadZone`] that ignores input closer to the
+    /// center than the given distance, in the `[0.0, 1.0]` interval.
+    ///
+    /// [`DeadZone`]: struct.DeadZone.html
+    pub fn radial(inner: f32) -> DeadZone {
+        DeadZone {
+            inner: inner.max(0.0).min(1.0),
+            outer: 1.0,
+        }
+    }
+
+    /// Sets the distance to the center from which input is considered to be
+    /// at its maximum.
+    ///
+    /// Worn sticks often fail to reach the edges, so a value slightly below
+    /// `1.0` is a good idea. By default, it is `1.0`.
+    pub fn outer(mut self, outer: f32) -> DeadZone {
+        self.outer = outer.max(self.inner).min(1.0);
+        self
+    }
+
+    /// Applies the [`DeadZone`] to a single axis value.
+    ///
+    /// [`DeadZone`]: struct.DeadZone.html
+    pub fn apply(self, value: f32) -> f32 {
+        self.rescale(value.abs()) * value.signum()
+    }
+
+    /// Applies the [`DeadZone`] to the position of a stick.
+    ///
+    /// [`DeadZone`]: struct.DeadZone.html
+    pub fn apply_2d(self, position: Vector) -> Vector {
+        let distance = position.norm();
+
+        if distance == 0.0 {
+            return position;
+        }
+
+        position * (self.rescale(distance) / distance)
+    }
+
+    fn rescale(self, distance: f32) -> f32 {
+        if distance <= self.inner {
+            0.0
+        } else if distance >= self.outer {
+            1.0
+        } else {
+            (distance - self.inner) / (self.outer - self.inner)
+        }
+    }
+}
+
+impl Default for DeadZone {
+    fn default() -> DeadZone {
+        DeadZone::NONE
+    }
+}
+
+/// How the distance of a stick to its center maps to movement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResponseCurve {
+    /// Movement is proportional to the distance.
+    Linear,
+
+    /// Movement grows with the square of the distance, giving more precision
+    /// to small movements.
+    Quadratic,
+
+    /// Movement grows with the cube of the distance.
+    Cubic,
+
+    /// Movement grows with the distance raised to the given power.
+    Power(f32),
+}
+
+impl ResponseCurve {
+    /// Applies the [`ResponseCurve`] to a distance, in the `[0.0, 1.0]`
+    /// interval.
+    ///
+    /// [`ResponseCurve`]: enum.ResponseCurve.html
+    pub fn apply(self, distance: f32) -> f32 {
+        match self {
+            ResponseCurve::Linear => distance,
+            ResponseCurve::Quadratic => distance * distance,
+            ResponseCurve::Cubic => distance * distance * distance,
+            ResponseCurve::Power(exponent) => distance.powf(exponent),
+        }
+    }
+}
+
+impl Default for ResponseCurve {
+    fn default() -> ResponseCurve {
+        ResponseCurve::Linear
+    }
+}
+
+/// The keys used to produce digital movement in an [`Axis2D`].
+///
+/// [`Axis2D`]: struct.Axis2D.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Keys {
+    /// The key that moves left.
+    pub left: KeyCode,
+
+    /// The key that moves right.
+    pub right: KeyCode,
+
+    /// The key that moves up.
+    pub up: KeyCode,
+
+    /// The key that moves down.
+    pub down: KeyCode,
+}
+
+impl Keys {
+    /// The `W`, `A`, `S`, and `D` keys.
+    pub const WASD: Keys = Keys {
+        left: KeyCode::A,
+        right: KeyCode::D,
+        up: KeyCode::W,
+        down: KeyCode::S,
+    };
+
+    /// The arrow keys.
+    pub const ARROWS: Keys = Keys {
+        left: KeyCode::Left,
+        right: KeyCode::Right,
+        up: KeyCode::Up,
+        down: KeyCode::Down,
+    };
+}
+
+/// A two-dimensional axis that produces normalized movement.
+///
+/// Its [`value`] uses screen coordinates: positive `x` points right and
+/// positive `y` points down.
+///
+/// [`value`]: #method.value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Axis2D {
+    position: Vector,
+    is_digital: bool,
+    dead_zone: DeadZone,
+    response_curve: ResponseCurve,
+}
+
+impl Axis2D {
+    /// Creates a new [`Axis2D`] at rest, without a [`DeadZone`] and with a
+    /// linear [`ResponseCurve`].
+    ///
+    /// [`Axis2D`]: struct.Axis2D.html
+    /// [`DeadZone`]: struct.DeadZone.html
+    /// [`ResponseCurve`]: enum.ResponseCurve.html
+    pub fn new() -> Axis2D {
+        Axis2D {
+            position: Vector::new(0.0, 0.0),
+            is_digital: false,
+            dead_zone: DeadZone::NONE,
+            response_curve: ResponseCurve::Linear,
+        }
+    }
+
+    /// Sets the [`DeadZone`] applied to analog input.
+    ///
+    /// [`DeadZone`]: struct.DeadZone.html
+    pub fn dead_zone(mut self, dead_zone: DeadZone) -> Axis2D {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    /// Sets the [`ResponseCurve`] applied to analog input.
+    ///
+    /// [`ResponseCurve`]: enum.ResponseCurve.html
+    pub fn response_curve(mut self, response_curve: ResponseCurve) -> Axis2D {
+        self.response_curve = response_curve;
+        self
+    }
+
+    /// Sets the analog horizontal value, in the `[-1.0, 1.0]` interval.
+    pub fn set_x(&mut self, x: f32) {
+        self.position.x = x;
+        self.is_digital = false;
+    }
+
+    /// Sets the analog vertical value, in the `[-1.0, 1.0]` interval.
+    ///
+    /// Positive values point down.
+    pub fn set_y(&mut self, y: f32) {
+        self.position.y = y;
+        self.is_digital = false;
+    }
+
+    /// Sets the digital direction from the state of four buttons.
+    ///
+    /// Opposite directions cancel each other and diagonals are normalized,
+    /// so moving diagonally is not faster.
+    pub fn set_digital(
+        &mut self,
+        left: bool,
+        right: bool,
+        up: bool,
+        down: bool,
+    ) {
+        let axis = |negative: bool, positive: bool| {
+            (positive as i8 - negative as i8) as f32
+        };
+
+        self.position = Vector::new(axis(left, right), axis(up, down));
+        self.is_digital = true;
+    }
+
+    /// Sets the digital direction from the given [`Keys`] of a [`Keyboard`].
+    ///
+    /// [`Keys`]: struct.Keys.html
+    /// [`Keyboard`]: ../struct.Keyboard.html
+    pub fn set_keys(&mut self, keyboard: &Keyboard, keys: Keys) {
+        self.set_digital(
+            keyboard.is_key_pressed(keys.left),
+            keyboard.is_key_pressed(keys.right),
+            keyboard.is_key_pressed(keys.up),
+            keyboard.is_key_pressed(keys.down),
+        );
+    }
+
+    /// Returns the movement of the [`Axis2D`].
+    ///
+    /// Its length is always in the `[0.0, 1.0]` interval.
+    ///
+    /// [`Axis2D`]: struct.Axis2D.html
+    pub fn value(&self) -> Vector {
+        let distance = self.position.norm();
+
+        if distance == 0.0 {
+            return Vector::new(0.0, 0.0);
+        }
+
+        let direction = self.position / distance;
+
+        if self.is_digital {
+            return direction;
+        }
+
+        let distance = self.dead_zone.rescale(distance.min(1.0));
+
+        direction * self.response_curve.apply(distance)
+    }
+}
+
+impl Default for Axis2D {
+    fn default() -> Axis2D {
+        Axis2D::new()
+    }
+}
@@ -1,4 +1,5 @@
-use crate::input::{gamepad, keyboard, mouse, window};
+use crate::graphics::Point;
+use crate::input::{gamepad, keyboard, mouse, touch, window};
 
 use std::time::SystemTime;
 
@@ -13,6 +14,10 @@ use std::time::SystemTime;
 /// [`Game::Input`]: ../trait.Game.html#associatedtype.Input
 /// [`Input`]: trait.Input.html
 #[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
@@ -32,6 +37,18 @@ pub enum Event {
         time: SystemTime,
     },
 
+    /// A touch event
+    Touch {
+        /// The finger identifier
+        id: touch::Id,
+
+        /// The phase of the touch
+        phase: touch::Phase,
+
+        /// The position of the finger
+        position: Point,
+    },
+
     /// A window event
     Window(window::Event),
 }
@@ -9,46 +9,131 @@ pub use gilrs::Button;
 
 use gilrs::Gilrs;
 use std::convert::TryInto;
-use std::time::SystemTime;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+// How often gamepads are polled when using a background thread. Polling at
+// 250 Hz catches presses shorter than a frame without keeping a core busy.
+const POLL_INTERVAL: Duration = Duration::from_millis(4);
 
 /// A gamepad identifier.
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub struct Id(gilrs::GamepadId);
 
 pub(crate) struct Tracker {
-    context: Gilrs,
+    source: Source,
+}
+
+enum Source {
+    Context(Gilrs),
+    Thread {
+        events: mpsc::Receiver<(Id, Event, SystemTime)>,
+        // Dropping it stops the thread
+        _stop: mpsc::Sender<()>,
+    },
 }
 
 impl Tracker {
     pub fn new() -> Option<Tracker> {
-        match Gilrs::new() {
-            Ok(context) => Some(Tracker { context }),
-            Err(gilrs::Error::NotImplemented(dummy_context)) => {
-                // Use the dummy context as a fallback on unsupported platforms
-                Some(Tracker {
-                    context: dummy_context,
-                })
-            }
-            _ => {
-                // Either `gilrs::error::InvalidAxisToBtn` has occured, or a
-                // platform specific error has occured.
-                None
-            }
+        Some(Tracker {
+            source: Source::Context(context()?),
+        })
+    }
+
+    /// Polls gamepads on a dedicated thread, queueing their events until
+    /// they are consumed with `next_event`.
+    ///
+    /// Some backends sample the state of the gamepads when polled, so polling
+    /// in the background avoids losing quick presses while a frame is drawn.
+    ///
+    /// The thread stops once the `Tracker` is dropped.
+    pub fn spawn() -> Option<Tracker> {
+        let (sender, receiver) = mpsc::channel();
+        let (ready, is_ready) = mpsc::sync_channel(1);
+        let (stop, should_stop) = mpsc::channel::<()>();
+
+        // The context is created in the thread, as it may not be `Send`
+        let _ = thread::Builder::new()
+            .name(String::from("coffee-gamepads"))
+            .spawn(move || {
+                let mut context = match context() {
+                    Some(context) => {
+                        let _ = ready.send(true);
+                        context
+                    }
+                    None => {
+                        let _ = ready.send(false);
+                        return;
+                    }
+                };
+
+                loop {
+                    while let Some(event) = next_event(&mut context) {
+                        if sender.send(event).is_err() {
+                            // The tracker was dropped
+                            return;
+                        }
+                    }
+
+                    // Waiting on the channel wakes the thread up as soon as
+                    // the tracker is dropped, even when no events arrive
+                    match should_stop.recv_timeout(POLL_INTERVAL) {
+                        Err(mpsc::RecvTimeoutError::Timeout) => {}
+                        _ => return,
+                    }
+                }
+            })
+            .ok()?;
+
+        if is_ready.recv().unwrap_or(false) {
+            Some(Tracker {
+                source: Source::Thread {
+                    events: receiver,
+                    _stop: stop,
+                },
+            })
+        } else {
+            None
         }
     }
 
     pub fn next_event(&mut self) -> Option<(Id, Event, SystemTime)> {
-        while let Some(gilrs::Event { id, event, time }) =
-            self.context.next_event()
-        {
-            match event.try_into() {
-                Ok(gamepad_event) => {
-                    return Some((Id(id), gamepad_event, time));
-                }
-                Err(_) => {}
-            }
+        match &mut self.source {
+            Source::Context(context) => next_event(context),
+            Source::Thread { events, .. } => events.try_recv().ok(),
+        }
+    }
+}
+
+fn context() -> Option<Gilrs> {
+    match Gilrs::new() {
+        Ok(context) => Some(context),
+        Err(gilrs::Error::NotImplemented(dummy_context)) => {
+            // Use the dummy context as a fallback on unsupported platforms
+            Some(dummy_context)
         }
+        _ => {
+            // Either `gilrs::error::InvalidAxisToBtn` has occured, or a
+            // platform specific error has occured.
+            None
+        }
+    }
+}
 
-        None
+fn next_event(context: &mut Gilrs) -> Option<(Id, Event, SystemTime)> {
+    while let Some(gilrs::Event { id, event, time }) = context.next_event() {
+        match event.try_into() {
+            Ok(gamepad_event) => {
+                return Some((Id(id), gamepad_event, time));
+            }
+            Err(_) => {}
+        }
     }
+
+    None
 }
@@ -4,6 +4,10 @@ use std::convert::TryFrom;
 
 /// A gamepad event.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// A gamepad was connected.
     Connected,
@@ -1,9 +1,12 @@
 //! Listen to keyboard events.
 
 mod event;
+mod modifiers;
 
+pub use crate::graphics::window::winit::event::ScanCode;
 pub use crate::graphics::window::winit::event::VirtualKeyCode as KeyCode;
 pub use event::Event;
+pub use modifiers::Modifiers;
 
 use super::{ButtonState, Event as InputEvent, Input};
 
@@ -18,6 +21,8 @@ use std::collections::HashSet;
 pub struct Keyboard {
     pressed_keys: HashSet<KeyCode>,
     released_keys: HashSet<KeyCode>,
+    pressed_scancodes: HashSet<ScanCode>,
+    released_scancodes: HashSet<ScanCode>,
 }
 
 impl Keyboard {
@@ -30,6 +35,25 @@ impl Keyboard {
     pub fn was_key_released(&self, key_code: KeyCode) -> bool {
         self.released_keys.contains(&key_code)
     }
+
+    /// Returns true if the key with the given scan code is currently pressed.
+    ///
+    /// Scan codes identify the physical position of a key, regardless of the
+    /// keyboard layout. Use them for bindings that depend on the placement of
+    /// the keys, like `WASD` movement. Scan codes are platform-specific, so
+    /// you will probably want to obtain them from a [`keyboard::Event`] while
+    /// letting the player configure the bindings.
+    ///
+    /// [`keyboard::Event`]: enum.Event.html
+    pub fn is_scancode_pressed(&self, scancode: ScanCode) -> bool {
+        self.pressed_scancodes.contains(&scancode)
+    }
+
+    /// Returns true if the key with the given scan code was released during
+    /// the last interaction.
+    pub fn was_scancode_released(&self, scancode: ScanCode) -> bool {
+        self.released_scancodes.contains(&scancode)
+    }
 }
 
 impl Input for Keyboard {
@@ -37,6 +61,8 @@ impl Input for Keyboard {
         Keyboard {
             pressed_keys: HashSet::new(),
             released_keys: HashSet::new(),
+            pressed_scancodes: HashSet::new(),
+            released_scancodes: HashSet::new(),
         }
     }
 
@@ -46,14 +72,22 @@ impl Input for Keyboard {
                 // Ignore mouse events...
             }
             InputEvent::Keyboard(keyboard_event) => match keyboard_event {
-                Event::Input { key_code, state } => {
+                Event::Input {
+                    key_code,
+                    scancode,
+                    state,
+                } => {
                     match state {
                         ButtonState::Pressed => {
                             let _ = self.pressed_keys.insert(key_code);
+                            let _ = self.pressed_scancodes.insert(scancode);
                         }
                         ButtonState::Released => {
                             let _ = self.pressed_keys.remove(&key_code);
                             let _ = self.released_keys.insert(key_code);
+
+                            let _ = self.pressed_scancodes.remove(&scancode);
+                            let _ = self.released_scancodes.insert(scancode);
                         }
                     };
                 }
@@ -62,6 +96,9 @@ impl Input for Keyboard {
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
+            InputEvent::Touch { .. } => {
+                // Ignore touch events...
+            }
             InputEvent::Window { .. } => {
                 // Ignore window events...
             }
@@ -70,5 +107,6 @@ impl Input for Keyboard {
 
     fn clear(&mut self) {
         self.released_keys.clear();
+        self.released_scancodes.clear();
     }
 }
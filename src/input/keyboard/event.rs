@@ -1,8 +1,12 @@
-use super::KeyCode;
+use super::{KeyCode, ScanCode};
 use crate::input::ButtonState;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// A keyboard event.
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// A keyboard key was pressed or released.
     Input {
@@ -11,6 +15,12 @@ pub enum Event {
 
         /// The key identifier
         key_code: KeyCode,
+
+        /// The hardware scan code of the key.
+        ///
+        /// Scan codes identify the physical position of a key, regardless of
+        /// the keyboard layout.
+        scancode: ScanCode,
     },
 
     /// Text was entered.
@@ -0,0 +1,117 @@
+use super::{Event, KeyCode};
+use crate::input::ButtonState;
+
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of modifier keys, like `Ctrl` or `Shift`.
+///
+/// Combine them with `|`:
+///
+/// ```
+/// use coffee::input::keyboard::Modifiers;
+///
+/// let redo = Modifiers::CTRL | Modifiers::SHIFT;
+///
+/// assert!(redo.contains(Modifiers::CTRL));
+/// assert!(!redo.contains(Modifiers::ALT));
+/// ```
+///
+/// Left and right modifier keys are not told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Modifiers {
+    bits: u8,
+}
+
+impl Modifiers {
+    /// No modifier keys.
+    pub const NONE: Modifiers = Modifiers { bits: 0 };
+
+    /// The `Shift` key.
+    pub const SHIFT: Modifiers = Modifiers { bits: 1 };
+
+    /// The `Ctrl` key.
+    pub const CTRL: Modifiers = Modifiers { bits: 1 << 1 };
+
+    /// The `Alt` key.
+    pub const ALT: Modifiers = Modifiers { bits: 1 << 2 };
+
+    /// The logo key, like the `Windows` key or the `Command` key on macOS.
+    pub const LOGO: Modifiers = Modifiers { bits: 1 << 3 };
+
+    /// Returns true if all the given modifiers are part of the set.
+    pub fn contains(self, modifiers: Modifiers) -> bool {
+        self.bits & modifiers.bits == modifiers.bits
+    }
+
+    /// Returns true if the set contains no modifier keys.
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns the modifier represented by the given key, if it is one.
+    pub fn from_key_code(key_code: KeyCode) -> Option<Modifiers> {
+        match key_code {
+            KeyCode::LShift | KeyCode::RShift => Some(Modifiers::SHIFT),
+            KeyCode::LControl | KeyCode::RControl => Some(Modifiers::CTRL),
+            KeyCode::LAlt | KeyCode::RAlt => Some(Modifiers::ALT),
+            KeyCode::LWin | KeyCode::RWin => Some(Modifiers::LOGO),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn update(&mut self, event: Event) {
+        if let Event::Input {
+            state, key_code, ..
+        } = event
+        {
+            if let Some(modifier) = Modifiers::from_key_code(key_code) {
+                match state {
+                    ButtonState::Pressed => self.bits |= modifier.bits,
+                    ButtonState::Released => self.bits &= !modifier.bits,
+                }
+            }
+        }
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Modifiers;
+
+    fn bitor(self, other: Modifiers) -> Modifiers {
+        Modifiers {
+            bits: self.bits | other.bits,
+        }
+    }
+}
+
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, other: Modifiers) {
+        self.bits |= other.bits;
+    }
+}
+
+impl std::fmt::Display for Modifiers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names = [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::LOGO, "Logo"),
+        ];
+
+        let mut names = names
+            .iter()
+            .filter(|(modifier, _)| self.contains(*modifier))
+            .map(|(_, name)| name);
+
+        if let Some(first) = names.next() {
+            write!(f, "{}", first)?;
+        }
+
+        for name in names {
+            write!(f, "+{}", name)?;
+        }
+
+        Ok(())
+    }
+}
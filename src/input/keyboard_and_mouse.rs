@@ -1,6 +1,7 @@
-use super::keyboard::Keyboard;
+use super::keyboard::{Keyboard, ScanCode};
 use super::mouse::Mouse;
 use super::{Event, Input};
+use crate::graphics::{Point, Transformation};
 
 /// A simple keyboard and mouse input tracker.
 ///
@@ -21,12 +22,36 @@ impl KeyboardAndMouse {
         &self.mouse
     }
 
+    /// Returns the current cursor position in the coordinates of a
+    /// [`Target`] transformed with the given [`Transformation`].
+    ///
+    /// See [`Mouse::cursor_position_in`].
+    ///
+    /// [`Target`]: ../graphics/struct.Target.html
+    /// [`Transformation`]: ../graphics/struct.Transformation.html
+    /// [`Mouse::cursor_position_in`]: mouse/struct.Mouse.html#method.cursor_position_in
+    pub fn cursor_position_in(
+        &self,
+        transformation: &Transformation,
+    ) -> Option<Point> {
+        self.mouse.cursor_position_in(transformation)
+    }
+
     /// Returns the [`Keyboard`] input.
     ///
     /// [`Keyboard`]: keyboard/struct.Keyboard.html
     pub fn keyboard(&self) -> &Keyboard {
         &self.keyboard
     }
+
+    /// Returns true if the key with the given scan code is currently pressed.
+    ///
+    /// See [`Keyboard::is_scancode_pressed`].
+    ///
+    /// [`Keyboard::is_scancode_pressed`]: keyboard/struct.Keyboard.html#method.is_scancode_pressed
+    pub fn is_scancode_pressed(&self, scancode: ScanCode) -> bool {
+        self.keyboard.is_scancode_pressed(scancode)
+    }
 }
 
 impl Input for KeyboardAndMouse {
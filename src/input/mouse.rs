@@ -8,7 +8,7 @@ pub use event::Event;
 pub use wheel_movement::WheelMovement;
 
 use super::{ButtonState, Event as InputEvent, Input};
-use crate::graphics::Point;
+use crate::graphics::{Point, Transformation, Vector};
 
 use std::collections::{HashMap, HashSet};
 
@@ -20,6 +20,7 @@ use std::collections::{HashMap, HashSet};
 #[derive(Debug, Clone)]
 pub struct Mouse {
     cursor_position: Point,
+    motion: Vector,
     wheel_movement: WheelMovement,
     is_cursor_taken: bool,
     is_cursor_within_window: bool,
@@ -33,6 +34,32 @@ impl Mouse {
         self.cursor_position
     }
 
+    /// Returns the current cursor position in the coordinates of a
+    /// [`Target`] transformed with the given [`Transformation`].
+    ///
+    /// This is useful to find what is under the cursor when drawing with a
+    /// camera that pans, zooms or rotates the world. It returns `None` if
+    /// the [`Transformation`] cannot be inverted.
+    ///
+    /// [`Target`]: ../../graphics/struct.Target.html
+    /// [`Transformation`]: ../../graphics/struct.Transformation.html
+    pub fn cursor_position_in(
+        &self,
+        transformation: &Transformation,
+    ) -> Option<Point> {
+        transformation
+            .inverse()
+            .map(|inverse| inverse.transform_point(self.cursor_position))
+    }
+
+    /// Returns the raw movement of the mouse during the last interaction.
+    ///
+    /// It is not affected by the acceleration of the operating system nor by
+    /// the bounds of the window.
+    pub fn motion(&self) -> Vector {
+        self.motion
+    }
+
     /// Returns the wheel movements during the last interaction.
     pub fn wheel_movement(&self) -> WheelMovement {
         self.wheel_movement
@@ -76,6 +103,7 @@ impl Input for Mouse {
     fn new() -> Mouse {
         Mouse {
             cursor_position: Point::new(0.0, 0.0),
+            motion: Vector::new(0.0, 0.0),
             wheel_movement: WheelMovement::new(0.0, 0.0),
             is_cursor_taken: false,
             is_cursor_within_window: false,
@@ -90,6 +118,9 @@ impl Input for Mouse {
                 Event::CursorMoved { x, y } => {
                     self.cursor_position = Point::new(x, y);
                 }
+                Event::Motion { delta_x, delta_y } => {
+                    self.motion += Vector::new(delta_x, delta_y);
+                }
                 Event::CursorTaken => {
                     self.is_cursor_taken = true;
                 }
@@ -134,6 +165,9 @@ impl Input for Mouse {
             InputEvent::Gamepad { .. } => {
                 // Ignore gamepad events...
             }
+            InputEvent::Touch { .. } => {
+                // Ignore touch events...
+            }
             InputEvent::Window { .. } => {
                 // Ignore window events...
             }
@@ -144,5 +178,6 @@ impl Input for Mouse {
         self.button_clicks.values_mut().for_each(Vec::clear);
         self.wheel_movement.horizontal = 0.0;
         self.wheel_movement.vertical = 0.0;
+        self.motion = Vector::new(0.0, 0.0);
     }
 }
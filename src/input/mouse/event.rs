@@ -3,6 +3,10 @@ use crate::input::ButtonState;
 
 /// A mouse event.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// The mouse cursor was moved
     CursorMoved {
@@ -13,6 +17,21 @@ pub enum Event {
         y: f32,
     },
 
+    /// The mouse was moved, as reported by the device.
+    ///
+    /// Unlike [`CursorMoved`], the deltas are not affected by the
+    /// acceleration of the operating system nor by the bounds of the window.
+    /// This is useful to control a camera or to aim with a grabbed cursor.
+    ///
+    /// [`CursorMoved`]: #variant.CursorMoved
+    Motion {
+        /// The horizontal movement, in device units
+        delta_x: f32,
+
+        /// The vertical movement, in device units
+        delta_y: f32,
+    },
+
     /// The mouse cursor entered the game window.
     CursorEntered,
 
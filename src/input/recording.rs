@@ -0,0 +1,197 @@
+use super::{Event, Input};
+
+/// A sequence of input events, each one tagged with the index of the
+/// interaction it happened in.
+///
+/// You can obtain a [`Recording`] with a [`Recorder`] and play it back with
+/// a [`ReplayInput`]. When the `serde-serialize` feature is enabled, it can be
+/// serialized and saved to a file.
+///
+/// [`Recording`]: struct.Recording.html
+/// [`Recorder`]: struct.Recorder.html
+/// [`ReplayInput`]: struct.ReplayInput.html
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Recording {
+    events: Vec<(u64, Event)>,
+    interactions: u64,
+}
+
+impl Recording {
+    /// Returns the recorded events along with the index of the interaction
+    /// they happened in.
+    pub fn events(&self) -> &[(u64, Event)] {
+        &self.events
+    }
+
+    /// Returns the amount of interactions covered by the [`Recording`].
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn interactions(&self) -> u64 {
+        self.interactions
+    }
+}
+
+/// An [`Input`] wrapper that records every event it receives.
+///
+/// Events are tagged with the index of the interaction they happened in, which
+/// increases every time [`Game::interact`] is called. Combined with a fixed
+/// timestep, this allows you to reproduce a play session by feeding the
+/// obtained [`Recording`] to a [`ReplayInput`].
+///
+/// [`Input`]: trait.Input.html
+/// [`Game::interact`]: ../trait.Game.html#method.interact
+/// [`Recording`]: struct.Recording.html
+/// [`ReplayInput`]: struct.ReplayInput.html
+#[derive(Debug)]
+pub struct Recorder<I: Input> {
+    input: I,
+    recording: Recording,
+}
+
+impl<I: Input> Recorder<I> {
+    /// Returns the wrapped [`Input`].
+    ///
+    /// [`Input`]: trait.Input.html
+    pub fn input(&self) -> &I {
+        &self.input
+    }
+
+    /// Returns the wrapped [`Input`] mutably.
+    ///
+    /// [`Input`]: trait.Input.html
+    pub fn input_mut(&mut self) -> &mut I {
+        &mut self.input
+    }
+
+    /// Returns the [`Recording`] produced so far.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+}
+
+impl<I: Input> Input for Recorder<I> {
+    fn new() -> Recorder<I> {
+        Recorder {
+            input: I::new(),
+            recording: Recording::default(),
+        }
+    }
+
+    fn update(&mut self, event: Event) {
+        self.recording
+            .events
+            .push((self.recording.interactions, event));
+
+        self.input.update(event);
+    }
+
+    fn clear(&mut self) {
+        self.input.clear();
+        self.recording.interactions += 1;
+    }
+}
+
+/// An [`Input`] wrapper that plays back a [`Recording`].
+///
+/// While a [`Recording`] is playing, the events of the player are ignored and
+/// the recorded events are fed to the wrapped [`Input`] right before the
+/// interaction they happened in. Once the [`Recording`] finishes, the events
+/// of the player are processed again.
+///
+/// [`Input`]: trait.Input.html
+/// [`Recording`]: struct.Recording.html
+#[derive(Debug)]
+pub struct ReplayInput<I: Input> {
+    input: I,
+    recording: Option<Recording>,
+    interaction: u64,
+    next_event: usize,
+}
+
+impl<I: Input> ReplayInput<I> {
+    /// Starts playing the given [`Recording`] from the next interaction.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn play(&mut self, recording: Recording) {
+        self.recording = Some(recording);
+        self.interaction = 0;
+        self.next_event = 0;
+
+        self.feed();
+    }
+
+    /// Returns true if a [`Recording`] is being played.
+    ///
+    /// [`Recording`]: struct.Recording.html
+    pub fn is_playing(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Returns the wrapped [`Input`].
+    ///
+    /// [`Input`]: trait.Input.html
+    pub fn input(&self) -> &I {
+        &self.input
+    }
+
+    /// Returns the wrapped [`Input`] mutably.
+    ///
+    /// [`Input`]: trait.Input.html
+    pub fn input_mut(&mut self) -> &mut I {
+        &mut self.input
+    }
+
+    fn feed(&mut self) {
+        let recording = match &self.recording {
+            Some(recording) => recording,
+            None => return,
+        };
+
+        while let Some((interaction, event)) =
+            recording.events.get(self.next_event)
+        {
+            if *interaction > self.interaction {
+                break;
+            }
+
+            self.input.update(*event);
+            self.next_event += 1;
+        }
+
+        if self.interaction >= recording.interactions {
+            self.recording = None;
+        }
+    }
+}
+
+impl<I: Input> Input for ReplayInput<I> {
+    fn new() -> ReplayInput<I> {
+        ReplayInput {
+            input: I::new(),
+            recording: None,
+            interaction: 0,
+            next_event: 0,
+        }
+    }
+
+    fn update(&mut self, event: Event) {
+        if self.recording.is_none() {
+            self.input.update(event);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.input.clear();
+
+        if self.recording.is_some() {
+            self.interaction += 1;
+            self.feed();
+        }
+    }
+}
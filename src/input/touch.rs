@@ -0,0 +1,104 @@
+//! Listen to touch events.
+
+pub use crate::graphics::window::winit::event::TouchPhase as Phase;
+
+use super::{Event as InputEvent, Input};
+use crate::graphics::Point;
+
+use std::collections::HashMap;
+
+/// A touch identifier.
+///
+/// It is unique for every finger while it touches the screen.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Id(u64);
+
+impl Id {
+    pub(crate) fn new(id: u64) -> Id {
+        Id(id)
+    }
+}
+
+/// A simple touch input tracker.
+///
+/// It keeps track of the fingers touching the screen. You can use this as
+/// your [`Game::Input`] directly!
+///
+/// [`Game::Input`]: ../../trait.Game.html#associatedtype.Input
+#[derive(Debug, Clone)]
+pub struct Touches {
+    active: HashMap<Id, Point>,
+    taps: Vec<Point>,
+}
+
+impl Touches {
+    /// Returns the position of the given finger, if it is touching the
+    /// screen.
+    pub fn position(&self, id: Id) -> Option<Point> {
+        self.active.get(&id).cloned()
+    }
+
+    /// Returns an iterator over the fingers touching the screen and their
+    /// positions.
+    pub fn active(&self) -> impl Iterator<Item = (Id, Point)> + '_ {
+        self.active.iter().map(|(id, position)| (*id, *position))
+    }
+
+    /// Returns the amount of fingers touching the screen.
+    pub fn len(&self) -> usize {
+        self.active.len()
+    }
+
+    /// Returns true if no finger is touching the screen.
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    /// Returns the positions where fingers were lifted during the last
+    /// interaction.
+    ///
+    /// Touches cancelled by the system are not included.
+    pub fn taps(&self) -> &[Point] {
+        &self.taps
+    }
+}
+
+impl Input for Touches {
+    fn new() -> Touches {
+        Touches {
+            active: HashMap::new(),
+            taps: Vec::new(),
+        }
+    }
+
+    fn update(&mut self, event: InputEvent) {
+        match event {
+            InputEvent::Touch {
+                id,
+                phase,
+                position,
+            } => match phase {
+                Phase::Started | Phase::Moved => {
+                    let _ = self.active.insert(id, position);
+                }
+                Phase::Ended => {
+                    let _ = self.active.remove(&id);
+
+                    self.taps.push(position);
+                }
+                Phase::Cancelled => {
+                    let _ = self.active.remove(&id);
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn clear(&mut self) {
+        self.taps.clear();
+    }
+}
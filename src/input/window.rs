@@ -2,4 +2,4 @@
 
 mod event;
 
-pub use event::Event;
+pub use event::{Event, NewSize};
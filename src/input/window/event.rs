@@ -1,5 +1,9 @@
 /// A window event.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 pub enum Event {
     /// The game window gained focus.
     Focused,
@@ -15,4 +19,54 @@ pub enum Event {
         /// The new Y coordinate of the window
         y: f32,
     },
+
+    /// The game window was resized.
+    ///
+    /// While the window is being resized interactively, only its last size
+    /// of every frame is delivered.
+    Resized(NewSize),
+
+    /// The DPI factor of the game window changed.
+    ///
+    /// This happens when the window is moved to a monitor with a different
+    /// pixel density, or when the display settings change.
+    DpiChanged(f64),
+
+    /// The display mode of the game window changed.
+    ///
+    /// This happens when the window is resized or when it enters or leaves
+    /// fullscreen. Use it to lay out your game again.
+    DisplayChanged {
+        /// The new width of the window
+        width: f32,
+
+        /// The new height of the window
+        height: f32,
+
+        /// Whether the window is now in fullscreen mode
+        is_fullscreen: bool,
+    },
+}
+
+/// The size of a game window after being resized.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct NewSize {
+    /// The new width of the window, in logical pixels
+    pub logical_width: f32,
+
+    /// The new height of the window, in logical pixels
+    pub logical_height: f32,
+
+    /// The new width of the window, in physical pixels
+    pub physical_width: u32,
+
+    /// The new height of the window, in physical pixels
+    pub physical_height: u32,
+
+    /// The DPI factor of the window
+    pub dpi: f64,
 }
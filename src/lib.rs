@@ -40,8 +40,9 @@
 //!         title: String::from("A caffeinated game"),
 //!         size: (1280, 1024),
 //!         resizable: true,
-//!         fullscreen: false,
+//!         fullscreen: None,
 //!         maximized: false,
+//!         ..WindowSettings::default()
 //!     })
 //! }
 //!
@@ -76,6 +77,9 @@
 #![deny(unsafe_code)]
 #![deny(rust_2018_idioms)]
 
+#[macro_use]
+mod trace;
+
 mod debug;
 mod game;
 mod result;
@@ -84,9 +88,12 @@ mod timer;
 pub mod graphics;
 pub mod input;
 pub mod load;
+pub mod rand;
+pub mod scene;
+pub mod tween;
 pub mod ui;
 
 pub use debug::Debug;
-pub use game::Game;
+pub use game::{ControlFlow, FrameTiming, Game, LoopSettings, Systems};
 pub use result::{Error, Result};
 pub use timer::Timer;
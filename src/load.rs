@@ -15,12 +15,29 @@
 //! compatible with any [`Task`]. Currently, Coffee includes a built-in loading
 //! screen: [`ProgressBar`], which shows a simple progress bar with some text.
 //!
+//! # Streaming
+//! Once your game is running, you can keep loading resources without blocking
+//! using a [`Stream`], which runs a queue of tasks incrementally.
+//!
+//! # Resuming
+//! A [`Journal`] records the stages of a [`Task`] on disk while it runs. If
+//! your game crashes while loading, the next run can find out which stages
+//! were completed and which one failed.
+//!
 //! [`Task`]: struct.Task.html
+//! [`Stream`]: struct.Stream.html
+//! [`Journal`]: struct.Journal.html
 //! [`LoadingScreen`]: loading_screen/trait.LoadingScreen.html
 //! [`ProgressBar`]: loading_screen/struct.ProgressBar.html
+mod journal;
+mod stream;
 mod task;
 
 pub mod loading_screen;
 
+pub use journal::Journal;
 pub use loading_screen::LoadingScreen;
-pub use task::{Join, Progress, Task};
+pub use stream::{Step, Stream};
+pub use task::{
+    clear_cache, Join, Progress, ScopedJoin, ScopedTask, Stage, Task,
+};
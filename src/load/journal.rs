@@ -0,0 +1,275 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+thread_local! {
+    static ACTIVE: RefCell<Option<File>> = RefCell::new(None);
+}
+
+/// A record on disk of the stages of a [`Task`] that have been completed.
+///
+/// A journaled [`Task`] writes an entry every time one of its stages starts
+/// or finishes. If the game crashes while loading, the next run can open the
+/// [`Journal`] to find out which stages were completed and which one was
+/// being run when the crash happened.
+///
+/// You can use this to skip processing assets that were already processed
+/// and saved to disk before the crash. Along with [`Task::cached`], this
+/// avoids doing the same work twice when resuming:
+///
+/// ```
+/// # use coffee::load::{Journal, Task};
+/// # use coffee::graphics::Image;
+/// # use coffee::Result;
+/// #
+/// # fn bake_atlas() -> Task<Image> { Image::load("atlas.png") }
+/// #
+/// fn load_atlas() -> Result<Task<Image>> {
+///     let journal = Journal::open("loading.journal")?;
+///
+///     if let Some(stage) = journal.crashed_stage() {
+///         eprintln!("Last loading crashed at: {}", stage);
+///     }
+///
+///     let atlas = if journal.is_completed("Baking atlas...") {
+///         Image::load("cache/atlas.png")
+///     } else {
+///         Task::stage("Baking atlas...", bake_atlas())
+///     };
+///
+///     Ok(Task::cached("atlas", atlas).journaled(journal))
+/// }
+/// ```
+///
+/// Stages are identified by their title, so keep them unique if you plan to
+/// resume them.
+///
+/// [`Task`]: struct.Task.html
+/// [`Journal`]: struct.Journal.html
+/// [`Task::cached`]: struct.Task.html#method.cached
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Journal {
+    path: PathBuf,
+    completed: HashSet<String>,
+    unfinished: Vec<String>,
+}
+
+impl Journal {
+    /// Opens the [`Journal`] stored at the given path.
+    ///
+    /// If the file does not exist, the [`Journal`] starts empty. It will be
+    /// created once a [`Task`] using it is run.
+    ///
+    /// Every line of the file records a stage that was either started or
+    /// completed. A completed stage also completes the stages started inside
+    /// of it, so only the ones that never finished are left:
+    ///
+    /// ```
+    /// use coffee::load::Journal;
+    ///
+    /// # fn main() -> coffee::Result<()> {
+    /// let path = std::env::temp_dir().join("coffee-doc-open.journal");
+    ///
+    /// std::fs::write(
+    ///     &path,
+    ///     "started Loading...\n\
+    ///      started Baking atlas...\n\
+    ///      completed Baking atlas...\n\
+    ///      started Compiling shaders...\n\
+    ///      started Compiling sky.frag...\n",
+    /// )
+    /// .expect("Write journal");
+    ///
+    /// let journal = Journal::open(&path)?;
+    ///
+    /// assert!(journal.is_completed("Baking atlas..."));
+    /// assert!(!journal.is_completed("Loading..."));
+    /// assert_eq!(journal.crashed_stage(), Some("Compiling sky.frag..."));
+    /// # std::fs::remove_file(&path).expect("Remove journal");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Journal`]: struct.Journal.html
+    /// [`Task`]: struct.Task.html
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Journal> {
+        let path = path.as_ref().to_path_buf();
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => {
+                String::new()
+            }
+            Err(error) => return Err(Error::File(path, error)),
+        };
+
+        let mut completed = HashSet::new();
+        let mut unfinished: Vec<String> = Vec::new();
+
+        for line in contents.lines() {
+            if line.starts_with(STARTED) {
+                unfinished.push(String::from(&line[STARTED.len()..]));
+            } else if line.starts_with(COMPLETED) {
+                let title = &line[COMPLETED.len()..];
+
+                if let Some(i) = unfinished.iter().rposition(|t| t == title) {
+                    unfinished.truncate(i);
+                }
+
+                let _ = completed.insert(String::from(title));
+            }
+        }
+
+        Ok(Journal {
+            path,
+            completed,
+            unfinished,
+        })
+    }
+
+    /// Returns the path of the [`Journal`].
+    ///
+    /// [`Journal`]: struct.Journal.html
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns true if a stage with the given title was completed in a
+    /// previous run.
+    pub fn is_completed(&self, stage: &str) -> bool {
+        self.completed.contains(stage)
+    }
+
+    /// Returns the title of the innermost stage that was started but never
+    /// completed in the previous run, if there is one.
+    ///
+    /// This is the stage that failed or was running when the game crashed.
+    pub fn crashed_stage(&self) -> Option<&str> {
+        self.unfinished.last().map(String::as_str)
+    }
+
+    // Creates the file of the journal with the stages completed so far, so
+    // entries keep piling up across crashes.
+    //
+    // Journaling is best-effort, so the task is run without recording if the
+    // file cannot be created.
+    pub(super) fn begin(&self) -> Recording {
+        let mut file = match File::create(&self.path) {
+            Ok(file) => file,
+            Err(error) => {
+                log::warn!(
+                    "Journal {} could not be created: {}",
+                    self.path.display(),
+                    error
+                );
+
+                return Recording { file: None };
+            }
+        };
+
+        for title in &self.completed {
+            if let Err(error) = writeln!(file, "{}{}", COMPLETED, title) {
+                log::warn!(
+                    "Journal {} could not be written: {}",
+                    self.path.display(),
+                    error
+                );
+
+                break;
+            }
+        }
+
+        Recording { file: Some(file) }
+    }
+
+    // Removes the file once the task succeeds, as there is nothing left to
+    // resume.
+    //
+    // A leftover file only makes the next run skip the completed stages, so
+    // failing to remove it does not fail the task.
+    pub(super) fn finish(&self) {
+        match fs::remove_file(&self.path) {
+            Ok(()) => {}
+            // The file may have never been created
+            Err(ref error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => {
+                log::warn!(
+                    "Journal {} could not be removed: {}",
+                    self.path.display(),
+                    error
+                );
+            }
+        }
+    }
+}
+
+// The open file of a journal, which can outlive a single step of a task.
+pub(super) struct Recording {
+    file: Option<File>,
+}
+
+impl Recording {
+    // Runs the given function while recording the stages it goes through.
+    pub(super) fn record<T, F>(self, f: F) -> (Recording, T)
+    where
+        F: FnOnce() -> T,
+    {
+        let previous = ACTIVE.with(|active| active.replace(self.file));
+        let result = f();
+        let file = ACTIVE.with(|active| active.replace(previous));
+
+        (Recording { file }, result)
+    }
+}
+
+/// Records the start of the stage with the given title in the active
+/// [`Journal`], if there is one.
+///
+/// [`Journal`]: struct.Journal.html
+pub(super) fn start_stage(title: &str) {
+    write(STARTED, title);
+}
+
+/// Records the completion of the stage with the given title in the active
+/// [`Journal`], if there is one.
+///
+/// [`Journal`]: struct.Journal.html
+pub(super) fn complete_stage(title: &str) {
+    write(COMPLETED, title);
+}
+
+/// Wraps an error of the stage with the given title in an [`Error::Stage`],
+/// if a [`Journal`] is active.
+///
+/// Errors already wrapped keep the title of the innermost failing stage.
+///
+/// [`Journal`]: struct.Journal.html
+/// [`Error::Stage`]: ../enum.Error.html#variant.Stage
+pub(super) fn stage_error(title: &str, error: Error) -> Error {
+    let is_recording = ACTIVE.with(|active| active.borrow().is_some());
+
+    match error {
+        Error::Stage { .. } => error,
+        error if is_recording => Error::Stage {
+            title: String::from(title),
+            error: Box::new(error),
+        },
+        error => error,
+    }
+}
+
+// Journaling is best-effort, a failed write should not stop the loading
+fn write(kind: &str, title: &str) {
+    ACTIVE.with(|active| {
+        if let Some(file) = active.borrow_mut().as_mut() {
+            let _ = writeln!(file, "{}{}", kind, title.replace('\n', " "));
+        }
+    });
+}
+
+const STARTED: &str = "started ";
+const COMPLETED: &str = "completed ";
@@ -8,17 +8,35 @@
 //! [`LoadingScreen`] trait.
 //!
 //! If you want a simple placeholder, you can try out the built-in
-//! [`ProgressBar`] loading screen.
+//! [`ProgressBar`] loading screen. If you want to show an image, try the
+//! [`Splash`] loading screen.
+//!
+//! If you want to build your loading screen using widgets, implement the
+//! [`Interface`] trait and use [`WithInterface`].
+//!
+//! Any loading screen can be wrapped in a [`Transition`] to fade it in and
+//! out smoothly, or to [`Crossfade`] it into the first frames of your game.
 //!
 //! [`Task`]: ../struct.Task.html
 //! [`LoadingScreen`]: trait.LoadingScreen.html
 //! [`ProgressBar`]: struct.ProgressBar.html
+//! [`Splash`]: struct.Splash.html
+//! [`Interface`]: trait.Interface.html
+//! [`WithInterface`]: struct.WithInterface.html
+//! [`Transition`]: struct.Transition.html
+//! [`Crossfade`]: struct.Crossfade.html
+mod interface;
 mod progress_bar;
+mod splash;
+mod transition;
 
+pub use interface::{Interface, WithInterface};
 pub use progress_bar::ProgressBar;
+pub use splash::Splash;
+pub use transition::{Crossfade, Transition};
 
 use crate::graphics;
-use crate::load::{Progress, Task};
+use crate::load::{Progress, ScopedTask};
 use crate::Result;
 
 /// A loading screen keeps track of the progress of a task and provides feedback
@@ -29,15 +47,16 @@ use crate::Result;
 /// associated type. Coffee will automatically use it when your game starts!
 ///
 /// # Future plans
-/// As of now, Coffee only ships with the [`ProgressBar`] loading screen. In the
-/// near future, the plan is to add more interesting (and configurable!) loading
-/// screens. If you make a cool loading screen or have an interesting idea and
+/// As of now, Coffee only ships with the [`ProgressBar`] and [`Splash`] loading
+/// screens. In the near future, the plan is to add more interesting (and
+/// configurable!) loading screens. If you make a cool loading screen or have an interesting idea and
 /// you would like to share it, feel free to [create an issue] or
 /// [open a pull request]!
 ///
 /// [`Task`]: ../struct.Task.html
 /// [`LoadingScreen`]: trait.LoadingScreen.html
 /// [`ProgressBar`]: struct.ProgressBar.html
+/// [`Splash`]: struct.Splash.html
 /// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
 /// [create an issue]: https://github.com/hecrj/coffee/issues
 /// [open a pull request]: https://github.com/hecrj/coffee/pulls
@@ -68,10 +87,15 @@ pub trait LoadingScreen {
     /// By default, it runs the task and refreshes the window when there is
     /// progress.
     ///
+    /// Any [`Task`] can be run by turning it into a [`ScopedTask`] with
+    /// `into`.
+    ///
     /// [`LoadingScreen`]: trait.LoadingScreen.html
-    fn run<T>(
+    /// [`Task`]: ../struct.Task.html
+    /// [`ScopedTask`]: ../struct.ScopedTask.html
+    fn run<'a, T>(
         &mut self,
-        task: Task<T>,
+        task: ScopedTask<'a, T>,
         window: &mut graphics::Window,
     ) -> Result<T> {
         task.run_with_window(window, |progress, window| {
@@ -79,6 +103,16 @@ pub trait LoadingScreen {
             window.swap_buffers();
         })
     }
+
+    /// Returns the [`Crossfade`] into the first frames of the game, if any.
+    ///
+    /// It is called once, after the loading finishes. By default, it returns
+    /// `None` and the game is shown right away.
+    ///
+    /// [`Crossfade`]: struct.Crossfade.html
+    fn crossfade(&mut self) -> Option<Crossfade> {
+        None
+    }
 }
 
 impl LoadingScreen for () {
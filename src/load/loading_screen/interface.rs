@@ -0,0 +1,136 @@
+use super::{LoadingScreen, Progress};
+use crate::graphics;
+use crate::ui::core::{self, Element, Renderer as _};
+use crate::ui::Runtime;
+use crate::Result;
+
+/// A loading screen built with the [`ui` module].
+///
+/// Implement this trait to describe your loading screen using widgets, and
+/// then set [`WithInterface`] as your [`Game::LoadingScreen`].
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::Gpu;
+/// use coffee::load::loading_screen::{Interface, WithInterface};
+/// use coffee::load::Progress;
+/// use coffee::ui::{Column, Element, Justify, ProgressBar, Renderer, Text};
+/// use coffee::Result;
+///
+/// struct Loading;
+///
+/// impl Interface for Loading {
+///     type Renderer = Renderer;
+///
+///     fn new(_gpu: &mut Gpu) -> Result<Loading> {
+///         Ok(Loading)
+///     }
+///
+///     fn layout(
+///         &mut self,
+///         progress: &Progress,
+///         width: f32,
+///         height: f32,
+///     ) -> Element<'_, ()> {
+///         let stage = progress.stage().map(String::as_str).unwrap_or("");
+///
+///         Column::new()
+///             .width(width as u32)
+///             .height(height as u32)
+///             .padding(50)
+///             .spacing(20)
+///             .justify_content(Justify::Center)
+///             .push(Text::new(stage).size(30))
+///             .push(ProgressBar::new(progress.percentage() / 100.0))
+///             .into()
+///     }
+/// }
+///
+/// type LoadingScreen = WithInterface<Loading>;
+/// ```
+///
+/// [`ui` module]: ../../ui/index.html
+/// [`WithInterface`]: struct.WithInterface.html
+/// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
+pub trait Interface {
+    /// The renderer used to draw the loading screen.
+    ///
+    /// Use the built-in [`ui::Renderer`] if you only need the built-in
+    /// widgets.
+    ///
+    /// [`ui::Renderer`]: ../../ui/struct.Renderer.html
+    type Renderer: core::Renderer;
+
+    /// Creates the [`Interface`].
+    ///
+    /// You can use the provided [`Gpu`] to load any additional assets.
+    ///
+    /// [`Interface`]: trait.Interface.html
+    /// [`Gpu`]: ../../graphics/struct.Gpu.html
+    fn new(gpu: &mut graphics::Gpu) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Produces the layout of the loading screen for the given [`Progress`].
+    ///
+    /// The size of the window is provided, so the layout can fill it.
+    ///
+    /// [`Progress`]: ../struct.Progress.html
+    fn layout(
+        &mut self,
+        progress: &Progress,
+        width: f32,
+        height: f32,
+    ) -> Element<'_, (), Self::Renderer>;
+
+    /// Builds the renderer configuration of the loading screen.
+    ///
+    /// By default, it returns `Default::default()`.
+    fn configuration() -> <Self::Renderer as core::Renderer>::Configuration {
+        Default::default()
+    }
+
+    /// Returns the color used to clear the frame before drawing the layout.
+    ///
+    /// By default, it returns [`Color::BLACK`].
+    ///
+    /// [`Color::BLACK`]: ../../graphics/struct.Color.html#associatedconstant.BLACK
+    fn background(&self) -> graphics::Color {
+        graphics::Color::BLACK
+    }
+}
+
+/// A [`LoadingScreen`] that draws an [`Interface`].
+///
+/// [`LoadingScreen`]: trait.LoadingScreen.html
+/// [`Interface`]: trait.Interface.html
+#[allow(missing_debug_implementations)]
+pub struct WithInterface<I: Interface> {
+    interface: I,
+    runtime: Runtime<I::Renderer>,
+    messages: Vec<()>,
+}
+
+impl<I: Interface> LoadingScreen for WithInterface<I> {
+    fn new(gpu: &mut graphics::Gpu) -> Result<Self> {
+        let renderer = I::Renderer::load(I::configuration()).run(gpu)?;
+
+        Ok(WithInterface {
+            interface: I::new(gpu)?,
+            runtime: Runtime::new(renderer),
+            messages: Vec::new(),
+        })
+    }
+
+    fn draw(&mut self, progress: &Progress, frame: &mut graphics::Frame<'_>) {
+        frame.clear(self.interface.background());
+
+        let (width, height) = (frame.width(), frame.height());
+        let layout = self.interface.layout(progress, width, height);
+
+        let _ = self.runtime.draw(layout, frame, &mut self.messages);
+
+        self.messages.clear();
+    }
+}
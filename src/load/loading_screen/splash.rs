@@ -0,0 +1,256 @@
+use std::f32::consts::PI;
+use std::time::{Duration, Instant};
+
+use super::{LoadingScreen, Progress};
+use crate::graphics::{
+    self, Color, Image, Mesh, Point, Quad, Rectangle, Shape,
+};
+use crate::load::{ScopedTask, Task};
+use crate::Result;
+
+/// A loading screen showing a splash image with an animated spinner.
+///
+/// The image is scaled to fit the window, keeping its aspect ratio, and
+/// centered on a background color. It can optionally fade in. The spinner
+/// advances every time the loading task reports progress.
+///
+/// # Usage
+/// Create a [`Splash`] with the [`Task`] that loads your image in your own
+/// [`LoadingScreen`], and delegate to it:
+///
+/// ```
+/// use coffee::graphics::{Color, Frame, Gpu, Image, Window};
+/// use coffee::load::loading_screen::{LoadingScreen, Splash};
+/// use coffee::load::{Progress, ScopedTask};
+/// use coffee::Result;
+/// use std::time::Duration;
+///
+/// struct Loading(Splash);
+///
+/// impl LoadingScreen for Loading {
+///     fn new(_gpu: &mut Gpu) -> Result<Loading> {
+///         Ok(Loading(
+///             Splash::new(Image::load("splash.png"))
+///                 .background(Color::WHITE)
+///                 .fade_in(Duration::from_millis(500)),
+///         ))
+///     }
+///
+///     fn draw(&mut self, progress: &Progress, frame: &mut Frame<'_>) {
+///         self.0.draw(progress, frame);
+///     }
+///
+///     fn run<'a, T>(
+///         &mut self,
+///         task: ScopedTask<'a, T>,
+///         window: &mut Window,
+///     ) -> Result<T> {
+///         self.0.run(task, window)
+///     }
+/// }
+/// ```
+///
+/// If you set [`Splash`] directly as your [`Game::LoadingScreen`], it only
+/// shows the spinner.
+///
+/// [`Splash`]: struct.Splash.html
+/// [`Task`]: ../struct.Task.html
+/// [`LoadingScreen`]: trait.LoadingScreen.html
+/// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
+#[allow(missing_debug_implementations)]
+pub struct Splash {
+    image: State,
+    background: Color,
+    spinner_color: Color,
+    fade_in: Duration,
+    start: Option<Instant>,
+}
+
+enum State {
+    Pending(Task<Image>),
+    Loaded(Image),
+    Empty,
+}
+
+impl Splash {
+    /// The amount of dots in the spinner.
+    const DOTS: usize = 8;
+
+    /// Creates a [`Splash`] showing the image produced by the given [`Task`].
+    ///
+    /// The [`Task`] is run right before the loading screen is displayed. Its
+    /// progress is not reported.
+    ///
+    /// [`Splash`]: struct.Splash.html
+    /// [`Task`]: ../struct.Task.html
+    pub fn new(image: Task<Image>) -> Splash {
+        Splash::with_state(State::Pending(image))
+    }
+
+    fn with_state(image: State) -> Splash {
+        Splash {
+            image,
+            background: Color::BLACK,
+            spinner_color: Color::WHITE,
+            fade_in: Duration::from_secs(0),
+            start: None,
+        }
+    }
+
+    /// Sets the background [`Color`] of the [`Splash`].
+    ///
+    /// By default, it is black.
+    ///
+    /// [`Color`]: ../../graphics/struct.Color.html
+    /// [`Splash`]: struct.Splash.html
+    pub fn background(mut self, color: Color) -> Splash {
+        self.background = color;
+        self
+    }
+
+    /// Sets the [`Color`] of the spinner of the [`Splash`].
+    ///
+    /// By default, it is white.
+    ///
+    /// [`Color`]: ../../graphics/struct.Color.html
+    /// [`Splash`]: struct.Splash.html
+    pub fn spinner_color(mut self, color: Color) -> Splash {
+        self.spinner_color = color;
+        self
+    }
+
+    /// Sets the duration of the fade-in of the [`Splash`], from its
+    /// background color.
+    ///
+    /// By default, it does not fade in.
+    ///
+    /// [`Splash`]: struct.Splash.html
+    pub fn fade_in(mut self, duration: Duration) -> Splash {
+        self.fade_in = duration;
+        self
+    }
+
+    fn prepare(&mut self, gpu: &mut graphics::Gpu) -> Result<()> {
+        match std::mem::replace(&mut self.image, State::Empty) {
+            State::Pending(task) => {
+                self.image = State::Loaded(task.run(gpu)?);
+            }
+            image => {
+                self.image = image;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_image(&self, frame: &mut graphics::Frame<'_>) {
+        if let State::Loaded(image) = &self.image {
+            let width = f32::from(image.width());
+            let height = f32::from(image.height());
+            let scale = (frame.width() / width).min(frame.height() / height);
+
+            image.draw(
+                Quad {
+                    position: Point::new(
+                        (frame.width() - width * scale) / 2.0,
+                        (frame.height() - height * scale) / 2.0,
+                    ),
+                    size: (width * scale, height * scale),
+                    ..Quad::default()
+                },
+                &mut frame.as_target(),
+            );
+        }
+    }
+
+    fn draw_spinner(&self, frame: &mut graphics::Frame<'_>, elapsed: f32) {
+        let radius = frame.height().min(frame.width()) / 40.0;
+        let center =
+            Point::new(frame.width() / 2.0, frame.height() - radius * 4.0);
+
+        // One full turn per second
+        let head = (elapsed * Self::DOTS as f32) as usize % Self::DOTS;
+        let mut mesh = Mesh::new();
+
+        for i in 0..Self::DOTS {
+            let angle = i as f32 / Self::DOTS as f32 * 2.0 * PI;
+            let distance = (head + Self::DOTS - i) % Self::DOTS;
+
+            mesh.fill(
+                Shape::Circle {
+                    center: Point::new(
+                        center.x + radius * angle.cos(),
+                        center.y + radius * angle.sin(),
+                    ),
+                    radius: radius / 4.0,
+                },
+                Color {
+                    a: self.spinner_color.a
+                        * (1.0 - distance as f32 / Self::DOTS as f32),
+                    ..self.spinner_color
+                },
+            );
+        }
+
+        mesh.draw(&mut frame.as_target());
+    }
+
+    fn draw_overlay(&self, frame: &mut graphics::Frame<'_>, elapsed: f32) {
+        let fade_in = self.fade_in.as_secs_f32();
+
+        if elapsed >= fade_in {
+            return;
+        }
+
+        let mut mesh = Mesh::new();
+
+        mesh.fill(
+            Shape::Rectangle(Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: frame.width(),
+                height: frame.height(),
+            }),
+            Color {
+                a: self.background.a * (1.0 - elapsed / fade_in),
+                ..self.background
+            },
+        );
+
+        mesh.draw(&mut frame.as_target());
+    }
+}
+
+impl LoadingScreen for Splash {
+    fn new(_gpu: &mut graphics::Gpu) -> Result<Self> {
+        Ok(Splash::with_state(State::Empty))
+    }
+
+    fn draw(&mut self, _progress: &Progress, frame: &mut graphics::Frame<'_>) {
+        // Errors can only be reported from `run`, so the image is skipped if
+        // it fails to load here
+        let _ = self.prepare(frame.gpu());
+
+        let elapsed = self.start.get_or_insert_with(Instant::now).elapsed();
+        let elapsed = elapsed.as_secs_f32();
+
+        frame.clear(self.background);
+
+        self.draw_image(frame);
+        self.draw_spinner(frame, elapsed);
+        self.draw_overlay(frame, elapsed);
+    }
+
+    fn run<'a, T>(
+        &mut self,
+        task: ScopedTask<'a, T>,
+        window: &mut graphics::Window,
+    ) -> Result<T> {
+        self.prepare(window.gpu())?;
+
+        task.run_with_window(window, |progress, window| {
+            self.draw(progress, &mut window.frame());
+            window.swap_buffers();
+        })
+    }
+}
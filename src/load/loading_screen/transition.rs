@@ -0,0 +1,268 @@
+use std::time::{Duration, Instant};
+
+use super::{LoadingScreen, Progress};
+use crate::graphics::{self, Canvas, Color, Mesh, Quad, Rectangle, Shape};
+use crate::load::ScopedTask;
+use crate::Result;
+
+/// A loading screen wrapper that fades another [`LoadingScreen`] in and out.
+///
+/// A [`Transition`] also keeps the wrapped [`LoadingScreen`] visible for a
+/// minimum amount of time, so fast loads do not make it flash on the screen.
+///
+/// # Usage
+/// Set `Transition<T>` as your [`Game::LoadingScreen`] associated type, where
+/// `T` is the [`LoadingScreen`] you want to wrap. For instance,
+/// `Transition<ProgressBar>`.
+///
+/// By default, it fades in and out from black in 250 milliseconds and it is
+/// displayed for at least a second. If you want to customize it, wrap it in
+/// your own [`LoadingScreen`] and use the builder methods in
+/// [`LoadingScreen::new`]. Use [`crossfade_to_game`] to blend it into the
+/// first frames of your game instead of fading it out.
+///
+/// [`LoadingScreen`]: trait.LoadingScreen.html
+/// [`Transition`]: struct.Transition.html
+/// [`crossfade_to_game`]: #method.crossfade_to_game
+/// [`Game::LoadingScreen`]: ../../trait.Game.html#associatedtype.LoadingScreen
+/// [`LoadingScreen::new`]: trait.LoadingScreen.html#tymethod.new
+#[derive(Debug)]
+pub struct Transition<T: LoadingScreen> {
+    screen: T,
+    fade_in: Duration,
+    fade_out: Duration,
+    minimum_duration: Duration,
+    color: Color,
+    crossfade: Option<Duration>,
+    captured: Option<Crossfade>,
+}
+
+impl<T: LoadingScreen> Transition<T> {
+    /// Wraps the given [`LoadingScreen`] in a [`Transition`].
+    ///
+    /// [`LoadingScreen`]: trait.LoadingScreen.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn new(screen: T) -> Transition<T> {
+        Transition {
+            screen,
+            fade_in: Duration::from_millis(250),
+            fade_out: Duration::from_millis(250),
+            minimum_duration: Duration::from_secs(1),
+            color: Color::BLACK,
+            crossfade: None,
+            captured: None,
+        }
+    }
+
+    /// Sets the duration of the fade-in of the [`Transition`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn fade_in(mut self, duration: Duration) -> Self {
+        self.fade_in = duration;
+        self
+    }
+
+    /// Sets the duration of the fade-out of the [`Transition`].
+    ///
+    /// [`Transition`]: struct.Transition.html
+    pub fn fade_out(mut self, duration: Duration) -> Self {
+        self.fade_out = duration;
+        self
+    }
+
+    /// Sets the minimum amount of time the wrapped [`LoadingScreen`] is
+    /// displayed, excluding the fade-out.
+    ///
+    /// [`LoadingScreen`]: trait.LoadingScreen.html
+    pub fn minimum_duration(mut self, duration: Duration) -> Self {
+        self.minimum_duration = duration;
+        self
+    }
+
+    /// Sets the [`Color`] the [`Transition`] fades from and to.
+    ///
+    /// [`Color`]: ../../graphics/struct.Color.html
+    /// [`Transition`]: struct.Transition.html
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Cross-fades the wrapped [`LoadingScreen`] into the first frames of the
+    /// game during the given duration, instead of fading it out.
+    ///
+    /// [`LoadingScreen`]: trait.LoadingScreen.html
+    pub fn crossfade_to_game(mut self, duration: Duration) -> Self {
+        self.crossfade = Some(duration);
+        self
+    }
+
+    fn draw_with_overlay(
+        &mut self,
+        progress: &Progress,
+        frame: &mut graphics::Frame<'_>,
+        opacity: f32,
+    ) {
+        self.screen.draw(progress, frame);
+
+        if opacity > 0.0 {
+            let mut mesh = Mesh::new();
+
+            mesh.fill(
+                Shape::Rectangle(Rectangle {
+                    x: 0.0,
+                    y: 0.0,
+                    width: frame.width(),
+                    height: frame.height(),
+                }),
+                Color {
+                    a: self.color.a * opacity.min(1.0),
+                    ..self.color
+                },
+            );
+
+            mesh.draw(&mut frame.as_target());
+        }
+    }
+}
+
+impl<T: LoadingScreen> LoadingScreen for Transition<T> {
+    fn new(gpu: &mut graphics::Gpu) -> Result<Self> {
+        Ok(Transition::new(T::new(gpu)?))
+    }
+
+    fn draw(&mut self, progress: &Progress, frame: &mut graphics::Frame<'_>) {
+        self.draw_with_overlay(progress, frame, 0.0);
+    }
+
+    fn run<'a, R>(
+        &mut self,
+        task: ScopedTask<'a, R>,
+        window: &mut graphics::Window,
+    ) -> Result<R> {
+        let start = Instant::now();
+        let mut last_progress = None;
+
+        let result = task.run_with_window(window, |progress, window| {
+            let opacity = 1.0 - ratio(start.elapsed(), self.fade_in);
+
+            self.draw_with_overlay(progress, &mut window.frame(), opacity);
+            window.swap_buffers();
+
+            last_progress = Some(progress.clone());
+        })?;
+
+        if let Some(progress) = last_progress {
+            let fade_out_start =
+                start + self.minimum_duration.max(self.fade_in);
+
+            loop {
+                let now = Instant::now();
+
+                let opacity = if now < fade_out_start {
+                    1.0 - ratio(now - start, self.fade_in)
+                } else if self.crossfade.is_some() {
+                    break;
+                } else {
+                    ratio(now - fade_out_start, self.fade_out)
+                };
+
+                self.draw_with_overlay(&progress, &mut window.frame(), opacity);
+                window.swap_buffers();
+
+                if now >= fade_out_start + self.fade_out {
+                    break;
+                }
+            }
+
+            if let Some(duration) = self.crossfade {
+                let screen = &mut self.screen;
+
+                let canvas = window
+                    .frame()
+                    .capture(|frame| screen.draw(&progress, frame))?;
+
+                self.captured = Some(Crossfade::new(canvas, duration));
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn crossfade(&mut self) -> Option<Crossfade> {
+        self.captured.take()
+    }
+}
+
+/// A cross-fade from the last frame of a [`LoadingScreen`] into the first
+/// frames of the game.
+///
+/// The game loop draws it on top of every frame until it finishes. Return one
+/// from [`LoadingScreen::crossfade`] to avoid popping abruptly into the game.
+///
+/// [`LoadingScreen`]: trait.LoadingScreen.html
+/// [`LoadingScreen::crossfade`]: trait.LoadingScreen.html#method.crossfade
+#[derive(Debug)]
+pub struct Crossfade {
+    canvas: Canvas,
+    duration: Duration,
+    start: Option<Instant>,
+}
+
+impl Crossfade {
+    /// Creates a [`Crossfade`] from the given [`Canvas`], containing the last
+    /// frame of a [`LoadingScreen`], lasting the given duration.
+    ///
+    /// The duration starts counting when the first frame of the game is
+    /// drawn.
+    ///
+    /// [`Crossfade`]: struct.Crossfade.html
+    /// [`Canvas`]: ../../graphics/struct.Canvas.html
+    /// [`LoadingScreen`]: trait.LoadingScreen.html
+    pub fn new(canvas: Canvas, duration: Duration) -> Crossfade {
+        Crossfade {
+            canvas,
+            duration,
+            start: None,
+        }
+    }
+
+    /// Returns true if the [`Crossfade`] has finished.
+    ///
+    /// [`Crossfade`]: struct.Crossfade.html
+    pub fn is_finished(&self) -> bool {
+        match self.start {
+            Some(start) => start.elapsed() >= self.duration,
+            None => false,
+        }
+    }
+
+    pub(crate) fn draw(&mut self, frame: &mut graphics::Frame<'_>) {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let opacity = 1.0 - ratio(start.elapsed(), self.duration);
+
+        if opacity > 0.0 {
+            let color = Color {
+                a: opacity,
+                ..Color::WHITE
+            };
+
+            self.canvas.draw(
+                Quad {
+                    size: (frame.width(), frame.height()),
+                    colors: [color; 4],
+                    ..Quad::default()
+                },
+                &mut frame.as_target(),
+            );
+        }
+    }
+}
+
+fn ratio(elapsed: Duration, total: Duration) -> f32 {
+    if total == Duration::from_secs(0) {
+        1.0
+    } else {
+        (elapsed.as_secs_f32() / total.as_secs_f32()).min(1.0)
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+
+use crate::graphics::Gpu;
+use crate::load::{Progress, Task};
+use crate::Result;
+
+/// A queue of tasks that can be run incrementally, across frames.
+///
+/// A [`Stream`] allows you to keep loading resources once your game is
+/// running, without blocking. This is useful to load the chunks of an open
+/// world as the player moves around, for instance.
+///
+/// Every call to [`step`] runs, at most, a single loading operation of the
+/// queued tasks, like loading an [`Image`]. Therefore, you should split your
+/// work in operations that are small enough to fit in a frame.
+///
+/// # Example
+/// ```
+/// use coffee::graphics::{Frame, Image};
+/// use coffee::load::{Step, Stream};
+///
+/// struct World {
+///     chunks: Stream<Image>,
+///     loaded: Vec<Image>,
+/// }
+///
+/// impl World {
+///     fn enter_area(&mut self) {
+///         self.chunks.push(Image::load("chunks/forest.png"));
+///         self.chunks.push(Image::load("chunks/river.png"));
+///     }
+///
+///     fn draw(&mut self, frame: &mut Frame) {
+///         match self.chunks.step(frame.gpu()) {
+///             Ok(Step::Loaded(chunk)) => self.loaded.push(chunk),
+///             Ok(Step::Loading) | Ok(Step::Idle) => {}
+///             Err(error) => eprintln!("Chunk failed to load: {}", error),
+///         }
+///
+///         // Draw the loaded chunks...
+///     }
+/// }
+/// ```
+///
+/// [`Stream`]: struct.Stream.html
+/// [`step`]: #method.step
+/// [`Image`]: ../graphics/struct.Image.html
+pub struct Stream<T> {
+    tasks: VecDeque<Task<T>>,
+    total_work: u32,
+    work_completed: u32,
+}
+
+/// The result of a [`Stream::step`] or a [`Task::step`].
+///
+/// [`Stream::step`]: struct.Stream.html#method.step
+/// [`Task::step`]: struct.Task.html#method.step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step<T> {
+    /// A task finished running and produced a value.
+    Loaded(T),
+
+    /// Part of a task was run, but it has not produced a value yet.
+    Loading,
+
+    /// There were no tasks left to run.
+    Idle,
+}
+
+impl<T> Stream<T> {
+    /// Creates an empty [`Stream`].
+    ///
+    /// [`Stream`]: struct.Stream.html
+    pub fn new() -> Stream<T> {
+        Stream {
+            tasks: VecDeque::new(),
+            total_work: 0,
+            work_completed: 0,
+        }
+    }
+
+    /// Queues a [`Task`] at the end of the [`Stream`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Stream`]: struct.Stream.html
+    pub fn push(&mut self, task: Task<T>) {
+        // Progress restarts once every queued task has been run
+        if self.tasks.is_empty() {
+            self.total_work = 0;
+            self.work_completed = 0;
+        }
+
+        self.total_work += task.total_work();
+        self.tasks.push_back(task);
+    }
+
+    /// Runs a bounded amount of work of the next [`Task`] of the [`Stream`],
+    /// if there is one.
+    ///
+    /// See [`Task::step`] to learn more.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Stream`]: struct.Stream.html
+    /// [`Task::step`]: struct.Task.html#method.step
+    pub fn step(&mut self, gpu: &mut Gpu) -> Result<Step<T>> {
+        let task = match self.tasks.front_mut() {
+            Some(task) => task,
+            None => return Ok(Step::Idle),
+        };
+
+        let work_left = task.total_work();
+        let step = task.step(gpu);
+
+        self.work_completed += work_left - task.total_work();
+
+        // Failed tasks are discarded, like finished ones
+        match step {
+            Ok(Step::Loading) => {}
+            _ => {
+                let _ = self.tasks.pop_front();
+            }
+        }
+
+        step
+    }
+
+    /// Returns the amount of tasks left to run in the [`Stream`].
+    ///
+    /// [`Stream`]: struct.Stream.html
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns true if there are no tasks left to run in the [`Stream`].
+    ///
+    /// [`Stream`]: struct.Stream.html
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Returns the [`Progress`] of the [`Stream`] since it was last empty.
+    ///
+    /// You can use it to draw a loading indicator while the [`Stream`] runs.
+    ///
+    /// [`Progress`]: struct.Progress.html
+    /// [`Stream`]: struct.Stream.html
+    pub fn progress(&self) -> Progress {
+        Progress::new(self.total_work, self.work_completed)
+    }
+}
+
+impl<T> Default for Stream<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for Stream<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Stream {{ tasks: {}, total_work: {}, work_completed: {} }}",
+            self.tasks.len(),
+            self.total_work,
+            self.work_completed
+        )
+    }
+}
@@ -1,6 +1,16 @@
 use crate::graphics;
+use crate::load::{journal, Journal, Step};
 use crate::Result;
 
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+thread_local! {
+    static CACHE: RefCell<HashMap<String, Box<dyn Any>>> =
+        RefCell::new(HashMap::new());
+}
+
 /// A `Task<T>` represents an operation that produces a value of type `T`.
 ///
 /// # Laziness
@@ -64,13 +74,59 @@ use crate::Result;
 /// }
 /// ```
 ///
+/// # Capturing data
+/// A [`Task`] is usually run after the function that creates it returns, like
+/// [`Game::load`]. Therefore, the closures of a [`Task`] cannot borrow data
+/// from their enclosing scope and need to be `'static`.
+///
+/// However, they are only called once. Instead of cloning or sharing your
+/// data, you can move it into the [`Task`] that needs it:
+///
+/// ```
+/// # use coffee::load::Task;
+/// # use coffee::graphics::Image;
+/// #
+/// pub struct Config {
+///     tileset: String,
+///     map_size: (usize, usize),
+/// }
+///
+/// pub struct Level {
+///     tileset: Image,
+///     map: Vec<u8>,
+/// }
+///
+/// impl Level {
+///     pub fn load(config: Config) -> Task<Level> {
+///         let Config { tileset, map_size } = config;
+///
+///         Image::load(tileset).map(move |tileset| Level {
+///             tileset,
+///             map: vec![0; map_size.0 * map_size.1],
+///         })
+///     }
+/// }
+/// ```
+///
+/// If you run the loading code yourself right away, use [`Task::scoped`]
+/// instead. It creates a [`ScopedTask`], which can borrow data.
+///
 /// [`Task`]: struct.Task.html
 /// [`Join`]: trait.Join.html
 /// [`Image::load`]: ../graphics/struct.Image.html#method.load
 /// [`map`]: #method.map
+/// [`Game::load`]: ../trait.Game.html#tymethod.load
+/// [`Task::scoped`]: #method.scoped
+/// [`ScopedTask`]: struct.ScopedTask.html
 pub struct Task<T> {
     total_work: u32,
-    function: Box<dyn FnOnce(&mut Worker<'_>) -> Result<T>>,
+    function: Option<Box<dyn FnOnce(&mut Worker<'_>) -> Result<Next<T>>>>,
+}
+
+// The outcome of running a single step of a `Task`
+enum Next<T> {
+    Done(T),
+    Continue(Task<T>),
 }
 
 impl<T> Task<T> {
@@ -98,16 +154,13 @@ impl<T> Task<T> {
     where
         F: 'static + FnOnce() -> Result<T>,
     {
-        Task {
-            total_work: 1,
-            function: Box::new(move |worker| {
-                let result = f();
+        Task::sequence(1, move |worker| {
+            let result = f();
 
-                worker.notify_progress(1);
+            worker.notify_progress(1);
 
-                result
-            }),
-        }
+            result
+        })
     }
 
     /// Creates a new [`Task`] from a lazy operation that cannot fail.
@@ -163,13 +216,76 @@ impl<T> Task<T> {
         })
     }
 
+    /// Creates a new [`ScopedTask`] that uses a [`Gpu`] and can borrow data
+    /// from its enclosing scope.
+    ///
+    /// A [`ScopedTask`] cannot be returned from [`Game::load`], but it can be
+    /// run right away with [`ScopedTask::run`], like in a custom loading
+    /// function or inside another [`Task`]:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::{Gpu, Image};
+    /// # use coffee::Result;
+    /// #
+    /// pub struct Config {
+    ///     tileset: String,
+    ///     map_size: (usize, usize),
+    /// }
+    ///
+    /// pub struct Level {
+    ///     tileset: Image,
+    ///     map: Vec<u8>,
+    /// }
+    ///
+    /// impl Level {
+    ///     pub fn load(gpu: &mut Gpu, config: &Config) -> Result<Level> {
+    ///         Task::scoped(|gpu| Image::new(gpu, &config.tileset))
+    ///             .map(|tileset| Level {
+    ///                 tileset,
+    ///                 map: vec![0; config.map_size.0 * config.map_size.1],
+    ///             })
+    ///             .run(gpu)
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`ScopedTask`]: struct.ScopedTask.html
+    /// [`ScopedTask::run`]: struct.ScopedTask.html#method.run
+    /// [`Gpu`]: ../graphics/struct.Gpu.html
+    /// [`Game::load`]: ../trait.Game.html#tymethod.load
+    /// [`Task`]: struct.Task.html
+    pub fn scoped<'a, F>(f: F) -> ScopedTask<'a, T>
+    where
+        F: 'a + FnOnce(&mut graphics::Gpu) -> Result<T>,
+    {
+        ScopedTask {
+            total_work: 1,
+            function: Box::new(move |worker| {
+                let result = f(worker.gpu());
+
+                worker.notify_progress(1);
+
+                result
+            }),
+        }
+    }
+
     pub(crate) fn sequence<F>(total_work: u32, f: F) -> Task<T>
     where
         F: 'static + FnOnce(&mut Worker<'_>) -> Result<T>,
+    {
+        Task::chain(total_work, move |worker| f(worker).map(Next::Done))
+    }
+
+    // Creates a task that may leave some work for later steps
+    fn chain<F>(total_work: u32, f: F) -> Task<T>
+    where
+        F: 'static + FnOnce(&mut Worker<'_>) -> Result<Next<T>>,
     {
         Task {
             total_work,
-            function: Box::new(f),
+            function: Some(Box::new(f)),
         }
     }
 
@@ -211,18 +327,164 @@ impl<T> Task<T> {
     where
         T: 'static,
     {
-        let title = title.into();
+        let stage = Stage::new(title.into(), task.total_work);
 
-        Task {
-            total_work: task.total_work,
-            function: Box::new(move |worker| {
-                worker.with_stage(title.clone(), task.function)
-            }),
-        }
+        Task::staged(stage, task)
+    }
+
+    fn staged(stage: Stage, task: Task<T>) -> Task<T>
+    where
+        T: 'static,
+    {
+        let total_work = task.total_work;
+
+        Task::chain(total_work, move |worker| {
+            if stage.work_completed == 0 {
+                journal::start_stage(&stage.title);
+            }
+
+            let next = worker
+                .with_stage(stage.clone(), move |worker| task.step_in(worker))
+                .map_err(|error| journal::stage_error(&stage.title, error))?;
+
+            Ok(match next {
+                Next::Done(value) => {
+                    journal::complete_stage(&stage.title);
+
+                    Next::Done(value)
+                }
+                Next::Continue(rest) => {
+                    let mut stage = stage;
+                    stage.work_completed += total_work - rest.total_work;
+
+                    Next::Continue(Task::staged(stage, rest))
+                }
+            })
+        })
+    }
+
+    /// Caches the output of a [`Task`] with the given key.
+    ///
+    /// The first time a cached [`Task`] with a given key is run, its output
+    /// is stored in an internal cache. From then on, any cached [`Task`] with
+    /// the same key and output type produces a clone of the stored value
+    /// instead of running. This is true even for tasks run by different
+    /// loading screens.
+    ///
+    /// Resources like [`Image`] are cheap to clone, so you can use this to
+    /// avoid decoding and uploading the same asset twice when it is referenced
+    /// in many places of a big task tree:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// # use coffee::graphics::Image;
+    /// #
+    /// fn load_ui_sprites() -> Task<Image> {
+    ///     Task::cached("ui.png", Image::load("ui.png"))
+    /// }
+    /// ```
+    ///
+    /// Use [`clear_cache`] to free the cached values.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Image`]: ../graphics/struct.Image.html
+    /// [`clear_cache`]: fn.clear_cache.html
+    pub fn cached<K: Into<String>>(key: K, task: Task<T>) -> Task<T>
+    where
+        T: 'static + Clone,
+    {
+        let key = key.into();
+        let total_work = task.total_work;
+
+        Task::chain(total_work, move |worker| {
+            let cached = CACHE.with(|cache| {
+                cache
+                    .borrow()
+                    .get(&key)
+                    .and_then(|value| value.downcast_ref::<T>())
+                    .cloned()
+            });
+
+            if let Some(value) = cached {
+                worker.notify_progress(total_work);
+
+                return Ok(Next::Done(value));
+            }
+
+            match task.step_in(worker)? {
+                Next::Done(value) => {
+                    CACHE.with(|cache| {
+                        let _ = cache
+                            .borrow_mut()
+                            .insert(key, Box::new(value.clone()));
+                    });
+
+                    Ok(Next::Done(value))
+                }
+                Next::Continue(rest) => {
+                    Ok(Next::Continue(Task::cached(key, rest)))
+                }
+            }
+        })
+    }
+
+    /// Records the stages of the [`Task`] in the given [`Journal`] while it
+    /// runs.
+    ///
+    /// Every stage is written to disk when it starts and when it completes.
+    /// If the [`Task`] succeeds, the [`Journal`] file is removed. Otherwise,
+    /// it is kept so the next run can resume, and the error is wrapped in an
+    /// [`Error::Stage`] reporting the stage that failed.
+    ///
+    /// Journaling is best-effort. If the [`Journal`] file cannot be written
+    /// or removed, a warning is logged and the [`Task`] runs as usual.
+    ///
+    /// This works both with [`run`] and with loading screens.
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Journal`]: struct.Journal.html
+    /// [`Error::Stage`]: ../enum.Error.html#variant.Stage
+    /// [`run`]: #method.run
+    pub fn journaled(self, journal: Journal) -> Task<T>
+    where
+        T: 'static,
+    {
+        Task::chain(self.total_work, move |worker| {
+            let recording = journal.begin();
+
+            self.recorded(journal, recording).step_in(worker)
+        })
+    }
+
+    fn recorded(
+        self,
+        journal: Journal,
+        recording: journal::Recording,
+    ) -> Task<T>
+    where
+        T: 'static,
+    {
+        Task::chain(self.total_work, move |worker| {
+            let (recording, next) =
+                recording.record(move || self.step_in(worker));
+
+            Ok(match next? {
+                Next::Done(value) => {
+                    journal.finish();
+
+                    Next::Done(value)
+                }
+                Next::Continue(rest) => {
+                    Next::Continue(rest.recorded(journal, recording))
+                }
+            })
+        })
     }
 
     /// Returns the total units of work of the [`Task`].
     ///
+    /// If the [`Task`] has been stepped, it returns the units of work left.
+    ///
     /// [`Task`]: struct.Task.html
     pub fn total_work(&self) -> u32 {
         self.total_work
@@ -233,6 +495,20 @@ impl<T> Task<T> {
     /// As [explained above], use this method to make your tasks return your
     /// own custom types, enhancing composability.
     ///
+    /// Like every function of a [`Task`], the given function is only called
+    /// once. Therefore, it can consume move-only values, like a channel:
+    ///
+    /// ```
+    /// # use coffee::load::Task;
+    /// use std::sync::mpsc;
+    ///
+    /// let (sender, receiver) = mpsc::channel();
+    /// sender.send(String::from("Hello!")).unwrap();
+    ///
+    /// let greeting = Task::succeed(move || receiver)
+    ///     .map(|receiver| receiver.recv().unwrap_or_default());
+    /// ```
+    ///
     /// [`Task`]: struct.Task.html
     /// [explained above]: #composition
     pub fn map<F, A>(self, f: F) -> Task<A>
@@ -240,7 +516,233 @@ impl<T> Task<T> {
         T: 'static,
         F: 'static + FnOnce(T) -> A,
     {
-        Task {
+        Task::chain(self.total_work, move |worker| {
+            Ok(match self.step_in(worker)? {
+                Next::Done(value) => Next::Done(f(value)),
+                Next::Continue(rest) => Next::Continue(rest.map(f)),
+            })
+        })
+    }
+
+    /// Runs a [`Task`] and obtains the produced value.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the [`Task`] has already produced its value using
+    /// [`step`].
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`step`]: #method.step
+    pub fn run(self, gpu: &mut graphics::Gpu) -> Result<T> {
+        let mut worker = Worker::Headless(gpu);
+
+        self.run_in(&mut worker)
+    }
+
+    /// Runs a bounded amount of work of the [`Task`].
+    ///
+    /// Every call runs a single loading operation, like loading an [`Image`]
+    /// or a whole [`TextureArray`]. It returns [`Step::Loading`] until the
+    /// [`Task`] produces its value, and [`Step::Idle`] afterwards.
+    ///
+    /// This allows you to load resources across frames while your game keeps
+    /// running. Use a [`Stream`] to queue many tasks and track their
+    /// progress.
+    ///
+    /// ```
+    /// use coffee::graphics::{Frame, Image};
+    /// use coffee::load::{Join, Step, Task};
+    ///
+    /// struct Forest {
+    ///     assets: Task<(Image, Image)>,
+    ///     trees: Option<(Image, Image)>,
+    /// }
+    ///
+    /// impl Forest {
+    ///     fn new() -> Forest {
+    ///         Forest {
+    ///             assets: (Image::load("oak.png"), Image::load("pine.png"))
+    ///                 .join(),
+    ///             trees: None,
+    ///         }
+    ///     }
+    ///
+    ///     fn draw(&mut self, frame: &mut Frame) {
+    ///         match self.assets.step(frame.gpu()) {
+    ///             Ok(Step::Loaded(trees)) => self.trees = Some(trees),
+    ///             Ok(Step::Loading) | Ok(Step::Idle) => {}
+    ///             Err(error) => eprintln!("Forest failed to load: {}", error),
+    ///         }
+    ///
+    ///         // Draw the trees, if they are loaded...
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [`Task`]: struct.Task.html
+    /// [`Image`]: ../graphics/struct.Image.html
+    /// [`TextureArray`]: ../graphics/struct.TextureArray.html
+    /// [`Step::Loading`]: enum.Step.html#variant.Loading
+    /// [`Step::Idle`]: enum.Step.html#variant.Idle
+    /// [`Stream`]: struct.Stream.html
+    pub fn step(&mut self, gpu: &mut graphics::Gpu) -> Result<Step<T>> {
+        let function = match self.function.take() {
+            Some(function) => function,
+            None => return Ok(Step::Idle),
+        };
+
+        let mut worker = Worker::Headless(gpu);
+
+        match function(&mut worker) {
+            Ok(Next::Done(value)) => {
+                self.total_work = 0;
+
+                Ok(Step::Loaded(value))
+            }
+            Ok(Next::Continue(rest)) => {
+                *self = rest;
+
+                Ok(Step::Loading)
+            }
+            Err(error) => {
+                self.total_work = 0;
+
+                Err(error)
+            }
+        }
+    }
+
+    fn run_in(self, worker: &mut Worker<'_>) -> Result<T> {
+        let mut task = self;
+
+        loop {
+            match task.step_in(worker)? {
+                Next::Done(value) => return Ok(value),
+                Next::Continue(rest) => task = rest,
+            }
+        }
+    }
+
+    fn step_in(self, worker: &mut Worker<'_>) -> Result<Next<T>> {
+        let function = self
+            .function
+            .expect("The task has already produced its value");
+
+        function(worker)
+    }
+}
+
+impl<T> std::fmt::Debug for Task<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Task {{ total_work: {} }}", self.total_work)
+    }
+}
+
+/// A [`Task`] that can borrow data from its enclosing scope.
+///
+/// Use [`Task::scoped`] to create one. Any [`Task`] can be turned into a
+/// [`ScopedTask`] using `into`.
+///
+/// A [`ScopedTask`] runs to completion once started, so it cannot be stepped
+/// or queued in a [`Stream`]. Otherwise, it works like a [`Task`]: it can be
+/// split in stages with [`ScopedTask::stage`], combined with [`ScopedJoin`],
+/// and run by any [`LoadingScreen`], which keeps track of its [`Progress`]:
+///
+/// ```
+/// # use coffee::load::Task;
+/// # use coffee::graphics::{Gpu, Image};
+/// # use coffee::Result;
+/// #
+/// use coffee::load::{ScopedJoin, ScopedTask};
+///
+/// pub struct Config {
+///     tileset: String,
+///     background: String,
+/// }
+///
+/// pub fn load_level(config: &Config) -> ScopedTask<'_, (Image, Image)> {
+///     (
+///         ScopedTask::stage(
+///             "Loading tileset...",
+///             Task::scoped(move |gpu| Image::new(gpu, &config.tileset)),
+///         ),
+///         ScopedTask::stage(
+///             "Loading background...",
+///             Task::scoped(move |gpu| Image::new(gpu, &config.background)),
+///         ),
+///     )
+///         .join()
+/// }
+/// ```
+///
+/// [`Task`]: struct.Task.html
+/// [`Task::scoped`]: struct.Task.html#method.scoped
+/// [`ScopedTask`]: struct.ScopedTask.html
+/// [`Stream`]: struct.Stream.html
+/// [`ScopedTask::stage`]: #method.stage
+/// [`ScopedJoin`]: trait.ScopedJoin.html
+/// [`LoadingScreen`]: loading_screen/trait.LoadingScreen.html
+/// [`Progress`]: struct.Progress.html
+pub struct ScopedTask<'a, T> {
+    total_work: u32,
+    function: Box<dyn FnOnce(&mut Worker<'_>) -> Result<T> + 'a>,
+}
+
+impl<'a, T> ScopedTask<'a, T> {
+    /// Adds a title to the [`ScopedTask`].
+    ///
+    /// Like [`Task::stage`], the title is reported in the [`Progress`] while
+    /// the [`ScopedTask`] runs, and it is recorded by a [`Journal`].
+    ///
+    /// [`ScopedTask`]: struct.ScopedTask.html
+    /// [`Task::stage`]: struct.Task.html#method.stage
+    /// [`Progress`]: struct.Progress.html
+    /// [`Journal`]: struct.Journal.html
+    pub fn stage<S: Into<String>>(
+        title: S,
+        task: ScopedTask<'a, T>,
+    ) -> ScopedTask<'a, T>
+    where
+        T: 'a,
+    {
+        let stage = Stage::new(title.into(), task.total_work);
+
+        ScopedTask {
+            total_work: task.total_work,
+            function: Box::new(move |worker| {
+                journal::start_stage(&stage.title);
+
+                let value =
+                    worker.with_stage(stage.clone(), task.function).map_err(
+                        |error| journal::stage_error(&stage.title, error),
+                    )?;
+
+                journal::complete_stage(&stage.title);
+
+                Ok(value)
+            }),
+        }
+    }
+
+    /// Returns the total units of work of the [`ScopedTask`].
+    ///
+    /// [`ScopedTask`]: struct.ScopedTask.html
+    pub fn total_work(&self) -> u32 {
+        self.total_work
+    }
+
+    /// Transforms the output of a [`ScopedTask`].
+    ///
+    /// Unlike [`Task::map`], the given function can borrow data.
+    ///
+    /// [`ScopedTask`]: struct.ScopedTask.html
+    /// [`Task::map`]: struct.Task.html#method.map
+    pub fn map<F, A>(self, f: F) -> ScopedTask<'a, A>
+    where
+        T: 'a,
+        F: 'a + FnOnce(T) -> A,
+    {
+        ScopedTask {
             total_work: self.total_work,
             function: Box::new(move |worker| match (self.function)(worker) {
                 Ok(value) => Ok(f(value)),
@@ -249,23 +751,21 @@ impl<T> Task<T> {
         }
     }
 
-    /// Runs a [`Task`] and obtains the produced value.
+    /// Runs a [`ScopedTask`] and obtains the produced value.
     ///
-    /// [`Task`]: struct.Task.html
+    /// [`ScopedTask`]: struct.ScopedTask.html
     pub fn run(self, gpu: &mut graphics::Gpu) -> Result<T> {
         let mut worker = Worker::Headless(gpu);
 
         (self.function)(&mut worker)
     }
 
-    /// Runs a [`Task`] and obtains the produced value.
+    /// Runs a [`ScopedTask`] and obtains the produced value.
     ///
     /// You can provide a function to keep track of [`Progress`].
     ///
-    /// [`Task`]: struct.Task.html
+    /// [`ScopedTask`]: struct.ScopedTask.html
     /// [`Progress`]: struct.Progress.html
-    /// [`Window`]: ../graphics/window/struct.Window.html
-    /// [open an issue]: https://github.com/hecrj/coffee/issues
     pub(crate) fn run_with_window<F>(
         self,
         window: &mut graphics::Window,
@@ -290,9 +790,18 @@ impl<T> Task<T> {
     }
 }
 
-impl<T> std::fmt::Debug for Task<T> {
+impl<'a, T: 'a> From<Task<T>> for ScopedTask<'a, T> {
+    fn from(task: Task<T>) -> ScopedTask<'a, T> {
+        ScopedTask {
+            total_work: task.total_work,
+            function: Box::new(move |worker| task.run_in(worker)),
+        }
+    }
+}
+
+impl<'a, T> std::fmt::Debug for ScopedTask<'a, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Task {{ total_work: {} }}", self.total_work)
+        write!(f, "ScopedTask {{ total_work: {} }}", self.total_work)
     }
 }
 
@@ -324,24 +833,32 @@ impl<'a> Worker<'a> {
             } => {
                 progress.work_completed += work;
 
+                for stage in &mut progress.stages {
+                    stage.work_completed += work;
+                }
+
                 listener(&progress, window);
             }
         };
     }
 
-    pub fn with_stage<T>(
-        &mut self,
-        title: String,
-        f: Box<dyn FnOnce(&mut Worker<'_>) -> T>,
-    ) -> T {
+    pub fn with_stage<T, F>(&mut self, stage: Stage, f: F) -> T
+    where
+        F: FnOnce(&mut Worker<'_>) -> T,
+    {
         match self {
             Worker::Headless(_) => f(self),
             Worker::Windowed { .. } => {
+                // Listeners already know about a stage that made progress
+                let is_new = stage.work_completed == 0;
+
                 if let Worker::Windowed { progress, .. } = self {
-                    progress.stages.push(title);
+                    progress.stages.push(stage);
                 }
 
-                self.notify_progress(0);
+                if is_new {
+                    self.notify_progress(0);
+                }
 
                 let result = f(self);
 
@@ -358,14 +875,22 @@ impl<'a> Worker<'a> {
 /// The progress of a [`Task`].
 ///
 /// [`Task`]: struct.Task.html
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Progress {
     total_work: u32,
     work_completed: u32,
-    stages: Vec<String>,
+    stages: Vec<Stage>,
 }
 
 impl Progress {
+    pub(super) fn new(total_work: u32, work_completed: u32) -> Progress {
+        Progress {
+            total_work,
+            work_completed,
+            stages: Vec::new(),
+        }
+    }
+
     /// Returns the total amount of work of the related [`Task`].
     ///
     /// [`Task`]: struct.Task.html
@@ -397,7 +922,69 @@ impl Progress {
     ///
     /// [`Task::state`]: struct.Task.html#method.stage
     pub fn stage(&self) -> Option<&String> {
-        self.stages.last()
+        self.stages.last().map(|stage| &stage.title)
+    }
+
+    /// Returns the [`Stage`] stack of the related [`Task`], from outermost to
+    /// innermost.
+    ///
+    /// Every [`Stage`] keeps track of its own work, so you can show a progress
+    /// bar per stage besides the overall one.
+    ///
+    /// [`Stage`]: struct.Stage.html
+    /// [`Task`]: struct.Task.html
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+}
+
+/// A [`Task::stage`] being run, with its own progress.
+///
+/// [`Task::stage`]: struct.Task.html#method.stage
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Stage {
+    title: String,
+    total_work: u32,
+    work_completed: u32,
+}
+
+impl Stage {
+    fn new(title: String, total_work: u32) -> Stage {
+        Stage {
+            title,
+            total_work,
+            work_completed: 0,
+        }
+    }
+
+    /// Returns the title of the [`Stage`].
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Returns the total amount of work of the [`Stage`].
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn total_work(&self) -> u32 {
+        self.total_work
+    }
+
+    /// Returns the amount of completed work of the [`Stage`].
+    ///
+    /// The returned value is guaranteed to be in [0, total_work].
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn completed_work(&self) -> u32 {
+        self.work_completed.min(self.total_work)
+    }
+
+    /// Returns the amount of progress of the [`Stage`] as a percentage.
+    ///
+    /// [`Stage`]: struct.Stage.html
+    pub fn percentage(&self) -> f32 {
+        self.completed_work() as f32 / self.total_work.max(1) as f32 * 100.0
     }
 }
 
@@ -422,11 +1009,13 @@ impl<A: 'static, B: 'static> Join for (Task<A>, Task<B>) {
     fn join(self) -> Task<(A, B)> {
         let (loader_a, loader_b) = self;
 
-        Task::sequence(
+        Task::chain(
             loader_a.total_work() + loader_b.total_work(),
-            move |task| {
-                (loader_a.function)(task)
-                    .and_then(|a| (loader_b.function)(task).map(|b| (a, b)))
+            move |worker| {
+                Ok(Next::Continue(match loader_a.step_in(worker)? {
+                    Next::Done(a) => loader_b.map(move |b| (a, b)),
+                    Next::Continue(rest) => (rest, loader_b).join(),
+                }))
             },
         )
     }
@@ -583,3 +1172,63 @@ impl<
             .map(|((a, b, c, d, e, f, g), h)| (a, b, c, d, e, f, g, h))
     }
 }
+
+/// Join multiple scoped tasks with ease.
+///
+/// It works like [`Join`], but for tuples of up to 8 instances of
+/// [`ScopedTask`].
+///
+/// [`Join`]: trait.Join.html
+/// [`ScopedTask`]: struct.ScopedTask.html
+pub trait ScopedJoin<'a> {
+    /// The resulting output of the [`ScopedTask`] after joining.
+    ///
+    /// [`ScopedTask`]: struct.ScopedTask.html
+    type Type;
+
+    /// Joins scoped tasks into a new one that collects the results.
+    fn join(self) -> ScopedTask<'a, Self::Type>;
+}
+
+// A scoped task runs to completion once started, so joining scoped tasks
+// simply runs them in order
+macro_rules! scoped_join {
+    ($($task:ident: $type:ident),+) => {
+        impl<'a, $($type: 'a),+> ScopedJoin<'a>
+            for ($(ScopedTask<'a, $type>,)+)
+        {
+            type Type = ($($type,)+);
+
+            fn join(self) -> ScopedTask<'a, ($($type,)+)> {
+                let ($($task,)+) = self;
+
+                ScopedTask {
+                    total_work: 0 $(+ $task.total_work)+,
+                    function: Box::new(move |worker| {
+                        Ok(($(($task.function)(worker)?,)+))
+                    }),
+                }
+            }
+        }
+    };
+}
+
+scoped_join!(a: A, b: B);
+scoped_join!(a: A, b: B, c: C);
+scoped_join!(a: A, b: B, c: C, d: D);
+scoped_join!(a: A, b: B, c: C, d: D, e: E);
+scoped_join!(a: A, b: B, c: C, d: D, e: E, f: F);
+scoped_join!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
+scoped_join!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
+
+/// Clears the cache of the tasks created with [`Task::cached`].
+///
+/// Cached values are kept alive until this is called. Values that are still
+/// used elsewhere are not freed, but they will be loaded again the next time
+/// a cached [`Task`] with the same key is run.
+///
+/// [`Task::cached`]: struct.Task.html#method.cached
+/// [`Task`]: struct.Task.html
+pub fn clear_cache() {
+    CACHE.with(|cache| cache.borrow_mut().clear());
+}
@@ -0,0 +1,184 @@
+//! Generate deterministic random numbers.
+//!
+//! Every run of a game has a seed, which you can set using
+//! [`WindowSettings::seed`] and obtain with [`Window::seed`]. Building all
+//! your randomness from it makes gameplay reproducible: combined with an
+//! [`input::Recorder`], the seed is all you need to replay a session or a bug
+//! report.
+//!
+//! [`WindowSettings::seed`]: ../graphics/struct.WindowSettings.html#structfield.seed
+//! [`Window::seed`]: ../graphics/struct.Window.html#method.seed
+//! [`input::Recorder`]: ../input/struct.Recorder.html
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::ops::Range;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A fast, deterministic random number generator.
+///
+/// The same seed always produces the same sequence of numbers, in every
+/// platform. It is not suitable for cryptography.
+///
+/// You can [`fork`] independent streams of numbers from an [`Rng`]. This way,
+/// cosmetic effects like particles can use as much randomness as they need
+/// without changing the outcome of gameplay.
+///
+/// # Example
+///
+/// ```
+/// use coffee::rand::Rng;
+/// use std::f32::consts::PI;
+///
+/// let mut gameplay = Rng::new(42);
+/// let mut particles = gameplay.fork("particles");
+///
+/// let damage = gameplay.range_i32(10..20);
+/// let angle = particles.range(0.0..PI * 2.0);
+///
+/// // The same seed produces the same numbers
+/// assert_eq!(Rng::new(42).range_i32(10..20), damage);
+/// assert_eq!(Rng::new(42).fork("particles").range(0.0..PI * 2.0), angle);
+/// ```
+///
+/// [`fork`]: #method.fork
+/// [`Rng`]: struct.Rng.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct Rng {
+    seed: u64,
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a new [`Rng`] with the given seed.
+    ///
+    /// [`Rng`]: struct.Rng.html
+    pub fn new(seed: u64) -> Rng {
+        Rng { seed, state: seed }
+    }
+
+    /// Creates a new [`Rng`] with an unpredictable seed.
+    ///
+    /// [`Rng`]: struct.Rng.html
+    pub fn from_entropy() -> Rng {
+        Rng::new(entropy())
+    }
+
+    /// Returns the seed of the [`Rng`].
+    ///
+    /// [`Rng`]: struct.Rng.html
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Creates an independent [`Rng`] for the stream with the given name.
+    ///
+    /// The new [`Rng`] only depends on the seed of this one and the name of
+    /// the stream. Therefore, it produces the same numbers no matter how many
+    /// have been generated by this [`Rng`] or other streams.
+    ///
+    /// [`Rng`]: struct.Rng.html
+    pub fn fork(&self, stream: &str) -> Rng {
+        // FNV-1a, which is stable across platforms and versions
+        let hash = stream.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+        Rng::new(mix(self.seed ^ hash))
+    }
+
+    /// Generates a random `u64`.
+    pub fn u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        mix(self.state)
+    }
+
+    /// Generates a random `u32`.
+    pub fn u32(&mut self) -> u32 {
+        (self.u64() >> 32) as u32
+    }
+
+    /// Generates a random `f32` in the range `[0.0, 1.0)`.
+    pub fn f32(&mut self) -> f32 {
+        (self.u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Returns `true` with the given probability, from `0.0` to `1.0`.
+    pub fn bool(&mut self, probability: f32) -> bool {
+        self.f32() < probability
+    }
+
+    /// Generates a random `f32` in the given range.
+    pub fn range(&mut self, range: Range<f32>) -> f32 {
+        range.start + self.f32() * (range.end - range.start)
+    }
+
+    /// Generates a random `i32` in the given range.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the range is empty.
+    pub fn range_i32(&mut self, range: Range<i32>) -> i32 {
+        assert!(range.start < range.end, "Empty range");
+
+        let length = (i64::from(range.end) - i64::from(range.start)) as u64;
+
+        (i64::from(range.start) + self.below(length) as i64) as i32
+    }
+
+    /// Generates a random index for a collection of the given length.
+    ///
+    /// # Panics
+    ///
+    /// It panics if the length is `0`.
+    pub fn index(&mut self, length: usize) -> usize {
+        assert!(length > 0, "Empty collection");
+
+        self.below(length as u64) as usize
+    }
+
+    /// Returns a random element of the given slice, or `None` if it is
+    /// empty.
+    pub fn choose<'a, T>(&mut self, elements: &'a [T]) -> Option<&'a T> {
+        if elements.is_empty() {
+            None
+        } else {
+            Some(&elements[self.index(elements.len())])
+        }
+    }
+
+    /// Shuffles the elements of the given slice.
+    pub fn shuffle<T>(&mut self, elements: &mut [T]) {
+        for i in (1..elements.len()).rev() {
+            elements.swap(i, self.index(i + 1));
+        }
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        ((u128::from(self.u64()) * u128::from(bound)) >> 64) as u64
+    }
+}
+
+fn mix(value: u64) -> u64 {
+    let mut z = value;
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
+
+fn entropy() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+
+    if let Ok(time) = SystemTime::now().duration_since(UNIX_EPOCH) {
+        hasher.write_u128(time.as_nanos());
+    }
+
+    hasher.finish()
+}
@@ -1,8 +1,9 @@
 use std::error;
 use std::fmt;
 use std::io;
+use std::path::PathBuf;
 
-use crate::graphics::texture_array;
+use crate::graphics::{texture_array, Backend};
 
 /// A convenient result with a locked [`Error`] type.
 ///
@@ -18,14 +19,51 @@ pub enum Error {
     /// The window creation failed.
     WindowCreation(String),
 
+    /// No graphics adapter supporting the enabled backends was found.
+    AdapterNotFound,
+
+    /// The requested graphics [`Backend`] has not been compiled in.
+    ///
+    /// Enable its feature to use it.
+    ///
+    /// [`Backend`]: graphics/enum.Backend.html
+    BackendNotCompiled(Backend),
+
+    /// Multisample anti-aliasing was requested with an amount of samples per
+    /// pixel that is not supported.
+    MultisamplingNotSupported(u16),
+
+    /// A texture is bigger than what the graphics processor supports.
+    TextureTooLarge {
+        /// The maximum width and height of a texture, in pixels.
+        max: u16,
+    },
+
     /// A texture array failed to load.
     TextureArray(texture_array::Error),
 
     /// A file failed to load.
     IO(io::Error),
 
+    /// The file at the given path failed to load.
+    File(PathBuf, io::Error),
+
     /// An image failed to load.
     Image(image::ImageError),
+
+    /// A font failed to load.
+    Font(String),
+
+    /// A stage of a journaled [`Task`] failed.
+    ///
+    /// [`Task`]: load/struct.Task.html
+    Stage {
+        /// The title of the stage that failed.
+        title: String,
+
+        /// The error that made the stage fail.
+        error: Box<Error>,
+    },
 }
 
 impl fmt::Display for Error {
@@ -34,11 +72,41 @@ impl fmt::Display for Error {
             Error::WindowCreation(error) => {
                 write!(f, "Window creation error: {}", error)
             }
+            Error::AdapterNotFound => {
+                write!(
+                    f,
+                    "No graphics adapter supporting the enabled backends \
+                     was found"
+                )
+            }
+            Error::BackendNotCompiled(backend) => write!(
+                f,
+                "The {:?} backend has not been compiled in, enable its \
+                 feature to use it",
+                backend
+            ),
+            Error::MultisamplingNotSupported(samples) => write!(
+                f,
+                "Multisampling with {} samples per pixel is not supported",
+                samples
+            ),
+            Error::TextureTooLarge { max } => write!(
+                f,
+                "Texture is too large, the maximum size is {}x{}",
+                max, max
+            ),
             Error::TextureArray(error) => {
                 write!(f, "Texture array error: {}", error)
             }
             Error::IO(error) => write!(f, "IO error: {}", error),
+            Error::File(path, error) => {
+                write!(f, "Could not load {}: {}", path.display(), error)
+            }
             Error::Image(error) => write!(f, "Image error: {}", error),
+            Error::Font(error) => write!(f, "Font error: {}", error),
+            Error::Stage { title, error } => {
+                write!(f, "Stage \"{}\" failed: {}", title, error)
+            }
         }
     }
 }
@@ -47,7 +115,9 @@ impl error::Error for Error {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Error::IO(error) => Some(error),
+            Error::File(_, error) => Some(error),
             Error::Image(error) => Some(error),
+            Error::Stage { error, .. } => Some(error.as_ref()),
             _ => None,
         }
     }
@@ -0,0 +1,541 @@
+//! Structure your game as a stack of scenes.
+//!
+//! Most games are made of different screens: a title screen, a main menu, the
+//! gameplay itself, a pause menu on top of it... A [`Scene`] describes one of
+//! these screens, and a [`SceneStack`] runs them as a [`Game`].
+//!
+//! Only the scene on top of the stack receives input and is updated. Scenes
+//! change the stack by returning a [`Command`] from [`Scene::update`]. New
+//! scenes are loaded with their own [`Task`], showing the loading screen of
+//! the [`SceneStack`]. Any [`Command`] can be animated with a [`Transition`]
+//! by using [`Command::with_transition`].
+//!
+//! # Example
+//!
+//! ```
+//! use coffee::graphics::{Color, Frame, Window};
+//! use coffee::input::{keyboard, KeyboardAndMouse};
+//! use coffee::load::Task;
+//! use coffee::scene::{Command, Scene, SceneStack};
+//! use coffee::Timer;
+//!
+//! struct Menu {
+//!     play: bool,
+//! }
+//!
+//! impl Scene for Menu {
+//!     type Input = KeyboardAndMouse;
+//!
+//!     fn load(_window: &Window) -> Task<Menu> {
+//!         Task::succeed(|| Menu { play: false })
+//!     }
+//!
+//!     fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+//!         frame.clear(Color::BLACK);
+//!     }
+//!
+//!     fn interact(
+//!         &mut self,
+//!         input: &mut KeyboardAndMouse,
+//!         _window: &mut Window,
+//!     ) {
+//!         let keyboard = input.keyboard();
+//!
+//!         self.play = keyboard.was_key_released(keyboard::KeyCode::Return);
+//!     }
+//!
+//!     fn update(
+//!         &mut self,
+//!         window: &Window,
+//!     ) -> Option<Command<KeyboardAndMouse>> {
+//!         if self.play {
+//!             Some(Command::push(Level::load(window)))
+//!         } else {
+//!             None
+//!         }
+//!     }
+//! }
+//!
+//! struct Level;
+//!
+//! impl Scene for Level {
+//!     type Input = KeyboardAndMouse;
+//!
+//!     fn load(_window: &Window) -> Task<Level> {
+//!         Task::succeed(|| Level)
+//!     }
+//!
+//!     fn draw(&mut self, frame: &mut Frame<'_>, _timer: &Timer) {
+//!         frame.clear(Color::WHITE);
+//!     }
+//! }
+//!
+//! // `SceneStack<Menu>` implements `Game`, so you can run it:
+//! // SceneStack::<Menu>::run(WindowSettings { ... })
+//! type MyGame = SceneStack<Menu>;
+//! ```
+//!
+//! [`Scene`]: trait.Scene.html
+//! [`SceneStack`]: struct.SceneStack.html
+//! [`Game`]: ../trait.Game.html
+//! [`Command`]: enum.Command.html
+//! [`Scene::update`]: trait.Scene.html#method.update
+//! [`Task`]: ../load/struct.Task.html
+//! [`Transition`]: ../graphics/transition/struct.Transition.html
+//! [`Command::with_transition`]: enum.Command.html#method.with_transition
+use std::marker::PhantomData;
+
+use crate::graphics::transition::{Effect, Transition};
+use crate::graphics::{Frame, Window};
+use crate::input::Input;
+use crate::load::{LoadingScreen, Task};
+use crate::{Error, Game, Result, Timer};
+
+/// A screen of your game, like a menu or a level.
+///
+/// It is analogous to [`Game`], but it can change the [`SceneStack`] running
+/// it by returning a [`Command`] from [`update`].
+///
+/// [`Game`]: ../trait.Game.html
+/// [`SceneStack`]: struct.SceneStack.html
+/// [`Command`]: enum.Command.html
+/// [`update`]: #method.update
+pub trait Scene {
+    /// The input data of the [`Scene`].
+    ///
+    /// Every [`Scene`] in a [`SceneStack`] shares the same input.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    type Input: Input;
+
+    /// Loads the [`Scene`].
+    ///
+    /// It is used to load the first [`Scene`] of a [`SceneStack`]. For the
+    /// rest, you provide the [`Task`] in a [`Command`], so they can receive
+    /// any arguments you need.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`Command`]: enum.Command.html
+    fn load(window: &Window) -> Task<Self>
+    where
+        Self: Sized;
+
+    /// Draws the [`Scene`].
+    ///
+    /// It is only called when the [`Scene`] is visible: when it is on top of
+    /// the [`SceneStack`] or under an [`overlay`].
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    /// [`overlay`]: #method.is_overlay
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer);
+
+    /// Consumes [`Input`] to let users interact with the [`Scene`].
+    ///
+    /// It works like [`Game::interact`], but it is only called when the
+    /// [`Scene`] is on top of the [`SceneStack`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Input`]: #associatedtype.Input
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    fn interact(&mut self, _input: &mut Self::Input, _window: &mut Window) {}
+
+    /// Updates the [`Scene`].
+    ///
+    /// It works like [`Game::update`], but it is only called when the
+    /// [`Scene`] is on top of the [`SceneStack`]. Return a [`Command`] to
+    /// change the [`SceneStack`]. It is applied at the beginning of the next
+    /// frame, and no other [`Scene`] is updated until then.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    /// [`Command`]: enum.Command.html
+    fn update(&mut self, _window: &Window) -> Option<Command<Self::Input>> {
+        None
+    }
+
+    /// Runs when the [`Scene`] becomes the top of the [`SceneStack`], either
+    /// because it was pushed or because the [`Scene`] above it was popped.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    fn on_enter(&mut self) {}
+
+    /// Runs when the [`Scene`] stops being the top of the [`SceneStack`],
+    /// either because it was removed or because another [`Scene`] was pushed
+    /// on top of it.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`SceneStack`]: struct.SceneStack.html
+    fn on_exit(&mut self) {}
+
+    /// Handles an error produced while loading the [`Scene`] of a [`Command`]
+    /// returned by this [`Scene`].
+    ///
+    /// The [`Command`] is discarded and this [`Scene`] stays on top.
+    ///
+    /// By default, it panics.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    /// [`Command`]: enum.Command.html
+    fn on_load_error(&mut self, error: Error) {
+        panic!("Scene failed to load: {}", error);
+    }
+
+    /// Returns whether the [`Scene`] is drawn on top of the [`Scene`] below
+    /// it, like a pause menu.
+    ///
+    /// By default, it returns false.
+    ///
+    /// [`Scene`]: trait.Scene.html
+    fn is_overlay(&self) -> bool {
+        false
+    }
+}
+
+/// A change of the scenes of a [`SceneStack`].
+///
+/// [`SceneStack`]: struct.SceneStack.html
+pub enum Command<I> {
+    /// Loads a new scene and puts it on top of the current one.
+    Push(Task<Box<dyn Scene<Input = I>>>),
+
+    /// Removes the current scene. If it was the last one, the game finishes.
+    Pop,
+
+    /// Loads a new scene and replaces the current one with it.
+    Replace(Task<Box<dyn Scene<Input = I>>>),
+
+    /// Applies a command animating a [`Transition`] with the given [`Effect`]
+    /// and amount of ticks.
+    ///
+    /// It is created with [`Command::with_transition`].
+    ///
+    /// [`Transition`]: ../graphics/transition/struct.Transition.html
+    /// [`Effect`]: ../graphics/transition/enum.Effect.html
+    /// [`Command::with_transition`]: #method.with_transition
+    Transition(Box<Command<I>>, Effect, u16),
+}
+
+impl<I> std::fmt::Debug for Command<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Command::Push(task) => write!(f, "Command::Push({:?})", task),
+            Command::Pop => write!(f, "Command::Pop"),
+            Command::Replace(task) => write!(f, "Command::Replace({:?})", task),
+            Command::Transition(command, effect, ticks) => write!(
+                f,
+                "Command::Transition({:?}, {:?}, {})",
+                command, effect, ticks
+            ),
+        }
+    }
+}
+
+impl<I: 'static> Command<I> {
+    /// Creates a [`Command`] that loads the [`Scene`] of the given [`Task`]
+    /// and puts it on top of the current one.
+    ///
+    /// [`Command`]: enum.Command.html
+    /// [`Scene`]: trait.Scene.html
+    /// [`Task`]: ../load/struct.Task.html
+    pub fn push<S>(task: Task<S>) -> Command<I>
+    where
+        S: 'static + Scene<Input = I>,
+    {
+        Command::Push(
+            task.map(|scene| -> Box<dyn Scene<Input = I>> { Box::new(scene) }),
+        )
+    }
+
+    /// Creates a [`Command`] that removes the current [`Scene`].
+    ///
+    /// [`Command`]: enum.Command.html
+    /// [`Scene`]: trait.Scene.html
+    pub fn pop() -> Command<I> {
+        Command::Pop
+    }
+
+    /// Creates a [`Command`] that loads the [`Scene`] of the given [`Task`]
+    /// and replaces the current one with it.
+    ///
+    /// [`Command`]: enum.Command.html
+    /// [`Scene`]: trait.Scene.html
+    /// [`Task`]: ../load/struct.Task.html
+    pub fn replace<S>(task: Task<S>) -> Command<I>
+    where
+        S: 'static + Scene<Input = I>,
+    {
+        Command::Replace(
+            task.map(|scene| -> Box<dyn Scene<Input = I>> { Box::new(scene) }),
+        )
+    }
+
+    /// Animates the [`Command`] with a [`Transition`] lasting the given
+    /// amount of ticks.
+    ///
+    /// The scenes visible before the [`Command`] is applied are captured, and
+    /// the [`Effect`] is drawn on top of the scenes visible after it. If the
+    /// [`Command`] fails to load its [`Scene`], nothing is animated.
+    ///
+    /// ```
+    /// use coffee::graphics::transition::Effect;
+    /// use coffee::graphics::Color;
+    /// use coffee::input::KeyboardAndMouse;
+    /// use coffee::scene::Command;
+    ///
+    /// let command: Command<KeyboardAndMouse> =
+    ///     Command::pop().with_transition(Effect::Fade(Color::BLACK), 30);
+    /// ```
+    ///
+    /// [`Command`]: enum.Command.html
+    /// [`Transition`]: ../graphics/transition/struct.Transition.html
+    /// [`Effect`]: ../graphics/transition/enum.Effect.html
+    /// [`Scene`]: trait.Scene.html
+    pub fn with_transition(self, effect: Effect, ticks: u16) -> Command<I> {
+        match self {
+            Command::Transition(command, _, _) => {
+                Command::Transition(command, effect, ticks)
+            }
+            command => Command::Transition(Box::new(command), effect, ticks),
+        }
+    }
+}
+
+/// A [`Game`] that runs a stack of scenes.
+///
+/// It starts with the [`Scene`] `S` and finishes once the stack is empty. New
+/// scenes are loaded using the [`LoadingScreen`] `L`, which is also used when
+/// the game starts.
+///
+/// [`Game`]: ../trait.Game.html
+/// [`Scene`]: trait.Scene.html
+/// [`LoadingScreen`]: ../load/loading_screen/trait.LoadingScreen.html
+pub struct SceneStack<S: Scene, L = ()> {
+    scenes: Vec<Box<dyn Scene<Input = S::Input>>>,
+    pending: Option<Command<S::Input>>,
+    outgoing: Option<Transition>,
+    transition: Option<Transition>,
+    loading_screen: Option<L>,
+    initial: PhantomData<S>,
+}
+
+impl<S: Scene, L> std::fmt::Debug for SceneStack<S, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SceneStack {{ scenes: {}, pending: {:?} }}",
+            self.scenes.len(),
+            self.pending
+        )
+    }
+}
+
+impl<S: Scene, L> SceneStack<S, L> {
+    /// Returns the amount of scenes in the [`SceneStack`].
+    ///
+    /// [`SceneStack`]: struct.SceneStack.html
+    pub fn len(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Returns true if the [`SceneStack`] has no scenes.
+    ///
+    /// [`SceneStack`]: struct.SceneStack.html
+    pub fn is_empty(&self) -> bool {
+        self.scenes.is_empty()
+    }
+}
+
+impl<S, L> SceneStack<S, L>
+where
+    S: 'static + Scene,
+    L: LoadingScreen,
+{
+    // Returns `true` if the scenes changed
+    fn apply(
+        &mut self,
+        command: Command<S::Input>,
+        window: &mut Window,
+    ) -> bool {
+        let (task, is_replace) = match command {
+            Command::Push(task) => (task, false),
+            Command::Replace(task) => (task, true),
+            Command::Pop => {
+                if let Some(mut scene) = self.scenes.pop() {
+                    scene.on_exit();
+                }
+
+                if let Some(scene) = self.scenes.last_mut() {
+                    scene.on_enter();
+                }
+
+                return true;
+            }
+            Command::Transition(command, _, _) => {
+                let outgoing = self.outgoing.take();
+                let is_applied = self.apply(*command, window);
+
+                if is_applied {
+                    self.transition = outgoing;
+                }
+
+                return is_applied;
+            }
+        };
+
+        match self.load(task, window) {
+            Ok(mut new_scene) => {
+                if let Some(mut scene) = self.scenes.pop() {
+                    scene.on_exit();
+
+                    if !is_replace {
+                        self.scenes.push(scene);
+                    }
+                }
+
+                new_scene.on_enter();
+                self.scenes.push(new_scene);
+
+                true
+            }
+            Err(error) => {
+                if let Some(scene) = self.scenes.last_mut() {
+                    scene.on_load_error(error);
+                }
+
+                false
+            }
+        }
+    }
+
+    fn load(
+        &mut self,
+        task: Task<Box<dyn Scene<Input = S::Input>>>,
+        window: &mut Window,
+    ) -> Result<Box<dyn Scene<Input = S::Input>>> {
+        // Tasks without any work are run directly, so the loading screen does
+        // not flash
+        if task.total_work() == 0 {
+            return task.run(window.gpu());
+        }
+
+        let mut loading_screen = match self.loading_screen.take() {
+            Some(loading_screen) => loading_screen,
+            None => L::new(window.gpu())?,
+        };
+
+        let result = loading_screen.run(task.into(), window);
+
+        self.loading_screen = Some(loading_screen);
+
+        result
+    }
+}
+
+impl<S, L> Game for SceneStack<S, L>
+where
+    S: 'static + Scene,
+    L: 'static + LoadingScreen,
+{
+    type Input = S::Input;
+    type LoadingScreen = L;
+
+    fn load(window: &Window) -> Task<Self> {
+        S::load(window).map(|mut scene| {
+            scene.on_enter();
+
+            SceneStack {
+                scenes: vec![Box::new(scene)],
+                pending: None,
+                outgoing: None,
+                transition: None,
+                loading_screen: None,
+                initial: PhantomData,
+            }
+        })
+    }
+
+    fn draw(&mut self, frame: &mut Frame<'_>, timer: &Timer) {
+        // The visible scenes are captured before applying a command with a
+        // transition. If they cannot be captured, they switch instantly.
+        if let Some(Command::Transition(_, effect, ticks)) = &self.pending {
+            if self.outgoing.is_none() {
+                let scenes = &mut self.scenes;
+
+                self.outgoing =
+                    Transition::capture(frame, *effect, *ticks, |frame| {
+                        draw_scenes(scenes, frame, timer)
+                    })
+                    .ok();
+            }
+        }
+
+        draw_scenes(&mut self.scenes, frame, timer);
+
+        if let Some(transition) = &self.transition {
+            transition.draw(frame);
+        }
+    }
+
+    fn interact(&mut self, input: &mut Self::Input, window: &mut Window) {
+        if let Some(command) = self.pending.take() {
+            let _ = self.apply(command, window);
+        }
+
+        if let Some(scene) = self.scenes.last_mut() {
+            scene.interact(input, window);
+        }
+    }
+
+    fn update(&mut self, window: &Window) {
+        if let Some(transition) = &mut self.transition {
+            transition.update();
+
+            if transition.is_finished() {
+                self.transition = None;
+            }
+        }
+
+        if self.pending.is_some() {
+            return;
+        }
+
+        if let Some(scene) = self.scenes.last_mut() {
+            self.pending = scene.update(window);
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.scenes.is_empty()
+    }
+}
+
+fn draw_scenes<I: Input>(
+    scenes: &mut [Box<dyn Scene<Input = I>>],
+    frame: &mut Frame<'_>,
+    timer: &Timer,
+) {
+    // Scenes are visible down to the first one that is not an overlay
+    let first_visible = scenes
+        .iter()
+        .rposition(|scene| !scene.is_overlay())
+        .unwrap_or(0);
+
+    for scene in &mut scenes[first_visible..] {
+        scene.draw(frame, timer);
+    }
+}
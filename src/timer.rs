@@ -5,8 +5,36 @@ use std::time;
 /// A [`Timer`] is updated once per frame, and it ticks [`Game::TICKS_PER_SECOND`]
 /// times every second. When the timer ticks, your game is updated.
 ///
+/// If you are writing your own main loop, you can drive a [`Timer`] yourself
+/// using [`accumulate`] and [`step`], reusing the same fixed-timestep logic
+/// that powers [`Game`].
+///
 /// [`Timer`]: struct.Timer.html
 /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+/// [`accumulate`]: #method.accumulate
+/// [`step`]: #method.step
+/// [`Game`]: trait.Game.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::Timer;
+/// use std::time::Duration;
+///
+/// let mut timer = Timer::new(60);
+///
+/// // Feed the time elapsed since the last frame...
+/// timer.accumulate(Duration::from_millis(35));
+///
+/// // ...and update your game once per step
+/// let mut updates = 0;
+///
+/// while timer.step() {
+///     updates += 1;
+/// }
+///
+/// assert_eq!(updates, 2);
+/// ```
 #[derive(Debug)]
 pub struct Timer {
     target_ticks: u16,
@@ -14,10 +42,17 @@ pub struct Timer {
     last_tick: time::Instant,
     accumulated_delta: time::Duration,
     has_ticked: bool,
+    is_running_slow: bool,
 }
 
 impl Timer {
-    pub(crate) fn new(ticks_per_second: u16) -> Timer {
+    /// Creates a new [`Timer`] that ticks the given amount of times per
+    /// second.
+    ///
+    /// A value of `0` creates a [`Timer`] that never ticks.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn new(ticks_per_second: u16) -> Timer {
         let (target_seconds, target_nanos) = match ticks_per_second {
             0 => (std::u64::MAX, 0),
             1 => (1, 0),
@@ -30,6 +65,7 @@ impl Timer {
             last_tick: time::Instant::now(),
             accumulated_delta: time::Duration::from_secs(0),
             has_ticked: false,
+            is_running_slow: false,
         }
     }
 
@@ -38,11 +74,28 @@ impl Timer {
         let diff = now - self.last_tick;
 
         self.last_tick = now;
-        self.accumulated_delta += diff;
         self.has_ticked = false;
+        self.accumulate(diff);
     }
 
-    pub(crate) fn tick(&mut self) -> bool {
+    /// Adds the given elapsed time to the accumulator of the [`Timer`].
+    ///
+    /// Call this once per frame with the time elapsed since the previous one,
+    /// and then call [`step`] until it returns `false`.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`step`]: #method.step
+    pub fn accumulate(&mut self, delta: time::Duration) {
+        self.accumulated_delta += delta;
+    }
+
+    /// Consumes a tick from the accumulator of the [`Timer`], if possible.
+    ///
+    /// Returns `true` when a tick was consumed, which means that your game
+    /// state should be updated once.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn step(&mut self) -> bool {
         if self.accumulated_delta >= self.target_delta {
             self.accumulated_delta -= self.target_delta;
             self.has_ticked = true;
@@ -53,6 +106,55 @@ impl Timer {
         }
     }
 
+    /// Discards the whole ticks left in the accumulator of the [`Timer`] and
+    /// returns how many were discarded.
+    ///
+    /// Call this after consuming as many ticks as you can afford in a frame.
+    /// It keeps the [`Timer`] from falling further and further behind when
+    /// your game cannot keep up, and updates [`is_running_slow`].
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`is_running_slow`]: #method.is_running_slow
+    pub fn skip_ticks(&mut self) -> u32 {
+        let skipped = (self.accumulated_delta.as_nanos()
+            / self.target_delta.as_nanos()) as u32;
+
+        self.accumulated_delta -= self.target_delta * skipped;
+
+        self.is_running_slow = skipped > 0;
+
+        skipped
+    }
+
+    /// Returns `true` if the [`Timer`] had to skip ticks the last time
+    /// [`skip_ticks`] was called.
+    ///
+    /// The main loop of a [`Game`] skips ticks when it cannot keep up with
+    /// [`Game::TICKS_PER_SECOND`]. You can use this to reduce the work done
+    /// by your game, like lowering particle counts.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    /// [`skip_ticks`]: #method.skip_ticks
+    /// [`Game`]: trait.Game.html
+    /// [`Game::TICKS_PER_SECOND`]: trait.Game.html#associatedconstant.TICKS_PER_SECOND
+    pub fn is_running_slow(&self) -> bool {
+        self.is_running_slow
+    }
+
+    /// Discards the time accumulated by the [`Timer`] and starts measuring
+    /// time again from now.
+    ///
+    /// Use this when resuming a paused game, so the time spent paused is not
+    /// simulated all at once.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn reset(&mut self) {
+        self.last_tick = time::Instant::now();
+        self.accumulated_delta = time::Duration::from_secs(0);
+        self.has_ticked = false;
+        self.is_running_slow = false;
+    }
+
     /// Returns `true` if the [`Timer`] has ticked since its last update.
     ///
     /// This tells you whether your game has been updated or not during a frame.
@@ -66,6 +168,19 @@ impl Timer {
         self.has_ticked
     }
 
+    /// Returns the time accumulated by the [`Timer`] that has not been
+    /// consumed by a tick yet.
+    ///
+    /// [`Timer`]: struct.Timer.html
+    pub fn accumulated(&self) -> time::Duration {
+        self.accumulated_delta
+    }
+
+    /// Returns the fixed duration of a tick.
+    pub fn tick_duration(&self) -> time::Duration {
+        self.target_delta
+    }
+
     /// Returns how close the next tick is.
     ///
     /// The returned value is in the `[0.0, 1.0]` interval. You should use this
@@ -0,0 +1,21 @@
+// Wraps a block in a `tracing` span with the given name, tagged with the
+// current frame number. It expands to the block alone when the `tracing`
+// feature is disabled.
+#[cfg(feature = "tracing")]
+macro_rules! span {
+    ($name:literal, $frame:expr, $body:block) => {{
+        let span = tracing::info_span!($name, frame = $frame);
+        let _enter = span.enter();
+
+        $body
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+macro_rules! span {
+    ($name:literal, $frame:expr, $body:block) => {{
+        let _ = $frame;
+
+        $body
+    }};
+}
@@ -0,0 +1,269 @@
+//! Animate values over time.
+//!
+//! A [`Tween`] interpolates between two values during a fixed amount of time,
+//! following an [`Easing`] curve. It can animate any [`Tweenable`] value,
+//! like an `f32`, a [`Point`], a [`Vector`], or a [`Color`].
+//!
+//! Every [`Animation`] can be composed: use [`Animation::then`] to play
+//! animations one after another and [`Animation::and`] to play them at the
+//! same time.
+//!
+//! # Example
+//!
+//! ```
+//! use coffee::graphics::{Color, Point};
+//! use coffee::tween::{Animation, Easing, Tween};
+//! use coffee::Timer;
+//! use std::time::Duration;
+//!
+//! struct Camera {
+//!     animation: Box<dyn Animation<Output = (Point, Color)>>,
+//! }
+//!
+//! impl Camera {
+//!     fn new() -> Camera {
+//!         let movement = Tween::new(
+//!             Point::new(0.0, 0.0),
+//!             Point::new(200.0, 0.0),
+//!             Duration::from_millis(500),
+//!             Easing::QuadOut,
+//!         )
+//!         .then(Tween::new(
+//!             Point::new(200.0, 0.0),
+//!             Point::new(200.0, 300.0),
+//!             Duration::from_millis(500),
+//!             Easing::QuadInOut,
+//!         ));
+//!
+//!         let fade = Tween::new(
+//!             Color::BLACK,
+//!             Color::WHITE,
+//!             Duration::from_secs(1),
+//!             Easing::Linear,
+//!         );
+//!
+//!         Camera {
+//!             animation: Box::new(movement.and(fade)),
+//!         }
+//!     }
+//!
+//!     // Call this from `Game::update`
+//!     fn update(&mut self, timer: &Timer) {
+//!         self.animation.update(timer);
+//!     }
+//!
+//!     // Use this from `Game::draw`
+//!     fn position_and_tint(&self) -> (Point, Color) {
+//!         self.animation.value()
+//!     }
+//! }
+//! ```
+//!
+//! [`Tween`]: struct.Tween.html
+//! [`Easing`]: enum.Easing.html
+//! [`Tweenable`]: trait.Tweenable.html
+//! [`Point`]: ../graphics/type.Point.html
+//! [`Vector`]: ../graphics/type.Vector.html
+//! [`Color`]: ../graphics/struct.Color.html
+//! [`Animation`]: trait.Animation.html
+//! [`Animation::then`]: trait.Animation.html#method.then
+//! [`Animation::and`]: trait.Animation.html#method.and
+mod easing;
+mod parallel;
+mod sequence;
+
+pub use easing::Easing;
+pub use parallel::Parallel;
+pub use sequence::Sequence;
+
+use crate::graphics::{Color, Point, Vector};
+use crate::Timer;
+
+use std::time::Duration;
+
+/// A value that changes over time.
+pub trait Animation {
+    /// The type of the animated value.
+    type Output;
+
+    /// Returns the current value of the [`Animation`].
+    ///
+    /// [`Animation`]: trait.Animation.html
+    fn value(&self) -> Self::Output;
+
+    /// Advances the [`Animation`] by the given amount of time.
+    ///
+    /// It returns the amount of time left over once the [`Animation`] has
+    /// finished, which is zero while it is still running.
+    ///
+    /// [`Animation`]: trait.Animation.html
+    fn advance(&mut self, delta: Duration) -> Duration;
+
+    /// Returns true if the [`Animation`] has finished.
+    ///
+    /// [`Animation`]: trait.Animation.html
+    fn is_finished(&self) -> bool;
+
+    /// Restarts the [`Animation`] from the beginning.
+    ///
+    /// [`Animation`]: trait.Animation.html
+    fn reset(&mut self);
+
+    /// Advances the [`Animation`] by a tick of the given [`Timer`].
+    ///
+    /// Call it from [`Game::update`] to keep animations in sync with your
+    /// game logic.
+    ///
+    /// [`Animation`]: trait.Animation.html
+    /// [`Timer`]: ../struct.Timer.html
+    /// [`Game::update`]: ../trait.Game.html#method.update
+    fn update(&mut self, timer: &Timer) {
+        let _ = self.advance(timer.tick_duration());
+    }
+
+    /// Plays the given [`Animation`] once this one has finished.
+    ///
+    /// [`Animation`]: trait.Animation.html
+    fn then<A>(self, next: A) -> Sequence<Self, A>
+    where
+        Self: Sized,
+        A: Animation<Output = Self::Output>,
+    {
+        Sequence::new(self, next)
+    }
+
+    /// Plays the given [`Animation`] at the same time as this one.
+    ///
+    /// The resulting [`Animation`] produces both values and finishes when
+    /// both animations have finished.
+    ///
+    /// [`Animation`]: trait.Animation.html
+    fn and<A>(self, other: A) -> Parallel<Self, A>
+    where
+        Self: Sized,
+        A: Animation,
+    {
+        Parallel::new(self, other)
+    }
+}
+
+/// An interpolation between two values following an [`Easing`] curve.
+///
+/// [`Easing`]: enum.Easing.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: Duration,
+    elapsed: Duration,
+    easing: Easing,
+}
+
+impl<T: Tweenable> Tween<T> {
+    /// Creates a new [`Tween`] from a value to another, lasting the given
+    /// amount of time.
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn new(from: T, to: T, duration: Duration, easing: Easing) -> Self {
+        Tween {
+            from,
+            to,
+            duration,
+            elapsed: Duration::from_secs(0),
+            easing,
+        }
+    }
+
+    /// Returns the progress of the [`Tween`], from `0.0` to `1.0`, before
+    /// applying its [`Easing`].
+    ///
+    /// [`Tween`]: struct.Tween.html
+    /// [`Easing`]: enum.Easing.html
+    pub fn progress(&self) -> f32 {
+        if self.duration == Duration::from_secs(0) {
+            1.0
+        } else {
+            (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        }
+    }
+
+    /// Returns the total duration of the [`Tween`].
+    ///
+    /// [`Tween`]: struct.Tween.html
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl<T: Tweenable> Animation for Tween<T> {
+    type Output = T;
+
+    fn value(&self) -> T {
+        self.from
+            .interpolate(self.to, self.easing.apply(self.progress()))
+    }
+
+    fn advance(&mut self, delta: Duration) -> Duration {
+        let elapsed = self.elapsed + delta;
+
+        if elapsed > self.duration {
+            self.elapsed = self.duration;
+
+            elapsed - self.duration
+        } else {
+            self.elapsed = elapsed;
+
+            Duration::from_secs(0)
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    fn reset(&mut self) {
+        self.elapsed = Duration::from_secs(0);
+    }
+}
+
+/// A value that can be animated by a [`Tween`].
+///
+/// [`Tween`]: struct.Tween.html
+pub trait Tweenable: Copy {
+    /// Interpolates linearly between the value and the given one.
+    ///
+    /// `t` is usually in the `[0.0, 1.0]` interval, but some [`Easing`]
+    /// curves overshoot it.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    fn interpolate(self, to: Self, t: f32) -> Self;
+}
+
+impl Tweenable for f32 {
+    fn interpolate(self, to: f32, t: f32) -> f32 {
+        self + (to - self) * t
+    }
+}
+
+impl Tweenable for Point {
+    fn interpolate(self, to: Point, t: f32) -> Point {
+        self + (to - self) * t
+    }
+}
+
+impl Tweenable for Vector {
+    fn interpolate(self, to: Vector, t: f32) -> Vector {
+        self + (to - self) * t
+    }
+}
+
+impl Tweenable for Color {
+    fn interpolate(self, to: Color, t: f32) -> Color {
+        Color {
+            r: self.r.interpolate(to.r, t),
+            g: self.g.interpolate(to.g, t),
+            b: self.b.interpolate(to.b, t),
+            a: self.a.interpolate(to.a, t),
+        }
+    }
+}
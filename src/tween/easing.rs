@@ -0,0 +1,117 @@
+use std::f32::consts::PI;
+
+/// The rate of change of a [`Tween`] over time.
+///
+/// You can preview most of these curves at [easings.net].
+///
+/// [`Tween`]: struct.Tween.html
+/// [easings.net]: https://easings.net
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// Constant speed.
+    Linear,
+
+    /// Quadratic, starting slow.
+    QuadIn,
+
+    /// Quadratic, ending slow.
+    QuadOut,
+
+    /// Quadratic, starting and ending slow.
+    QuadInOut,
+
+    /// Cubic, starting slow.
+    CubicIn,
+
+    /// Cubic, ending slow.
+    CubicOut,
+
+    /// Cubic, starting and ending slow.
+    CubicInOut,
+
+    /// Sinusoidal, starting slow.
+    SineIn,
+
+    /// Sinusoidal, ending slow.
+    SineOut,
+
+    /// Sinusoidal, starting and ending slow.
+    SineInOut,
+
+    /// Overshoots the target slightly before settling on it.
+    BackOut,
+
+    /// Bounces against the target before settling on it.
+    BounceOut,
+}
+
+impl Easing {
+    /// Applies the [`Easing`] curve to the given progress, in the
+    /// `[0.0, 1.0]` interval.
+    ///
+    /// [`Easing`]: enum.Easing.html
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.max(0.0).min(1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => t * (2.0 - t),
+            Easing::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    -1.0 + (4.0 - 2.0 * t) * t
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => {
+                let t = t - 1.0;
+
+                t * t * t + 1.0
+            }
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    let t = 2.0 * t - 2.0;
+
+                    0.5 * t * t * t + 1.0
+                }
+            }
+            Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+            Easing::SineOut => (t * PI / 2.0).sin(),
+            Easing::SineInOut => 0.5 * (1.0 - (t * PI).cos()),
+            Easing::BackOut => {
+                const OVERSHOOT: f32 = 1.70158;
+
+                let t = t - 1.0;
+
+                t * t * ((OVERSHOOT + 1.0) * t + OVERSHOOT) + 1.0
+            }
+            Easing::BounceOut => {
+                if t < 1.0 / 2.75 {
+                    7.5625 * t * t
+                } else if t < 2.0 / 2.75 {
+                    let t = t - 1.5 / 2.75;
+
+                    7.5625 * t * t + 0.75
+                } else if t < 2.5 / 2.75 {
+                    let t = t - 2.25 / 2.75;
+
+                    7.5625 * t * t + 0.9375
+                } else {
+                    let t = t - 2.625 / 2.75;
+
+                    7.5625 * t * t + 0.984375
+                }
+            }
+        }
+    }
+}
+
+impl Default for Easing {
+    fn default() -> Easing {
+        Easing::Linear
+    }
+}
@@ -0,0 +1,50 @@
+use crate::tween::Animation;
+
+use std::time::Duration;
+
+/// An [`Animation`] that plays two animations at the same time.
+///
+/// Use [`Animation::and`] to create one.
+///
+/// [`Animation`]: trait.Animation.html
+/// [`Animation::and`]: trait.Animation.html#method.and
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Parallel<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Parallel<A, B> {
+    pub(super) fn new(first: A, second: B) -> Self {
+        Parallel { first, second }
+    }
+}
+
+impl<A, B> Animation for Parallel<A, B>
+where
+    A: Animation,
+    B: Animation,
+{
+    type Output = (A::Output, B::Output);
+
+    fn value(&self) -> (A::Output, B::Output) {
+        (self.first.value(), self.second.value())
+    }
+
+    fn advance(&mut self, delta: Duration) -> Duration {
+        let first = self.first.advance(delta);
+        let second = self.second.advance(delta);
+
+        // The time left over by the longest animation
+        first.min(second)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.first.is_finished() && self.second.is_finished()
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
@@ -0,0 +1,56 @@
+use crate::tween::Animation;
+
+use std::time::Duration;
+
+/// An [`Animation`] that plays two animations one after another.
+///
+/// Use [`Animation::then`] to create one.
+///
+/// [`Animation`]: trait.Animation.html
+/// [`Animation::then`]: trait.Animation.html#method.then
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sequence<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Sequence<A, B> {
+    pub(super) fn new(first: A, second: B) -> Self {
+        Sequence { first, second }
+    }
+}
+
+impl<A, B> Animation for Sequence<A, B>
+where
+    A: Animation,
+    B: Animation<Output = A::Output>,
+{
+    type Output = A::Output;
+
+    fn value(&self) -> A::Output {
+        if self.first.is_finished() {
+            self.second.value()
+        } else {
+            self.first.value()
+        }
+    }
+
+    fn advance(&mut self, delta: Duration) -> Duration {
+        let remaining = self.first.advance(delta);
+
+        if self.first.is_finished() {
+            self.second.advance(remaining)
+        } else {
+            Duration::from_secs(0)
+        }
+    }
+
+    fn is_finished(&self) -> bool {
+        self.first.is_finished() && self.second.is_finished()
+    }
+
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+    }
+}
@@ -139,14 +139,23 @@
 //! [`core`]: core/index.html
 pub mod core;
 mod renderer;
+mod runtime;
+mod settings;
+mod shortcut;
+mod theme;
 pub mod widget;
 
 #[doc(no_inline)]
 pub use self::core::{Align, Justify};
-pub use renderer::{Configuration, Renderer};
+pub use renderer::{Configuration, Primitives, Renderer};
+pub use runtime::Runtime;
+pub use settings::Settings;
+pub use shortcut::{Shortcut, Shortcuts};
+pub use theme::Theme;
 pub use widget::{
-    button, image, progress_bar, slider, Button, Checkbox, Image, ProgressBar,
-    Radio, Slider, Text,
+    button, gauge, image, log_view, mouse_area, panel, progress_bar, rule,
+    slider, tabs, virtual_keyboard, Button, Checkbox, Gauge, Image, LogView,
+    ProgressBar, Radio, RadioGroup, Rule, Slider, Space, Text, VirtualKeyboard,
 };
 
 /// A [`Column`] using the built-in [`Renderer`].
@@ -167,6 +176,30 @@ pub type Row<'a, Message> = widget::Row<'a, Message, Renderer>;
 /// [`Renderer`]: struct.Renderer.html
 pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 
+/// A [`Modal`] using the built-in [`Renderer`].
+///
+/// [`Modal`]: widget/modal/struct.Modal.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Modal<'a, Message> = widget::Modal<'a, Message, Renderer>;
+
+/// A [`MouseArea`] using the built-in [`Renderer`].
+///
+/// [`MouseArea`]: widget/mouse_area/struct.MouseArea.html
+/// [`Renderer`]: struct.Renderer.html
+pub type MouseArea<'a, Message> = widget::MouseArea<'a, Message, Renderer>;
+
+/// A [`Positioned`] widget using the built-in [`Renderer`].
+///
+/// [`Positioned`]: widget/struct.Positioned.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Positioned<'a, Message> = widget::Positioned<'a, Message, Renderer>;
+
+/// [`Tabs`] using the built-in [`Renderer`].
+///
+/// [`Tabs`]: widget/tabs/struct.Tabs.html
+/// [`Renderer`]: struct.Renderer.html
+pub type Tabs<'a, Message> = widget::Tabs<'a, Message, Renderer>;
+
 /// An [`Element`] using the built-in [`Renderer`].
 ///
 /// [`Element`]: core/struct.Element.html
@@ -174,10 +207,10 @@ pub type Panel<'a, Message> = widget::Panel<'a, Message, Renderer>;
 pub type Element<'a, Message> = self::core::Element<'a, Message, Renderer>;
 
 use crate::game::{self, Loop as _};
-use crate::graphics::{Point, Window, WindowSettings};
+use crate::graphics::{Color, Window, WindowSettings};
 use crate::input::{self, mouse, Input as _};
 use crate::load::Task;
-use crate::ui::core::{Event, Interface, MouseCursor, Renderer as _};
+use crate::ui::core::{MouseCursor, Renderer as _};
 use crate::{Debug, Game, Result};
 use std::convert::TryInto;
 
@@ -252,6 +285,78 @@ pub trait UserInterface: Game {
         window: &Window,
     ) -> self::core::Element<'_, Self::Message, Self::Renderer>;
 
+    /// Returns the color used to explain the layout of the whole user
+    /// interface, if any.
+    ///
+    /// When it returns a color, the bounds of every node of the layout are
+    /// drawn on top of the user interface using it. Use a translucent color
+    /// and toggle it while debugging your layout.
+    ///
+    /// By default, it returns `None`.
+    fn explain(&self) -> Option<Color> {
+        None
+    }
+
+    /// Returns the scale factor of the user interface.
+    ///
+    /// Layout sizes and font sizes are multiplied by this factor when drawn.
+    /// You can return [`Window::dpi`] to keep the user interface readable on
+    /// high DPI displays. The layout is computed in logical units, so divide
+    /// the size of the [`Window`] by the same factor in [`layout`].
+    ///
+    /// By default, it returns `1.0`.
+    ///
+    /// [`Window::dpi`]: ../graphics/struct.Window.html#method.dpi
+    /// [`Window`]: ../graphics/struct.Window.html
+    /// [`layout`]: #tymethod.layout
+    fn scale(&self, _window: &Window) -> f32 {
+        1.0
+    }
+
+    /// Returns the [`Settings`] of the user interface.
+    ///
+    /// Use them to let your players adjust the user interface to their needs,
+    /// like choosing a bigger scale or a high-contrast [`Theme`].
+    ///
+    /// By default, it returns the default [`Settings`] using the factor
+    /// returned by [`scale`].
+    ///
+    /// [`Settings`]: struct.Settings.html
+    /// [`Theme`]: struct.Theme.html
+    /// [`scale`]: #method.scale
+    fn settings(&self, window: &Window) -> Settings {
+        Settings {
+            scale: self.scale(window),
+            ..Settings::default()
+        }
+    }
+
+    /// Returns the keyboard [`Shortcuts`] of the user interface.
+    ///
+    /// They are triggered before the widgets process any input, producing
+    /// their messages without having to match keys in [`Game::interact`].
+    ///
+    /// By default, there are none.
+    ///
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    /// [`Game::interact`]: ../trait.Game.html#method.interact
+    fn shortcuts(&self, _window: &Window) -> Shortcuts<Self::Message> {
+        Shortcuts::new()
+    }
+
+    /// Announces the accessibility label of the hovered element.
+    ///
+    /// It is called whenever the mouse moves over an [`Element`] with a
+    /// different [`Element::accessibility_label`]. Forward the label to a
+    /// screen reader or a text-to-speech engine to make your user interface
+    /// accessible to players with visual impairments.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Element`]: core/struct.Element.html
+    /// [`Element::accessibility_label`]: core/struct.Element.html#method.accessibility_label
+    fn announce(&mut self, _label: &str, _window: &mut Window) {}
+
     /// Builds the renderer configuration for the user interface.
     ///
     /// By default, it returns `Default::default()`.
@@ -276,26 +381,21 @@ pub trait UserInterface: Game {
 }
 
 struct Loop<UI: UserInterface> {
-    renderer: UI::Renderer,
+    runtime: Runtime<UI::Renderer>,
     messages: Vec<UI::Message>,
     mouse_cursor: MouseCursor,
-    cache: Option<core::Cache>,
-    cursor_position: Point,
-    events: Vec<Event>,
+    accessibility_label: Option<String>,
 }
 
 impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
     type Attributes = UI::Renderer;
 
-    fn new(renderer: UI::Renderer, game: &mut UI, window: &Window) -> Self {
-        let cache = Interface::compute(game.layout(window), &renderer).cache();
+    fn new(renderer: UI::Renderer, _game: &mut UI, _window: &Window) -> Self {
         Loop {
-            renderer,
+            runtime: Runtime::new(renderer),
             messages: Vec::new(),
             mouse_cursor: MouseCursor::OutOfBounds,
-            cache: Some(cache),
-            cursor_position: Point::new(0.0, 0.0),
-            events: Vec::new(),
+            accessibility_label: None,
         }
     }
 
@@ -306,16 +406,7 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
     fn on_input(&mut self, input: &mut UI::Input, event: input::Event) {
         input.update(event);
 
-        match event {
-            input::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
-                self.cursor_position = Point::new(x, y);
-            }
-            _ => {}
-        };
-
-        if let Some(ui_event) = Event::from_input(event) {
-            self.events.push(ui_event);
-        }
+        self.runtime.on_input(event);
     }
 
     fn after_draw(
@@ -326,26 +417,17 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
         debug: &mut Debug,
     ) {
         debug.ui_started();
-        let mut interface = Interface::compute_with_cache(
-            ui.layout(window),
-            &self.renderer,
-            self.cache.take().unwrap(),
-        );
-
-        let cursor_position = self.cursor_position;
         let messages = &mut self.messages;
+        let settings = ui.settings(window);
 
-        self.events.drain(..).for_each(|event| {
-            interface.on_event(event, cursor_position, messages)
-        });
+        self.runtime.explain(ui.explain());
+        self.runtime.scale(settings.scale);
+        self.runtime.theme(settings.theme);
+        self.runtime.trigger(&ui.shortcuts(window), messages);
 
-        let new_cursor = interface.draw(
-            &mut self.renderer,
-            &mut window.frame(),
-            cursor_position,
-        );
-
-        self.cache = Some(interface.cache());
+        let new_cursor =
+            self.runtime
+                .draw(ui.layout(window), &mut window.frame(), messages);
 
         if new_cursor != self.mouse_cursor {
             if new_cursor == MouseCursor::OutOfBounds {
@@ -364,6 +446,16 @@ impl<UI: UserInterface> game::Loop<UI> for Loop<UI> {
             window.update_cursor(Some(self.mouse_cursor.into()));
         }
 
+        let label = self.runtime.accessibility_label();
+
+        if label != self.accessibility_label.as_ref().map(String::as_str) {
+            self.accessibility_label = label.map(String::from);
+
+            if let Some(label) = &self.accessibility_label {
+                ui.announce(label, window);
+            }
+        }
+
         for message in messages.drain(..) {
             ui.react(message, window);
         }
@@ -161,6 +161,156 @@ impl<'a, Message, Renderer> Element<'a, Message, Renderer> {
         }
     }
 
+    /// Sets the [`MouseCursor`] to show while the [`Element`] is hovered.
+    ///
+    /// This overrides the [`MouseCursor`] chosen by the [`Renderer`]. If
+    /// nested elements specify a [`MouseCursor`] too, the topmost hovered one
+    /// wins.
+    ///
+    /// [`MouseCursor`]: enum.MouseCursor.html
+    /// [`Element`]: struct.Element.html
+    /// [`Renderer`]: trait.Renderer.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::core::{Element, MouseCursor};
+    /// use coffee::ui::{button, Button, Renderer};
+    ///
+    /// #[derive(Debug, Clone, Copy)]
+    /// enum Message {
+    ///     ItemPicked,
+    /// }
+    ///
+    /// fn item(state: &mut button::State) -> Element<Message, Renderer> {
+    ///     let button = Button::new(state, "Pick").on_press(Message::ItemPicked);
+    ///
+    ///     Element::from(button).cursor(MouseCursor::Grab)
+    /// }
+    /// ```
+    pub fn cursor(self, cursor: MouseCursor) -> Element<'a, Message, Renderer>
+    where
+        Message: 'static,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(Cursor::new(self, cursor)),
+        }
+    }
+
+    /// Shows a tooltip with the given content while the [`Element`] is
+    /// hovered.
+    ///
+    /// The tooltip appears next to the cursor once the mouse rests over the
+    /// [`Element`] for a while. The delay can be configured in the
+    /// [`Renderer`]. If nested elements have a tooltip too, the innermost
+    /// hovered one wins.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Renderer`]: trait.Renderer.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::core::Element;
+    /// use coffee::ui::{button, Button, Renderer};
+    ///
+    /// #[derive(Debug, Clone, Copy)]
+    /// enum Message {
+    ///     Saved,
+    /// }
+    ///
+    /// fn save(state: &mut button::State) -> Element<Message, Renderer> {
+    ///     let button = Button::new(state, "Save").on_press(Message::Saved);
+    ///
+    ///     Element::from(button).tooltip("Saves the current game")
+    /// }
+    /// ```
+    pub fn tooltip<S>(self, content: S) -> Element<'a, Message, Renderer>
+    where
+        S: Into<String>,
+        Message: 'static,
+        Renderer: 'a + core::Renderer,
+    {
+        Element {
+            widget: Box::new(Tooltip::new(self, content.into())),
+        }
+    }
+
+    /// Describes the [`Element`] to assistive technologies, like screen
+    /// readers.
+    ///
+    /// The label of the hovered [`Element`] is reported to
+    /// [`UserInterface::announce`] whenever it changes, so you can forward it
+    /// to a text-to-speech engine. If nested elements have a label too, the
+    /// innermost hovered one wins.
+    ///
+    /// The label does not affect layout or drawing.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`UserInterface::announce`]: ../trait.UserInterface.html#method.announce
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::core::Element;
+    /// use coffee::ui::{button, Button, Renderer};
+    ///
+    /// #[derive(Debug, Clone, Copy)]
+    /// enum Message {
+    ///     Closed,
+    /// }
+    ///
+    /// fn close(state: &mut button::State) -> Element<Message, Renderer> {
+    ///     let button = Button::new(state, "X").on_press(Message::Closed);
+    ///
+    ///     Element::from(button).accessibility_label("Close the inventory")
+    /// }
+    /// ```
+    pub fn accessibility_label<S>(
+        self,
+        label: S,
+    ) -> Element<'a, Message, Renderer>
+    where
+        S: Into<String>,
+        Message: 'static,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(AccessibilityLabel::new(self, label.into())),
+        }
+    }
+
+    /// Adds a margin around the [`Element`], in pixels.
+    ///
+    /// [`Element`]: struct.Element.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use coffee::ui::core::Element;
+    /// use coffee::ui::{Renderer, Text};
+    ///
+    /// fn title<'a>() -> Element<'a, (), Renderer> {
+    ///     Element::from(Text::new("Inventory")).margin(0, 0, 20, 0)
+    /// }
+    /// ```
+    pub fn margin(
+        self,
+        top: u32,
+        right: u32,
+        bottom: u32,
+        left: u32,
+    ) -> Element<'a, Message, Renderer>
+    where
+        Message: 'static,
+        Renderer: 'a,
+    {
+        Element {
+            widget: Box::new(Margin::new(self, [top, right, bottom, left])),
+        }
+    }
+
     pub(crate) fn compute_layout(&self, renderer: &Renderer) -> result::Layout {
         let node = self.widget.node(renderer);
 
@@ -237,6 +387,22 @@ where
         self.widget.draw(renderer, layout, cursor_position)
     }
 
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.widget.accessibility_label(layout, cursor_position)
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.widget.hash(state);
     }
@@ -296,4 +462,349 @@ where
     fn hash(&self, state: &mut Hasher) {
         self.element.widget.hash(state);
     }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.element.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.element
+            .widget
+            .accessibility_label(layout, cursor_position)
+    }
+}
+
+struct Cursor<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    cursor: MouseCursor,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Cursor<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cursor")
+            .field("element", &self.element)
+            .field("cursor", &self.cursor)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Cursor<'a, Message, Renderer> {
+    fn new(
+        element: Element<'a, Message, Renderer>,
+        cursor: MouseCursor,
+    ) -> Self {
+        Cursor { element, cursor }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Cursor<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.element.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.element.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.element.widget.hash(state);
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        let bounds = layout.bounds();
+
+        self.element
+            .widget
+            .cursor(layout, cursor_position)
+            .or_else(|| {
+                if bounds.contains(cursor_position) {
+                    Some(self.cursor)
+                } else {
+                    None
+                }
+            })
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.element
+            .widget
+            .accessibility_label(layout, cursor_position)
+    }
+}
+
+struct Margin<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    margin: [u32; 4],
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Margin<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Margin")
+            .field("element", &self.element)
+            .field("margin", &self.margin)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Margin<'a, Message, Renderer> {
+    fn new(element: Element<'a, Message, Renderer>, margin: [u32; 4]) -> Self {
+        Margin { element, margin }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Margin<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let [top, right, bottom, left] = self.margin;
+
+        self.element
+            .widget
+            .node(renderer)
+            .restyle(|style| style.margin(top, right, bottom, left))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.element.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        self.margin.hash(state);
+        self.element.widget.hash(state);
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.element.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.element
+            .widget
+            .accessibility_label(layout, cursor_position)
+    }
+}
+
+struct Tooltip<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    content: String,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Tooltip<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tooltip")
+            .field("element", &self.element)
+            .field("content", &self.content)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer> {
+    fn new(element: Element<'a, Message, Renderer>, content: String) -> Self {
+        Tooltip { element, content }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tooltip<'a, Message, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.element.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let cursor =
+            self.element.widget.draw(renderer, layout, cursor_position);
+
+        if bounds.contains(cursor_position) {
+            renderer.tooltip(&self.content, cursor_position);
+        }
+
+        cursor
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.element.widget.hash(state);
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.element.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.element
+            .widget
+            .accessibility_label(layout, cursor_position)
+    }
+}
+
+struct AccessibilityLabel<'a, Message, Renderer> {
+    element: Element<'a, Message, Renderer>,
+    label: String,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for AccessibilityLabel<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AccessibilityLabel")
+            .field("element", &self.element)
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> AccessibilityLabel<'a, Message, Renderer> {
+    fn new(element: Element<'a, Message, Renderer>, label: String) -> Self {
+        AccessibilityLabel { element, label }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for AccessibilityLabel<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.element.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.element
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.element.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.element.widget.hash(state);
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.element.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        let bounds = layout.bounds();
+
+        self.element
+            .widget
+            .accessibility_label(layout, cursor_position)
+            .or_else(|| {
+                if bounds.contains(cursor_position) {
+                    Some(self.label.as_str())
+                } else {
+                    None
+                }
+            })
+    }
 }
@@ -1,7 +1,7 @@
 use std::hash::Hasher;
 use stretch::result;
 
-use crate::graphics::{Frame, Point};
+use crate::graphics::{Color, Frame, Point};
 use crate::ui::core::{self, Element, Event, Layout, MouseCursor};
 
 pub struct Interface<'a, Message, Renderer> {
@@ -72,18 +72,35 @@ where
         renderer: &mut Renderer,
         frame: &mut Frame<'_>,
         cursor_position: Point,
+        explain: Option<Color>,
     ) -> MouseCursor {
         let Interface { root, layout, .. } = self;
 
+        if let Some(color) = explain {
+            renderer.explain(&Self::layout(layout), color);
+        }
+
         let cursor =
             root.widget
                 .draw(renderer, Self::layout(layout), cursor_position);
 
+        let cursor = root
+            .widget
+            .cursor(Self::layout(layout), cursor_position)
+            .unwrap_or(cursor);
+
         renderer.flush(frame);
 
         cursor
     }
 
+    pub fn accessibility_label(&self, cursor_position: Point) -> Option<&str> {
+        let Interface { root, layout, .. } = self;
+
+        root.widget
+            .accessibility_label(Self::layout(layout), cursor_position)
+    }
+
     pub fn cache(self) -> Cache {
         Cache {
             hash: self.hash,
@@ -56,4 +56,11 @@ impl<'a> Layout<'a> {
             .iter()
             .map(move |layout| Layout::new(layout, self.position))
     }
+
+    pub(crate) fn translate(&self, offset: Vector) -> Layout<'a> {
+        Layout {
+            layout: self.layout,
+            position: self.position + offset,
+        }
+    }
 }
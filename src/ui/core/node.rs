@@ -57,4 +57,19 @@ impl Node {
             Box::new(move |size| Ok(measure(size))),
         ))
     }
+
+    /// Replaces the [`Style`] of the [`Node`] with the result of the given
+    /// function.
+    ///
+    /// [`Node`]: struct.Node.html
+    /// [`Style`]: struct.Style.html
+    pub(crate) fn restyle<F>(mut self, f: F) -> Node
+    where
+        F: FnOnce(Style) -> Style,
+    {
+        let style = f(Style(self.0.style()));
+
+        self.0.set_style(style.0);
+        self
+    }
 }
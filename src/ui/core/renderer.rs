@@ -1,13 +1,16 @@
-use crate::graphics::{Color, Frame};
+use crate::graphics::{Color, Frame, Point};
 use crate::load::Task;
 use crate::ui::core::Layout;
+use crate::ui::Theme;
 
 /// The renderer of a user interface.
 ///
 /// The implementor of this trait will also need to implement the `Renderer`
-/// trait of the [widgets] you want to use.
+/// trait of the [widgets] you want to use. Alternatively, it can implement
+/// [`Primitives`] to render all the built-in widgets at once.
 ///
 /// [widgets]: ../widget/index.html
+/// [`Primitives`]: ../trait.Primitives.html
 pub trait Renderer {
     /// The configuration of the renderer.
     ///
@@ -33,6 +36,47 @@ pub trait Renderer {
     /// [`Element::explain`]: struct.Element.html#method.explain
     fn explain(&mut self, layout: &Layout<'_>, color: Color);
 
+    /// Requests a tooltip with the given content to be shown near the cursor.
+    ///
+    /// This will be called on every frame while an [`Element`] with a tooltip
+    /// is hovered, once [`Widget::draw`] has been called for it. The
+    /// [`Renderer`] should wait for the mouse to rest before showing the
+    /// tooltip, and it should draw it on top of everything else in [`flush`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Element`]: struct.Element.html
+    /// [`Widget::draw`]: trait.Widget.html#tymethod.draw
+    /// [`Renderer`]: trait.Renderer.html
+    /// [`flush`]: #tymethod.flush
+    fn tooltip(&mut self, _content: &str, _cursor_position: Point) {}
+
+    /// Sets the scale factor of the user interface.
+    ///
+    /// The layout of the user interface is computed in logical units. The
+    /// [`Renderer`] should multiply everything it draws in [`flush`],
+    /// including font sizes, by this factor.
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Renderer`]: trait.Renderer.html
+    /// [`flush`]: #tymethod.flush
+    fn scale(&mut self, _scale: f32) {}
+
+    /// Sets the [`Theme`] of the user interface.
+    ///
+    /// The [`Renderer`] should use its colors to draw the widgets. If it
+    /// implements [`Primitives`], it can store the [`Theme`] and return it in
+    /// [`Primitives::theme`].
+    ///
+    /// By default, it does nothing.
+    ///
+    /// [`Theme`]: ../struct.Theme.html
+    /// [`Renderer`]: trait.Renderer.html
+    /// [`Primitives`]: ../trait.Primitives.html
+    /// [`Primitives::theme`]: ../trait.Primitives.html#method.theme
+    fn theme(&mut self, _theme: Theme) {}
+
     /// Flushes the renderer to draw on the given [`Frame`].
     ///
     /// This method will be called by the runtime after calling [`Widget::draw`]
@@ -1,6 +1,8 @@
 use std::hash::{Hash, Hasher};
 use stretch::{geometry, style};
 
+use crate::graphics::Point;
+
 /// The appearance of a [`Node`].
 ///
 /// [`Node`]: struct.Node.html
@@ -72,12 +74,18 @@ impl Style {
         self
     }
 
-    pub(crate) fn align_items(mut self, align: Align) -> Self {
+    /// Sets the alignment of the children of a [`Node`] on its cross axis.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn align_items(mut self, align: Align) -> Self {
         self.0.align_items = align.into();
         self
     }
 
-    pub(crate) fn justify_content(mut self, justify: Justify) -> Self {
+    /// Sets the distribution of the children of a [`Node`] on its main axis.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn justify_content(mut self, justify: Justify) -> Self {
         self.0.justify_content = justify.into();
         self
     }
@@ -110,6 +118,46 @@ impl Style {
 
         self
     }
+
+    /// Sets the margin of a [`Node`] in pixels.
+    ///
+    /// The margin is empty space around the [`Node`], outside of its bounds.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn margin(
+        mut self,
+        top: u32,
+        right: u32,
+        bottom: u32,
+        left: u32,
+    ) -> Self {
+        self.0.margin = stretch::geometry::Rect {
+            start: style::Dimension::Points(left as f32),
+            end: style::Dimension::Points(right as f32),
+            top: style::Dimension::Points(top as f32),
+            bottom: style::Dimension::Points(bottom as f32),
+        };
+
+        self
+    }
+
+    /// Places a [`Node`] at the given position in pixels, relative to its
+    /// parent.
+    ///
+    /// The [`Node`] is taken out of the layout of its parent, so it neither
+    /// takes space nor moves its siblings.
+    ///
+    /// [`Node`]: struct.Node.html
+    pub fn position(mut self, position: Point) -> Self {
+        self.0.position_type = style::PositionType::Absolute;
+        self.0.position = stretch::geometry::Rect {
+            start: style::Dimension::Points(position.x),
+            top: style::Dimension::Points(position.y),
+            ..Default::default()
+        };
+
+        self
+    }
 }
 
 impl Default for Style {
@@ -129,7 +177,9 @@ impl Hash for Style {
         hash_size(&self.0.max_size, state);
 
         hash_rect(&self.0.margin, state);
+        hash_rect(&self.0.position, state);
 
+        (self.0.position_type as u8).hash(state);
         (self.0.flex_direction as u8).hash(state);
         (self.0.align_items as u8).hash(state);
         (self.0.justify_content as u8).hash(state);
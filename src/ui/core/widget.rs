@@ -49,6 +49,46 @@ pub trait Widget<Message, Renderer>: std::fmt::Debug {
     /// [`Text`]: ../widget/text/struct.Text.html
     fn hash(&self, state: &mut Hasher);
 
+    /// Returns the [`MouseCursor`] preferred by the [`Widget`] at the given
+    /// cursor position, if any.
+    ///
+    /// When a preference is returned, it overrides the [`MouseCursor`]
+    /// produced by [`Widget::draw`]. Widgets containing children should
+    /// forward this call, letting the topmost hovered [`Widget`] decide.
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`MouseCursor`]: enum.MouseCursor.html
+    /// [`Widget`]: trait.Widget.html
+    /// [`Widget::draw`]: trait.Widget.html#tymethod.draw
+    fn cursor(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        None
+    }
+
+    /// Returns the accessibility label of the [`Widget`] at the given cursor
+    /// position, if any.
+    ///
+    /// The runtime uses it to describe the hovered part of the user
+    /// interface to assistive technologies, like screen readers. Widgets
+    /// containing children should forward this call, like they do with
+    /// [`cursor`].
+    ///
+    /// By default, it returns `None`.
+    ///
+    /// [`Widget`]: trait.Widget.html
+    /// [`cursor`]: #method.cursor
+    fn accessibility_label(
+        &self,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> Option<&str> {
+        None
+    }
+
     /// Processes a runtime [`Event`].
     ///
     /// It receives:
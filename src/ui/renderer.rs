@@ -1,18 +1,33 @@
 mod button;
 mod checkbox;
+mod gauge;
 mod image;
+mod log_view;
+mod modal;
 mod panel;
+mod primitives;
 mod progress_bar;
 mod radio;
+mod rule;
 mod slider;
+mod tabs;
 mod text;
+mod tooltip;
+mod virtual_keyboard;
 
-use crate::graphics::{Batch, Color, Font, Frame, Image, Mesh, Shape};
+pub use primitives::Primitives;
+
+use crate::graphics::{
+    self, Batch, Color, Font, FontFace, Frame, HorizontalAlignment, Image,
+    IntoQuad, Mesh, Point, Quad, Rectangle, Shape, Sprite, Target,
+    Transformation, Vector, VerticalAlignment,
+};
 use crate::load::{Join, Task};
-use crate::ui::core;
+use crate::ui::{core, Theme};
 
 use std::cell::RefCell;
 use std::rc::Rc;
+use std::time::{Duration, Instant};
 
 /// A renderer capable of drawing all the [built-in widgets].
 ///
@@ -26,7 +41,132 @@ pub struct Renderer {
     pub(crate) sprites: Batch,
     pub(crate) images: Vec<Batch>,
     pub(crate) font: Rc<RefCell<Font>>,
+    pub(crate) epoch: Instant,
+    sprite_sheet: Image,
+    text: Vec<QueuedText>,
+    layers: Vec<Layer>,
+    clip: Option<Rectangle<f32>>,
     explain_mesh: Mesh,
+    tooltip: tooltip::Tooltip,
+    scale: f32,
+    theme: Theme,
+}
+
+impl Renderer {
+    pub(crate) fn add_text(&mut self, text: graphics::Text<'_>) {
+        self.text.push(QueuedText::new(text));
+    }
+
+    // Adds a sprite of the sprite sheet, greyed out when it belongs to a
+    // disabled widget
+    fn add_sprite(&mut self, sprite: Sprite, is_disabled: bool) {
+        if is_disabled {
+            self.sprites.add(Disabled(sprite));
+        } else {
+            self.sprites.add(sprite);
+        }
+    }
+
+    pub(crate) fn push_layer(&mut self, background: Rectangle<f32>) {
+        self.take_layer(Some(background));
+    }
+
+    pub(crate) fn clip_to(&mut self, bounds: Option<Rectangle<f32>>) {
+        self.take_layer(None);
+        self.clip = bounds;
+    }
+
+    fn take_layer(&mut self, background: Option<Rectangle<f32>>) {
+        let sprites = Batch::new(self.sprite_sheet.clone());
+
+        self.layers.push(Layer {
+            sprites: std::mem::replace(&mut self.sprites, sprites),
+            images: std::mem::replace(&mut self.images, Vec::new()),
+            text: std::mem::replace(&mut self.text, Vec::new()),
+            clip: self.clip,
+            background,
+        });
+    }
+
+    fn draw_contents(
+        font: &mut Font,
+        sprites: &Batch,
+        images: &[Batch],
+        text: &[QueuedText],
+        scale: f32,
+        target: &mut Target<'_>,
+    ) {
+        {
+            let mut target = target.transform(Transformation::scale(scale));
+
+            sprites.draw(&mut target);
+
+            for image in images {
+                image.draw(&mut target);
+            }
+        }
+
+        // Text is scaled by size, instead of transformed, to keep it crisp
+        for text in text {
+            font.add(text.as_text(scale));
+        }
+
+        font.draw(target);
+    }
+}
+
+// Drawn contents that are clipped or covered by a modal overlay
+struct Layer {
+    sprites: Batch,
+    images: Vec<Batch>,
+    text: Vec<QueuedText>,
+    clip: Option<Rectangle<f32>>,
+    background: Option<Rectangle<f32>>,
+}
+
+// Text is queued by the renderer, instead of the font, to keep the order of
+// the layers
+struct QueuedText {
+    content: String,
+    position: Point,
+    bounds: (f32, f32),
+    size: f32,
+    color: Color,
+    horizontal_alignment: HorizontalAlignment,
+    vertical_alignment: VerticalAlignment,
+    face: FontFace,
+}
+
+impl QueuedText {
+    fn new(text: graphics::Text<'_>) -> QueuedText {
+        QueuedText {
+            content: String::from(text.content),
+            position: text.position,
+            bounds: text.bounds,
+            size: text.size,
+            color: text.color,
+            horizontal_alignment: text.horizontal_alignment,
+            vertical_alignment: text.vertical_alignment,
+            face: text.face,
+        }
+    }
+
+    fn as_text(&self, scale: f32) -> graphics::Text<'_> {
+        graphics::Text {
+            content: &self.content,
+            position: Point::new(
+                self.position.x * scale,
+                self.position.y * scale,
+            ),
+            bounds: (self.bounds.0 * scale, self.bounds.1 * scale),
+            size: self.size * scale,
+            color: self.color,
+            horizontal_alignment: self.horizontal_alignment,
+            vertical_alignment: self.vertical_alignment,
+            face: self.face,
+            ..graphics::Text::default()
+        }
+    }
 }
 
 impl std::fmt::Debug for Renderer {
@@ -42,13 +182,23 @@ impl core::Renderer for Renderer {
     type Configuration = Configuration;
 
     fn load(config: Configuration) -> Task<Renderer> {
+        let tooltip_delay = config.tooltip_delay;
+
         (config.sprites, config.font)
             .join()
-            .map(|(sprites, font)| Renderer {
-                sprites: Batch::new(sprites),
+            .map(move |(sprites, font)| Renderer {
+                sprites: Batch::new(sprites.clone()),
                 images: Vec::new(),
                 font: Rc::new(RefCell::new(font)),
+                epoch: Instant::now(),
+                sprite_sheet: sprites,
+                text: Vec::new(),
+                layers: Vec::new(),
+                clip: None,
                 explain_mesh: Mesh::new(),
+                tooltip: tooltip::Tooltip::new(tooltip_delay),
+                scale: 1.0,
+                theme: Theme::default(),
             })
     }
 
@@ -61,24 +211,95 @@ impl core::Renderer for Renderer {
             .for_each(|layout| self.explain(&layout, color));
     }
 
+    fn tooltip(&mut self, content: &str, cursor_position: Point) {
+        self.tooltip.request(content, cursor_position);
+    }
+
+    fn scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    fn theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
     fn flush(&mut self, frame: &mut Frame<'_>) {
-        let target = &mut frame.as_target();
+        let scale = self.scale;
+        let bounds = (frame.width() / scale, frame.height() / scale);
 
-        self.sprites.draw(target);
-        self.sprites.clear();
+        let mut font = self.font.borrow_mut();
+
+        for layer in self.layers.drain(..) {
+            match layer.clip {
+                Some(clip) => {
+                    let region = Rectangle {
+                        x: clip.x * scale,
+                        y: clip.y * scale,
+                        width: clip.width * scale,
+                        height: clip.height * scale,
+                    };
+
+                    // Keep the coordinates of the frame inside the viewport
+                    let offset = Vector::new(-region.x, -region.y);
+                    let mut viewport = frame.viewport(region);
+                    let mut target =
+                        viewport.transform(Transformation::translate(offset));
 
-        for image in &self.images {
-            image.draw(target);
+                    Self::draw_contents(
+                        &mut font,
+                        &layer.sprites,
+                        &layer.images,
+                        &layer.text,
+                        scale,
+                        &mut target,
+                    );
+                }
+                None => Self::draw_contents(
+                    &mut font,
+                    &layer.sprites,
+                    &layer.images,
+                    &layer.text,
+                    scale,
+                    &mut frame.as_target(),
+                ),
+            }
+
+            if let Some(background) = layer.background {
+                modal::draw_background(
+                    background,
+                    self.theme.backdrop,
+                    &mut frame
+                        .as_target()
+                        .transform(Transformation::scale(scale)),
+                );
+            }
         }
 
+        let target = &mut frame.as_target();
+
+        Self::draw_contents(
+            &mut font,
+            &self.sprites,
+            &self.images,
+            &self.text,
+            scale,
+            target,
+        );
+
+        self.sprites.clear();
         self.images.clear();
+        self.text.clear();
+        self.clip = None;
 
-        self.font.borrow_mut().draw(target);
+        let target = &mut target.transform(Transformation::scale(scale));
 
         if !self.explain_mesh.is_empty() {
             self.explain_mesh.draw(target);
             self.explain_mesh = Mesh::new();
         }
+
+        self.tooltip
+            .draw(&mut font, target, bounds, self.theme.text);
     }
 }
 
@@ -118,6 +339,12 @@ pub struct Configuration {
     /// [`Text`]: widget/text/struct.Text.html
     /// [Inconsolata Regular]: https://fonts.google.com/specimen/Inconsolata
     pub font: Task<Font>,
+
+    /// The time the mouse needs to rest over an element before its tooltip
+    /// is shown.
+    ///
+    /// By default, it is 500 milliseconds.
+    pub tooltip_delay: Duration,
 }
 
 impl Default for Configuration {
@@ -134,6 +361,25 @@ impl Default for Configuration {
             font: Font::load_from_bytes(include_bytes!(
                 "../../resources/font/Inconsolata-Regular.ttf"
             )),
+            tooltip_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+// A sprite drawn with half of its opacity, like the disabled widgets of the
+// `Primitives` renderer
+struct Disabled(Sprite);
+
+impl IntoQuad for Disabled {
+    fn into_quad(self, x_unit: f32, y_unit: f32) -> Quad {
+        let color = Color {
+            a: 0.5,
+            ..Color::WHITE
+        };
+
+        Quad {
+            colors: [color; 4],
+            ..self.0.into_quad(x_unit, y_unit)
         }
     }
 }
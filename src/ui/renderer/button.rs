@@ -26,6 +26,13 @@ const RIGHT: Rectangle<u16> = Rectangle {
     height: LEFT.height,
 };
 
+const DISABLED_LABEL: Color = Color {
+    r: 0.6,
+    g: 0.6,
+    b: 0.6,
+    a: 0.6,
+};
+
 impl button::Renderer for Renderer {
     fn draw(
         &mut self,
@@ -34,13 +41,15 @@ impl button::Renderer for Renderer {
         state: &button::State,
         label: &str,
         class: button::Class,
+        is_disabled: bool,
     ) -> MouseCursor {
-        let mouse_over = bounds.contains(cursor_position);
+        let mouse_over = !is_disabled && bounds.contains(cursor_position);
+        let is_highlighted = mouse_over || state.is_focused();
 
         let mut state_offset = 0;
 
-        if mouse_over {
-            if state.is_pressed() {
+        if is_highlighted {
+            if mouse_over && state.is_pressed() {
                 bounds.y += 4.0;
                 state_offset = RIGHT.x + RIGHT.width;
             } else {
@@ -62,6 +71,7 @@ impl button::Renderer for Renderer {
             },
             position: Point::new(bounds.x, bounds.y),
             scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -72,6 +82,7 @@ impl button::Renderer for Renderer {
             },
             position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
             scale: (bounds.width - (LEFT.width + RIGHT.width) as f32, 1.0),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -85,13 +96,16 @@ impl button::Renderer for Renderer {
                 bounds.y,
             ),
             scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
-        self.font.borrow_mut().add(Text {
+        self.add_text(Text {
             content: label,
             position: Point::new(bounds.x, bounds.y - 4.0),
             bounds: (bounds.width, bounds.height),
-            color: if mouse_over {
+            color: if is_disabled {
+                DISABLED_LABEL
+            } else if is_highlighted {
                 Color::WHITE
             } else {
                 Color {
@@ -17,28 +17,38 @@ impl checkbox::Renderer for Renderer {
         bounds: Rectangle<f32>,
         text_bounds: Rectangle<f32>,
         is_checked: bool,
+        is_disabled: bool,
     ) -> MouseCursor {
-        let mouse_over = bounds.contains(cursor_position)
-            || text_bounds.contains(cursor_position);
+        let mouse_over = !is_disabled
+            && (bounds.contains(cursor_position)
+                || text_bounds.contains(cursor_position));
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: SPRITE.x + (if mouse_over { SPRITE.width } else { 0 }),
-                ..SPRITE
-            },
-            position: Point::new(bounds.x, bounds.y),
-            scale: (1.0, 1.0),
-        });
-
-        if is_checked {
-            self.sprites.add(Sprite {
+        self.add_sprite(
+            Sprite {
                 source: Rectangle {
-                    x: SPRITE.x + SPRITE.width * 2,
+                    x: SPRITE.x + (if mouse_over { SPRITE.width } else { 0 }),
                     ..SPRITE
                 },
                 position: Point::new(bounds.x, bounds.y),
                 scale: (1.0, 1.0),
-            });
+                ..Sprite::default()
+            },
+            is_disabled,
+        );
+
+        if is_checked {
+            self.add_sprite(
+                Sprite {
+                    source: Rectangle {
+                        x: SPRITE.x + SPRITE.width * 2,
+                        ..SPRITE
+                    },
+                    position: Point::new(bounds.x, bounds.y),
+                    scale: (1.0, 1.0),
+                    ..Sprite::default()
+                },
+                is_disabled,
+            );
         }
 
         if mouse_over {
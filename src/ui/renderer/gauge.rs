@@ -0,0 +1,55 @@
+use crate::graphics::{Point, Rectangle, Sprite};
+use crate::ui::{gauge, Renderer};
+
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+
+// A single pixel of the progress bar fill, in every class
+const DOT: Rectangle<u16> = Rectangle {
+    x: 6,
+    y: 58,
+    width: 1,
+    height: 1,
+};
+
+const CLASS_HEIGHT: u16 = 49;
+
+const SEGMENTS: u16 = 40;
+const START_ANGLE: f32 = PI * 0.75;
+const SWEEP: f32 = PI * 1.5;
+
+impl gauge::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        range: RangeInclusive<f32>,
+        value: f32,
+    ) {
+        let (start, end) = range.into_inner();
+        let progress = (value - start) / (end - start).max(f32::EPSILON);
+
+        let center = bounds.center();
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let thickness = (radius * 0.2).max(2.0);
+        let arc_radius = radius - thickness / 2.0;
+
+        for i in 0..SEGMENTS {
+            let t = (i as f32 + 0.5) / SEGMENTS as f32;
+            let angle = START_ANGLE + SWEEP * t;
+            let class_index = if t <= progress { 0 } else { 1 };
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: DOT.y + class_index * CLASS_HEIGHT,
+                    ..DOT
+                },
+                position: Point::new(
+                    center.x + arc_radius * angle.cos() - thickness / 2.0,
+                    center.y + arc_radius * angle.sin() - thickness / 2.0,
+                ),
+                scale: (thickness, thickness),
+                ..Sprite::default()
+            });
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::graphics::{
+    self, Color, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+use crate::ui::core::MouseCursor;
+use crate::ui::widget::{log_view, panel};
+use crate::ui::Renderer;
+
+const PADDING: f32 = 10.0;
+
+impl log_view::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        entries: &[&log_view::Entry],
+        text_size: f32,
+    ) -> MouseCursor {
+        panel::Renderer::draw(self, bounds);
+
+        let line_width = bounds.width - PADDING * 2.0;
+
+        // The vertical padding shrinks when needed, so every visible entry
+        // fits in the bounds
+        let rows = entries.len() as f32 * text_size;
+        let padding = PADDING.min((bounds.height - rows) / 2.0).max(0.0);
+
+        // Show the newest entry at the bottom
+        let mut y = bounds.y + bounds.height - padding;
+
+        for entry in entries.iter().rev() {
+            y -= text_size;
+
+            self.add_text(graphics::Text {
+                content: &entry.message,
+                position: Point::new(bounds.x + PADDING, y),
+                bounds: (line_width, text_size),
+                color: level_color(entry.level),
+                size: text_size,
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Top,
+                ..graphics::Text::default()
+            });
+        }
+
+        if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+}
+
+fn level_color(level: log_view::Level) -> Color {
+    match level {
+        log_view::Level::Trace => Color::from_rgb(120, 120, 120),
+        log_view::Level::Debug => Color::from_rgb(140, 180, 220),
+        log_view::Level::Info => Color::WHITE,
+        log_view::Level::Warn => Color::from_rgb(240, 200, 80),
+        log_view::Level::Error => Color::from_rgb(230, 80, 80),
+    }
+}
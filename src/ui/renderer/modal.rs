@@ -0,0 +1,20 @@
+use crate::graphics::{Color, Mesh, Rectangle, Shape, Target};
+use crate::ui::widget::modal;
+use crate::ui::Renderer;
+
+impl modal::Renderer for Renderer {
+    fn draw(&mut self, bounds: Rectangle<f32>) {
+        self.push_layer(bounds);
+    }
+}
+
+pub fn draw_background(
+    bounds: Rectangle<f32>,
+    color: Color,
+    target: &mut Target<'_>,
+) {
+    let mut mesh = Mesh::new();
+
+    mesh.fill(Shape::Rectangle(bounds), color);
+    mesh.draw(target);
+}
@@ -1,9 +1,22 @@
-use crate::graphics::{Point, Rectangle, Sprite};
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, Sprite, Text,
+    VerticalAlignment,
+};
+use crate::ui::core::MouseCursor;
 use crate::ui::widget::panel;
 use crate::ui::Renderer;
 
 const PANEL_WIDTH: u16 = 28;
 const PANEL_HEIGHT: u16 = 34;
+const SCROLLBAR_WIDTH: f32 = 4.0;
+
+// A single pixel of the empty progress bar track
+const DOT: Rectangle<u16> = Rectangle {
+    x: 6,
+    y: 107,
+    width: 1,
+    height: 1,
+};
 
 const TOP_LEFT: Rectangle<u16> = Rectangle {
     x: 0,
@@ -83,6 +96,7 @@ impl panel::Renderer for Renderer {
                 bounds.width - (TOP_LEFT.width + TOP_RIGHT.width) as f32,
                 1.0,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -102,6 +116,7 @@ impl panel::Renderer for Renderer {
                 bounds.height
                     - (TOP_BORDER.height + BOTTOM_BORDER.height) as f32,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -111,6 +126,7 @@ impl panel::Renderer for Renderer {
                 1.0,
                 bounds.height - (TOP_BORDER.height + BOTTOM_LEFT.height) as f32,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -124,6 +140,7 @@ impl panel::Renderer for Renderer {
                 bounds.height
                     - (TOP_BORDER.height + BOTTOM_RIGHT.height) as f32,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -145,6 +162,7 @@ impl panel::Renderer for Renderer {
                 bounds.width - (BOTTOM_LEFT.width + BOTTOM_LEFT.width) as f32,
                 1.0,
             ),
+            ..Sprite::default()
         });
 
         self.sprites.add(Sprite {
@@ -156,4 +174,107 @@ impl panel::Renderer for Renderer {
             ..Sprite::default()
         });
     }
+
+    fn draw_title_bar(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        title: &str,
+        is_collapsed: bool,
+        is_collapsible: bool,
+    ) -> MouseCursor {
+        let is_hovered = is_collapsible && bounds.contains(cursor_position);
+
+        if !is_collapsed {
+            self.sprites.add(Sprite {
+                source: DOT,
+                position: Point::new(bounds.x, bounds.y + bounds.height - 1.0),
+                scale: (bounds.width, 1.0),
+                ..Sprite::default()
+            });
+        }
+
+        self.add_text(Text {
+            content: &title_bar_label(title, is_collapsed, is_collapsible),
+            position: Point::new(bounds.x, bounds.y),
+            bounds: (bounds.width, bounds.height),
+            color: if is_hovered {
+                Color::WHITE
+            } else {
+                Color {
+                    r: 0.9,
+                    g: 0.9,
+                    b: 0.9,
+                    a: 1.0,
+                }
+            },
+            size: 20.0,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+            ..Text::default()
+        });
+
+        if is_hovered {
+            MouseCursor::Pointer
+        } else if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    fn draw_scrollbar(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content_height: f32,
+        offset: f32,
+    ) {
+        let track = Rectangle {
+            x: bounds.x + bounds.width - SCROLLBAR_WIDTH,
+            width: SCROLLBAR_WIDTH,
+            ..bounds
+        };
+
+        let thumb = scrollbar_thumb(track, content_height, offset);
+
+        self.sprites.add(Sprite {
+            source: DOT,
+            position: Point::new(thumb.x, thumb.y),
+            scale: (thumb.width, thumb.height),
+            ..Sprite::default()
+        });
+    }
+
+    fn clip(&mut self, bounds: Option<Rectangle<f32>>) {
+        self.clip_to(bounds);
+    }
+}
+
+// Marks the title of collapsible panels with their state
+pub fn title_bar_label(
+    title: &str,
+    is_collapsed: bool,
+    is_collapsible: bool,
+) -> String {
+    match (is_collapsible, is_collapsed) {
+        (false, _) => String::from(title),
+        (true, false) => format!("- {}", title),
+        (true, true) => format!("+ {}", title),
+    }
+}
+
+// Places the thumb of a scrollbar inside its track, proportionally to the
+// visible part of the content
+pub fn scrollbar_thumb(
+    track: Rectangle<f32>,
+    content_height: f32,
+    offset: f32,
+) -> Rectangle<f32> {
+    let ratio = (track.height / content_height.max(1.0)).min(1.0);
+
+    Rectangle {
+        y: track.y + offset * ratio,
+        height: track.height * ratio,
+        ..track
+    }
 }
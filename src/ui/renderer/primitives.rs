@@ -0,0 +1,1001 @@
+use crate::graphics::{
+    self, Color, Font, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+use crate::ui::core::{MouseCursor, Node, Style};
+use crate::ui::renderer::panel::{scrollbar_thumb, title_bar_label};
+use crate::ui::widget::{
+    button, checkbox, gauge, image, log_view, modal, panel, progress_bar,
+    radio, rule, slider, tabs, text, virtual_keyboard,
+};
+use crate::ui::Theme;
+
+use std::cell::RefCell;
+use std::f32::consts::PI;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const PADDING: f32 = 10.0;
+const BORDER: f32 = 2.0;
+const SCROLLBAR_WIDTH: f32 = 4.0;
+const LABEL_SIZE: f32 = 20.0;
+
+const GAUGE_SEGMENTS: u16 = 40;
+const INDETERMINATE_PERIOD: f32 = 1.5;
+const INDETERMINATE_WIDTH: f32 = 0.3;
+
+/// A set of drawing primitives capable of rendering all the
+/// [built-in widgets].
+///
+/// Implementing the `Renderer` trait of every widget can be a lot of work when
+/// writing a custom [`core::Renderer`]. Instead, you can implement
+/// [`Primitives`] once: any type implementing it also implements the
+/// `Renderer` trait of all the built-in widgets, drawing them with flat
+/// colors using the required methods.
+///
+/// Each widget is drawn by a provided `draw_*` method. You can override any
+/// of them to customize the look of a particular widget while keeping the
+/// defaults for the rest.
+///
+/// [built-in widgets]: widget/index.html
+/// [`core::Renderer`]: core/trait.Renderer.html
+/// [`Primitives`]: trait.Primitives.html
+pub trait Primitives {
+    /// Draws a quad filled with the given [`Color`].
+    ///
+    /// [`Color`]: ../graphics/struct.Color.html
+    fn quad(&mut self, bounds: Rectangle<f32>, color: Color);
+
+    /// Draws the given region of an [`Image`] stretched over a quad.
+    ///
+    /// [`Image`]: ../graphics/struct.Image.html
+    fn textured_quad(
+        &mut self,
+        bounds: Rectangle<f32>,
+        image: graphics::Image,
+        source: Rectangle<u16>,
+    );
+
+    /// Draws some [`Text`].
+    ///
+    /// [`Text`]: ../graphics/struct.Text.html
+    fn text(&mut self, text: graphics::Text<'_>);
+
+    /// Returns the [`Font`] used to measure text.
+    ///
+    /// [`Font`]: ../graphics/struct.Font.html
+    fn font(&self) -> Rc<RefCell<Font>>;
+
+    /// Restricts the primitives drawn afterwards to the given bounds.
+    ///
+    /// `None` removes the restriction.
+    fn clip(&mut self, bounds: Option<Rectangle<f32>>);
+
+    /// Returns the [`Theme`] used to draw the widgets.
+    ///
+    /// Store the [`Theme`] received in [`core::Renderer::theme`] and return
+    /// it here to follow the [`Settings`] of the user interface.
+    ///
+    /// By default, it returns [`Theme::DARK`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`core::Renderer::theme`]: core/trait.Renderer.html#method.theme
+    /// [`Settings`]: struct.Settings.html
+    /// [`Theme::DARK`]: struct.Theme.html#associatedconstant.DARK
+    fn theme(&self) -> Theme {
+        Theme::DARK
+    }
+
+    /// Draws the outline of the given bounds with the given [`Color`] and
+    /// width.
+    ///
+    /// By default, it draws four quads.
+    ///
+    /// [`Color`]: ../graphics/struct.Color.html
+    fn stroke(&mut self, bounds: Rectangle<f32>, color: Color, width: f32) {
+        self.quad(
+            Rectangle {
+                height: width,
+                ..bounds
+            },
+            color,
+        );
+
+        self.quad(
+            Rectangle {
+                y: bounds.y + bounds.height - width,
+                height: width,
+                ..bounds
+            },
+            color,
+        );
+
+        self.quad(Rectangle { width, ..bounds }, color);
+
+        self.quad(
+            Rectangle {
+                x: bounds.x + bounds.width - width,
+                width,
+                ..bounds
+            },
+            color,
+        );
+    }
+
+    /// Draws a [`Button`].
+    ///
+    /// See [`button::Renderer::draw`].
+    ///
+    /// [`Button`]: widget/button/struct.Button.html
+    /// [`button::Renderer::draw`]: widget/button/trait.Renderer.html#tymethod.draw
+    fn draw_button(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &button::State,
+        label: &str,
+        class: button::Class,
+        is_disabled: bool,
+    ) -> MouseCursor {
+        let theme = self.theme();
+
+        let mouse_over = !is_disabled && bounds.contains(cursor_position);
+        let is_pressed = mouse_over && state.is_pressed();
+
+        let color = match class {
+            button::Class::Primary => theme.accent,
+            button::Class::Secondary => theme.surface,
+            button::Class::Positive => theme.positive,
+        };
+
+        self.quad(bounds, if is_disabled { theme.surface } else { color });
+
+        if is_pressed {
+            self.quad(bounds, theme.backdrop);
+        } else if mouse_over || state.is_focused() {
+            self.stroke(bounds, theme.text, BORDER);
+        }
+
+        self.text(graphics::Text {
+            content: label,
+            position: Point::new(bounds.x, bounds.y),
+            bounds: (bounds.width, bounds.height),
+            color: if is_disabled {
+                theme.disabled
+            } else {
+                theme.text
+            },
+            size: LABEL_SIZE,
+            horizontal_alignment: HorizontalAlignment::Center,
+            vertical_alignment: VerticalAlignment::Center,
+            ..graphics::Text::default()
+        });
+
+        if mouse_over {
+            MouseCursor::Pointer
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws a [`Checkbox`].
+    ///
+    /// See [`checkbox::Renderer::draw`].
+    ///
+    /// [`Checkbox`]: widget/checkbox/struct.Checkbox.html
+    /// [`checkbox::Renderer::draw`]: widget/checkbox/trait.Renderer.html#tymethod.draw
+    fn draw_checkbox(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        label_bounds: Rectangle<f32>,
+        is_checked: bool,
+        is_disabled: bool,
+    ) -> MouseCursor {
+        let mouse_over = !is_disabled
+            && (bounds.contains(cursor_position)
+                || label_bounds.contains(cursor_position));
+
+        draw_toggle(self, bounds, is_checked, mouse_over, is_disabled);
+
+        if mouse_over {
+            MouseCursor::Pointer
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws a [`Gauge`].
+    ///
+    /// See [`gauge::Renderer::draw`].
+    ///
+    /// [`Gauge`]: widget/gauge/struct.Gauge.html
+    /// [`gauge::Renderer::draw`]: widget/gauge/trait.Renderer.html#tymethod.draw
+    fn draw_gauge(
+        &mut self,
+        bounds: Rectangle<f32>,
+        range: RangeInclusive<f32>,
+        value: f32,
+    ) {
+        let theme = self.theme();
+
+        let (start, end) = range.into_inner();
+        let progress = (value - start) / (end - start).max(f32::EPSILON);
+
+        let center = bounds.center();
+        let radius = bounds.width.min(bounds.height) / 2.0;
+        let thickness = (radius * 0.2).max(2.0);
+        let arc_radius = radius - thickness / 2.0;
+
+        // The arc is drawn with small quads along a circumference
+        for i in 0..GAUGE_SEGMENTS {
+            let t = (f32::from(i) + 0.5) / f32::from(GAUGE_SEGMENTS);
+            let angle = PI * 0.75 + PI * 1.5 * t;
+
+            self.quad(
+                Rectangle {
+                    x: center.x + arc_radius * angle.cos() - thickness / 2.0,
+                    y: center.y + arc_radius * angle.sin() - thickness / 2.0,
+                    width: thickness,
+                    height: thickness,
+                },
+                if t <= progress {
+                    theme.accent
+                } else {
+                    theme.surface
+                },
+            );
+        }
+    }
+
+    /// Draws an [`Image`] widget.
+    ///
+    /// The image keeps its aspect ratio and it is centered in its bounds.
+    ///
+    /// See [`image::Renderer::draw`].
+    ///
+    /// [`Image`]: widget/image/struct.Image.html
+    /// [`image::Renderer::draw`]: widget/image/trait.Renderer.html#tymethod.draw
+    fn draw_image(
+        &mut self,
+        bounds: Rectangle<f32>,
+        image: graphics::Image,
+        source: Rectangle<u16>,
+    ) {
+        let width = f32::from(source.width.max(1));
+        let height = f32::from(source.height.max(1));
+        let ratio = (bounds.width / width).min(bounds.height / height);
+        let center = bounds.center();
+
+        self.textured_quad(
+            Rectangle {
+                x: center.x - width * ratio / 2.0,
+                y: center.y - height * ratio / 2.0,
+                width: width * ratio,
+                height: height * ratio,
+            },
+            image,
+            source,
+        );
+    }
+
+    /// Draws a [`LogView`].
+    ///
+    /// See [`log_view::Renderer::draw`].
+    ///
+    /// [`LogView`]: widget/log_view/struct.LogView.html
+    /// [`log_view::Renderer::draw`]: widget/log_view/trait.Renderer.html#tymethod.draw
+    fn draw_log_view(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        entries: &[&log_view::Entry],
+        text_size: f32,
+    ) -> MouseCursor {
+        self.draw_panel(bounds);
+
+        let content = Rectangle {
+            x: bounds.x + PADDING,
+            y: bounds.y + PADDING,
+            width: bounds.width - PADDING * 2.0,
+            height: bounds.height - PADDING * 2.0,
+        };
+
+        self.clip(Some(content));
+
+        // Show the newest entry at the bottom
+        let mut y = content.y + content.height;
+
+        for entry in entries.iter().rev() {
+            y -= text_size;
+
+            if y + text_size < content.y {
+                break;
+            }
+
+            self.text(graphics::Text {
+                content: &entry.message,
+                position: Point::new(content.x, y),
+                bounds: (content.width, text_size),
+                color: level_color(entry.level),
+                size: text_size,
+                ..graphics::Text::default()
+            });
+        }
+
+        self.clip(None);
+
+        if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws the background of a [`Modal`] overlay.
+    ///
+    /// See [`modal::Renderer::draw`].
+    ///
+    /// [`Modal`]: widget/modal/struct.Modal.html
+    /// [`modal::Renderer::draw`]: widget/modal/trait.Renderer.html#tymethod.draw
+    fn draw_modal(&mut self, bounds: Rectangle<f32>) {
+        self.quad(bounds, self.theme().backdrop);
+    }
+
+    /// Draws a [`Panel`].
+    ///
+    /// See [`panel::Renderer::draw`].
+    ///
+    /// [`Panel`]: widget/panel/struct.Panel.html
+    /// [`panel::Renderer::draw`]: widget/panel/trait.Renderer.html#tymethod.draw
+    fn draw_panel(&mut self, bounds: Rectangle<f32>) {
+        let theme = self.theme();
+
+        self.quad(bounds, theme.background);
+        self.stroke(bounds, theme.surface, BORDER);
+    }
+
+    /// Draws the title bar of a [`Panel`].
+    ///
+    /// See [`panel::Renderer::draw_title_bar`].
+    ///
+    /// [`Panel`]: widget/panel/struct.Panel.html
+    /// [`panel::Renderer::draw_title_bar`]: widget/panel/trait.Renderer.html#tymethod.draw_title_bar
+    fn draw_panel_title_bar(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        title: &str,
+        is_collapsed: bool,
+        is_collapsible: bool,
+    ) -> MouseCursor {
+        let theme = self.theme();
+        let is_hovered = is_collapsible && bounds.contains(cursor_position);
+
+        if is_hovered {
+            self.quad(bounds, theme.highlight);
+        }
+
+        if !is_collapsed {
+            self.quad(
+                Rectangle {
+                    y: bounds.y + bounds.height - BORDER,
+                    height: BORDER,
+                    ..bounds
+                },
+                theme.surface,
+            );
+        }
+
+        let content = title_bar_label(title, is_collapsed, is_collapsible);
+
+        self.text(graphics::Text {
+            content: &content,
+            position: Point::new(bounds.x, bounds.y),
+            bounds: (bounds.width, bounds.height),
+            color: theme.text,
+            size: LABEL_SIZE,
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+            ..graphics::Text::default()
+        });
+
+        if is_hovered {
+            MouseCursor::Pointer
+        } else if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws the scrollbar of a [`Panel`].
+    ///
+    /// See [`panel::Renderer::draw_scrollbar`].
+    ///
+    /// [`Panel`]: widget/panel/struct.Panel.html
+    /// [`panel::Renderer::draw_scrollbar`]: widget/panel/trait.Renderer.html#tymethod.draw_scrollbar
+    fn draw_panel_scrollbar(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content_height: f32,
+        offset: f32,
+    ) {
+        let theme = self.theme();
+        let track = Rectangle {
+            x: bounds.x + bounds.width - SCROLLBAR_WIDTH,
+            width: SCROLLBAR_WIDTH,
+            ..bounds
+        };
+
+        self.quad(track, theme.surface);
+        self.quad(scrollbar_thumb(track, content_height, offset), theme.accent);
+    }
+
+    /// Draws a [`ProgressBar`].
+    ///
+    /// It receives the progress of the [`ProgressBar`], or `None` if it is
+    /// indeterminate. See [`progress_bar::Renderer`].
+    ///
+    /// [`ProgressBar`]: widget/progress_bar/struct.ProgressBar.html
+    /// [`progress_bar::Renderer`]: widget/progress_bar/trait.Renderer.html
+    fn draw_progress_bar(
+        &mut self,
+        bounds: Rectangle<f32>,
+        progress: Option<f32>,
+    ) {
+        let theme = self.theme();
+
+        self.quad(bounds, theme.surface);
+
+        let (start, end) = match progress {
+            Some(progress) => (0.0, progress.max(0.0).min(1.0)),
+            None => {
+                // A segment slides from left to right, entering and leaving
+                // the bar
+                let elapsed = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map(|duration| duration.as_secs_f32())
+                    .unwrap_or(0.0);
+
+                let phase =
+                    (elapsed % INDETERMINATE_PERIOD) / INDETERMINATE_PERIOD;
+                let head = phase * (1.0 + INDETERMINATE_WIDTH);
+
+                ((head - INDETERMINATE_WIDTH).max(0.0), head.min(1.0))
+            }
+        };
+
+        if end > start {
+            self.quad(
+                Rectangle {
+                    x: bounds.x + start * bounds.width,
+                    width: (end - start) * bounds.width,
+                    ..bounds
+                },
+                theme.accent,
+            );
+        }
+    }
+
+    /// Draws a [`Radio`] button.
+    ///
+    /// See [`radio::Renderer::draw`].
+    ///
+    /// [`Radio`]: widget/radio/struct.Radio.html
+    /// [`radio::Renderer::draw`]: widget/radio/trait.Renderer.html#tymethod.draw
+    fn draw_radio(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        bounds_with_label: Rectangle<f32>,
+        is_selected: bool,
+    ) -> MouseCursor {
+        let mouse_over = bounds_with_label.contains(cursor_position);
+
+        draw_toggle(self, bounds, is_selected, mouse_over, false);
+
+        if mouse_over {
+            MouseCursor::Pointer
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws a [`Rule`].
+    ///
+    /// See [`rule::Renderer::draw`].
+    ///
+    /// [`Rule`]: widget/rule/struct.Rule.html
+    /// [`rule::Renderer::draw`]: widget/rule/trait.Renderer.html#tymethod.draw
+    fn draw_rule(&mut self, bounds: Rectangle<f32>, _is_horizontal: bool) {
+        self.quad(bounds, self.theme().surface);
+    }
+
+    /// Draws a [`Slider`].
+    ///
+    /// See [`slider::Renderer::draw`].
+    ///
+    /// [`Slider`]: widget/slider/struct.Slider.html
+    /// [`slider::Renderer::draw`]: widget/slider/trait.Renderer.html#tymethod.draw
+    fn draw_slider(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &slider::State,
+        range: RangeInclusive<f32>,
+        value: f32,
+        is_disabled: bool,
+    ) -> MouseCursor {
+        let theme = self.theme();
+
+        let marker_width = (bounds.height / 2.0).min(16.0);
+        let center_y = bounds.y + bounds.height / 2.0;
+
+        self.quad(
+            Rectangle {
+                x: bounds.x + marker_width / 2.0,
+                y: center_y - BORDER,
+                width: bounds.width - marker_width,
+                height: BORDER * 2.0,
+            },
+            theme.surface,
+        );
+
+        let (range_start, range_end) = range.into_inner();
+
+        let marker_offset = (bounds.width - marker_width)
+            * ((value - range_start) / (range_end - range_start).max(1.0));
+
+        let mouse_over = !is_disabled && bounds.contains(cursor_position);
+        let is_active = state.is_dragging() || state.is_focused() || mouse_over;
+
+        self.quad(
+            Rectangle {
+                x: bounds.x + marker_offset.round(),
+                y: bounds.y,
+                width: marker_width,
+                height: bounds.height,
+            },
+            if is_disabled {
+                theme.surface
+            } else if is_active {
+                theme.highlight
+            } else {
+                theme.accent
+            },
+        );
+
+        if state.is_dragging() {
+            MouseCursor::Grabbing
+        } else if mouse_over {
+            MouseCursor::Grab
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws a [`VirtualKeyboard`].
+    ///
+    /// See [`virtual_keyboard::Renderer::draw`].
+    ///
+    /// [`VirtualKeyboard`]: widget/virtual_keyboard/struct.VirtualKeyboard.html
+    /// [`virtual_keyboard::Renderer::draw`]: widget/virtual_keyboard/trait.Renderer.html#tymethod.draw
+    fn draw_virtual_keyboard(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        keys: &[(virtual_keyboard::Key, Rectangle<f32>)],
+        selected: usize,
+        is_shifted: bool,
+    ) -> MouseCursor {
+        let theme = self.theme();
+
+        for (i, (key, key_bounds)) in keys.iter().enumerate() {
+            let is_highlighted =
+                i == selected || key_bounds.contains(cursor_position);
+
+            self.quad(
+                *key_bounds,
+                if is_highlighted {
+                    theme.accent
+                } else {
+                    theme.surface
+                },
+            );
+
+            let label = super::virtual_keyboard::label(*key, is_shifted);
+
+            self.text(graphics::Text {
+                content: &label,
+                position: Point::new(key_bounds.x, key_bounds.y),
+                bounds: (key_bounds.width, key_bounds.height),
+                color: theme.text,
+                size: LABEL_SIZE,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                ..graphics::Text::default()
+            });
+        }
+
+        let is_over_key = keys
+            .iter()
+            .any(|(_, bounds)| bounds.contains(cursor_position));
+
+        if is_over_key {
+            MouseCursor::Pointer
+        } else if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Draws the headers of [`Tabs`].
+    ///
+    /// See [`tabs::Renderer::draw`].
+    ///
+    /// [`Tabs`]: widget/tabs/struct.Tabs.html
+    /// [`tabs::Renderer::draw`]: widget/tabs/trait.Renderer.html#tymethod.draw
+    fn draw_tabs(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        headers: &[(&str, Rectangle<f32>)],
+        selected: usize,
+    ) -> MouseCursor {
+        let theme = self.theme();
+
+        for (i, (label, header_bounds)) in headers.iter().enumerate() {
+            let is_hovered = header_bounds.contains(cursor_position);
+
+            self.quad(
+                *header_bounds,
+                if i == selected {
+                    theme.accent
+                } else if is_hovered {
+                    theme.highlight
+                } else {
+                    theme.surface
+                },
+            );
+
+            self.text(graphics::Text {
+                content: label,
+                position: Point::new(header_bounds.x, header_bounds.y),
+                bounds: (header_bounds.width, header_bounds.height),
+                color: theme.text,
+                size: LABEL_SIZE,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                ..graphics::Text::default()
+            });
+        }
+
+        let is_over_header =
+            headers.iter().enumerate().any(|(i, (_, bounds))| {
+                i != selected && bounds.contains(cursor_position)
+            });
+
+        if is_over_header {
+            MouseCursor::Pointer
+        } else if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+
+    /// Creates the layout [`Node`] of a [`Text`] widget.
+    ///
+    /// By default, the contents are measured using the [`Font`] returned by
+    /// [`font`].
+    ///
+    /// See [`text::Renderer::node`].
+    ///
+    /// [`Node`]: core/struct.Node.html
+    /// [`Text`]: widget/text/struct.Text.html
+    /// [`Font`]: ../graphics/struct.Font.html
+    /// [`font`]: #tymethod.font
+    /// [`text::Renderer::node`]: widget/text/trait.Renderer.html#tymethod.node
+    fn text_node(&self, style: Style, content: &str, size: f32) -> Node {
+        super::text::node(self.font(), style, content, size)
+    }
+
+    /// Draws a [`Text`] widget.
+    ///
+    /// See [`text::Renderer::draw`].
+    ///
+    /// [`Text`]: widget/text/struct.Text.html
+    /// [`text::Renderer::draw`]: widget/text/trait.Renderer.html#tymethod.draw
+    fn draw_text(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content: &str,
+        size: f32,
+        color: Color,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) {
+        self.text(graphics::Text {
+            content,
+            position: Point::new(bounds.x, bounds.y),
+            bounds: (bounds.width, bounds.height),
+            color,
+            size,
+            horizontal_alignment,
+            vertical_alignment,
+            ..graphics::Text::default()
+        });
+    }
+}
+
+fn draw_toggle<P: Primitives + ?Sized>(
+    primitives: &mut P,
+    bounds: Rectangle<f32>,
+    is_on: bool,
+    mouse_over: bool,
+    is_disabled: bool,
+) {
+    let theme = primitives.theme();
+
+    primitives.quad(bounds, theme.background);
+    primitives.stroke(
+        bounds,
+        if mouse_over {
+            theme.highlight
+        } else {
+            theme.surface
+        },
+        BORDER,
+    );
+
+    if is_on {
+        let inset = (bounds.width.min(bounds.height) / 4.0).round();
+
+        primitives.quad(
+            Rectangle {
+                x: bounds.x + inset,
+                y: bounds.y + inset,
+                width: bounds.width - inset * 2.0,
+                height: bounds.height - inset * 2.0,
+            },
+            if is_disabled {
+                theme.disabled
+            } else {
+                theme.accent
+            },
+        );
+    }
+}
+
+fn level_color(level: log_view::Level) -> Color {
+    match level {
+        log_view::Level::Trace => Color::from_rgb(120, 120, 120),
+        log_view::Level::Debug => Color::from_rgb(140, 180, 220),
+        log_view::Level::Info => Color::WHITE,
+        log_view::Level::Warn => Color::from_rgb(240, 200, 80),
+        log_view::Level::Error => Color::from_rgb(230, 80, 80),
+    }
+}
+
+impl<T: Primitives> button::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &button::State,
+        label: &str,
+        class: button::Class,
+        is_disabled: bool,
+    ) -> MouseCursor {
+        self.draw_button(
+            cursor_position,
+            bounds,
+            state,
+            label,
+            class,
+            is_disabled,
+        )
+    }
+}
+
+impl<T: Primitives> checkbox::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        label_bounds: Rectangle<f32>,
+        is_checked: bool,
+        is_disabled: bool,
+    ) -> MouseCursor {
+        self.draw_checkbox(
+            cursor_position,
+            bounds,
+            label_bounds,
+            is_checked,
+            is_disabled,
+        )
+    }
+}
+
+impl<T: Primitives> gauge::Renderer for T {
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        range: RangeInclusive<f32>,
+        value: f32,
+    ) {
+        self.draw_gauge(bounds, range, value);
+    }
+}
+
+impl<T: Primitives> image::Renderer for T {
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        image: graphics::Image,
+        source: Rectangle<u16>,
+    ) {
+        self.draw_image(bounds, image, source);
+    }
+}
+
+impl<T: Primitives> log_view::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        entries: &[&log_view::Entry],
+        text_size: f32,
+    ) -> MouseCursor {
+        self.draw_log_view(cursor_position, bounds, entries, text_size)
+    }
+}
+
+impl<T: Primitives> modal::Renderer for T {
+    fn draw(&mut self, bounds: Rectangle<f32>) {
+        self.draw_modal(bounds);
+    }
+}
+
+impl<T: Primitives> panel::Renderer for T {
+    fn draw(&mut self, bounds: Rectangle<f32>) {
+        self.draw_panel(bounds);
+    }
+
+    fn draw_title_bar(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        title: &str,
+        is_collapsed: bool,
+        is_collapsible: bool,
+    ) -> MouseCursor {
+        self.draw_panel_title_bar(
+            cursor_position,
+            bounds,
+            title,
+            is_collapsed,
+            is_collapsible,
+        )
+    }
+
+    fn draw_scrollbar(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content_height: f32,
+        offset: f32,
+    ) {
+        self.draw_panel_scrollbar(bounds, content_height, offset);
+    }
+
+    fn clip(&mut self, bounds: Option<Rectangle<f32>>) {
+        Primitives::clip(self, bounds);
+    }
+}
+
+impl<T: Primitives> progress_bar::Renderer for T {
+    fn draw(&mut self, bounds: Rectangle<f32>, progress: f32) {
+        self.draw_progress_bar(bounds, Some(progress));
+    }
+
+    fn draw_indeterminate(&mut self, bounds: Rectangle<f32>) {
+        self.draw_progress_bar(bounds, None);
+    }
+}
+
+impl<T: Primitives> radio::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        bounds_with_label: Rectangle<f32>,
+        is_selected: bool,
+    ) -> MouseCursor {
+        self.draw_radio(cursor_position, bounds, bounds_with_label, is_selected)
+    }
+}
+
+impl<T: Primitives> rule::Renderer for T {
+    fn draw(&mut self, bounds: Rectangle<f32>, is_horizontal: bool) {
+        self.draw_rule(bounds, is_horizontal);
+    }
+}
+
+impl<T: Primitives> slider::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        state: &slider::State,
+        range: RangeInclusive<f32>,
+        value: f32,
+        is_disabled: bool,
+    ) -> MouseCursor {
+        self.draw_slider(
+            cursor_position,
+            bounds,
+            state,
+            range,
+            value,
+            is_disabled,
+        )
+    }
+}
+
+impl<T: Primitives> tabs::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        headers: &[(&str, Rectangle<f32>)],
+        selected: usize,
+    ) -> MouseCursor {
+        self.draw_tabs(cursor_position, bounds, headers, selected)
+    }
+}
+
+impl<T: Primitives> text::Renderer for T {
+    fn node(&self, style: Style, content: &str, size: f32) -> Node {
+        self.text_node(style, content, size)
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content: &str,
+        size: f32,
+        color: Color,
+        horizontal_alignment: HorizontalAlignment,
+        vertical_alignment: VerticalAlignment,
+    ) {
+        self.draw_text(
+            bounds,
+            content,
+            size,
+            color,
+            horizontal_alignment,
+            vertical_alignment,
+        );
+    }
+}
+
+impl<T: Primitives> virtual_keyboard::Renderer for T {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        keys: &[(virtual_keyboard::Key, Rectangle<f32>)],
+        selected: usize,
+        is_shifted: bool,
+    ) -> MouseCursor {
+        self.draw_virtual_keyboard(
+            cursor_position,
+            bounds,
+            keys,
+            selected,
+            is_shifted,
+        )
+    }
+}
@@ -22,21 +22,16 @@ const RIGHT: Rectangle<u16> = Rectangle {
     height: LEFT.height,
 };
 
+const INDETERMINATE_PERIOD: f32 = 1.5;
+const INDETERMINATE_WIDTH: f32 = 0.3;
+
 impl progress_bar::Renderer for Renderer {
-    fn draw(
-        &mut self,
-        bounds: Rectangle<f32>,
-        progress: f32,
-    ) {
+    fn draw(&mut self, bounds: Rectangle<f32>, progress: f32) {
         let active_class = 0;
-        let background_class = 1;
-        let full = 1.0;
         let left_width_f32 = LEFT.width as f32 / 100.0;
         let background_width = 1.0 - 2.0 * left_width_f32;
 
-        self.sprites.add(left_sprite(bounds, background_class, full));
-        self.sprites.add(background_sprite(bounds, background_class, full));
-        self.sprites.add(right_sprite(bounds, background_class, full));
+        self.draw_background(bounds);
 
         if progress > 0.0 {
             let area = bound(progress / left_width_f32);
@@ -53,6 +48,52 @@ impl progress_bar::Renderer for Renderer {
             self.sprites.add(right_sprite(bounds, active_class, area));
         }
     }
+
+    fn draw_indeterminate(&mut self, bounds: Rectangle<f32>) {
+        let active_class = 0;
+
+        self.draw_background(bounds);
+
+        let elapsed = self.epoch.elapsed().as_secs_f32();
+        let phase = (elapsed % INDETERMINATE_PERIOD) / INDETERMINATE_PERIOD;
+
+        // A segment slides from left to right, entering and leaving the bar
+        let start = (phase * (1.0 + INDETERMINATE_WIDTH) - INDETERMINATE_WIDTH)
+            .max(0.0);
+        let end = (phase * (1.0 + INDETERMINATE_WIDTH)).min(1.0);
+
+        let min_width = (LEFT.width + RIGHT.width) as f32;
+        let width = (end - start) * bounds.width;
+
+        if width < min_width {
+            return;
+        }
+
+        let segment = Rectangle {
+            x: bounds.x + start * bounds.width,
+            width,
+            ..bounds
+        };
+
+        self.sprites.add(left_sprite(segment, active_class, 1.0));
+        self.sprites
+            .add(background_sprite(segment, active_class, 1.0));
+        self.sprites.add(right_sprite(segment, active_class, 1.0));
+    }
+}
+
+impl Renderer {
+    fn draw_background(&mut self, bounds: Rectangle<f32>) {
+        let background_class = 1;
+        let full = 1.0;
+
+        self.sprites
+            .add(left_sprite(bounds, background_class, full));
+        self.sprites
+            .add(background_sprite(bounds, background_class, full));
+        self.sprites
+            .add(right_sprite(bounds, background_class, full));
+    }
 }
 
 fn bound(v: f32) -> f32 {
@@ -73,6 +114,7 @@ fn left_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
         },
         position: Point::new(bounds.x, bounds.y),
         scale: (1.0, 1.0),
+        ..Sprite::default()
     }
 }
 
@@ -85,6 +127,7 @@ fn background_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Spr
         },
         position: Point::new(bounds.x + LEFT.width as f32, bounds.y),
         scale: ((bounds.width - (LEFT.width + RIGHT.width) as f32) * area, 1.0),
+        ..Sprite::default()
     }
 }
 
@@ -101,5 +144,6 @@ fn right_sprite(bounds: Rectangle<f32>, class_index: u16, area: f32) -> Sprite {
             bounds.y,
         ),
         scale: (1.0, 1.0),
+        ..Sprite::default()
     }
 }
@@ -27,6 +27,7 @@ impl radio::Renderer for Renderer {
             },
             position: Point::new(bounds.x, bounds.y),
             scale: (1.0, 1.0),
+            ..Sprite::default()
         });
 
         if is_selected {
@@ -37,6 +38,7 @@ impl radio::Renderer for Renderer {
                 },
                 position: Point::new(bounds.x, bounds.y),
                 scale: (1.0, 1.0),
+                ..Sprite::default()
             });
         }
 
@@ -0,0 +1,22 @@
+use crate::graphics::{Point, Rectangle, Sprite};
+use crate::ui::widget::rule;
+use crate::ui::Renderer;
+
+// A single pixel of the empty progress bar track
+const DOT: Rectangle<u16> = Rectangle {
+    x: 6,
+    y: 107,
+    width: 1,
+    height: 1,
+};
+
+impl rule::Renderer for Renderer {
+    fn draw(&mut self, bounds: Rectangle<f32>, _is_horizontal: bool) {
+        self.sprites.add(Sprite {
+            source: DOT,
+            position: Point::new(bounds.x, bounds.y),
+            scale: (bounds.width, bounds.height),
+            ..Sprite::default()
+        });
+    }
+}
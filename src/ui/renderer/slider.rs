@@ -26,35 +26,44 @@ impl slider::Renderer for Renderer {
         state: &slider::State,
         range: RangeInclusive<f32>,
         value: f32,
+        is_disabled: bool,
     ) -> MouseCursor {
-        self.sprites.add(Sprite {
-            source: RAIL,
-            position: Point::new(
-                bounds.x + MARKER.width as f32 / 2.0,
-                bounds.y + 12.5,
-            ),
-            scale: (bounds.width - MARKER.width as f32, 1.0),
-        });
+        self.add_sprite(
+            Sprite {
+                source: RAIL,
+                position: Point::new(
+                    bounds.x + MARKER.width as f32 / 2.0,
+                    bounds.y + 12.5,
+                ),
+                scale: (bounds.width - MARKER.width as f32, 1.0),
+                ..Sprite::default()
+            },
+            is_disabled,
+        );
 
         let (range_start, range_end) = range.into_inner();
 
         let marker_offset = (bounds.width - MARKER.width as f32)
             * ((value - range_start) / (range_end - range_start).max(1.0));
 
-        let mouse_over = bounds.contains(cursor_position);
-        let is_active = state.is_dragging() || mouse_over;
+        let mouse_over = !is_disabled && bounds.contains(cursor_position);
+        let is_active = state.is_dragging() || state.is_focused() || mouse_over;
 
-        self.sprites.add(Sprite {
-            source: Rectangle {
-                x: MARKER.x + (if is_active { MARKER.width } else { 0 }),
-                ..MARKER
+        self.add_sprite(
+            Sprite {
+                source: Rectangle {
+                    x: MARKER.x + (if is_active { MARKER.width } else { 0 }),
+                    ..MARKER
+                },
+                position: Point::new(
+                    bounds.x + marker_offset.round(),
+                    bounds.y + (if state.is_dragging() { 2.0 } else { 0.0 }),
+                ),
+                scale: (1.0, 1.0),
+                ..Sprite::default()
             },
-            position: Point::new(
-                bounds.x + marker_offset.round(),
-                bounds.y + (if state.is_dragging() { 2.0 } else { 0.0 }),
-            ),
-            scale: (1.0, 1.0),
-        });
+            is_disabled,
+        );
 
         if state.is_dragging() {
             MouseCursor::Grabbing
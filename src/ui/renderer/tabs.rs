@@ -0,0 +1,126 @@
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, Sprite, Text,
+    VerticalAlignment,
+};
+use crate::ui::core::MouseCursor;
+use crate::ui::{tabs, Renderer};
+
+const LEFT: Rectangle<u16> = Rectangle {
+    x: 0,
+    y: 34,
+    width: 6,
+    height: 49,
+};
+
+const BACKGROUND: Rectangle<u16> = Rectangle {
+    x: LEFT.width,
+    y: LEFT.y,
+    width: 1,
+    height: LEFT.height,
+};
+
+const RIGHT: Rectangle<u16> = Rectangle {
+    x: LEFT.height - LEFT.width,
+    y: LEFT.y,
+    width: LEFT.width,
+    height: LEFT.height,
+};
+
+const PRIMARY_CLASS: u16 = 0;
+const SECONDARY_CLASS: u16 = 1;
+
+impl tabs::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        headers: &[(&str, Rectangle<f32>)],
+        selected: usize,
+    ) -> MouseCursor {
+        for (i, (label, header_bounds)) in headers.iter().enumerate() {
+            let is_selected = i == selected;
+            let is_hovered = header_bounds.contains(cursor_position);
+
+            let class_index = if is_selected {
+                PRIMARY_CLASS
+            } else {
+                SECONDARY_CLASS
+            };
+
+            // Headers are button sprites scaled to fit
+            let scale_y = header_bounds.height / LEFT.height as f32;
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: LEFT.y + class_index * LEFT.height,
+                    ..LEFT
+                },
+                position: Point::new(header_bounds.x, header_bounds.y),
+                scale: (1.0, scale_y),
+                ..Sprite::default()
+            });
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: BACKGROUND.y + class_index * BACKGROUND.height,
+                    ..BACKGROUND
+                },
+                position: Point::new(
+                    header_bounds.x + LEFT.width as f32,
+                    header_bounds.y,
+                ),
+                scale: (
+                    header_bounds.width - (LEFT.width + RIGHT.width) as f32,
+                    scale_y,
+                ),
+                ..Sprite::default()
+            });
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: RIGHT.y + class_index * RIGHT.height,
+                    ..RIGHT
+                },
+                position: Point::new(
+                    header_bounds.x + header_bounds.width - RIGHT.width as f32,
+                    header_bounds.y,
+                ),
+                scale: (1.0, scale_y),
+                ..Sprite::default()
+            });
+
+            self.add_text(Text {
+                content: label,
+                position: Point::new(header_bounds.x, header_bounds.y - 4.0),
+                bounds: (header_bounds.width, header_bounds.height),
+                color: if is_selected || is_hovered {
+                    Color::WHITE
+                } else {
+                    Color {
+                        r: 0.9,
+                        g: 0.9,
+                        b: 0.9,
+                        a: 1.0,
+                    }
+                },
+                size: 20.0,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                ..Text::default()
+            });
+        }
+
+        let is_over_header =
+            headers.iter().enumerate().any(|(i, (_, bounds))| {
+                i != selected && bounds.contains(cursor_position)
+            });
+
+        if is_over_header {
+            MouseCursor::Pointer
+        } else if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::graphics::{
+    self, Color, Font, Mesh, Point, Rectangle, Shape, Target,
+};
+
+use std::f32;
+use std::time::{Duration, Instant};
+
+const TEXT_SIZE: f32 = 16.0;
+const PADDING: f32 = 6.0;
+const CURSOR_OFFSET: (f32, f32) = (12.0, 16.0);
+
+const BACKGROUND: Color = Color {
+    r: 0.1,
+    g: 0.1,
+    b: 0.1,
+    a: 0.9,
+};
+
+pub struct Tooltip {
+    delay: Duration,
+    request: Option<(String, Point)>,
+    hover: Option<Hover>,
+}
+
+struct Hover {
+    content: String,
+    position: Point,
+    since: Instant,
+    is_visible: bool,
+}
+
+impl Tooltip {
+    pub fn new(delay: Duration) -> Tooltip {
+        Tooltip {
+            delay,
+            request: None,
+            hover: None,
+        }
+    }
+
+    pub fn request(&mut self, content: &str, cursor_position: Point) {
+        // The innermost element is drawn first, so the first request wins
+        if self.request.is_none() {
+            self.request = Some((String::from(content), cursor_position));
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        font: &mut Font,
+        target: &mut Target<'_>,
+        bounds: (f32, f32),
+        text_color: Color,
+    ) {
+        let (content, position) = match self.request.take() {
+            Some(request) => request,
+            None => {
+                self.hover = None;
+                return;
+            }
+        };
+
+        let now = Instant::now();
+
+        // The delay restarts whenever the mouse moves before the tooltip is
+        // shown. Once visible, the tooltip follows the cursor.
+        let mut hover = match self.hover.take() {
+            Some(hover)
+                if hover.content == content
+                    && (hover.is_visible || hover.position == position) =>
+            {
+                hover
+            }
+            _ => Hover {
+                content,
+                position,
+                since: now,
+                is_visible: false,
+            },
+        };
+
+        hover.is_visible =
+            hover.is_visible || now.duration_since(hover.since) >= self.delay;
+
+        if hover.is_visible {
+            let (width, height) = font.measure(graphics::Text {
+                content: &hover.content,
+                size: TEXT_SIZE,
+                bounds: (f32::INFINITY, f32::INFINITY),
+                ..graphics::Text::default()
+            });
+
+            let width = width + PADDING * 2.0;
+            let height = height + PADDING * 2.0;
+
+            let x = (position.x + CURSOR_OFFSET.0)
+                .min(bounds.0 - width)
+                .max(0.0);
+            let y = (position.y + CURSOR_OFFSET.1)
+                .min(bounds.1 - height)
+                .max(0.0);
+
+            let mut mesh = Mesh::new();
+
+            mesh.fill(
+                Shape::Rectangle(Rectangle {
+                    x,
+                    y,
+                    width,
+                    height,
+                }),
+                BACKGROUND,
+            );
+
+            mesh.draw(target);
+
+            font.add(graphics::Text {
+                content: &hover.content,
+                position: Point::new(x + PADDING, y + PADDING),
+                size: TEXT_SIZE,
+                color: text_color,
+                ..graphics::Text::default()
+            });
+
+            font.draw(target);
+        }
+
+        hover.position = position;
+        self.hover = Some(hover);
+    }
+}
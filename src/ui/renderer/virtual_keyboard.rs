@@ -0,0 +1,132 @@
+use crate::graphics::{
+    Color, HorizontalAlignment, Point, Rectangle, Sprite, Text,
+    VerticalAlignment,
+};
+use crate::ui::core::MouseCursor;
+use crate::ui::{virtual_keyboard, Renderer};
+
+const LEFT: Rectangle<u16> = Rectangle {
+    x: 0,
+    y: 34,
+    width: 6,
+    height: 49,
+};
+
+const BACKGROUND: Rectangle<u16> = Rectangle {
+    x: LEFT.width,
+    y: LEFT.y,
+    width: 1,
+    height: LEFT.height,
+};
+
+const RIGHT: Rectangle<u16> = Rectangle {
+    x: LEFT.height - LEFT.width,
+    y: LEFT.y,
+    width: LEFT.width,
+    height: LEFT.height,
+};
+
+const PRIMARY_CLASS: u16 = 0;
+const SECONDARY_CLASS: u16 = 1;
+
+impl virtual_keyboard::Renderer for Renderer {
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        keys: &[(virtual_keyboard::Key, Rectangle<f32>)],
+        selected: usize,
+        is_shifted: bool,
+    ) -> MouseCursor {
+        for (i, (key, key_bounds)) in keys.iter().enumerate() {
+            let is_highlighted =
+                i == selected || key_bounds.contains(cursor_position);
+
+            let class_index = if is_highlighted {
+                PRIMARY_CLASS
+            } else {
+                SECONDARY_CLASS
+            };
+
+            // Keys are button sprites scaled to fit
+            let scale_y = key_bounds.height / LEFT.height as f32;
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: LEFT.y + class_index * LEFT.height,
+                    ..LEFT
+                },
+                position: Point::new(key_bounds.x, key_bounds.y),
+                scale: (1.0, scale_y),
+                ..Sprite::default()
+            });
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: BACKGROUND.y + class_index * BACKGROUND.height,
+                    ..BACKGROUND
+                },
+                position: Point::new(
+                    key_bounds.x + LEFT.width as f32,
+                    key_bounds.y,
+                ),
+                scale: (
+                    key_bounds.width - (LEFT.width + RIGHT.width) as f32,
+                    scale_y,
+                ),
+                ..Sprite::default()
+            });
+
+            self.sprites.add(Sprite {
+                source: Rectangle {
+                    y: RIGHT.y + class_index * RIGHT.height,
+                    ..RIGHT
+                },
+                position: Point::new(
+                    key_bounds.x + key_bounds.width - RIGHT.width as f32,
+                    key_bounds.y,
+                ),
+                scale: (1.0, scale_y),
+                ..Sprite::default()
+            });
+
+            let label = label(*key, is_shifted);
+
+            self.add_text(Text {
+                content: &label,
+                position: Point::new(key_bounds.x, key_bounds.y - 2.0),
+                bounds: (key_bounds.width, key_bounds.height),
+                color: Color::WHITE,
+                size: 20.0,
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+                ..Text::default()
+            });
+        }
+
+        let is_over_key = keys
+            .iter()
+            .any(|(_, bounds)| bounds.contains(cursor_position));
+
+        if is_over_key {
+            MouseCursor::Pointer
+        } else if bounds.contains(cursor_position) {
+            MouseCursor::Idle
+        } else {
+            MouseCursor::OutOfBounds
+        }
+    }
+}
+
+pub(super) fn label(key: virtual_keyboard::Key, is_shifted: bool) -> String {
+    match key {
+        virtual_keyboard::Key::Character(character) if is_shifted => {
+            character.to_ascii_uppercase().to_string()
+        }
+        virtual_keyboard::Key::Character(character) => character.to_string(),
+        virtual_keyboard::Key::Shift => String::from("Shift"),
+        virtual_keyboard::Key::Space => String::from("Space"),
+        virtual_keyboard::Key::Backspace => String::from("Delete"),
+        virtual_keyboard::Key::Enter => String::from("Done"),
+    }
+}
@@ -0,0 +1,255 @@
+use crate::graphics::{Color, Frame, Point};
+use crate::input::keyboard::{self, Modifiers};
+use crate::input::{self, mouse, ButtonState};
+use crate::ui::core::{self, Element, Event, Interface, MouseCursor};
+use crate::ui::{Shortcuts, Theme};
+
+/// A user interface runtime driven by your own loop.
+///
+/// [`UserInterface::run`] uses a [`Runtime`] internally. You can use one
+/// directly when you want to lay out and draw widgets somewhere else, like in a
+/// [`LoadingScreen`], where you are in charge of feeding input events and
+/// providing the [`Frame`] to draw on.
+///
+/// [`UserInterface::run`]: trait.UserInterface.html#method.run
+/// [`Runtime`]: struct.Runtime.html
+/// [`LoadingScreen`]: ../load/loading_screen/trait.LoadingScreen.html
+/// [`Frame`]: ../graphics/struct.Frame.html
+pub struct Runtime<Renderer> {
+    renderer: Renderer,
+    cache: Option<core::Cache>,
+    cursor_position: Point,
+    modifiers: Modifiers,
+    events: Vec<Event>,
+    explain: Option<Color>,
+    scale: f32,
+    theme: Theme,
+    accessibility_label: Option<String>,
+}
+
+impl<Renderer> std::fmt::Debug for Runtime<Renderer>
+where
+    Renderer: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Runtime")
+            .field("renderer", &self.renderer)
+            .field("cursor_position", &self.cursor_position)
+            .field("modifiers", &self.modifiers)
+            .field("events", &self.events)
+            .field("scale", &self.scale)
+            .field("theme", &self.theme)
+            .finish()
+    }
+}
+
+impl<Renderer> Runtime<Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Runtime`] that draws using the given renderer.
+    ///
+    /// You can obtain a renderer by running the [`Task`] returned by
+    /// [`core::Renderer::load`].
+    ///
+    /// [`Runtime`]: struct.Runtime.html
+    /// [`Task`]: ../load/struct.Task.html
+    /// [`core::Renderer::load`]: core/trait.Renderer.html#tymethod.load
+    pub fn new(renderer: Renderer) -> Runtime<Renderer> {
+        Runtime {
+            renderer,
+            cache: None,
+            cursor_position: Point::new(0.0, 0.0),
+            modifiers: Modifiers::NONE,
+            events: Vec::new(),
+            explain: None,
+            scale: 1.0,
+            theme: Theme::default(),
+            accessibility_label: None,
+        }
+    }
+
+    /// Returns the renderer of the [`Runtime`].
+    ///
+    /// [`Runtime`]: struct.Runtime.html
+    pub fn renderer(&mut self) -> &mut Renderer {
+        &mut self.renderer
+    }
+
+    /// Sets the color used to explain the layout of the whole user interface,
+    /// or `None` to stop explaining it.
+    ///
+    /// While set, the bounds of every node of the layout are drawn, like if
+    /// the root [`Element`] was wrapped with [`Element::explain`]. This helps
+    /// you find out why a widget is not sized as expected.
+    ///
+    /// [`Element`]: core/struct.Element.html
+    /// [`Element::explain`]: core/struct.Element.html#method.explain
+    pub fn explain(&mut self, color: Option<Color>) {
+        self.explain = color;
+    }
+
+    /// Sets the scale factor of the user interface.
+    ///
+    /// Layout sizes and font sizes are multiplied by this factor when drawn,
+    /// and the mouse cursor position is divided by it. You can use it to keep
+    /// the user interface readable on high DPI displays.
+    ///
+    /// Keep in mind that the layout is computed in logical units. If your
+    /// root [`Element`] fills the window, divide the size of the window by
+    /// the scale factor.
+    ///
+    /// [`Element`]: core/struct.Element.html
+    pub fn scale(&mut self, scale: f32) {
+        self.scale = scale;
+    }
+
+    /// Sets the [`Theme`] of the user interface.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub fn theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    /// Returns the accessibility label of the hovered [`Element`], as of the
+    /// last call to [`draw`].
+    ///
+    /// [`Element`]: core/struct.Element.html
+    /// [`draw`]: #method.draw
+    pub fn accessibility_label(&self) -> Option<&str> {
+        self.accessibility_label.as_ref().map(String::as_str)
+    }
+
+    /// Returns the last known position of the mouse cursor, in the logical
+    /// units of the user interface.
+    pub fn cursor_position(&self) -> Point {
+        self.cursor_position
+    }
+
+    /// Feeds an input event to the [`Runtime`].
+    ///
+    /// The event will be processed by the widgets the next time you call
+    /// [`draw`].
+    ///
+    /// [`Runtime`]: struct.Runtime.html
+    /// [`draw`]: #method.draw
+    pub fn on_input(&mut self, event: input::Event) {
+        let event = match event {
+            input::Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                let x = x / self.scale;
+                let y = y / self.scale;
+
+                self.cursor_position = Point::new(x, y);
+
+                input::Event::Mouse(mouse::Event::CursorMoved { x, y })
+            }
+            _ => event,
+        };
+
+        if let Some(ui_event) = Event::from_input(event) {
+            self.events.push(ui_event);
+        }
+    }
+
+    /// Triggers the given [`Shortcuts`] using the pending input events.
+    ///
+    /// The messages of the triggered shortcuts are pushed to `messages` and
+    /// their key presses are discarded, so the widgets never see them. Call
+    /// this before [`draw`].
+    ///
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    /// [`draw`]: #method.draw
+    pub fn trigger<Message>(
+        &mut self,
+        shortcuts: &Shortcuts<Message>,
+        messages: &mut Vec<Message>,
+    ) {
+        if shortcuts.is_empty() {
+            return;
+        }
+
+        // Modifiers are only tracked here, they are updated for good once the
+        // events are processed in `draw`
+        let mut modifiers = self.modifiers;
+
+        self.events.retain(|event| match *event {
+            Event::Keyboard(keyboard_event) => {
+                modifiers.update(keyboard_event);
+
+                match keyboard_event {
+                    keyboard::Event::Input {
+                        state: ButtonState::Pressed,
+                        key_code,
+                        ..
+                    } if Modifiers::from_key_code(key_code).is_none() => {
+                        match shortcuts.trigger(key_code, modifiers) {
+                            Some(message) => {
+                                messages.push(message);
+                                false
+                            }
+                            None => true,
+                        }
+                    }
+                    _ => true,
+                }
+            }
+            _ => true,
+        });
+    }
+
+    /// Lays out the given [`Element`], processes the pending input events and
+    /// draws the result on the given [`Frame`].
+    ///
+    /// The messages produced by the widgets are pushed to `messages`. The
+    /// returned [`MouseCursor`] is the one the widgets want to show.
+    ///
+    /// [`Element`]: core/struct.Element.html
+    /// [`Frame`]: ../graphics/struct.Frame.html
+    /// [`MouseCursor`]: core/enum.MouseCursor.html
+    pub fn draw<'a, Message>(
+        &mut self,
+        root: Element<'a, Message, Renderer>,
+        frame: &mut Frame<'_>,
+        messages: &mut Vec<Message>,
+    ) -> MouseCursor {
+        let mut interface = match self.cache.take() {
+            Some(cache) => {
+                Interface::compute_with_cache(root, &self.renderer, cache)
+            }
+            None => Interface::compute(root, &self.renderer),
+        };
+
+        let cursor_position = self.cursor_position;
+        let mut modifiers = self.modifiers;
+
+        self.renderer.scale(self.scale);
+        self.renderer.theme(self.theme);
+
+        self.events.drain(..).for_each(|event| {
+            if let Event::Keyboard(keyboard_event) = event {
+                modifiers.update(keyboard_event);
+            }
+
+            interface.on_event(event, cursor_position, messages)
+        });
+
+        self.modifiers = modifiers;
+
+        let cursor = interface.draw(
+            &mut self.renderer,
+            frame,
+            cursor_position,
+            self.explain,
+        );
+
+        let label = interface.accessibility_label(cursor_position);
+
+        if label != self.accessibility_label() {
+            self.accessibility_label = label.map(String::from);
+        }
+
+        self.cache = Some(interface.cache());
+
+        cursor
+    }
+}
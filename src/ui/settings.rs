@@ -0,0 +1,52 @@
+use crate::ui::Theme;
+
+/// The settings of a user interface.
+///
+/// Return them in [`UserInterface::settings`] to adapt your user interface
+/// to the needs of your players, like a bigger scale or a high-contrast
+/// [`Theme`].
+///
+/// [`UserInterface::settings`]: trait.UserInterface.html#method.settings
+/// [`Theme`]: struct.Theme.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{Settings, Theme};
+///
+/// let settings = Settings {
+///     scale: 1.5,
+///     theme: Theme::HIGH_CONTRAST,
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Settings {
+    /// The scale factor of the user interface.
+    ///
+    /// Layout sizes and font sizes are multiplied by this factor when drawn.
+    /// The layout is computed in logical units, so divide the size of the
+    /// [`Window`] by the same factor in [`UserInterface::layout`].
+    ///
+    /// By default, it is `1.0`.
+    ///
+    /// [`Window`]: ../graphics/struct.Window.html
+    /// [`UserInterface::layout`]: trait.UserInterface.html#tymethod.layout
+    pub scale: f32,
+
+    /// The [`Theme`] of the user interface.
+    ///
+    /// By default, it is [`Theme::DARK`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    /// [`Theme::DARK`]: struct.Theme.html#associatedconstant.DARK
+    pub theme: Theme,
+}
+
+impl Default for Settings {
+    fn default() -> Settings {
+        Settings {
+            scale: 1.0,
+            theme: Theme::default(),
+        }
+    }
+}
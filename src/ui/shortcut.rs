@@ -0,0 +1,196 @@
+use crate::input::keyboard::{KeyCode, Modifiers};
+
+/// A keyboard shortcut that produces a message, also known as an accelerator.
+///
+/// A [`Shortcut`] is triggered when its key is pressed while holding exactly
+/// its modifiers. For instance, a `Ctrl+S` shortcut is not triggered by
+/// `Ctrl+Shift+S`.
+///
+/// Register your shortcuts in [`UserInterface::shortcuts`] using
+/// [`Shortcuts`]. If you drive a [`Runtime`] yourself, pass them to
+/// [`Runtime::trigger`] instead.
+///
+/// [`Shortcut`]: struct.Shortcut.html
+/// [`UserInterface::shortcuts`]: trait.UserInterface.html#method.shortcuts
+/// [`Shortcuts`]: struct.Shortcuts.html
+/// [`Runtime`]: struct.Runtime.html
+/// [`Runtime::trigger`]: struct.Runtime.html#method.trigger
+pub struct Shortcut<Message> {
+    key_code: KeyCode,
+    modifiers: Modifiers,
+    message: Box<dyn Fn() -> Message>,
+}
+
+impl<Message> Shortcut<Message> {
+    /// Creates a new [`Shortcut`] producing the given message when the key is
+    /// pressed along with the given modifiers.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn new(
+        key_code: KeyCode,
+        modifiers: Modifiers,
+        message: Message,
+    ) -> Shortcut<Message>
+    where
+        Message: 'static + Clone,
+    {
+        Shortcut {
+            key_code,
+            modifiers,
+            message: Box::new(move || message.clone()),
+        }
+    }
+
+    /// Returns the key of the [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn key_code(&self) -> KeyCode {
+        self.key_code
+    }
+
+    /// Returns the modifiers of the [`Shortcut`].
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    fn matches(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.key_code == key_code && self.modifiers == modifiers
+    }
+}
+
+impl<Message> std::fmt::Debug for Shortcut<Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Shortcut")
+            .field("key_code", &self.key_code)
+            .field("modifiers", &self.modifiers)
+            .finish()
+    }
+}
+
+/// Shows the [`Shortcut`] like in menus, for instance `Ctrl+Shift+Z`.
+///
+/// [`Shortcut`]: struct.Shortcut.html
+impl<Message> std::fmt::Display for Shortcut<Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.modifiers.is_empty() {
+            write!(f, "{:?}", self.key_code)
+        } else {
+            write!(f, "{}+{:?}", self.modifiers, self.key_code)
+        }
+    }
+}
+
+/// A set of keyboard shortcuts.
+///
+/// Shortcuts are triggered before the widgets process any input, so they
+/// work regardless of the widget being hovered or focused. The key presses
+/// that trigger them are not seen by the widgets.
+///
+/// ```
+/// use coffee::input::keyboard::{KeyCode, Modifiers};
+/// use coffee::ui::{Shortcut, Shortcuts};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// enum Message {
+///     Save,
+///     Undo,
+///     Redo,
+/// }
+///
+/// let shortcuts = Shortcuts::new()
+///     .push(Shortcut::new(KeyCode::S, Modifiers::CTRL, Message::Save))
+///     .push(Shortcut::new(KeyCode::Z, Modifiers::CTRL, Message::Undo))
+///     .push(Shortcut::new(
+///         KeyCode::Z,
+///         Modifiers::CTRL | Modifiers::SHIFT,
+///         Message::Redo,
+///     ));
+///
+/// assert!(shortcuts.contains(KeyCode::Z, Modifiers::CTRL));
+/// assert!(!shortcuts.contains(KeyCode::Y, Modifiers::CTRL));
+/// ```
+pub struct Shortcuts<Message> {
+    shortcuts: Vec<Shortcut<Message>>,
+}
+
+impl<Message> Shortcuts<Message> {
+    /// Creates an empty set of [`Shortcuts`].
+    ///
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    pub fn new() -> Shortcuts<Message> {
+        Shortcuts {
+            shortcuts: Vec::new(),
+        }
+    }
+
+    /// Adds a [`Shortcut`] to the [`Shortcuts`].
+    ///
+    /// # Panics
+    ///
+    /// It panics if a [`Shortcut`] with the same key and modifiers was
+    /// already added. Use [`contains`] first if your shortcuts can be
+    /// configured by your players.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    /// [`Shortcuts`]: struct.Shortcuts.html
+    /// [`contains`]: #method.contains
+    pub fn push(mut self, shortcut: Shortcut<Message>) -> Self {
+        if self.contains(shortcut.key_code, shortcut.modifiers) {
+            panic!("Shortcut conflict: {} is already registered", shortcut);
+        }
+
+        self.shortcuts.push(shortcut);
+        self
+    }
+
+    /// Returns true if a [`Shortcut`] with the given key and modifiers was
+    /// added.
+    ///
+    /// [`Shortcut`]: struct.Shortcut.html
+    pub fn contains(&self, key_code: KeyCode, modifiers: Modifiers) -> bool {
+        self.find(key_code, modifiers).is_some()
+    }
+
+    /// Returns true if there are no shortcuts.
+    pub fn is_empty(&self) -> bool {
+        self.shortcuts.is_empty()
+    }
+
+    /// Returns an iterator over the shortcuts, in the order they were added.
+    pub fn iter(&self) -> impl Iterator<Item = &Shortcut<Message>> {
+        self.shortcuts.iter()
+    }
+
+    pub(crate) fn trigger(
+        &self,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+    ) -> Option<Message> {
+        self.find(key_code, modifiers)
+            .map(|shortcut| (shortcut.message)())
+    }
+
+    fn find(
+        &self,
+        key_code: KeyCode,
+        modifiers: Modifiers,
+    ) -> Option<&Shortcut<Message>> {
+        self.shortcuts
+            .iter()
+            .find(|shortcut| shortcut.matches(key_code, modifiers))
+    }
+}
+
+impl<Message> Default for Shortcuts<Message> {
+    fn default() -> Shortcuts<Message> {
+        Shortcuts::new()
+    }
+}
+
+impl<Message> std::fmt::Debug for Shortcuts<Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.shortcuts.iter()).finish()
+    }
+}
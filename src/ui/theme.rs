@@ -0,0 +1,147 @@
+use crate::graphics::Color;
+
+/// The colors used to draw the built-in widgets.
+///
+/// Renderers implementing [`Primitives`] draw every widget using the
+/// [`Theme`] returned by [`Primitives::theme`]. The built-in [`Renderer`]
+/// takes the look of its widgets from its sprite sheet, so it only uses the
+/// [`Theme`] for the colors it draws by itself: the backdrop of modals and
+/// the text of tooltips.
+///
+/// You can choose the [`Theme`] of your user interface in its [`Settings`].
+///
+/// [`Primitives`]: trait.Primitives.html
+/// [`Theme`]: struct.Theme.html
+/// [`Primitives::theme`]: trait.Primitives.html#method.theme
+/// [`Renderer`]: struct.Renderer.html
+/// [`Settings`]: struct.Settings.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// The color of panels and of the inside of toggles.
+    pub background: Color,
+
+    /// The color of idle widgets, like secondary buttons and empty tracks.
+    pub surface: Color,
+
+    /// The color of hovered widgets.
+    pub highlight: Color,
+
+    /// The color of primary buttons, selections and filled tracks.
+    pub accent: Color,
+
+    /// The color of positive buttons.
+    pub positive: Color,
+
+    /// The color of disabled labels and marks.
+    pub disabled: Color,
+
+    /// The color drawn behind modals.
+    pub backdrop: Color,
+
+    /// The color of labels and borders.
+    pub text: Color,
+}
+
+impl Theme {
+    /// The default, dark [`Theme`].
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub const DARK: Theme = Theme {
+        background: Color {
+            r: 0.15,
+            g: 0.15,
+            b: 0.18,
+            a: 1.0,
+        },
+        surface: Color {
+            r: 0.25,
+            g: 0.25,
+            b: 0.3,
+            a: 1.0,
+        },
+        highlight: Color {
+            r: 0.4,
+            g: 0.4,
+            b: 0.47,
+            a: 1.0,
+        },
+        accent: Color {
+            r: 0.2,
+            g: 0.55,
+            b: 0.85,
+            a: 1.0,
+        },
+        positive: Color {
+            r: 0.25,
+            g: 0.65,
+            b: 0.35,
+            a: 1.0,
+        },
+        disabled: Color {
+            r: 0.6,
+            g: 0.6,
+            b: 0.6,
+            a: 0.6,
+        },
+        backdrop: Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.6,
+        },
+        text: Color::WHITE,
+    };
+
+    /// A high-contrast [`Theme`].
+    ///
+    /// It draws white text over pure black and dark, saturated colors. Every
+    /// label meets a contrast ratio of at least 4.5:1 with its background.
+    ///
+    /// [`Theme`]: struct.Theme.html
+    pub const HIGH_CONTRAST: Theme = Theme {
+        background: Color::BLACK,
+        surface: Color {
+            r: 0.2,
+            g: 0.2,
+            b: 0.2,
+            a: 1.0,
+        },
+        highlight: Color {
+            r: 0.45,
+            g: 0.45,
+            b: 0.45,
+            a: 1.0,
+        },
+        accent: Color {
+            r: 0.0,
+            g: 0.3,
+            b: 0.9,
+            a: 1.0,
+        },
+        positive: Color {
+            r: 0.0,
+            g: 0.45,
+            b: 0.0,
+            a: 1.0,
+        },
+        disabled: Color {
+            r: 0.75,
+            g: 0.75,
+            b: 0.75,
+            a: 1.0,
+        },
+        backdrop: Color {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+            a: 0.85,
+        },
+        text: Color::WHITE,
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::DARK
+    }
+}
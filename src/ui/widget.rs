@@ -9,7 +9,8 @@
 //! ```
 //!
 //! However, if you want to use a custom renderer, you will need to work with
-//! the definitions of [`Row`], [`Column`], and [`Panel`] found in this module.
+//! the definitions of [`Row`], [`Column`], [`Panel`], [`Modal`], [`Tabs`], and
+//! [`Positioned`] found in this module.
 //!
 //! # Customization
 //! Every drawable widget has its own module with a `Renderer` trait that must
@@ -22,26 +23,47 @@
 //! [`Row`]: struct.Row.html
 //! [`Column`]: struct.Column.html
 //! [`Panel`]: struct.Panel.html
+//! [`Modal`]: struct.Modal.html
+//! [`Tabs`]: struct.Tabs.html
+//! [`Positioned`]: struct.Positioned.html
 //! [`Renderer`]: ../struct.Renderer.html
 mod column;
+mod positioned;
 mod row;
+mod space;
 
 pub mod button;
 pub mod checkbox;
+pub mod gauge;
 pub mod image;
+pub mod log_view;
+pub mod modal;
+pub mod mouse_area;
 pub mod panel;
 pub mod progress_bar;
 pub mod radio;
+pub mod rule;
 pub mod slider;
+pub mod tabs;
 pub mod text;
+pub mod virtual_keyboard;
 
 pub use self::image::Image;
 pub use button::Button;
 pub use checkbox::Checkbox;
 pub use column::Column;
+pub use gauge::Gauge;
+pub use log_view::LogView;
+pub use modal::Modal;
+pub use mouse_area::MouseArea;
 pub use panel::Panel;
+pub use positioned::Positioned;
 pub use progress_bar::ProgressBar;
-pub use radio::Radio;
+pub use radio::{Radio, RadioGroup};
 pub use row::Row;
+pub use rule::Rule;
 pub use slider::Slider;
+pub use space::Space;
+pub use tabs::Tabs;
 pub use text::Text;
+pub use virtual_keyboard::VirtualKeyboard;
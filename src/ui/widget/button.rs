@@ -7,7 +7,7 @@
 //! [`Class`]: enum.Class.html
 
 use crate::graphics::{Point, Rectangle};
-use crate::input::{mouse, ButtonState};
+use crate::input::{keyboard, mouse, ButtonState};
 use crate::ui::core::{
     Align, Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
 };
@@ -44,6 +44,11 @@ pub struct Button<'a, Message> {
     label: String,
     class: Class,
     on_press: Option<Message>,
+    on_hover: Option<Message>,
+    on_mouse_down: Option<Message>,
+    on_release: Option<Message>,
+    on_focus: Option<Message>,
+    is_disabled: bool,
     style: Style,
 }
 
@@ -57,6 +62,11 @@ where
             .field("label", &self.label)
             .field("class", &self.class)
             .field("on_press", &self.on_press)
+            .field("on_hover", &self.on_hover)
+            .field("on_mouse_down", &self.on_mouse_down)
+            .field("on_release", &self.on_release)
+            .field("on_focus", &self.on_focus)
+            .field("is_disabled", &self.is_disabled)
             .field("style", &self.style)
             .finish()
     }
@@ -77,6 +87,11 @@ impl<'a, Message> Button<'a, Message> {
             label: String::from(label),
             class: Class::Primary,
             on_press: None,
+            on_hover: None,
+            on_mouse_down: None,
+            on_release: None,
+            on_focus: None,
+            is_disabled: false,
             style: Style::default().min_width(100),
         }
     }
@@ -125,6 +140,59 @@ impl<'a, Message> Button<'a, Message> {
         self.on_press = Some(msg);
         self
     }
+
+    /// Sets the message that will be produced when the mouse cursor enters the
+    /// [`Button`].
+    ///
+    /// This is useful to play a sound or show a preview.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_hover(mut self, msg: Message) -> Self {
+        self.on_hover = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// pressed on the [`Button`].
+    ///
+    /// Unlike [`on_press`], it does not wait for the mouse button to be
+    /// released.
+    ///
+    /// [`Button`]: struct.Button.html
+    /// [`on_press`]: #method.on_press
+    pub fn on_mouse_down(mut self, msg: Message) -> Self {
+        self.on_mouse_down = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// released after being pressed on the [`Button`], even if the cursor is
+    /// not over it anymore.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_release(mut self, msg: Message) -> Self {
+        self.on_release = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`Button`] gets
+    /// focused.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn on_focus(mut self, msg: Message) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    /// Sets whether the [`Button`] is disabled or not.
+    ///
+    /// A disabled [`Button`] is greyed out and it ignores any interaction.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Button<'a, Message>
@@ -143,14 +211,58 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.is_disabled {
+            self.state.is_pressed = false;
+            self.state.is_focused = false;
+            self.state.is_hovered = false;
+            self.state.is_held = false;
+            return;
+        }
+
         match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                let is_hovered = layout.bounds().contains(Point::new(x, y));
+
+                if is_hovered && !self.state.is_hovered {
+                    if let Some(on_hover) = self.on_hover {
+                        messages.push(on_hover);
+                    }
+                }
+
+                self.state.is_hovered = is_hovered;
+            }
             Event::Mouse(mouse::Event::Input {
                 button: mouse::Button::Left,
                 state,
             }) => {
-                if let Some(on_press) = self.on_press {
-                    let bounds = layout.bounds();
+                let bounds = layout.bounds();
+
+                if state == ButtonState::Pressed {
+                    let was_focused = self.state.is_focused;
+
+                    self.state.is_focused = bounds.contains(cursor_position);
+                    self.state.is_held = self.state.is_focused;
+
+                    if self.state.is_held {
+                        if let Some(on_mouse_down) = self.on_mouse_down {
+                            messages.push(on_mouse_down);
+                        }
+                    }
+
+                    if let (false, true, Some(on_focus)) =
+                        (was_focused, self.state.is_focused, self.on_focus)
+                    {
+                        messages.push(on_focus);
+                    }
+                } else if self.state.is_held {
+                    self.state.is_held = false;
+
+                    if let Some(on_release) = self.on_release {
+                        messages.push(on_release);
+                    }
+                }
 
+                if let Some(on_press) = self.on_press {
                     match state {
                         ButtonState::Pressed => {
                             self.state.is_pressed =
@@ -169,6 +281,22 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::Return,
+                state: ButtonState::Pressed,
+                ..
+            })
+            | Event::Keyboard(keyboard::Event::Input {
+                key_code: keyboard::KeyCode::NumpadEnter,
+                state: ButtonState::Pressed,
+                ..
+            }) => {
+                if let (true, Some(on_press)) =
+                    (self.state.is_focused, self.on_press)
+                {
+                    messages.push(on_press);
+                }
+            }
             _ => {}
         }
     }
@@ -185,6 +313,7 @@ where
             self.state,
             &self.label,
             self.class,
+            self.is_disabled,
         )
     }
 
@@ -199,6 +328,9 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_pressed: bool,
+    is_focused: bool,
+    is_hovered: bool,
+    is_held: bool,
 }
 
 impl State {
@@ -216,6 +348,24 @@ impl State {
     pub fn is_pressed(&self) -> bool {
         self.is_pressed
     }
+
+    /// Returns whether the associated [`Button`] is focused or not.
+    ///
+    /// A [`Button`] gets focused when clicked, and it can then be pressed
+    /// with the Enter key.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Returns whether the mouse cursor is over the associated [`Button`] or
+    /// not.
+    ///
+    /// [`Button`]: struct.Button.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
 }
 
 /// The type of a [`Button`].
@@ -257,6 +407,7 @@ pub trait Renderer {
     ///   * the local state of the [`Button`]
     ///   * the label of the [`Button`]
     ///   * the [`Class`] of the [`Button`]
+    ///   * whether the [`Button`] is disabled or not
     ///
     /// [`Button`]: struct.Button.html
     /// [`State`]: struct.State.html
@@ -268,6 +419,7 @@ pub trait Renderer {
         state: &State,
         label: &str,
         class: Class,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
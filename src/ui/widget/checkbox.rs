@@ -41,6 +41,7 @@ pub struct Checkbox<Message> {
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
     label_color: Color,
+    is_disabled: bool,
 }
 
 impl<Message> std::fmt::Debug for Checkbox<Message> {
@@ -49,6 +50,7 @@ impl<Message> std::fmt::Debug for Checkbox<Message> {
             .field("is_checked", &self.is_checked)
             .field("label", &self.label)
             .field("label_color", &self.label_color)
+            .field("is_disabled", &self.is_disabled)
             .finish()
     }
 }
@@ -73,6 +75,7 @@ impl<Message> Checkbox<Message> {
             on_toggle: Box::new(f),
             label: String::from(label),
             label_color: Color::WHITE,
+            is_disabled: false,
         }
     }
 
@@ -84,6 +87,16 @@ impl<Message> Checkbox<Message> {
         self.label_color = color;
         self
     }
+
+    /// Sets whether the [`Checkbox`] is disabled or not.
+    ///
+    /// A disabled [`Checkbox`] is greyed out and it ignores any interaction.
+    ///
+    /// [`Checkbox`]: struct.Checkbox.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Checkbox<Message>
@@ -115,7 +128,7 @@ where
                     .children()
                     .any(|child| child.bounds().contains(cursor_position));
 
-                if mouse_over {
+                if mouse_over && !self.is_disabled {
                     messages.push((self.on_toggle)(!self.is_checked));
                 }
             }
@@ -138,7 +151,14 @@ where
             text_bounds,
             &self.label,
             20.0,
-            self.label_color,
+            if self.is_disabled {
+                Color {
+                    a: self.label_color.a * 0.5,
+                    ..self.label_color
+                }
+            } else {
+                self.label_color
+            },
             HorizontalAlignment::Left,
             VerticalAlignment::Top,
         );
@@ -149,6 +169,7 @@ where
             children[0].bounds(),
             text_bounds,
             self.is_checked,
+            self.is_disabled,
         )
     }
 
@@ -172,6 +193,7 @@ pub trait Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * the bounds of the label of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is checked or not
+    ///   * whether the [`Checkbox`] is disabled or not
     ///
     /// [`Checkbox`]: struct.Checkbox.html
     fn draw(
@@ -180,6 +202,7 @@ pub trait Renderer {
         bounds: Rectangle<f32>,
         label_bounds: Rectangle<f32>,
         is_checked: bool,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
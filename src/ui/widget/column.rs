@@ -103,7 +103,7 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the horizontal alignment of the contents of the [`Column`] .
+    /// Sets the horizontal alignment of the contents of the [`Column`].
     ///
     /// [`Column`]: struct.Column.html
     pub fn align_items(mut self, align: Align) -> Self {
@@ -112,7 +112,7 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
     }
 
     /// Sets the vertical distribution strategy for the contents of the
-    /// [`Column`] .
+    /// [`Column`].
     ///
     /// [`Column`]: struct.Column.html
     pub fn justify_content(mut self, justify: Justify) -> Self {
@@ -120,6 +120,17 @@ impl<'a, Message, Renderer> Column<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the vertical distribution strategy for the contents of the
+    /// [`Column`].
+    ///
+    /// It is a shorter alias of [`justify_content`].
+    ///
+    /// [`Column`]: struct.Column.html
+    /// [`justify_content`]: #method.justify_content
+    pub fn justify(self, justify: Justify) -> Self {
+        self.justify_content(justify)
+    }
+
     /// Adds an [`Element`] to the [`Column`].
     ///
     /// [`Element`]: ../core/struct.Element.html
@@ -200,6 +211,34 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
         cursor
     }
 
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| {
+                child.widget.cursor(layout, cursor_position)
+            })
+            .last()
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| {
+                child.widget.accessibility_label(layout, cursor_position)
+            })
+            .last()
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
         self.spacing.hash(state);
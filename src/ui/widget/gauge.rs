@@ -0,0 +1,115 @@
+//! Display a value within a range as a circular gauge.
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// A circular arc that fills up as its value approaches the end of its range.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`gauge::Renderer`] trait.
+///
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`gauge::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::Gauge;
+///
+/// let health = 75.0;
+///
+/// Gauge::new(0.0..=100.0, health).size(80);
+/// ```
+#[derive(Debug)]
+pub struct Gauge {
+    range: RangeInclusive<f32>,
+    value: f32,
+    style: Style,
+}
+
+impl Gauge {
+    /// Creates a new [`Gauge`] showing the given value within a range.
+    ///
+    /// The value is clamped to the range.
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Gauge {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+            style: Style::default().width(100).height(100),
+        }
+    }
+
+    /// Sets the size of the [`Gauge`] in pixels.
+    ///
+    /// A [`Gauge`] is always as wide as it is tall.
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    pub fn size(mut self, size: u32) -> Self {
+        self.style = self.style.width(size).height(size);
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Gauge
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> MouseCursor {
+        renderer.draw(layout.bounds(), self.range.clone(), self.value);
+
+        MouseCursor::OutOfBounds
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+}
+
+/// The renderer of a [`Gauge`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Gauge`] in your user interface.
+///
+/// [`Gauge`]: struct.Gauge.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Gauge`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Gauge`]
+    ///   * the range of values of the [`Gauge`]
+    ///   * the current value of the [`Gauge`]
+    ///
+    /// [`Gauge`]: struct.Gauge.html
+    fn draw(
+        &mut self,
+        bounds: Rectangle<f32>,
+        range: RangeInclusive<f32>,
+        value: f32,
+    );
+}
+
+impl<'a, Message, Renderer> From<Gauge> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(gauge: Gauge) -> Element<'a, Message, Renderer> {
+        Element::new(gauge)
+    }
+}
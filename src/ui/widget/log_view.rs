@@ -0,0 +1,437 @@
+//! Display a bounded log of messages, like the output of an in-game console.
+//!
+//! A [`LogView`] has some local [`State`] that stores the messages.
+//!
+//! [`LogView`]: struct.LogView.html
+//! [`State`]: struct.State.html
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::mouse;
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// A scrollable list of log messages.
+///
+/// A [`LogView`] shows the most recent messages of its [`State`] that match
+/// its filter. It follows new messages automatically, unless the user scrolls
+/// up with the mouse wheel.
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`log_view::Renderer`] trait.
+///
+/// [`LogView`]: struct.LogView.html
+/// [`State`]: struct.State.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`log_view::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::log_view::{self, Level};
+/// use coffee::ui::LogView;
+///
+/// let state = &mut log_view::State::new(100);
+///
+/// state.push("Game saved", Level::Info);
+/// state.push("Texture is too big", Level::Warn);
+///
+/// LogView::new(state).filter(Level::Warn).height(200);
+/// ```
+#[derive(Debug)]
+pub struct LogView<'a> {
+    state: &'a mut State,
+    filter: Level,
+    text_size: u16,
+    style: Style,
+}
+
+impl<'a> LogView<'a> {
+    /// Creates a new [`LogView`] showing the messages of the given [`State`].
+    ///
+    /// [`LogView`]: struct.LogView.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State) -> Self {
+        LogView {
+            state,
+            filter: Level::Trace,
+            text_size: 16,
+            style: Style::default().fill_width().height(200),
+        }
+    }
+
+    /// Sets the minimum [`Level`] of the messages shown by the [`LogView`].
+    ///
+    /// By default, all the messages are shown.
+    ///
+    /// [`Level`]: enum.Level.html
+    /// [`LogView`]: struct.LogView.html
+    pub fn filter(mut self, level: Level) -> Self {
+        self.filter = level;
+        self
+    }
+
+    /// Sets the text size of the [`LogView`] in pixels.
+    ///
+    /// [`LogView`]: struct.LogView.html
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = size;
+        self
+    }
+
+    /// Sets the width of the [`LogView`] in pixels.
+    ///
+    /// [`LogView`]: struct.LogView.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`LogView`] in pixels.
+    ///
+    /// [`LogView`]: struct.LogView.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+
+    fn visible_lines(&self, bounds: Rectangle<f32>) -> usize {
+        ((bounds.height / self.text_size as f32) as usize).max(1)
+    }
+
+    // The entries that match the filter, along with their ids, from oldest to
+    // newest
+    fn filtered(&self) -> impl DoubleEndedIterator<Item = (u64, &Entry)> {
+        let filter = self.filter;
+        let first_id = self.state.first_id;
+
+        self.state
+            .entries
+            .iter()
+            .enumerate()
+            .map(move |(i, entry)| (first_id + i as u64, entry))
+            .filter(move |(_, entry)| entry.level >= filter)
+    }
+
+    // The amount of filtered entries below the visible ones, clamped so a
+    // full page is always shown
+    fn scroll(&self, visible_lines: usize) -> usize {
+        let total = self.filtered().count();
+        let max_scroll = total.saturating_sub(visible_lines);
+
+        let below = match self.state.anchor {
+            Some(anchor) => {
+                self.filtered().filter(|(id, _)| *id > anchor).count()
+            }
+            None => 0,
+        };
+
+        below.min(max_scroll)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for LogView<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _messages: &mut Vec<Message>,
+    ) {
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta_y, .. }) => {
+                let bounds = layout.bounds();
+
+                if bounds.contains(cursor_position) {
+                    let visible_lines = self.visible_lines(bounds);
+                    let total = self.filtered().count();
+                    let max_scroll = total.saturating_sub(visible_lines);
+
+                    let scroll = self.scroll(visible_lines) as f32 + delta_y;
+                    let scroll = (scroll.max(0.0) as usize).min(max_scroll);
+
+                    // The view is anchored to the newest visible entry, so
+                    // it does not move when messages are pushed
+                    self.state.anchor = if scroll == 0 {
+                        None
+                    } else {
+                        self.filtered().rev().nth(scroll).map(|(id, _)| id)
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let visible_lines = self.visible_lines(bounds);
+
+        let mut entries: Vec<&Entry> = self
+            .filtered()
+            .rev()
+            .skip(self.scroll(visible_lines))
+            .take(visible_lines)
+            .map(|(_, entry)| entry)
+            .collect();
+
+        entries.reverse();
+
+        renderer.draw(cursor_position, bounds, &entries, self.text_size as f32)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+}
+
+/// The local state of a [`LogView`].
+///
+/// It stores the messages in a ring buffer: once it is full, pushing a new
+/// message discards the oldest one.
+///
+/// [`LogView`]: struct.LogView.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct State {
+    entries: VecDeque<Entry>,
+    capacity: usize,
+    // The id of the oldest stored entry, increased on every discard
+    first_id: u64,
+    // The id of the newest visible entry, unless following new messages
+    anchor: Option<u64>,
+}
+
+impl State {
+    /// Creates a new [`State`] able to hold the given amount of messages.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new(capacity: usize) -> State {
+        State {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            first_id: 0,
+            anchor: None,
+        }
+    }
+
+    /// Adds a new message with the given [`Level`].
+    ///
+    /// If the [`State`] is full, the oldest message is discarded.
+    ///
+    /// [`Level`]: enum.Level.html
+    /// [`State`]: struct.State.html
+    pub fn push<S: Into<String>>(&mut self, message: S, level: Level) {
+        if self.entries.len() == self.capacity {
+            let _ = self.entries.pop_front();
+            self.first_id += 1;
+
+            // Keep scrolled views within the remaining messages
+            let first_id = self.first_id;
+            self.anchor = self.anchor.map(|anchor| anchor.max(first_id));
+        }
+
+        self.entries.push_back(Entry {
+            message: message.into(),
+            level,
+        });
+    }
+
+    /// Moves the messages recorded by the given [`Logger`] since the last
+    /// call into the [`State`].
+    ///
+    /// [`Logger`]: struct.Logger.html
+    /// [`State`]: struct.State.html
+    pub fn pull(&mut self, logger: &Logger) {
+        let entries = match logger.queue.lock() {
+            Ok(mut queue) => queue.drain(..).collect::<Vec<_>>(),
+            Err(_) => return,
+        };
+
+        for entry in entries {
+            self.push(entry.message, entry.level);
+        }
+    }
+
+    /// Removes all the messages.
+    pub fn clear(&mut self) {
+        self.first_id += self.entries.len() as u64;
+        self.entries.clear();
+        self.anchor = None;
+    }
+
+    /// Returns an iterator over the stored messages, from oldest to newest.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Returns whether the associated [`LogView`] is following new messages.
+    ///
+    /// [`LogView`]: struct.LogView.html
+    pub fn is_following(&self) -> bool {
+        self.anchor.is_none()
+    }
+}
+
+/// A [`log::Log`] implementation that records messages for a [`State`].
+///
+/// Install it as the logger of your game and [`pull`] its messages into the
+/// [`State`] of a [`LogView`] every frame. It can be cloned and shared
+/// between threads.
+///
+/// ```
+/// use coffee::ui::log_view::{self, Logger};
+///
+/// let logger = Logger::new(100);
+///
+/// log::set_boxed_logger(Box::new(logger.clone())).expect("Install logger");
+/// log::set_max_level(log::LevelFilter::Info);
+///
+/// log::warn!("Texture is too big");
+///
+/// let mut state = log_view::State::new(100);
+/// state.pull(&logger);
+///
+/// assert_eq!(state.entries().count(), 1);
+/// ```
+///
+/// [`log::Log`]: https://docs.rs/log/0.4/log/trait.Log.html
+/// [`State`]: struct.State.html
+/// [`pull`]: struct.State.html#method.pull
+/// [`LogView`]: struct.LogView.html
+#[derive(Debug, Clone)]
+pub struct Logger {
+    queue: Arc<Mutex<VecDeque<Entry>>>,
+    capacity: usize,
+}
+
+impl Logger {
+    /// Creates a new [`Logger`] able to hold the given amount of messages
+    /// until they are pulled.
+    ///
+    /// If it is full, the oldest message is discarded.
+    ///
+    /// [`Logger`]: struct.Logger.html
+    pub fn new(capacity: usize) -> Logger {
+        Logger {
+            queue: Arc::new(Mutex::new(VecDeque::new())),
+            capacity: capacity.max(1),
+        }
+    }
+}
+
+impl log::Log for Logger {
+    fn enabled(&self, _metadata: &log::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if let Ok(mut queue) = self.queue.lock() {
+            if queue.len() == self.capacity {
+                let _ = queue.pop_front();
+            }
+
+            queue.push_back(Entry {
+                message: record.args().to_string(),
+                level: Level::from(record.level()),
+            });
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// A message stored in a [`State`].
+///
+/// [`State`]: struct.State.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The contents of the message.
+    pub message: String,
+
+    /// The [`Level`] of the message.
+    ///
+    /// [`Level`]: enum.Level.html
+    pub level: Level,
+}
+
+/// The importance of a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Very detailed information.
+    Trace,
+
+    /// Information useful for debugging.
+    Debug,
+
+    /// General information.
+    Info,
+
+    /// A potential problem.
+    Warn,
+
+    /// A serious problem.
+    Error,
+}
+
+impl From<log::Level> for Level {
+    fn from(level: log::Level) -> Level {
+        match level {
+            log::Level::Trace => Level::Trace,
+            log::Level::Debug => Level::Debug,
+            log::Level::Info => Level::Info,
+            log::Level::Warn => Level::Warn,
+            log::Level::Error => Level::Error,
+        }
+    }
+}
+
+/// The renderer of a [`LogView`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`LogView`] in your user interface.
+///
+/// [`LogView`]: struct.LogView.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`LogView`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`LogView`]
+    ///   * the visible entries, from oldest to newest
+    ///   * the text size of the [`LogView`]
+    ///
+    /// [`LogView`]: struct.LogView.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        entries: &[&Entry],
+        text_size: f32,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<LogView<'a>> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(log_view: LogView<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(log_view)
+    }
+}
@@ -0,0 +1,305 @@
+//! Display content on top of the rest of your user interface.
+use std::f32;
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Align, Element, Event, Hasher, Justify, Layout, MouseCursor, Node, Style,
+    Widget,
+};
+
+/// A container that can display content on top of another widget.
+///
+/// While its overlay is shown, a [`Modal`] dims the underlying widget, centers
+/// the overlay on top of it, and captures every event. The underlying widget
+/// does not receive any interaction until the overlay is hidden.
+///
+/// This is useful to build confirmation dialogs and pause menus.
+///
+/// It implements [`Widget`] when the [`core::Renderer`] implements the
+/// [`modal::Renderer`] trait.
+///
+/// [`Modal`]: struct.Modal.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`modal::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{Column, Modal, Panel, Text};
+///
+/// pub enum Message { /* ... */ }
+///
+/// let is_paused = true;
+///
+/// let game = Column::<Message>::new().push(Text::new("Playing..."));
+/// let mut modal = Modal::new(game).width(800).height(600);
+///
+/// if is_paused {
+///     modal = modal.overlay(Panel::new(Text::new("Paused")).width(300));
+/// }
+/// ```
+pub struct Modal<'a, Message, Renderer> {
+    style: Style,
+    underlay: Element<'a, Message, Renderer>,
+    overlay: Option<Element<'a, Message, Renderer>>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Modal<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Modal")
+            .field("style", &self.style)
+            .field("underlay", &self.underlay)
+            .field("overlay", &self.overlay)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Modal<'a, Message, Renderer> {
+    /// Creates a new [`Modal`] wrapping the given widget.
+    ///
+    /// No overlay is shown by default.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn new<E>(underlay: E) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+    {
+        Modal {
+            style: Style::default().fill_width(),
+            underlay: underlay.into(),
+            overlay: None,
+        }
+    }
+
+    /// Shows the given widget on top of the [`Modal`].
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn overlay<E>(mut self, overlay: E) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+    {
+        self.overlay = Some(overlay.into());
+        self
+    }
+
+    /// Sets the width of the [`Modal`] in pixels.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the height of the [`Modal`] in pixels.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Modal<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let mut children = vec![self.underlay.widget.node(renderer)];
+
+        if let Some(overlay) = &self.overlay {
+            let mut style = Style::default()
+                .fill_width()
+                .fill_height()
+                .align_items(Align::Center)
+                .justify_content(Justify::Center);
+
+            style.0.position_type = stretch::style::PositionType::Absolute;
+            style.0.position.start = stretch::style::Dimension::Points(0.0);
+            style.0.position.top = stretch::style::Dimension::Points(0.0);
+
+            children.push(Node::with_children(
+                style,
+                vec![overlay.widget.node(renderer)],
+            ));
+        }
+
+        Node::with_children(self.style, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let mut children = layout.children();
+
+        let underlay_layout = match children.next() {
+            Some(layout) => layout,
+            None => return,
+        };
+
+        match &mut self.overlay {
+            Some(overlay) => {
+                // The overlay captures every event while it is shown
+                if let Some(container) = children.next() {
+                    if let Some(layout) = container.children().next() {
+                        overlay.widget.on_event(
+                            event,
+                            layout,
+                            cursor_position,
+                            messages,
+                        );
+                    }
+                }
+            }
+            None => self.underlay.widget.on_event(
+                event,
+                underlay_layout,
+                cursor_position,
+                messages,
+            ),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+
+        let underlay_layout = match children.next() {
+            Some(layout) => layout,
+            None => return MouseCursor::OutOfBounds,
+        };
+
+        let overlay = match &self.overlay {
+            Some(overlay) => overlay,
+            None => {
+                return self.underlay.widget.draw(
+                    renderer,
+                    underlay_layout,
+                    cursor_position,
+                );
+            }
+        };
+
+        // The underlay is drawn as if the cursor was out of the window, so it
+        // does not show any hover effect.
+        let _ = self.underlay.widget.draw(
+            renderer,
+            underlay_layout,
+            Point::new(f32::INFINITY, f32::INFINITY),
+        );
+
+        renderer.draw(bounds);
+
+        let cursor = children
+            .next()
+            .and_then(|layout| {
+                layout.children().next().map(|layout| {
+                    overlay.widget.draw(renderer, layout, cursor_position)
+                })
+            })
+            .unwrap_or(MouseCursor::OutOfBounds);
+
+        if cursor == MouseCursor::OutOfBounds
+            && bounds.contains(cursor_position)
+        {
+            MouseCursor::Idle
+        } else {
+            cursor
+        }
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        let mut children = layout.children();
+        let underlay_layout = children.next()?;
+
+        match &self.overlay {
+            Some(overlay) => {
+                let overlay_layout = children.next()?;
+                let layout = overlay_layout.children().next()?;
+
+                overlay.widget.cursor(layout, cursor_position)
+            }
+            None => self
+                .underlay
+                .widget
+                .cursor(underlay_layout, cursor_position),
+        }
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        let mut children = layout.children();
+        let underlay_layout = children.next()?;
+
+        match &self.overlay {
+            Some(overlay) => {
+                let overlay_layout = children.next()?;
+                let layout = overlay_layout.children().next()?;
+
+                overlay.widget.accessibility_label(layout, cursor_position)
+            }
+            None => self
+                .underlay
+                .widget
+                .accessibility_label(underlay_layout, cursor_position),
+        }
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.underlay.widget.hash(state);
+
+        if let Some(overlay) = &self.overlay {
+            overlay.widget.hash(state);
+        }
+    }
+}
+
+/// The renderer of a [`Modal`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Modal`] in your user interface.
+///
+/// [`Modal`]: struct.Modal.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws the background of a [`Modal`] overlay.
+    ///
+    /// It receives the bounds of the [`Modal`]. It is called after drawing
+    /// the underlying widget and before drawing the overlay, which must be
+    /// drawn on top of anything drawn before.
+    ///
+    /// [`Modal`]: struct.Modal.html
+    fn draw(&mut self, bounds: Rectangle<f32>);
+}
+
+impl<'a, Message, Renderer> From<Modal<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        modal: Modal<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(modal)
+    }
+}
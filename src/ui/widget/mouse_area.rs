@@ -0,0 +1,267 @@
+//! Listen to mouse interactions on any widget.
+//!
+//! A [`MouseArea`] has some local [`State`].
+//!
+//! [`MouseArea`]: struct.MouseArea.html
+//! [`State`]: struct.State.html
+
+use crate::graphics::Point;
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+};
+
+/// A wrapper that produces messages when the mouse interacts with its
+/// content.
+///
+/// It does not draw anything by itself, so it implements [`Widget`] for any
+/// [`core::Renderer`].
+///
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{mouse_area, MouseArea, Text};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     ShowPreview,
+///     HidePreview,
+/// }
+///
+/// let state = &mut mouse_area::State::new();
+///
+/// MouseArea::<Message>::new(state, Text::new("Fireball"))
+///     .on_hover(Message::ShowPreview)
+///     .on_leave(Message::HidePreview);
+/// ```
+pub struct MouseArea<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_hover: Option<Message>,
+    on_leave: Option<Message>,
+    on_mouse_down: Option<Message>,
+    on_release: Option<Message>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for MouseArea<'a, Message, Renderer>
+where
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MouseArea")
+            .field("state", &self.state)
+            .field("content", &self.content)
+            .field("on_hover", &self.on_hover)
+            .field("on_leave", &self.on_leave)
+            .field("on_mouse_down", &self.on_mouse_down)
+            .field("on_release", &self.on_release)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer> {
+    /// Creates a new [`MouseArea`] with some local [`State`] wrapping the
+    /// given [`Widget`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    /// [`State`]: struct.State.html
+    /// [`Widget`]: ../../core/trait.Widget.html
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+    {
+        MouseArea {
+            state,
+            content: content.into(),
+            on_hover: None,
+            on_leave: None,
+            on_mouse_down: None,
+            on_release: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the mouse cursor enters the
+    /// [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_hover(mut self, msg: Message) -> Self {
+        self.on_hover = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the mouse cursor leaves the
+    /// [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_leave(mut self, msg: Message) -> Self {
+        self.on_leave = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// pressed on the [`MouseArea`].
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_mouse_down(mut self, msg: Message) -> Self {
+        self.on_mouse_down = Some(msg);
+        self
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// released after being pressed on the [`MouseArea`], even if the cursor
+    /// is not over it anymore.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn on_release(mut self, msg: Message) -> Self {
+        self.on_release = Some(msg);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MouseArea<'a, Message, Renderer>
+where
+    Message: Copy + std::fmt::Debug,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        self.content.widget.node(renderer)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { x, y }) => {
+                let is_hovered = bounds.contains(Point::new(x, y));
+
+                let message = match (self.state.is_hovered, is_hovered) {
+                    (false, true) => self.on_hover,
+                    (true, false) => self.on_leave,
+                    _ => None,
+                };
+
+                if let Some(message) = message {
+                    messages.push(message);
+                }
+
+                self.state.is_hovered = is_hovered;
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                self.state.is_held = bounds.contains(cursor_position);
+
+                if let (true, Some(on_mouse_down)) =
+                    (self.state.is_held, self.on_mouse_down)
+                {
+                    messages.push(on_mouse_down);
+                }
+            }
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Released,
+            }) => {
+                if let (true, Some(on_release)) =
+                    (self.state.is_held, self.on_release)
+                {
+                    messages.push(on_release);
+                }
+
+                self.state.is_held = false;
+            }
+            _ => {}
+        }
+
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.content.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.content.widget.hash(state);
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.content.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.content
+            .widget
+            .accessibility_label(layout, cursor_position)
+    }
+}
+
+/// The local state of a [`MouseArea`].
+///
+/// [`MouseArea`]: struct.MouseArea.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_hovered: bool,
+    is_held: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the mouse cursor is over the associated [`MouseArea`]
+    /// or not.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+
+    /// Returns whether the left mouse button was pressed on the associated
+    /// [`MouseArea`] and has not been released yet.
+    ///
+    /// [`MouseArea`]: struct.MouseArea.html
+    pub fn is_held(&self) -> bool {
+        self.is_held
+    }
+}
+
+impl<'a, Message, Renderer> From<MouseArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static,
+    Message: 'static + Copy + std::fmt::Debug,
+{
+    fn from(
+        mouse_area: MouseArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mouse_area)
+    }
+}
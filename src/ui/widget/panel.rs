@@ -1,11 +1,21 @@
 //! Wrap your widgets in a box.
+//!
+//! A [`Panel`] can have some local [`State`] to be collapsed from its title
+//! bar and to scroll its content.
+//!
+//! [`Panel`]: struct.Panel.html
+//! [`State`]: struct.State.html
 use std::hash::Hash;
 
-use crate::graphics::{Point, Rectangle};
+use crate::graphics::{Point, Rectangle, Vector};
+use crate::input::{mouse, ButtonState};
 use crate::ui::core::{
     Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
 };
 
+const TITLE_HEIGHT: u32 = 30;
+const SCROLL_SPEED: f32 = 20.0;
+
 /// A box that can wrap a widget.
 ///
 /// It implements [`Widget`] when the [`core::Renderer`] implements the
@@ -29,7 +39,33 @@ use crate::ui::core::{
 /// )
 ///     .width(500);
 /// ```
+///
+/// # Collapsing and scrolling
+/// A [`Panel`] with a title and a [`State`] can be collapsed by clicking its
+/// title bar. If it also has a maximum height, its content scrolls with the
+/// mouse wheel when it does not fit. This is useful for debug and
+/// configuration panels holding long content:
+///
+/// ```
+/// use coffee::ui::{panel, Panel, Text};
+///
+/// pub enum Message { /* ... */ }
+///
+/// let mut state = panel::State::new();
+///
+/// Panel::<Message>::new(Text::new("Lots of settings..."))
+///     .title("Settings")
+///     .state(&mut state)
+///     .max_height(300);
+/// ```
+///
+/// [`Panel`]: struct.Panel.html
+/// [`State`]: struct.State.html
 pub struct Panel<'a, Message, Renderer> {
+    state: Option<&'a mut State>,
+    title: Option<String>,
+    max_height: Option<u32>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message>>,
     style: Style,
     content: Element<'a, Message, Renderer>,
 }
@@ -37,6 +73,9 @@ pub struct Panel<'a, Message, Renderer> {
 impl<'a, Message, Renderer> std::fmt::Debug for Panel<'a, Message, Renderer> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Panel")
+            .field("state", &self.state)
+            .field("title", &self.title)
+            .field("max_height", &self.max_height)
             .field("style", &self.style)
             .field("content", &self.content)
             .finish()
@@ -53,6 +92,10 @@ impl<'a, Message, Renderer> Panel<'a, Message, Renderer> {
         E: 'a + Into<Element<'a, Message, Renderer>>,
     {
         Panel {
+            state: None,
+            title: None,
+            max_height: None,
+            on_toggle: None,
             style: Style::default().padding(20),
             content: content.into(),
         }
@@ -73,6 +116,143 @@ impl<'a, Message, Renderer> Panel<'a, Message, Renderer> {
         self.style = self.style.max_width(max_width);
         self
     }
+
+    /// Sets the maximum height of the content of the [`Panel`] in pixels.
+    ///
+    /// Content taller than this is clipped. If the [`Panel`] has a [`State`],
+    /// it can be scrolled with the mouse wheel.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    /// [`State`]: struct.State.html
+    pub fn max_height(mut self, max_height: u32) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Shows a title bar with the given title on top of the [`Panel`].
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(String::from(title));
+        self
+    }
+
+    /// Sets the local [`State`] of the [`Panel`].
+    ///
+    /// It allows the [`Panel`] to be collapsed from its title bar and to keep
+    /// track of its scroll offset.
+    ///
+    /// [`State`]: struct.State.html
+    /// [`Panel`]: struct.Panel.html
+    pub fn state(mut self, state: &'a mut State) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets the message produced when the [`Panel`] is collapsed or expanded.
+    ///
+    /// The function receives whether the [`Panel`] is collapsed. The
+    /// [`State`] is updated automatically, so you only need it if your game
+    /// reacts to the change.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    /// [`State`]: struct.State.html
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    // Only panels with a title bar can be expanded again
+    fn is_collapsed(&self) -> bool {
+        match (&self.title, &self.state) {
+            (Some(_), Some(state)) => state.is_collapsed,
+            _ => false,
+        }
+    }
+
+    fn on_title_bar_event(
+        &mut self,
+        event: Event,
+        bounds: Rectangle<f32>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        if let (
+            Some(state),
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: button_state,
+            }),
+        ) = (&mut self.state, event)
+        {
+            let is_hovered = bounds.contains(cursor_position);
+
+            match button_state {
+                ButtonState::Pressed => {
+                    state.is_pressed = is_hovered;
+                }
+                ButtonState::Released => {
+                    if state.is_pressed && is_hovered {
+                        state.is_collapsed = !state.is_collapsed;
+
+                        if let Some(on_toggle) = &self.on_toggle {
+                            messages.push(on_toggle(state.is_collapsed));
+                        }
+                    }
+
+                    state.is_pressed = false;
+                }
+            }
+        }
+    }
+
+    // Returns the layout of the content inside the given viewport, moved by
+    // the scroll offset
+    fn content_layout<'b>(
+        &self,
+        viewport: &'b Layout<'b>,
+    ) -> Option<Layout<'b>> {
+        if self.max_height.is_none() {
+            return Some(viewport.translate(Vector::new(0.0, 0.0)));
+        }
+
+        let bounds = viewport.bounds();
+
+        viewport.children().next().map(|content| {
+            let offset = self.scroll_offset(bounds, content.bounds());
+
+            content.translate(Vector::new(0.0, -offset))
+        })
+    }
+
+    fn scroll_offset(
+        &self,
+        viewport: Rectangle<f32>,
+        content: Rectangle<f32>,
+    ) -> f32 {
+        let max_offset = (content.height - viewport.height).max(0.0);
+
+        self.state
+            .as_ref()
+            .map(|state| state.scroll.min(max_offset))
+            .unwrap_or(0.0)
+    }
+
+    // Hides the cursor from the clipped parts of the content
+    fn content_cursor(
+        &self,
+        viewport: Rectangle<f32>,
+        cursor_position: Point,
+    ) -> Point {
+        if self.max_height.is_none() || viewport.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(std::f32::NEG_INFINITY, std::f32::NEG_INFINITY)
+        }
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer>
@@ -81,10 +261,40 @@ where
     Renderer: self::Renderer,
 {
     fn node(&self, renderer: &Renderer) -> Node {
-        Node::with_children(
-            self.style,
-            vec![self.content.widget.node(renderer)],
-        )
+        let mut style = self.style;
+        let mut children = Vec::new();
+
+        if self.title.is_some() {
+            style.0.flex_direction = stretch::style::FlexDirection::Column;
+
+            children.push(Node::new(
+                Style::default().fill_width().height(TITLE_HEIGHT),
+            ));
+        }
+
+        if !self.is_collapsed() {
+            let content = self.content.widget.node(renderer);
+
+            children.push(match self.max_height {
+                Some(max_height) => {
+                    let mut viewport =
+                        Style::default().fill_width().max_height(max_height);
+                    viewport.0.flex_direction =
+                        stretch::style::FlexDirection::Column;
+
+                    // The content keeps its height and overflows the viewport
+                    let content = content.restyle(|mut style| {
+                        style.0.flex_shrink = 0.0;
+                        style
+                    });
+
+                    Node::with_children(viewport, vec![content])
+                }
+                None => content,
+            });
+        }
+
+        Node::with_children(style, children)
     }
 
     fn on_event(
@@ -94,14 +304,51 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
-        [&mut self.content]
-            .iter_mut()
-            .zip(layout.children())
-            .for_each(|(child, layout)| {
-                child
-                    .widget
-                    .on_event(event, layout, cursor_position, messages)
-            });
+        let mut children = layout.children();
+
+        if self.title.is_some() {
+            if let Some(title_bar) = children.next() {
+                self.on_title_bar_event(
+                    event,
+                    title_bar.bounds(),
+                    cursor_position,
+                    messages,
+                );
+            }
+        }
+
+        let viewport = match children.next() {
+            Some(viewport) => viewport,
+            None => return,
+        };
+
+        let bounds = viewport.bounds();
+
+        if let Event::Mouse(mouse::Event::WheelScrolled { delta_y, .. }) = event
+        {
+            if let (Some(_), Some(state), Some(content)) =
+                (self.max_height, &mut self.state, viewport.children().next())
+            {
+                if bounds.contains(cursor_position) {
+                    let max_offset =
+                        (content.bounds().height - bounds.height).max(0.0);
+                    let offset = state.scroll - delta_y * SCROLL_SPEED;
+
+                    state.scroll = offset.max(0.0).min(max_offset);
+                }
+            }
+        }
+
+        let cursor_position = self.content_cursor(bounds, cursor_position);
+
+        if let Some(content) = self.content_layout(&viewport) {
+            self.content.widget.on_event(
+                event,
+                content,
+                cursor_position,
+                messages,
+            );
+        }
     }
 
     fn draw(
@@ -112,18 +359,55 @@ where
     ) -> MouseCursor {
         let bounds = layout.bounds();
         let mut cursor = MouseCursor::OutOfBounds;
+        let mut children = layout.children();
         renderer.draw(bounds);
 
-        [&self.content].iter().zip(layout.children()).for_each(
-            |(child, layout)| {
-                let new_cursor =
-                    child.widget.draw(renderer, layout, cursor_position);
+        if let Some(title) = &self.title {
+            if let Some(title_bar) = children.next() {
+                cursor = renderer.draw_title_bar(
+                    cursor_position,
+                    title_bar.bounds(),
+                    title,
+                    self.is_collapsed(),
+                    self.state.is_some(),
+                );
+            }
+        }
+
+        if let Some(viewport) = children.next() {
+            let viewport_bounds = viewport.bounds();
+            let is_clipped = self.max_height.is_some();
+
+            if let Some(content) = self.content_layout(&viewport) {
+                let content_bounds = content.bounds();
+
+                if is_clipped {
+                    renderer.clip(Some(viewport_bounds));
+                }
+
+                let new_cursor = self.content.widget.draw(
+                    renderer,
+                    content,
+                    self.content_cursor(viewport_bounds, cursor_position),
+                );
+
+                if is_clipped {
+                    renderer.clip(None);
+
+                    if content_bounds.height > viewport_bounds.height {
+                        renderer.draw_scrollbar(
+                            viewport_bounds,
+                            content_bounds.height,
+                            self.scroll_offset(viewport_bounds, content_bounds),
+                        );
+                    }
+                }
 
                 if new_cursor != MouseCursor::OutOfBounds {
                     cursor = new_cursor;
                 }
-            },
-        );
+            }
+        }
 
         if cursor == MouseCursor::OutOfBounds {
             if bounds.contains(cursor_position) {
@@ -136,8 +420,95 @@ where
         }
     }
 
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        let skip = if self.title.is_some() { 1 } else { 0 };
+
+        layout.children().nth(skip).and_then(|viewport| {
+            let cursor_position =
+                self.content_cursor(viewport.bounds(), cursor_position);
+
+            self.content_layout(&viewport).and_then(|content| {
+                self.content.widget.cursor(content, cursor_position)
+            })
+        })
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        let skip = if self.title.is_some() { 1 } else { 0 };
+
+        layout.children().nth(skip).and_then(|viewport| {
+            let cursor_position =
+                self.content_cursor(viewport.bounds(), cursor_position);
+
+            self.content_layout(&viewport).and_then(|content| {
+                self.content
+                    .widget
+                    .accessibility_label(content, cursor_position)
+            })
+        })
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
+        self.title.hash(state);
+        self.max_height.hash(state);
+        self.is_collapsed().hash(state);
+    }
+}
+
+/// The local state of a [`Panel`].
+///
+/// [`Panel`]: struct.Panel.html
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct State {
+    is_collapsed: bool,
+    is_pressed: bool,
+    scroll: f32,
+}
+
+impl State {
+    /// Creates a new, expanded [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the associated [`Panel`] is collapsed.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub fn is_collapsed(&self) -> bool {
+        self.is_collapsed
+    }
+
+    /// Collapses or expands the associated [`Panel`].
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub fn set_collapsed(&mut self, is_collapsed: bool) {
+        self.is_collapsed = is_collapsed;
+    }
+
+    /// Returns the scroll offset of the content of the associated [`Panel`],
+    /// in pixels.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub fn scroll(&self) -> f32 {
+        self.scroll
+    }
+
+    /// Scrolls the content of the associated [`Panel`] back to the top.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    pub fn scroll_to_top(&mut self) {
+        self.scroll = 0.0;
     }
 }
 
@@ -155,6 +526,49 @@ pub trait Renderer {
     ///
     /// [`Panel`]: struct.Panel.html
     fn draw(&mut self, bounds: Rectangle<f32>);
+
+    /// Draws the title bar of a [`Panel`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the title bar
+    ///   * the title of the [`Panel`]
+    ///   * whether the [`Panel`] is collapsed
+    ///   * whether the [`Panel`] can be collapsed
+    ///
+    /// It returns the current [`MouseCursor`].
+    ///
+    /// [`Panel`]: struct.Panel.html
+    /// [`MouseCursor`]: ../../core/enum.MouseCursor.html
+    fn draw_title_bar(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        title: &str,
+        is_collapsed: bool,
+        is_collapsible: bool,
+    ) -> MouseCursor;
+
+    /// Draws the scrollbar of a [`Panel`] with overflowing content.
+    ///
+    /// It receives the bounds of the visible content, the total height of
+    /// the content and its current scroll offset.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    fn draw_scrollbar(
+        &mut self,
+        bounds: Rectangle<f32>,
+        content_height: f32,
+        offset: f32,
+    );
+
+    /// Restricts anything drawn afterwards to the given bounds.
+    ///
+    /// `None` removes the restriction. Clipping does not nest: a scrolling
+    /// [`Panel`] inside another one removes the clipping of its parent.
+    ///
+    /// [`Panel`]: struct.Panel.html
+    fn clip(&mut self, bounds: Option<Rectangle<f32>>);
 }
 
 impl<'a, Message, Renderer> From<Panel<'a, Message, Renderer>>
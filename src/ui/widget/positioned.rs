@@ -0,0 +1,141 @@
+use crate::graphics::Point;
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Widget,
+};
+
+/// A wrapper that places its content at an absolute position.
+///
+/// The position is relative to the container of the [`Positioned`] widget.
+/// The content is taken out of the layout of the container, so it neither
+/// takes space nor moves its siblings. This is useful to place HUD elements
+/// in the corners of the screen.
+///
+/// It does not draw anything by itself, so it implements [`Widget`] for any
+/// [`core::Renderer`].
+///
+/// [`Positioned`]: struct.Positioned.html
+/// [`Widget`]: ../core/trait.Widget.html
+/// [`core::Renderer`]: ../core/trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::graphics::Point;
+/// use coffee::ui::{Column, Positioned, Text};
+///
+/// let (width, height) = (1280, 1024);
+///
+/// let hud = Column::<()>::new()
+///     .width(width)
+///     .height(height)
+///     .push(Positioned::at(Point::new(20.0, 20.0), Text::new("HP: 100")))
+///     .push(Positioned::at(
+///         Point::new(width as f32 - 200.0, 20.0),
+///         Text::new("Score: 9001"),
+///     ));
+/// ```
+pub struct Positioned<'a, Message, Renderer> {
+    position: Point,
+    content: Element<'a, Message, Renderer>,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug
+    for Positioned<'a, Message, Renderer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Positioned")
+            .field("position", &self.position)
+            .field("content", &self.content)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Positioned<'a, Message, Renderer> {
+    /// Creates a new [`Positioned`] widget placing the given content at the
+    /// given position, in pixels.
+    ///
+    /// [`Positioned`]: struct.Positioned.html
+    pub fn at<E>(position: Point, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Positioned {
+            position,
+            content: content.into(),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Positioned<'a, Message, Renderer>
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let position = self.position;
+
+        self.content
+            .widget
+            .node(renderer)
+            .restyle(|style| style.position(position))
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        self.content
+            .widget
+            .on_event(event, layout, cursor_position, messages)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        self.content.widget.draw(renderer, layout, cursor_position)
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        (self.position.x as u32).hash(state);
+        (self.position.y as u32).hash(state);
+
+        self.content.widget.hash(state);
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.content.widget.cursor(layout, cursor_position)
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.content
+            .widget
+            .accessibility_label(layout, cursor_position)
+    }
+}
+
+impl<'a, Message, Renderer> From<Positioned<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'static,
+    Renderer: 'a,
+{
+    fn from(
+        positioned: Positioned<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(positioned)
+    }
+}
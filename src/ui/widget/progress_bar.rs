@@ -10,13 +10,21 @@ use crate::ui::core::{
 use std::hash::Hash;
 
 /// A widget that displays a progress of an action.
-/// 
+///
+/// A [`ProgressBar`] can be determinate, showing a specific amount of
+/// progress, or [indeterminate], showing activity when the amount of work
+/// is unknown.
+///
 /// It implements [`Widget`] when the associated [`core::Renderer`] implements
-/// the [`button::Renderer`] trait.
+/// the [`progress_bar::Renderer`] trait.
+///
+/// [`ProgressBar`]: struct.ProgressBar.html
+/// [indeterminate]: #method.indeterminate
 ///
 /// [`Widget`]: ../../core/trait.Widget.html
 /// [`core::Renderer`]: ../../core/trait.Renderer.html
 /// [`progress_bar::Renderer`]: trait.Renderer.html
+///
 /// # Example
 ///
 /// ```
@@ -28,17 +36,32 @@ use std::hash::Hash;
 /// ```
 #[derive(Debug)]
 pub struct ProgressBar {
-    progress: f32,
+    progress: Option<f32>,
     style: Style,
 }
 
 impl ProgressBar {
-    /// Creates a new [`ProgressBar`] with given progress.
+    /// Creates a new determinate [`ProgressBar`] with given progress.
+    ///
+    /// The progress should be a value between `0.0` and `1.0`.
     ///
     /// [`ProgressBar`]: struct.ProgressBar.html
     pub fn new(progress: f32) -> Self {
         ProgressBar {
-            progress,
+            progress: Some(progress),
+            style: Style::default().fill_width(),
+        }
+    }
+
+    /// Creates a new indeterminate [`ProgressBar`].
+    ///
+    /// An indeterminate [`ProgressBar`] shows that some work is being done
+    /// without telling how much is left.
+    ///
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    pub fn indeterminate() -> Self {
+        ProgressBar {
+            progress: None,
             style: Style::default().fill_width(),
         }
     }
@@ -74,10 +97,10 @@ where
         layout: Layout<'_>,
         _cursor_position: Point,
     ) -> MouseCursor {
-        renderer.draw(
-            layout.bounds(),
-            self.progress,
-        );
+        match self.progress {
+            Some(progress) => renderer.draw(layout.bounds(), progress),
+            None => renderer.draw_indeterminate(layout.bounds()),
+        }
 
         MouseCursor::OutOfBounds
     }
@@ -100,13 +123,20 @@ pub trait Renderer {
     /// It receives:
     ///   * the bounds of the [`ProgressBar`]
     ///   * the progress of the [`ProgressBar`]
-    ///   
+    ///
     /// [`ProgressBar`]: struct.ProgressBar.html
-    fn draw(
-        &mut self,
-        bounds: Rectangle<f32>,
-        progress: f32,
-    );
+    fn draw(&mut self, bounds: Rectangle<f32>, progress: f32);
+
+    /// Draws an [indeterminate] [`ProgressBar`].
+    ///
+    /// It receives the bounds of the [`ProgressBar`]. By default, it draws an
+    /// empty [`ProgressBar`].
+    ///
+    /// [indeterminate]: struct.ProgressBar.html#method.indeterminate
+    /// [`ProgressBar`]: struct.ProgressBar.html
+    fn draw_indeterminate(&mut self, bounds: Rectangle<f32>) {
+        self.draw(bounds, 0.0);
+    }
 }
 
 impl<'a, Message, Renderer> From<ProgressBar> for Element<'a, Message, Renderer>
@@ -210,3 +210,153 @@ where
         Element::new(checkbox)
     }
 }
+
+/// A group of mutually exclusive [`Radio`] buttons.
+///
+/// It lays out a [`Radio`] button for every option in a [`Column`] or a
+/// [`Row`]. Only the option equal to the current selected value is selected.
+///
+/// [`Radio`]: struct.Radio.html
+/// [`Column`]: ../struct.Column.html
+/// [`Row`]: ../struct.Row.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{Element, RadioGroup};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Difficulty {
+///     Easy,
+///     Normal,
+///     Hard,
+/// }
+///
+/// #[derive(Debug, Clone, Copy)]
+/// pub enum Message {
+///     DifficultySelected(Difficulty),
+/// }
+///
+/// let difficulty = Difficulty::Normal;
+///
+/// let group: Element<Message> = RadioGroup::new(
+///     &[
+///         ("Easy", Difficulty::Easy),
+///         ("Normal", Difficulty::Normal),
+///         ("Hard", Difficulty::Hard),
+///     ],
+///     Some(difficulty),
+///     Message::DifficultySelected,
+/// )
+/// .spacing(10)
+/// .into();
+/// ```
+pub struct RadioGroup<Message> {
+    radios: Vec<Radio<Message>>,
+    spacing: u16,
+    is_horizontal: bool,
+}
+
+impl<Message> std::fmt::Debug for RadioGroup<Message>
+where
+    Message: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RadioGroup")
+            .field("radios", &self.radios)
+            .field("spacing", &self.spacing)
+            .field("is_horizontal", &self.is_horizontal)
+            .finish()
+    }
+}
+
+impl<Message> RadioGroup<Message> {
+    /// Creates a new [`RadioGroup`].
+    ///
+    /// It expects:
+    ///   * the label and the value of every option, in order
+    ///   * the current selected value
+    ///   * a function that will be called when an option is selected. It
+    ///   receives the value of the option and must produce a `Message`.
+    ///
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn new<F, V>(options: &[(&str, V)], selected: Option<V>, f: F) -> Self
+    where
+        V: Eq + Copy,
+        F: 'static + Fn(V) -> Message,
+    {
+        RadioGroup {
+            radios: options
+                .iter()
+                .map(|(label, value)| Radio {
+                    is_selected: Some(*value) == selected,
+                    on_click: f(*value),
+                    label: String::from(*label),
+                    label_color: Color::WHITE,
+                })
+                .collect(),
+            spacing: 20,
+            is_horizontal: false,
+        }
+    }
+
+    /// Sets the spacing between the [`Radio`] buttons of the [`RadioGroup`],
+    /// in pixels.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn spacing(mut self, px: u16) -> Self {
+        self.spacing = px;
+        self
+    }
+
+    /// Lays out the [`Radio`] buttons of the [`RadioGroup`] in a row,
+    /// instead of a column.
+    ///
+    /// [`Radio`]: struct.Radio.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn horizontal(mut self) -> Self {
+        self.is_horizontal = true;
+        self
+    }
+
+    /// Sets the [`Color`] of the labels of the [`RadioGroup`].
+    ///
+    /// [`Color`]: ../../../../graphics/struct.Color.html
+    /// [`RadioGroup`]: struct.RadioGroup.html
+    pub fn label_color(mut self, color: Color) -> Self {
+        for radio in &mut self.radios {
+            radio.label_color = color;
+        }
+
+        self
+    }
+}
+
+impl<'a, Message, Renderer> From<RadioGroup<Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + self::Renderer + text::Renderer,
+    Message: 'static + Copy + std::fmt::Debug,
+{
+    fn from(group: RadioGroup<Message>) -> Element<'a, Message, Renderer> {
+        let spacing = group.spacing;
+
+        if group.is_horizontal {
+            let row = Row::<Message, Renderer>::new().spacing(spacing);
+
+            group
+                .radios
+                .into_iter()
+                .fold(row, |row, radio| row.push(radio))
+                .into()
+        } else {
+            let column = Column::<Message, Renderer>::new().spacing(spacing);
+
+            group
+                .radios
+                .into_iter()
+                .fold(column, |column, radio| column.push(radio))
+                .into()
+        }
+    }
+}
@@ -100,7 +100,7 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self
     }
 
-    /// Sets the vertical alignment of the contents of the [`Row`] .
+    /// Sets the vertical alignment of the contents of the [`Row`].
     ///
     /// [`Row`]: struct.Row.html
     pub fn align_items(mut self, align: Align) -> Self {
@@ -109,7 +109,7 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
     }
 
     /// Sets the horizontal distribution strategy for the contents of the
-    /// [`Row`] .
+    /// [`Row`].
     ///
     /// [`Row`]: struct.Row.html
     pub fn justify_content(mut self, justify: Justify) -> Self {
@@ -117,6 +117,17 @@ impl<'a, Message, Renderer> Row<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the horizontal distribution strategy for the contents of the
+    /// [`Row`].
+    ///
+    /// It is a shorter alias of [`justify_content`].
+    ///
+    /// [`Row`]: struct.Row.html
+    /// [`justify_content`]: #method.justify_content
+    pub fn justify(self, justify: Justify) -> Self {
+        self.justify_content(justify)
+    }
+
     /// Adds an [`Element`] to the [`Row`].
     ///
     /// [`Element`]: ../core/struct.Element.html
@@ -197,6 +208,34 @@ impl<'a, Message, Renderer> Widget<Message, Renderer>
         cursor
     }
 
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| {
+                child.widget.cursor(layout, cursor_position)
+            })
+            .last()
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        self.children
+            .iter()
+            .zip(layout.children())
+            .filter_map(|(child, layout)| {
+                child.widget.accessibility_label(layout, cursor_position)
+            })
+            .last()
+    }
+
     fn hash(&self, state: &mut Hasher) {
         self.style.hash(state);
         self.spacing.hash(state);
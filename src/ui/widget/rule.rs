@@ -0,0 +1,138 @@
+//! Separate content with a line.
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::ui::core::{
+    Align, Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// A line that separates the children of a [`Row`] or a [`Column`].
+///
+/// A horizontal [`Rule`] stretches over the width of its [`Column`], while a
+/// vertical one stretches over the height of its [`Row`].
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`rule::Renderer`] trait.
+///
+/// [`Row`]: ../struct.Row.html
+/// [`Column`]: ../struct.Column.html
+/// [`Rule`]: struct.Rule.html
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`rule::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{Column, Rule, Text};
+///
+/// Column::<()>::new()
+///     .spacing(10)
+///     .push(Text::new("Options"))
+///     .push(Rule::horizontal())
+///     .push(Text::new("Volume"));
+/// ```
+#[derive(Debug)]
+pub struct Rule {
+    is_horizontal: bool,
+    style: Style,
+}
+
+impl Rule {
+    /// The default thickness of a [`Rule`], in pixels.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub const THICKNESS: u32 = 2;
+
+    /// Creates a horizontal [`Rule`], to separate the children of a
+    /// [`Column`].
+    ///
+    /// [`Rule`]: struct.Rule.html
+    /// [`Column`]: ../struct.Column.html
+    pub fn horizontal() -> Self {
+        Rule {
+            is_horizontal: true,
+            style: Style::default()
+                .height(Self::THICKNESS)
+                .align_self(Align::Stretch),
+        }
+    }
+
+    /// Creates a vertical [`Rule`], to separate the children of a [`Row`].
+    ///
+    /// [`Rule`]: struct.Rule.html
+    /// [`Row`]: ../struct.Row.html
+    pub fn vertical() -> Self {
+        Rule {
+            is_horizontal: false,
+            style: Style::default()
+                .width(Self::THICKNESS)
+                .align_self(Align::Stretch),
+        }
+    }
+
+    /// Sets the thickness of the [`Rule`], in pixels.
+    ///
+    /// [`Rule`]: struct.Rule.html
+    pub fn thickness(mut self, thickness: u32) -> Self {
+        self.style = if self.is_horizontal {
+            self.style.height(thickness)
+        } else {
+            self.style.width(thickness)
+        };
+
+        self
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Rule
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> MouseCursor {
+        renderer.draw(layout.bounds(), self.is_horizontal);
+
+        MouseCursor::OutOfBounds
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.is_horizontal.hash(state);
+    }
+}
+
+/// The renderer of a [`Rule`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`Rule`] in your user interface.
+///
+/// [`Rule`]: struct.Rule.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`Rule`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`Rule`]
+    ///   * whether the [`Rule`] is horizontal or vertical
+    ///
+    /// [`Rule`]: struct.Rule.html
+    fn draw(&mut self, bounds: Rectangle<f32>, is_horizontal: bool);
+}
+
+impl<'a, Message, Renderer> From<Rule> for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(rule: Rule) -> Element<'a, Message, Renderer> {
+        Element::new(rule)
+    }
+}
@@ -8,7 +8,7 @@ use std::hash::Hash;
 use std::ops::RangeInclusive;
 
 use crate::graphics::{Point, Rectangle};
-use crate::input::{mouse, ButtonState};
+use crate::input::{keyboard, mouse, ButtonState};
 use crate::ui::core::{
     Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
 };
@@ -46,6 +46,8 @@ pub struct Slider<'a, Message> {
     range: RangeInclusive<f32>,
     value: f32,
     on_change: Box<dyn Fn(f32) -> Message>,
+    step: f32,
+    is_disabled: bool,
     style: Style,
 }
 
@@ -55,6 +57,8 @@ impl<'a, Message> std::fmt::Debug for Slider<'a, Message> {
             .field("state", &self.state)
             .field("range", &self.range)
             .field("value", &self.value)
+            .field("step", &self.step)
+            .field("is_disabled", &self.is_disabled)
             .field("style", &self.style)
             .finish()
     }
@@ -85,8 +89,10 @@ impl<'a, Message> Slider<'a, Message> {
         Slider {
             state,
             value: value.max(*range.start()).min(*range.end()),
+            step: (range.end() - range.start()) / 20.0,
             range,
             on_change: Box::new(on_change),
+            is_disabled: false,
             style: Style::default().min_width(100).fill_width(),
         }
     }
@@ -98,6 +104,27 @@ impl<'a, Message> Slider<'a, Message> {
         self.style = self.style.width(width);
         self
     }
+
+    /// Sets the amount the value of the [`Slider`] changes when adjusted
+    /// with the arrow keys.
+    ///
+    /// By default, it is a twentieth of the range of the [`Slider`].
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets whether the [`Slider`] is disabled or not.
+    ///
+    /// A disabled [`Slider`] is greyed out and it ignores any interaction.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Slider<'a, Message>
@@ -115,6 +142,12 @@ where
         cursor_position: Point,
         messages: &mut Vec<Message>,
     ) {
+        if self.is_disabled {
+            self.state.is_dragging = false;
+            self.state.is_focused = false;
+            return;
+        }
+
         let mut change = || {
             let bounds = layout.bounds();
 
@@ -137,10 +170,14 @@ where
                 state,
             }) => match state {
                 ButtonState::Pressed => {
-                    if layout.bounds().contains(cursor_position) {
+                    let is_clicked = layout.bounds().contains(cursor_position);
+
+                    if is_clicked {
                         change();
                         self.state.is_dragging = true;
                     }
+
+                    self.state.is_focused = is_clicked;
                 }
                 ButtonState::Released => {
                     self.state.is_dragging = false;
@@ -151,6 +188,23 @@ where
                     change();
                 }
             }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) if self.state.is_focused => {
+                let direction = match key_code {
+                    keyboard::KeyCode::Left | keyboard::KeyCode::Down => -1.0,
+                    keyboard::KeyCode::Right | keyboard::KeyCode::Up => 1.0,
+                    _ => return,
+                };
+
+                let value = (self.value + self.step * direction)
+                    .max(*self.range.start())
+                    .min(*self.range.end());
+
+                messages.push((self.on_change)(value));
+            }
             _ => {}
         }
     }
@@ -167,6 +221,7 @@ where
             self.state,
             self.range.clone(),
             self.value,
+            self.is_disabled,
         )
     }
 
@@ -181,6 +236,7 @@ where
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct State {
     is_dragging: bool,
+    is_focused: bool,
 }
 
 impl State {
@@ -198,6 +254,16 @@ impl State {
     pub fn is_dragging(&self) -> bool {
         self.is_dragging
     }
+
+    /// Returns whether the associated [`Slider`] is focused or not.
+    ///
+    /// A [`Slider`] gets focused when clicked, and it can then be adjusted
+    /// with the arrow keys.
+    ///
+    /// [`Slider`]: struct.Slider.html
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
 }
 
 /// The renderer of a [`Slider`].
@@ -216,6 +282,7 @@ pub trait Renderer {
     ///   * the local state of the [`Slider`]
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * whether the [`Slider`] is disabled or not
     ///
     /// [`Slider`]: struct.Slider.html
     /// [`State`]: struct.State.html
@@ -227,6 +294,7 @@ pub trait Renderer {
         state: &State,
         range: RangeInclusive<f32>,
         value: f32,
+        is_disabled: bool,
     ) -> MouseCursor;
 }
 
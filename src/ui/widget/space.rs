@@ -0,0 +1,79 @@
+use std::hash::Hash;
+
+use crate::graphics::Point;
+use crate::ui::core::{
+    Element, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+/// An empty widget that takes a fixed amount of space.
+///
+/// Use it to add explicit gaps between the children of a [`Row`] or a
+/// [`Column`].
+///
+/// It does not draw anything, so it implements [`Widget`] for any
+/// [`core::Renderer`].
+///
+/// [`Row`]: struct.Row.html
+/// [`Column`]: struct.Column.html
+/// [`Widget`]: ../core/trait.Widget.html
+/// [`core::Renderer`]: ../core/trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{Column, Space, Text};
+///
+/// Column::<()>::new()
+///     .push(Text::new("New game"))
+///     .push(Space::with_height(40))
+///     .push(Text::new("Quit"));
+/// ```
+#[derive(Debug)]
+pub struct Space {
+    style: Style,
+}
+
+impl Space {
+    /// Creates a [`Space`] with the given width, in pixels.
+    ///
+    /// [`Space`]: struct.Space.html
+    pub fn with_width(width: u32) -> Self {
+        Space {
+            style: Style::default().width(width).height(0),
+        }
+    }
+
+    /// Creates a [`Space`] with the given height, in pixels.
+    ///
+    /// [`Space`]: struct.Space.html
+    pub fn with_height(height: u32) -> Self {
+        Space {
+            style: Style::default().width(0).height(height),
+        }
+    }
+}
+
+impl<Message, Renderer> Widget<Message, Renderer> for Space {
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut Renderer,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+    ) -> MouseCursor {
+        MouseCursor::OutOfBounds
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<Space> for Element<'a, Message, Renderer> {
+    fn from(space: Space) -> Element<'a, Message, Renderer> {
+        Element::new(space)
+    }
+}
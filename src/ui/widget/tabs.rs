@@ -0,0 +1,373 @@
+//! Switch between pages of content using a row of tab headers.
+//!
+//! A [`Tabs`] has some local [`State`].
+//!
+//! [`Tabs`]: struct.Tabs.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::{mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+const HEADER_HEIGHT: u32 = 40;
+const HEADER_MIN_WIDTH: u32 = 120;
+
+/// A container that shows a row of tab headers and the content of the
+/// selected tab.
+///
+/// Only the content of the selected tab is kept, so you can build every tab
+/// in your `layout` method without worrying about the cost of the hidden
+/// ones.
+///
+/// It implements [`Widget`] when the [`core::Renderer`] implements the
+/// [`tabs::Renderer`] trait.
+///
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`tabs::Renderer`]: trait.Renderer.html
+///
+/// # Example
+///
+/// ```
+/// use coffee::ui::{tabs, Tabs, Text};
+///
+/// pub enum Message { /* ... */ }
+///
+/// let mut state = tabs::State::new();
+///
+/// let options = Tabs::<Message>::new(&mut state)
+///     .push("Video", Text::new("Resolution, fullscreen..."))
+///     .push("Audio", Text::new("Volume, output device..."))
+///     .push("Controls", Text::new("Key bindings..."));
+/// ```
+pub struct Tabs<'a, Message, Renderer> {
+    state: &'a mut State,
+    labels: Vec<String>,
+    content: Option<Element<'a, Message, Renderer>>,
+    on_change: Option<Box<dyn Fn(usize) -> Message>>,
+    style: Style,
+}
+
+impl<'a, Message, Renderer> std::fmt::Debug for Tabs<'a, Message, Renderer> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tabs")
+            .field("state", &self.state)
+            .field("labels", &self.labels)
+            .field("content", &self.content)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'a, Message, Renderer> Tabs<'a, Message, Renderer> {
+    /// Creates new [`Tabs`] without any tab.
+    ///
+    /// It expects the local [`State`] of the [`Tabs`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`State`]: struct.State.html
+    pub fn new(state: &'a mut State) -> Self {
+        let mut style = Style::default().fill_width();
+        style.0.flex_direction = stretch::style::FlexDirection::Column;
+
+        Tabs {
+            state,
+            labels: Vec::new(),
+            content: None,
+            on_change: None,
+            style,
+        }
+    }
+
+    /// Adds a tab with the given label and content to the [`Tabs`].
+    ///
+    /// The content is only kept if the tab is selected.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn push<E>(mut self, label: &str, content: E) -> Self
+    where
+        E: 'a + Into<Element<'a, Message, Renderer>>,
+    {
+        if self.labels.len() == self.state.selected {
+            self.content = Some(content.into());
+        }
+
+        self.labels.push(String::from(label));
+        self
+    }
+
+    /// Sets the message produced when a different tab is selected.
+    ///
+    /// The function receives the index of the selected tab. The [`State`]
+    /// is updated automatically, so you only need it if your game reacts to
+    /// the change.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn on_change<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(usize) -> Message,
+    {
+        self.on_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`] in pixels.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn width(mut self, width: u32) -> Self {
+        self.style = self.style.width(width);
+        self
+    }
+
+    /// Sets the maximum width of the [`Tabs`] in pixels.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn max_width(mut self, max_width: u32) -> Self {
+        self.style = self.style.max_width(max_width);
+        self
+    }
+
+    /// Sets the height of the [`Tabs`] in pixels.
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    pub fn height(mut self, height: u32) -> Self {
+        self.style = self.style.height(height);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Tabs<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, renderer: &Renderer) -> Node {
+        let headers = Node::with_children(
+            Style::default(),
+            self.labels
+                .iter()
+                .map(|_| {
+                    Node::new(
+                        Style::default()
+                            .min_width(HEADER_MIN_WIDTH)
+                            .height(HEADER_HEIGHT),
+                    )
+                })
+                .collect(),
+        );
+
+        let mut children = vec![headers];
+
+        if let Some(content) = &self.content {
+            children.push(content.widget.node(renderer));
+        }
+
+        Node::with_children(self.style, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let mut children = layout.children();
+
+        if let Some(headers) = children.next() {
+            if let Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state,
+            }) = event
+            {
+                let hovered = headers.children().position(|header| {
+                    header.bounds().contains(cursor_position)
+                });
+
+                match state {
+                    ButtonState::Pressed => {
+                        self.state.pressed = hovered;
+                    }
+                    ButtonState::Released => {
+                        let pressed = self.state.pressed.take();
+
+                        if let (Some(index), true) =
+                            (hovered, hovered == pressed)
+                        {
+                            if index != self.state.selected {
+                                self.state.selected = index;
+
+                                if let Some(on_change) = &self.on_change {
+                                    messages.push(on_change(index));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let (Some(content), Some(layout)) =
+            (&mut self.content, children.next())
+        {
+            content
+                .widget
+                .on_event(event, layout, cursor_position, messages);
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+
+        let headers: Vec<_> = children
+            .next()
+            .map(|headers| {
+                self.labels
+                    .iter()
+                    .map(String::as_str)
+                    .zip(headers.children().map(|header| header.bounds()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut cursor = renderer.draw(
+            cursor_position,
+            bounds,
+            &headers,
+            self.state.selected,
+        );
+
+        if let (Some(content), Some(layout)) = (&self.content, children.next())
+        {
+            let new_cursor =
+                content.widget.draw(renderer, layout, cursor_position);
+
+            if new_cursor != MouseCursor::OutOfBounds {
+                cursor = new_cursor;
+            }
+        }
+
+        if cursor == MouseCursor::OutOfBounds
+            && bounds.contains(cursor_position)
+        {
+            MouseCursor::Idle
+        } else {
+            cursor
+        }
+    }
+
+    fn cursor(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<MouseCursor> {
+        match (&self.content, layout.children().nth(1)) {
+            (Some(content), Some(layout)) => {
+                content.widget.cursor(layout, cursor_position)
+            }
+            _ => None,
+        }
+    }
+
+    fn accessibility_label(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<&str> {
+        match (&self.content, layout.children().nth(1)) {
+            (Some(content), Some(layout)) => {
+                content.widget.accessibility_label(layout, cursor_position)
+            }
+            _ => None,
+        }
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+        self.labels.len().hash(state);
+        self.state.selected.hash(state);
+
+        if let Some(content) = &self.content {
+            content.widget.hash(state);
+        }
+    }
+}
+
+/// The local state of [`Tabs`].
+///
+/// [`Tabs`]: struct.Tabs.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    selected: usize,
+    pressed: Option<usize>,
+}
+
+impl State {
+    /// Creates a new [`State`] with the first tab selected.
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns the index of the selected tab.
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the tab with the given index.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+}
+
+/// The renderer of [`Tabs`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use [`Tabs`] in your user interface.
+///
+/// [`Tabs`]: struct.Tabs.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws the headers of [`Tabs`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`Tabs`]
+    ///   * the label and bounds of every tab header
+    ///   * the index of the selected tab
+    ///
+    /// It returns the current [`MouseCursor`].
+    ///
+    /// [`Tabs`]: struct.Tabs.html
+    /// [`MouseCursor`]: ../../core/enum.MouseCursor.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        headers: &[(&str, Rectangle<f32>)],
+        selected: usize,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<Tabs<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'static + self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        tabs: Tabs<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(tabs)
+    }
+}
@@ -0,0 +1,457 @@
+//! Enter text without a physical keyboard.
+//!
+//! A [`VirtualKeyboard`] has some local [`State`].
+//!
+//! [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+//! [`State`]: struct.State.html
+use std::hash::Hash;
+
+use crate::graphics::{Point, Rectangle};
+use crate::input::{gamepad, keyboard, mouse, ButtonState};
+use crate::ui::core::{
+    Element, Event, Hasher, Layout, MouseCursor, Node, Style, Widget,
+};
+
+const KEY_SIZE: f32 = 40.0;
+const SPACING: f32 = 4.0;
+const COLUMNS: usize = 10;
+
+const ROWS: [&str; 4] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+// The last row contains the special keys and their width, in keys
+const SPECIAL_ROW: [(Key, usize); 4] = [
+    (Key::Shift, 2),
+    (Key::Space, 4),
+    (Key::Backspace, 2),
+    (Key::Enter, 2),
+];
+
+/// The character produced by the backspace key of a [`VirtualKeyboard`].
+///
+/// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+pub const BACKSPACE: char = '\u{8}';
+
+/// The character produced by the enter key of a [`VirtualKeyboard`].
+///
+/// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+pub const ENTER: char = '\r';
+
+/// An on-screen QWERTY keyboard that produces a message for every character
+/// entered.
+///
+/// It is useful for text entry, like the name of a player, when a physical
+/// keyboard is not available.
+///
+/// A key can be selected using the arrow keys or the directional pad of any
+/// gamepad, and pressed using the return key or the south button of the
+/// gamepad. Keys can also be clicked with the mouse. The west button of the
+/// gamepad presses the backspace key directly.
+///
+/// The backspace and enter keys produce the [`BACKSPACE`] and [`ENTER`]
+/// characters, respectively, matching the characters received in a
+/// [`keyboard::Event::TextEntered`].
+///
+/// It implements [`Widget`] when the associated [`core::Renderer`] implements
+/// the [`virtual_keyboard::Renderer`] trait.
+///
+/// [`BACKSPACE`]: constant.BACKSPACE.html
+/// [`ENTER`]: constant.ENTER.html
+/// [`keyboard::Event::TextEntered`]: ../../../input/keyboard/enum.Event.html#variant.TextEntered
+/// [`Widget`]: ../../core/trait.Widget.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+/// [`virtual_keyboard::Renderer`]: trait.Renderer.html
+///
+/// # Example
+/// ```
+/// use coffee::ui::{virtual_keyboard, VirtualKeyboard};
+///
+/// pub enum Message {
+///     CharacterEntered(char),
+/// }
+///
+/// let state = &mut virtual_keyboard::State::new();
+///
+/// VirtualKeyboard::new(state, Message::CharacterEntered);
+/// ```
+pub struct VirtualKeyboard<'a, Message> {
+    state: &'a mut State,
+    on_input: Box<dyn Fn(char) -> Message>,
+    style: Style,
+}
+
+impl<'a, Message> std::fmt::Debug for VirtualKeyboard<'a, Message> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VirtualKeyboard")
+            .field("state", &self.state)
+            .field("style", &self.style)
+            .finish()
+    }
+}
+
+impl<'a, Message> VirtualKeyboard<'a, Message> {
+    /// Creates a new [`VirtualKeyboard`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`VirtualKeyboard`]
+    ///   * a function that will be called when a character is entered. It
+    ///   receives the character and must produce a `Message`.
+    ///
+    /// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+    /// [`State`]: struct.State.html
+    pub fn new<F>(state: &'a mut State, on_input: F) -> Self
+    where
+        F: 'static + Fn(char) -> Message,
+    {
+        let rows = ROWS.len() + 1;
+
+        VirtualKeyboard {
+            state,
+            on_input: Box::new(on_input),
+            style: Style::default()
+                .width(extent(COLUMNS) as u32)
+                .height(extent(rows) as u32),
+        }
+    }
+
+    fn press(&mut self, key: Key, messages: &mut Vec<Message>) {
+        match key {
+            Key::Character(character) => {
+                let character = if self.state.is_shifted {
+                    character.to_ascii_uppercase()
+                } else {
+                    character
+                };
+
+                messages.push((self.on_input)(character));
+            }
+            Key::Shift => {
+                self.state.is_shifted = !self.state.is_shifted;
+            }
+            Key::Space => messages.push((self.on_input)(' ')),
+            Key::Backspace => messages.push((self.on_input)(BACKSPACE)),
+            Key::Enter => messages.push((self.on_input)(ENTER)),
+        }
+    }
+
+    fn selected(&self, bounds: Rectangle<f32>) -> Option<Key> {
+        let (row, column) = self.state.selected;
+
+        keys(bounds)
+            .into_iter()
+            .find(|key| key.row == row && key.column == column)
+            .map(|key| key.key)
+    }
+
+    fn select(&mut self, direction: Direction, bounds: Rectangle<f32>) {
+        let keys = keys(bounds);
+        let (row, column) = self.state.selected;
+
+        let (row, column) = match direction {
+            Direction::Left => (row, column.saturating_sub(1)),
+            Direction::Right => {
+                (row, (column + 1).min(row_length(&keys, row) - 1))
+            }
+            Direction::Up | Direction::Down => {
+                let target = match direction {
+                    Direction::Up => row.saturating_sub(1),
+                    _ => (row + 1).min(ROWS.len()),
+                };
+
+                // Keep the closest key horizontally
+                let center = keys
+                    .iter()
+                    .find(|key| key.row == row && key.column == column)
+                    .map(|key| key.bounds.center().x)
+                    .unwrap_or(0.0);
+
+                let closest = keys
+                    .iter()
+                    .filter(|key| key.row == target)
+                    .min_by(|a, b| {
+                        let a = (a.bounds.center().x - center).abs();
+                        let b = (b.bounds.center().x - center).abs();
+
+                        a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                    })
+                    .map(|key| key.column)
+                    .unwrap_or(0);
+
+                (target, closest)
+            }
+        };
+
+        self.state.selected = (row, column);
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for VirtualKeyboard<'a, Message>
+where
+    Renderer: self::Renderer,
+{
+    fn node(&self, _renderer: &Renderer) -> Node {
+        Node::new(self.style)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        messages: &mut Vec<Message>,
+    ) {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::Input {
+                button: mouse::Button::Left,
+                state: ButtonState::Pressed,
+            }) => {
+                let clicked = keys(bounds)
+                    .into_iter()
+                    .find(|key| key.bounds.contains(cursor_position));
+
+                if let Some(clicked) = clicked {
+                    self.state.selected = (clicked.row, clicked.column);
+                    self.press(clicked.key, messages);
+                }
+            }
+            Event::Keyboard(keyboard::Event::Input {
+                key_code,
+                state: ButtonState::Pressed,
+                ..
+            }) => match key_code {
+                keyboard::KeyCode::Left => self.select(Direction::Left, bounds),
+                keyboard::KeyCode::Right => {
+                    self.select(Direction::Right, bounds)
+                }
+                keyboard::KeyCode::Up => self.select(Direction::Up, bounds),
+                keyboard::KeyCode::Down => self.select(Direction::Down, bounds),
+                keyboard::KeyCode::Return | keyboard::KeyCode::NumpadEnter => {
+                    if let Some(key) = self.selected(bounds) {
+                        self.press(key, messages);
+                    }
+                }
+                _ => {}
+            },
+            Event::Gamepad {
+                event: gamepad::Event::ButtonPressed(button),
+                ..
+            } => match button {
+                gamepad::Button::DPadLeft => {
+                    self.select(Direction::Left, bounds)
+                }
+                gamepad::Button::DPadRight => {
+                    self.select(Direction::Right, bounds)
+                }
+                gamepad::Button::DPadUp => self.select(Direction::Up, bounds),
+                gamepad::Button::DPadDown => {
+                    self.select(Direction::Down, bounds)
+                }
+                gamepad::Button::South => {
+                    if let Some(key) = self.selected(bounds) {
+                        self.press(key, messages);
+                    }
+                }
+                gamepad::Button::West => self.press(Key::Backspace, messages),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> MouseCursor {
+        let keys = keys(layout.bounds());
+        let (row, column) = self.state.selected;
+
+        let selected = keys
+            .iter()
+            .position(|key| key.row == row && key.column == column)
+            .unwrap_or(0);
+
+        let keys: Vec<(Key, Rectangle<f32>)> =
+            keys.into_iter().map(|key| (key.key, key.bounds)).collect();
+
+        renderer.draw(
+            cursor_position,
+            layout.bounds(),
+            &keys,
+            selected,
+            self.state.is_shifted,
+        )
+    }
+
+    fn hash(&self, state: &mut Hasher) {
+        self.style.hash(state);
+    }
+}
+
+/// A key of a [`VirtualKeyboard`].
+///
+/// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A key that enters a character.
+    ///
+    /// The character is lowercase. It is entered in uppercase when the
+    /// [`VirtualKeyboard`] is shifted.
+    ///
+    /// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+    Character(char),
+
+    /// The key that toggles uppercase characters.
+    Shift,
+
+    /// The space bar.
+    Space,
+
+    /// The key that enters [`BACKSPACE`].
+    ///
+    /// [`BACKSPACE`]: constant.BACKSPACE.html
+    Backspace,
+
+    /// The key that enters [`ENTER`].
+    ///
+    /// [`ENTER`]: constant.ENTER.html
+    Enter,
+}
+
+/// The local state of a [`VirtualKeyboard`].
+///
+/// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    selected: (usize, usize),
+    is_shifted: bool,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    ///
+    /// [`State`]: struct.State.html
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the associated [`VirtualKeyboard`] enters uppercase
+    /// characters or not.
+    ///
+    /// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+    pub fn is_shifted(&self) -> bool {
+        self.is_shifted
+    }
+}
+
+/// The renderer of a [`VirtualKeyboard`].
+///
+/// Your [`core::Renderer`] will need to implement this trait before being
+/// able to use a [`VirtualKeyboard`] in your user interface.
+///
+/// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+/// [`core::Renderer`]: ../../core/trait.Renderer.html
+pub trait Renderer {
+    /// Draws a [`VirtualKeyboard`].
+    ///
+    /// It receives:
+    ///   * the current cursor position
+    ///   * the bounds of the [`VirtualKeyboard`]
+    ///   * the keys of the [`VirtualKeyboard`] and their bounds
+    ///   * the index of the selected key
+    ///   * whether the [`VirtualKeyboard`] is shifted or not
+    ///
+    /// [`VirtualKeyboard`]: struct.VirtualKeyboard.html
+    fn draw(
+        &mut self,
+        cursor_position: Point,
+        bounds: Rectangle<f32>,
+        keys: &[(Key, Rectangle<f32>)],
+        selected: usize,
+        is_shifted: bool,
+    ) -> MouseCursor;
+}
+
+impl<'a, Message, Renderer> From<VirtualKeyboard<'a, Message>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+    Message: 'static,
+{
+    fn from(
+        virtual_keyboard: VirtualKeyboard<'a, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(virtual_keyboard)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+struct PlacedKey {
+    key: Key,
+    row: usize,
+    column: usize,
+    bounds: Rectangle<f32>,
+}
+
+fn extent(keys: usize) -> f32 {
+    keys as f32 * (KEY_SIZE + SPACING) - SPACING
+}
+
+// Lays out the rows of keys, centered horizontally
+fn keys(bounds: Rectangle<f32>) -> Vec<PlacedKey> {
+    let mut keys = Vec::new();
+
+    for (row, characters) in ROWS.iter().enumerate() {
+        let length = characters.chars().count();
+        let offset = (extent(COLUMNS) - extent(length)) / 2.0;
+
+        for (column, character) in characters.chars().enumerate() {
+            keys.push(PlacedKey {
+                key: Key::Character(character),
+                row,
+                column,
+                bounds: Rectangle {
+                    x: bounds.x + offset + column as f32 * (KEY_SIZE + SPACING),
+                    y: bounds.y + row as f32 * (KEY_SIZE + SPACING),
+                    width: KEY_SIZE,
+                    height: KEY_SIZE,
+                },
+            });
+        }
+    }
+
+    let mut x = bounds.x;
+    let y = bounds.y + ROWS.len() as f32 * (KEY_SIZE + SPACING);
+
+    for (column, (key, width)) in SPECIAL_ROW.iter().enumerate() {
+        keys.push(PlacedKey {
+            key: *key,
+            row: ROWS.len(),
+            column,
+            bounds: Rectangle {
+                x,
+                y,
+                width: extent(*width),
+                height: KEY_SIZE,
+            },
+        });
+
+        x += extent(*width) + SPACING;
+    }
+
+    keys
+}
+
+fn row_length(keys: &[PlacedKey], row: usize) -> usize {
+    keys.iter().filter(|key| key.row == row).count()
+}
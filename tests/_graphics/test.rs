@@ -1,8 +1,10 @@
 use coffee::graphics::{Canvas, Gpu, Image};
 
 mod mesh;
+mod tiling;
 
 use mesh::Mesh;
+use tiling::Tiling;
 
 use std::fs::File;
 use std::io::Read;
@@ -11,16 +13,18 @@ use std::path::PathBuf;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Test {
     Mesh,
+    Tiling,
 }
 
 impl Test {
     pub fn all() -> Vec<Test> {
-        vec![Test::Mesh]
+        vec![Test::Mesh, Test::Tiling]
     }
 
     pub fn run(&self, gpu: &mut Gpu) -> Drawing {
         let draw = match self {
             Test::Mesh => Mesh::draw(),
+            Test::Tiling => Tiling::draw(),
         };
 
         Drawing {
@@ -36,6 +40,7 @@ impl std::string::ToString for Test {
     fn to_string(&self) -> String {
         let name = match self {
             Test::Mesh => "mesh",
+            Test::Tiling => "tiling",
         };
 
         String::from(name)
@@ -0,0 +1,57 @@
+use coffee::graphics::{Canvas, Color, Image, Point, Quad, Rectangle};
+use coffee::load::Task;
+
+pub struct Tiling {}
+
+impl Tiling {
+    pub fn draw() -> Task<Canvas> {
+        Task::using_gpu(|gpu| {
+            let mut canvas =
+                Canvas::new(gpu, 300, 300).expect("Canvas creation");
+
+            // An image wider than the maximum texture size is split into two
+            // tiles of the same width
+            let width = u32::from(gpu.limits().max_texture_size) + 2;
+            let seam = (width + 1) / 2;
+
+            // Stripes are laid out from the seam, so the drawing does not
+            // depend on the limits of the graphics adapter
+            let stripes = image::RgbaImage::from_fn(width, 4, |x, y| {
+                let stripe = (i64::from(x) - i64::from(seam)).div_euclid(10);
+
+                let color = match (stripe % 2 == 0, y < 2) {
+                    (true, true) => Color::RED,
+                    (false, true) => Color::BLUE,
+                    (true, false) => Color::GREEN,
+                    (false, false) => Color::WHITE,
+                };
+
+                image::Rgba(color.to_rgba())
+            });
+
+            let image = Image::from_image(
+                gpu,
+                &image::DynamicImage::ImageRgba8(stripes),
+            )
+            .expect("Image creation");
+
+            // Draw the pixels around the seam, so both tiles are visible
+            image.draw(
+                Quad {
+                    source: Rectangle {
+                        x: (seam - 150) as f32 / width as f32,
+                        y: 0.0,
+                        width: 300.0 / width as f32,
+                        height: 1.0,
+                    },
+                    position: Point::new(0.0, 0.0),
+                    size: (300.0, 300.0),
+                    ..Quad::default()
+                },
+                &mut canvas.as_target(gpu),
+            );
+
+            Ok(canvas)
+        })
+    }
+}
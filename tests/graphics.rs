@@ -21,8 +21,9 @@ fn graphics() -> Result<()> {
         title: String::from("Graphics integration tests - Coffee"),
         size: (1280, 1024),
         resizable: false,
-        fullscreen: false,
+        fullscreen: None,
         maximized: false,
+        ..WindowSettings::default()
     })
 }
 